@@ -199,6 +199,7 @@ def main():
     print(f"\n=== Release version: {release_version} ===")
 
     manifest = {
+        "manifest_schema_version": 1,
         "release_version": release_version,
         "components": manifest_components,
     }
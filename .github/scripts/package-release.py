@@ -499,6 +499,7 @@ def main():
 
     # manifest.json 생성
     manifest = {
+        "manifest_schema_version": 1,
         "release_version": release_version,
         "tag": release_tag,
         "components": manifest_components,
@@ -394,12 +394,18 @@ impl DaemonClient {
         self.post_json_long("/api/install/run", &body).await
     }
 
-    /// POST /api/install/component/{key} — 특정 컴포넌트 설치
-    pub async fn install_component(&self, key: &str) -> anyhow::Result<Value> {
-        self.post_json_long(
-            &format!("/api/install/component/{}", key),
-            &serde_json::json!({}),
-        ).await
+    /// POST /api/install/component/{key} — 특정 컴포넌트 설치 (`version` 지정 시 해당 버전)
+    pub async fn install_component(&self, key: &str, version: Option<&str>) -> anyhow::Result<Value> {
+        let body = match version {
+            Some(v) => serde_json::json!({ "version": v }),
+            None => serde_json::json!({}),
+        };
+        self.post_json_long(&format!("/api/install/component/{}", key), &body).await
+    }
+
+    /// POST /api/install/component/{key}/reinstall — 손상된 컴포넌트 재설치
+    pub async fn reinstall_component(&self, key: &str) -> anyhow::Result<Value> {
+        self.post_json_long(&format!("/api/install/component/{}/reinstall", key), &serde_json::json!({})).await
     }
 
     /// GET /api/install/progress — 설치 진행 상태 조회
@@ -407,6 +413,11 @@ impl DaemonClient {
         self.get_json("/api/install/progress").await
     }
 
+    /// POST /api/install/cancel — 진행 중인 설치 취소
+    pub async fn cancel_install(&self) -> anyhow::Result<Value> {
+        self.post_json("/api/install/cancel", &serde_json::json!({})).await
+    }
+
     // ============ Extensions ============
 
     /// GET /api/extensions — 설치된 익스텐션 목록
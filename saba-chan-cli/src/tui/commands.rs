@@ -13,6 +13,7 @@ use crate::client::DaemonClient;
 use crate::config;
 use crate::module_registry::{ModuleRegistry, LIFECYCLE_COMMANDS};
 use crate::process;
+use saba_chan_updater_lib::{ApplyExitCode, UpdateConfig, UpdateManager, VerifyIssue};
 
 // ═══════════════════════════════════════════════════════
 // 커맨드 제출 (Enter 키)
@@ -140,7 +141,7 @@ fn get_subcommands_for(first: &str) -> Vec<&'static str> {
         "daemon" => vec!["start", "stop", "status", "restart"],
         "bot" => vec!["start", "stop", "status", "token", "prefix", "mode", "relay", "node-token"],
         "config" => vec!["show", "set", "get", "reset", "gui", "system-language"],
-        "update" => vec!["check", "status", "download", "apply", "config", "set", "install", "launch-apply"],
+        "update" => vec!["check", "status", "pending", "verify", "download", "apply", "config", "set", "install", "reinstall", "repair", "launch-apply"],
         "migration" => vec!["scan"],
         _ => vec![],
     }
@@ -255,7 +256,7 @@ pub fn autocomplete(app: &mut App) {
                 "bot mode", "bot relay", "bot node-token",
                 "config show", "config set", "config get", "config reset", "config system-language",
                 "update check", "update status", "update download", "update apply",
-                "update launch-apply",
+                "update reinstall", "update launch-apply",
                 "migration scan",
             ];
             let prefix = app.input.trim();
@@ -290,7 +291,7 @@ fn dispatch_sync(app: &mut App, lower: &[&str], orig: &[&str]) -> Option<Vec<Out
             Out::Text("  extension list|enable|disable|install|remove|manifest|rescan".into()),
         ]),
         Some("update") if lower.len() == 1 => Some(vec![
-            Out::Text("  update check|status|download|apply|config|set|install".into()),
+            Out::Text("  update check|status|pending|verify|download|apply|config|set|install".into()),
         ]),
         Some("daemon") if lower.len() == 1 => Some(vec![
             Out::Text("  daemon start|stop|status|restart".into()),
@@ -742,7 +743,7 @@ fn cmd_help(app: &App) -> Vec<Out> {
         Out::Text("  extension [list|enable|disable|install|remove|registry|rescan]".into()),
         Out::Text("  daemon    [start|stop|status|restart]".into()),
         Out::Text("  bot       [start|stop|status|token|prefix|mode|relay|node-token]".into()),
-        Out::Text("  update    [check|status|download|apply|config|install|launch-apply]".into()),
+        Out::Text("  update    [check|status|download|apply|config|install|reinstall|launch-apply]".into()),
         Out::Text("  config    [show|set|get|reset|gui|system-language]".into()),
         Out::Text("  migration [scan] <directory>".into()),
         Out::Text("  menu     — Interactive menu mode (F2)".into()),
@@ -1068,7 +1069,7 @@ async fn exec_update(client: &DaemonClient, args: &[&str]) -> Vec<Out> {
                 let comps = v["components"].as_array();
                 if let Some(comps) = comps {
                     let any = comps.iter().any(|c| c["update_available"].as_bool().unwrap_or(false));
-                    if any {
+                    let mut lines = if any {
                         let mut lines = vec![Out::Ok("Updates available:".into())];
                         for c in comps {
                             let marker = if c["update_available"].as_bool().unwrap_or(false) { "⬆" } else { "✓" };
@@ -1076,7 +1077,13 @@ async fn exec_update(client: &DaemonClient, args: &[&str]) -> Vec<Out> {
                                 marker, c["component"].as_str().unwrap_or("?"), c["current_version"].as_str().unwrap_or("?"), c["latest_version"].as_str().unwrap_or("?"))));
                         }
                         lines
-                    } else { vec![Out::Ok("All up to date.".into())] }
+                    } else { vec![Out::Ok("All up to date.".into())] };
+                    for c in comps {
+                        if let Some(w) = c["warning"].as_str() {
+                            lines.push(Out::Info(format!("  ⚠ {}: {}", c["component"].as_str().unwrap_or("?"), w)));
+                        }
+                    }
+                    lines
                 } else { vec![Out::Ok(format!("{}", v))] }
             }
             Err(e) => vec![Out::Err(format!("✗ {}", e))],
@@ -1091,10 +1098,22 @@ async fn exec_update(client: &DaemonClient, args: &[&str]) -> Vec<Out> {
             }
             Err(e) => vec![Out::Err(format!("✗ {}", e))],
         },
+        Some("pending") => exec_update_pending(args.contains(&"--json")),
+        Some("verify") => exec_update_verify(args.contains(&"--json")),
         Some("download") => match client.download_updates().await {
             Ok(v) => vec![Out::Ok(format!("✓ {}", v.get("message").and_then(|m| m.as_str()).unwrap_or("Download initiated")))],
             Err(e) => vec![Out::Err(format!("✗ {}", e))],
         },
+        // --json: 데몬 응답 그대로 출력 (exit_code: 0/10/20/30)
+        // 요청 실패는 원인이 네트워크(데몬 연결 실패 등)일 때만 30, 그 외는 20
+        Some("apply") if args.contains(&"--json") => match client.apply_updates().await {
+            Ok(v) => vec![Out::Text(v.to_string())],
+            Err(e) => vec![Out::Text(serde_json::json!({
+                "ok": false,
+                "error": e.to_string(),
+                "exit_code": ApplyExitCode::from_error(&e).code(),
+            }).to_string())],
+        },
         Some("apply") => match client.apply_updates().await {
             Ok(v) => vec![Out::Ok(format!("✓ {}", v.get("message").and_then(|m| m.as_str()).unwrap_or("Applied")))],
             Err(e) => vec![Out::Err(format!("✗ {}", e))],
@@ -1122,11 +1141,27 @@ async fn exec_update(client: &DaemonClient, args: &[&str]) -> Vec<Out> {
             }
         }
         Some("install") if args.len() >= 2 => {
-            match client.install_component(args[1]).await {
+            // `install <component>@<version>` — 지정 버전으로 설치/롤백
+            let (key, version) = match args[1].split_once('@') {
+                Some((key, version)) => (key, Some(version)),
+                None => (args[1], None),
+            };
+            match client.install_component(key, version).await {
+                Ok(v) if v.get("ok").and_then(|o| o.as_bool()) == Some(false) => vec![Out::Err(format!(
+                    "✗ {}", v.get("error").and_then(|m| m.as_str()).unwrap_or("Install failed")
+                ))],
                 Ok(v) => vec![Out::Ok(format!("✓ {}", v.get("message").and_then(|m| m.as_str()).unwrap_or("Installed")))],
                 Err(e) => vec![Out::Err(format!("✗ {}", e))],
             }
         }
+        // `reinstall <component>` (별칭 `repair`) — 설치되어 있어도 백업 후 새로 설치
+        Some("reinstall") | Some("repair") if args.len() >= 2 => match client.reinstall_component(args[1]).await {
+            Ok(v) if v.get("ok").and_then(|o| o.as_bool()) == Some(false) => vec![Out::Err(format!(
+                "✗ {}", v.get("error").and_then(|m| m.as_str()).unwrap_or("Reinstall failed")
+            ))],
+            Ok(v) => vec![Out::Ok(format!("✓ {}", v.get("message").and_then(|m| m.as_str()).unwrap_or("Reinstalled")))],
+            Err(e) => vec![Out::Err(format!("✗ {}", e))],
+        },
         Some("install") => match client.run_install(None).await {
             Ok(v) => vec![Out::Ok(format!("✓ {}", v.get("message").and_then(|m| m.as_str()).unwrap_or("Install initiated")))],
             Err(e) => vec![Out::Err(format!("✗ {}", e))],
@@ -1163,8 +1198,74 @@ async fn exec_update(client: &DaemonClient, args: &[&str]) -> Vec<Out> {
                 None => vec![Out::Err("✗ Updater executable not found. Searched in target/release and target/debug.".into())],
             }
         }
-        _ => vec![Out::Text("  update check|status|download|apply|config|set|install|launch-apply [targets...]".into())],
+        _ => vec![Out::Text("  update check|status|pending [--json]|verify [--json]|download|apply [--json]|config|set|install [<component>[@<version>]]|reinstall <component>|launch-apply [targets...]".into())],
+    }
+}
+
+/// installed-manifest.json과 실제 설치 파일의 불일치 점검
+fn exec_update_verify(json_mode: bool) -> Vec<Out> {
+    let modules_dir = saba_chan_updater_lib::constants::resolve_modules_dir();
+    let mgr = UpdateManager::new(UpdateConfig::default(), &modules_dir.to_string_lossy());
+    let issues = mgr.verify_installed();
+
+    if json_mode {
+        let items: Vec<Value> = issues.iter()
+            .map(|(component, issue)| serde_json::json!({ "component": component, "issue": issue }))
+            .collect();
+        let out = serde_json::json!({ "ok": issues.is_empty(), "issues": items });
+        return vec![Out::Text(out.to_string())];
+    }
+
+    if issues.is_empty() {
+        return vec![Out::Ok("✓ Installed manifest matches files on disk.".into())];
+    }
+    let mut lines = vec![Out::Err(format!("✗ {} component(s) out of sync:", issues.len()))];
+    for (component, issue) in &issues {
+        let detail = match issue {
+            VerifyIssue::Missing => "missing on disk".to_string(),
+            VerifyIssue::VersionMismatch { recorded, detected } =>
+                format!("recorded {} but found {}", recorded, detected),
+            VerifyIssue::UnknownComponent => "unknown component key".to_string(),
+        };
+        lines.push(Out::Text(format!("  {:<20} {}", component, detail)));
     }
+    lines
+}
+
+/// 다운로드되었지만 아직 적용되지 않은 업데이트 목록 (staging의 pending 매니페스트, 적용하지 않음)
+fn exec_update_pending(json_mode: bool) -> Vec<Out> {
+    let modules_dir = saba_chan_updater_lib::constants::resolve_modules_dir();
+    let mut mgr = UpdateManager::new(UpdateConfig::default(), &modules_dir.to_string_lossy());
+    // pending 매니페스트가 없으면 대기 중인 업데이트가 없는 것
+    let pending = match mgr.load_pending_manifest() {
+        Ok(_) => mgr.pending_updates(),
+        Err(_) => Vec::new(),
+    };
+    let requires_restart = pending.iter().any(|p| p.restart_required);
+
+    if json_mode {
+        let out = serde_json::json!({
+            "ok": true,
+            "pending": pending,
+            "requires_restart": requires_restart,
+        });
+        return vec![Out::Text(serde_json::to_string(&out).unwrap_or_else(|_| "{}".to_string()))];
+    }
+
+    if pending.is_empty() {
+        return vec![Out::Ok("No pending updates.".into())];
+    }
+    let mut lines = vec![Out::Ok(format!("Pending updates ({}):", pending.len()))];
+    for p in &pending {
+        let restart = if p.restart_required { "  [restart]" } else { "" };
+        lines.push(Out::Text(format!("  {:<20} {:<10} {}{}",
+            p.component, p.version.as_deref().unwrap_or("?"), p.staged_path, restart)));
+    }
+    if requires_restart {
+        lines.push(Out::Blank);
+        lines.push(Out::Info("Applying these updates restarts Saba-chan (use: update apply).".into()));
+    }
+    lines
 }
 
 // ═══ 모듈 단축 명령어 (palworld start, 팰월드 시작 등) ═══
@@ -1014,9 +1014,19 @@ async function preloadLightData() {
 let heartbeatClientId = null;
 let heartbeatTimer = null;
 
+// 설치(userData 경로)별 고정 키 — 크래시 후 재접속 시 데몬이 이전 등록을 대체하도록 함
+function getGuiClientKey() {
+    const hash = require('crypto').createHash('sha256').update(app.getPath('userData')).digest('hex');
+    return `gui-${hash.slice(0, 32)}`;
+}
+
 async function registerWithDaemon() {
     try {
-        const res = await axios.post(`${IPC_BASE}/api/client/register`, { kind: 'gui' }, { timeout: 3000 });
+        const res = await axios.post(
+            `${IPC_BASE}/api/client/register`,
+            { kind: 'gui', client_key: getGuiClientKey() },
+            { timeout: 3000 },
+        );
         heartbeatClientId = res.data.client_id;
         console.log(`[Heartbeat] Registered with daemon as client: ${heartbeatClientId}`);
         return true;
@@ -1977,9 +1987,21 @@ ipcMain.handle('console:toggleAlwaysOnTop', async (_event, instanceId, pinned) =
     return { ok: false };
 });
 
+// 마지막 /api/modules 응답 — ETag가 같으면(304) 재사용
+let moduleListCache = null;
+
 ipcMain.handle('module:list', async () => {
     try {
-        const response = await axios.get(`${IPC_BASE}/api/modules`);
+        const headers = moduleListCache ? { 'If-None-Match': moduleListCache.etag } : {};
+        const response = await axios.get(`${IPC_BASE}/api/modules`, {
+            headers,
+            validateStatus: (status) => (status >= 200 && status < 300) || status === 304,
+        });
+        if (response.status === 304 && moduleListCache) {
+            return moduleListCache.data;
+        }
+        const etag = response.headers.etag;
+        moduleListCache = etag ? { etag, data: response.data } : null;
         return response.data;
     } catch (error) {
         if (error.response) {
@@ -2000,6 +2022,7 @@ ipcMain.handle('module:refresh', async () => {
     try {
         sendStatus('modules', t('modules.refreshing'));
         const response = await axios.post(`${IPC_BASE}/api/modules/refresh`);
+        moduleListCache = null;
         sendStatus('modules', t('modules.refresh_complete'));
         return response.data;
     } catch (error) {
@@ -2694,10 +2717,13 @@ ipcMain.handle('updater:status', async () => {
 });
 
 // 선택 컴포넌트 다운로드 — 데몬 API `/api/updates/download`
-// body: { components: ["module-minecraft", "saba-core"] } (비어있으면 전체)
-ipcMain.handle('updater:download', async (_event, components) => {
+// body: { components: ["module-minecraft", "saba-core"] } (비어있으면 전체, exclude로 일부 제외)
+ipcMain.handle('updater:download', async (_event, components, exclude) => {
     try {
-        const body = { components: Array.isArray(components) ? components : [] };
+        const body = {
+            components: Array.isArray(components) ? components : [],
+            exclude: Array.isArray(exclude) ? exclude : [],
+        };
         const response = await axios.post(`${IPC_BASE}/api/updates/download`, body, { timeout: 600000 });
         return response.data;
     } catch (err) {
@@ -60,7 +60,7 @@ contextBridge.exposeInMainWorld('api', {
     // Updater — 데몬 HTTP API를 통한 업데이트 관리
     updaterCheck: () => ipcRenderer.invoke('updater:check'),
     updaterStatus: () => ipcRenderer.invoke('updater:status'),
-    updaterDownload: (components) => ipcRenderer.invoke('updater:download', components),
+    updaterDownload: (components, exclude) => ipcRenderer.invoke('updater:download', components, exclude),
     updaterDownloadProgress: () => ipcRenderer.invoke('updater:downloadProgress'),
     updaterApply: (components) => ipcRenderer.invoke('updater:apply', components),
     updaterLaunchApply: (targets) => ipcRenderer.invoke('updater:launchApply', targets),
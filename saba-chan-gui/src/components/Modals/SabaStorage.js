@@ -3,7 +3,7 @@ import { useCallback, useEffect, useRef, useState } from 'react';
 import { useTranslation } from 'react-i18next';
 import { useExtensions } from '../../contexts/ExtensionContext';
 import { Icon } from '../Icon';
-import { SabaSpinner, SabaToggle } from '../ui/SabaUI';
+import { SabaCheckbox, SabaSpinner, SabaToggle } from '../ui/SabaUI';
 import { QuestionModal } from './Modals';
 
 // ─────────────────────────────────────────────────────────────
@@ -148,6 +148,8 @@ function ComponentsTab({ devMode }) {
     const [checking, setChecking] = useState(false);
     const [busyKeys, setBusyKeys] = useState(new Set());
     const [busyAll, setBusyAll] = useState(false);
+    // '모두 업데이트'에서 제외할 컴포넌트 키 (체크 해제한 카드)
+    const [excludedKeys, setExcludedKeys] = useState(new Set());
     const [error, setError] = useState(null);
     const [message, setMessage] = useState(null);
     const [confirmRestart, setConfirmRestart] = useState(false);
@@ -362,8 +364,19 @@ function ComponentsTab({ devMode }) {
         [refreshStatus, markBusy, clearBusy],
     );
 
+    const toggleExcluded = useCallback(
+        (key, included) =>
+            setExcludedKeys((prev) => {
+                const n = new Set(prev);
+                if (included) n.delete(key);
+                else n.add(key);
+                return n;
+            }),
+        [],
+    );
+
     const executeUpdateAll = useCallback(async () => {
-        const updatable = components.filter((c) => c.update_available);
+        const updatable = components.filter((c) => c.update_available && !excludedKeys.has(c.key));
         if (updatable.length === 0) return;
         let hadError = false;
         setBusyAll(true);
@@ -463,10 +476,10 @@ function ComponentsTab({ devMode }) {
         if (daemonApplied.length > 0) setMessage(t('saba_storage.apply_completed', { names: daemonApplied.join(', ') }));
         else if (!hadError) setMessage(t('saba_storage.update_done'));
         setBusyAll(false);
-    }, [components, refreshStatus, markBusy, clearBusy, startProgressPolling, stopProgressPolling]);
+    }, [components, excludedKeys, refreshStatus, markBusy, clearBusy, startProgressPolling, stopProgressPolling]);
 
     const handleUpdateAll = useCallback(() => {
-        const updatable = components.filter((c) => c.update_available);
+        const updatable = components.filter((c) => c.update_available && !excludedKeys.has(c.key));
         if (updatable.length === 0) return;
         const hasNeedsUpdater = updatable.some((c) => c.key === 'gui' || c.key === 'saba-core');
         if (hasNeedsUpdater) {
@@ -475,7 +488,7 @@ function ComponentsTab({ devMode }) {
         } else {
             executeUpdateAll();
         }
-    }, [components, executeUpdateAll]);
+    }, [components, excludedKeys, executeUpdateAll]);
 
     const handleConfirmRestart = useCallback(() => {
         setConfirmRestart(false);
@@ -548,6 +561,7 @@ function ComponentsTab({ devMode }) {
     }, []);
 
     const updatable = components.filter((c) => c.update_available);
+    const selectedCount = updatable.filter((c) => !excludedKeys.has(c.key)).length;
     const allUpToDate = components.length > 0 && updatable.length === 0;
     const anyBusy = checking || busyAll || busyKeys.size > 0;
 
@@ -597,7 +611,7 @@ function ComponentsTab({ devMode }) {
                         {updatable.length > 0 && (
                             <button
                                 className="ss-icon-btn accent"
-                                disabled={anyBusy}
+                                disabled={anyBusy || selectedCount === 0}
                                 onClick={handleUpdateAll}
                                 title={t('saba_storage.update_all', '모두 업데이트')}
                             >
@@ -635,6 +649,15 @@ function ComponentsTab({ devMode }) {
                                     <span className="ss-card-version">v{c.current_version}</span>
                                 </div>
                                 <div className="ss-card-actions">
+                                    {c.update_available && updatable.length > 1 && (
+                                        <SabaCheckbox
+                                            size="sm"
+                                            checked={!excludedKeys.has(c.key)}
+                                            onChange={(checked) => toggleExcluded(c.key, checked)}
+                                            disabled={anyBusy}
+                                            title={t('saba_storage.include_in_update_all', '모두 업데이트에 포함')}
+                                        />
+                                    )}
                                     {c.update_available && !c.downloaded && (
                                         <>
                                             {isDownloading ? (
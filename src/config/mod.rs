@@ -1,6 +1,9 @@
 //! 글로벌 설정 — 컴파일 타임 내장 기본값
 //!
 //! 값이 사실상 고정이므로 코드에 직접 내장합니다.
+//! 하트비트/워치독 타이밍만 `SABA_HEARTBEAT_*` 환경 변수로 조정할 수 있습니다.
+
+use anyhow::bail;
 
 /// 글로벌 설정 (하드코딩 기본값)
 ///
@@ -10,6 +13,7 @@
 pub struct GlobalConfig {
     pub ipc_socket: String,
     pub log_buffer_size: usize,
+    pub heartbeat: HeartbeatConfig,
 }
 
 impl Default for GlobalConfig {
@@ -17,14 +21,123 @@ impl Default for GlobalConfig {
         Self {
             ipc_socket: "./ipc.sock".to_string(),
             log_buffer_size: 10_000,
+            heartbeat: HeartbeatConfig::default(),
         }
     }
 }
 
 impl GlobalConfig {
-    /// 기본 설정을 반환합니다 (항상 성공).
+    /// 기본 설정에 환경 변수 오버라이드를 적용합니다.
+    ///
+    /// 오버라이드 값이 숫자가 아니거나 허용 범위를 벗어나면 에러를 반환합니다.
     pub fn load() -> anyhow::Result<Self> {
-        Ok(Self::default())
+        Ok(Self {
+            heartbeat: HeartbeatConfig::from_env_with(|key| std::env::var(key).ok())?,
+            ..Self::default()
+        })
+    }
+}
+
+/// 초 단위 타이밍 값의 상한 (1일)
+const MAX_TIMING_SECS: u64 = 86_400;
+/// 렌더러 재기동 시도 횟수 상한
+const MAX_RESTART_ATTEMPTS_LIMIT: u32 = 10;
+
+/// 하트비트 reaper / 렌더러 워치독 타이밍 설정
+///
+/// 느린 머신에서 GUI 기동이 늦어 워치독이 불필요하게 데몬을 종료하지 않도록
+/// 환경 변수로 조정할 수 있습니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeartbeatConfig {
+    /// 만료 클라이언트 정리 주기 (초) — `SABA_HEARTBEAT_REAPER_INTERVAL_SECS`
+    pub reaper_interval_secs: u64,
+    /// 마지막 하트비트 이후 클라이언트를 만료 처리하기까지의 시간 (초) — `SABA_HEARTBEAT_TIMEOUT_SECS`
+    pub client_timeout_secs: u64,
+    /// 워치독 점검 주기 (초) — `SABA_WATCHDOG_CHECK_INTERVAL_SECS`
+    pub watchdog_check_interval_secs: u64,
+    /// 렌더러가 모두 끊긴 뒤 재기동 전 자연 재접속을 기다리는 시간 (초) — `SABA_WATCHDOG_GRACE_SECS`
+    pub grace_period_secs: u64,
+    /// 렌더러 재기동 후 재접속을 기다리는 시간 (초) — `SABA_WATCHDOG_RESTART_WAIT_SECS`
+    pub restart_wait_secs: u64,
+    /// 데몬 자체 종료 전 렌더러 재기동 시도 횟수 — `SABA_WATCHDOG_MAX_RESTARTS`
+    pub max_restart_attempts: u32,
+}
+
+impl Default for HeartbeatConfig {
+    fn default() -> Self {
+        Self {
+            reaper_interval_secs: 30,
+            client_timeout_secs: 90,
+            watchdog_check_interval_secs: 5,
+            grace_period_secs: 15,
+            restart_wait_secs: 60,
+            max_restart_attempts: 2,
+        }
+    }
+}
+
+impl HeartbeatConfig {
+    /// 기본값에 `lookup`으로 읽은 오버라이드를 적용한 뒤 검증합니다.
+    pub fn from_env_with(lookup: impl Fn(&str) -> Option<String>) -> anyhow::Result<Self> {
+        let mut cfg = Self::default();
+        let secs_fields: [(&str, &mut u64); 5] = [
+            ("SABA_HEARTBEAT_REAPER_INTERVAL_SECS", &mut cfg.reaper_interval_secs),
+            ("SABA_HEARTBEAT_TIMEOUT_SECS", &mut cfg.client_timeout_secs),
+            ("SABA_WATCHDOG_CHECK_INTERVAL_SECS", &mut cfg.watchdog_check_interval_secs),
+            ("SABA_WATCHDOG_GRACE_SECS", &mut cfg.grace_period_secs),
+            ("SABA_WATCHDOG_RESTART_WAIT_SECS", &mut cfg.restart_wait_secs),
+        ];
+        for (key, field) in secs_fields {
+            if let Some(raw) = lookup(key) {
+                *field = parse_override(key, &raw)?;
+            }
+        }
+        if let Some(raw) = lookup("SABA_WATCHDOG_MAX_RESTARTS") {
+            cfg.max_restart_attempts = parse_override("SABA_WATCHDOG_MAX_RESTARTS", &raw)?;
+        }
+        cfg.validate()?;
+        Ok(cfg)
+    }
+
+    /// 값 범위 검증 — 0초 주기나 비정상적으로 긴 대기 시간을 거부합니다.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let secs_fields = [
+            ("reaper_interval_secs", self.reaper_interval_secs),
+            ("client_timeout_secs", self.client_timeout_secs),
+            ("watchdog_check_interval_secs", self.watchdog_check_interval_secs),
+            ("grace_period_secs", self.grace_period_secs),
+            ("restart_wait_secs", self.restart_wait_secs),
+        ];
+        for (name, value) in secs_fields {
+            if value == 0 || value > MAX_TIMING_SECS {
+                bail!("heartbeat.{} must be between 1 and {} seconds (got {})", name, MAX_TIMING_SECS, value);
+            }
+        }
+        if self.max_restart_attempts > MAX_RESTART_ATTEMPTS_LIMIT {
+            bail!(
+                "heartbeat.max_restart_attempts must be at most {} (got {})",
+                MAX_RESTART_ATTEMPTS_LIMIT, self.max_restart_attempts
+            );
+        }
+        // reaper가 한 주기 안에 살아 있는 클라이언트를 만료시키지 않도록
+        if self.client_timeout_secs < self.reaper_interval_secs {
+            bail!(
+                "heartbeat.client_timeout_secs ({}) must not be shorter than reaper_interval_secs ({})",
+                self.client_timeout_secs, self.reaper_interval_secs
+            );
+        }
+        Ok(())
+    }
+
+    pub fn client_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.client_timeout_secs)
+    }
+}
+
+fn parse_override<T: std::str::FromStr>(key: &str, raw: &str) -> anyhow::Result<T> {
+    match raw.trim().parse() {
+        Ok(v) => Ok(v),
+        Err(_) => bail!("{} must be a non-negative integer (got '{}')", key, raw),
     }
 }
 
@@ -37,5 +150,40 @@ mod tests {
         let cfg = GlobalConfig::default();
         assert_eq!(cfg.ipc_socket, "./ipc.sock");
         assert_eq!(cfg.log_buffer_size, 10_000);
+        assert_eq!(cfg.heartbeat, HeartbeatConfig::default());
+    }
+
+    #[test]
+    fn test_heartbeat_defaults_are_valid() {
+        HeartbeatConfig::default().validate().unwrap();
+        let cfg = HeartbeatConfig::from_env_with(|_| None).unwrap();
+        assert_eq!(cfg, HeartbeatConfig::default());
+    }
+
+    #[test]
+    fn test_heartbeat_env_overrides() {
+        let cfg = HeartbeatConfig::from_env_with(|key| match key {
+            "SABA_WATCHDOG_GRACE_SECS" => Some("45".into()),
+            "SABA_WATCHDOG_MAX_RESTARTS" => Some(" 0 ".into()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(cfg.grace_period_secs, 45);
+        assert_eq!(cfg.max_restart_attempts, 0);
+        assert_eq!(cfg.restart_wait_secs, 60);
+    }
+
+    #[test]
+    fn test_heartbeat_rejects_invalid_overrides() {
+        let with = |k: &'static str, v: &'static str| {
+            HeartbeatConfig::from_env_with(move |key| (key == k).then(|| v.to_string()))
+        };
+        assert!(with("SABA_WATCHDOG_GRACE_SECS", "abc").is_err());
+        assert!(with("SABA_WATCHDOG_GRACE_SECS", "-5").is_err());
+        assert!(with("SABA_WATCHDOG_CHECK_INTERVAL_SECS", "0").is_err());
+        assert!(with("SABA_WATCHDOG_RESTART_WAIT_SECS", "100000").is_err());
+        assert!(with("SABA_WATCHDOG_MAX_RESTARTS", "50").is_err());
+        // 만료 시간이 reaper 주기보다 짧으면 거부
+        assert!(with("SABA_HEARTBEAT_TIMEOUT_SECS", "10").is_err());
     }
 }
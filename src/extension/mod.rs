@@ -57,6 +57,17 @@ where
     deserializer.deserialize_any(DepsVisitor)
 }
 
+/// hook 바인딩 `timeout_secs` 디시리얼라이저 — 0초는 hook이 즉시 타임아웃되므로 로드 시점에 거부
+fn deserialize_timeout_secs<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match Option::<u64>::deserialize(deserializer)? {
+        Some(0) => Err(serde::de::Error::custom("timeout_secs must be at least 1")),
+        other => Ok(other),
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════
 //  구조화된 에러 타입
 // ═══════════════════════════════════════════════════════════════
@@ -66,7 +77,7 @@ where
 #[derive(Debug, Clone, Serialize)]
 pub struct ExtensionError {
     /// 머신 판별용 코드 (예: "not_found", "dependency_missing", "dependency_not_enabled",
-    /// "has_dependents", "in_use", "not_mounted", "id_mismatch", "manifest_not_found")
+    /// "has_dependents", "in_use", "not_mounted", "id_mismatch", "manifest_not_found", "invalid_manifest")
     pub error_code: String,
     /// 사람이 읽을 수 있는 메시지
     pub message: String,
@@ -162,6 +173,109 @@ impl ExtensionError {
             related: vec![manifest_id.to_string(), dir_name.to_string()],
         }
     }
+    fn invalid_manifest(archive: &str, detail: &str) -> Self {
+        Self {
+            error_code: "invalid_manifest".to_string(),
+            message: format!("Invalid manifest.json in '{}': {}", archive, detail),
+            related: vec![archive.to_string()],
+        }
+    }
+    fn checksum_mismatch(ext_id: &str, expected: &str, actual: &str) -> Self {
+        Self {
+            error_code: "checksum_mismatch".to_string(),
+            message: format!(
+                "Checksum mismatch for extension '{}': expected {}, got {}",
+                ext_id, expected, actual
+            ),
+            related: vec![ext_id.to_string()],
+        }
+    }
+    fn unsafe_archive(archive: &str, entry: &str) -> Self {
+        Self {
+            error_code: "unsafe_archive".to_string(),
+            message: format!(
+                "Archive '{}' contains an entry outside the extension directory: {}",
+                archive, entry
+            ),
+            related: vec![entry.to_string()],
+        }
+    }
+    fn registry_http_error(url: &str, status: u16) -> Self {
+        Self {
+            error_code: "registry_http_error".to_string(),
+            message: format!("Extension registry returned HTTP {} ({})", status, url),
+            related: vec![url.to_string()],
+        }
+    }
+    fn registry_parse_error(url: &str, detail: &str) -> Self {
+        Self {
+            error_code: "registry_parse_error".to_string(),
+            message: format!("Failed to parse extension registry from {}: {}", url, detail),
+            related: vec![url.to_string()],
+        }
+    }
+    fn registry_schema_unsupported(url: &str, version: u32) -> Self {
+        Self {
+            error_code: "registry_schema_unsupported".to_string(),
+            message: format!(
+                "Extension registry schema_version {} is not supported (max {})",
+                version, SUPPORTED_MANIFEST_SCHEMA_VERSION
+            ),
+            related: vec![url.to_string()],
+        }
+    }
+    fn app_version_too_old(ext_id: &str, required: &str, running: &str) -> Self {
+        Self {
+            error_code: "app_version_too_old".to_string(),
+            message: format!(
+                "Extension '{}' requires saba-core {} or newer, but {} is running",
+                ext_id, required, running
+            ),
+            related: vec![CORE_COMPONENT_KEY.to_string(), required.to_string()],
+        }
+    }
+    fn invalid_instance_data(ext_id: &str, errors: &[FieldError]) -> Self {
+        Self {
+            error_code: "invalid_instance_data".to_string(),
+            message: format!(
+                "Invalid extension_data for '{}': {}",
+                ext_id,
+                errors.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("; ")
+            ),
+            related: errors.iter().map(|e| e.field.clone()).collect(),
+        }
+    }
+    fn update_breaks_dependent(ext_id: &str, new_version: &str, dependent: &str, required: &str) -> Self {
+        Self {
+            error_code: "update_breaks_dependent".to_string(),
+            message: format!(
+                "Skipped update of '{}' to {}: enabled extension '{}' requires {} {}",
+                ext_id, new_version, dependent, ext_id, required
+            ),
+            related: vec![dependent.to_string(), required.to_string()],
+        }
+    }
+    fn update_dependency_unsatisfied(ext_id: &str, dep: &str, required: &str, installed: &str) -> Self {
+        Self {
+            error_code: "update_dependency_unsatisfied".to_string(),
+            message: format!(
+                "Skipped update of '{}': requires {} {} but {} is installed",
+                ext_id, dep, required, installed
+            ),
+            related: vec![dep.to_string(), required.to_string()],
+        }
+    }
+    fn dependency_cycle(ext_id: &str, cycle: &[String]) -> Self {
+        Self {
+            error_code: "dependency_cycle".to_string(),
+            message: format!(
+                "Skipped update of '{}': circular dependency among {}",
+                ext_id,
+                cycle.join(", ")
+            ),
+            related: cycle.to_vec(),
+        }
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -216,6 +330,9 @@ pub struct HookBinding {
     pub condition: Option<String>, // "instance.ext_data.docker_enabled"
     #[serde(default, rename = "async")]
     pub is_async: Option<bool>, // true면 tokio::spawn으로 백그라운드 실행
+    /// 이 바인딩의 실행 제한 시간(초, 1 이상) — 없으면 디스패치 호출 측의 기본값 사용
+    #[serde(default, deserialize_with = "deserialize_timeout_secs")]
+    pub timeout_secs: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -249,6 +366,79 @@ pub struct FieldDef {
     pub optional: Option<bool>,
 }
 
+impl FieldDef {
+    /// 값이 선언된 타입과 맞는지 검사 — 값이 없거나 null이면 `optional`/`default`가 있을 때만 허용.
+    /// 알 수 없는 타입 이름은 검사하지 않음.
+    fn check(&self, name: &str, value: Option<&Value>) -> Option<FieldError> {
+        let found = match value {
+            None | Some(Value::Null) => {
+                if self.optional == Some(true) || self.default.is_some() {
+                    return None;
+                }
+                "missing"
+            }
+            Some(v) => {
+                let matches = match self.field_type.as_str() {
+                    "boolean" => v.is_boolean(),
+                    "number" => v.is_number(),
+                    "integer" => v.as_f64().is_some_and(|n| n.fract() == 0.0),
+                    // 설정 API가 숫자로 해석되는 문자열을 Number로 저장하므로 수도 허용
+                    "string" => v.is_string() || v.is_number(),
+                    "object" => v.is_object(),
+                    "array" => v.is_array(),
+                    _ => true,
+                };
+                if matches {
+                    return None;
+                }
+                json_type_name(v)
+            }
+        };
+        Some(FieldError {
+            field: name.to_string(),
+            expected: self.field_type.clone(),
+            found: found.to_string(),
+        })
+    }
+}
+
+/// `instance_fields` 타입 검증 실패 항목
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct FieldError {
+    pub field: String,
+    /// 선언된 타입 (FieldDef.field_type)
+    pub expected: String,
+    /// 실제 값의 JSON 타입 (없으면 "missing")
+    pub found: String,
+}
+
+impl std::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "field '{}' expects {} but got {}", self.field, self.expected, self.found)
+    }
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// 선언된 `instance_fields` 기준으로 `extension_data`를 검사 (필드명 순)
+fn validate_fields(fields: &HashMap<String, FieldDef>, data: &HashMap<String, Value>) -> Vec<FieldError> {
+    let mut errors: Vec<FieldError> = fields
+        .iter()
+        .filter_map(|(name, def)| def.check(name, data.get(name)))
+        .collect();
+    errors.sort_by(|a, b| a.field.cmp(&b.field));
+    errors
+}
+
 // ═══════════════════════════════════════════════════════════════
 //  발견된 익스텐션
 // ═══════════════════════════════════════════════════════════════
@@ -282,6 +472,9 @@ pub struct ExtensionListItem {
     /// 익스텐션 디렉토리에 icon.png가 존재하는지 여부
     #[serde(default)]
     pub has_icon: bool,
+    /// 강제 비활성화된 이유 (예: "dependency 'steamcmd' removed") — 다시 활성화하면 사라짐
+    #[serde(default)]
+    pub disabled_reason: Option<String>,
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -381,6 +574,31 @@ pub struct ExtensionUpdateInfo {
     pub installed: bool,
 }
 
+/// 원격 익스텐션 설치 단계
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallPhase {
+    /// 아카이브 다운로드 중
+    Downloading,
+    /// SHA-256 무결성 검증 중
+    Verifying,
+    /// 아카이브 검사 및 압축 해제 중
+    Extracting,
+    /// 설치 완료
+    Done,
+}
+
+/// 원격 익스텐션 설치 진행 상태 (`install_from_url_with_progress` 콜백으로 전달)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstallProgress {
+    pub ext_id: String,
+    pub phase: InstallPhase,
+    /// 수신한 바이트 수
+    pub bytes_received: u64,
+    /// 전체 바이트 수 (Content-Length, 0이면 알 수 없음)
+    pub total_bytes: u64,
+}
+
 // ═══════════════════════════════════════════════════════════════
 //  ExtensionManager
 // ═══════════════════════════════════════════════════════════════
@@ -391,17 +609,44 @@ const DEFAULT_MANIFEST_URL: &str =
     "https://raw.githubusercontent.com/WareAoba/saba-chan-extensions/main/manifest.json";
 // NOTE: 향후 ExtensionManager::new()에서 constants::extensions_manifest_url() 사용 권장
 
+/// `min_app_version`을 비교할 코어 컴포넌트 키 (`installed_versions`에 없으면 실행 중인 빌드 버전 사용)
+const CORE_COMPONENT_KEY: &str = "saba-core";
+
+/// 이 빌드가 해석할 수 있는 원격 매니페스트 최대 schema_version
+const SUPPORTED_MANIFEST_SCHEMA_VERSION: u32 = 1;
+
+/// 진행률 hook의 기본 실행 제한 시간 — SteamCMD/다운로드 등 장시간 프로비저닝 허용 (30분)
+const PROGRESS_HOOK_TIMEOUT_SECS: u64 = 1800;
+
+/// 원격 매니페스트 요청 타임아웃
+const MANIFEST_FETCH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// ETag 기반 원격 매니페스트 캐시 — 304 응답 시 재사용
+#[derive(Debug, Clone)]
+struct ManifestCache {
+    url: String,
+    etag: String,
+    extensions: Vec<RemoteExtensionInfo>,
+}
+
 pub struct ExtensionManager {
     extensions_dir: PathBuf,
     discovered: HashMap<String, DiscoveredExtension>,
     enabled: HashSet<String>,
     state_path: PathBuf,
+    /// `force_disable`로 꺼진 익스텐션의 사유 (ext_id → 사유)
+    /// enabled 목록 옆의 `extensions_disabled_reasons.json`에 영속화
+    disabled_reason: HashMap<String, String>,
+    /// 마지막 `discover`에서 압축 해제하지 못한 zip (파일명 → 에러). 다른 zip의 추출은 계속 진행
+    zip_errors: HashMap<String, String>,
     /// 익스텐션 글로벌 설정 (`extensionConfig.json`)
     /// 구조: ext_id → { key → value }
     extension_config: HashMap<String, HashMap<String, Value>>,
     config_path: PathBuf,
     /// 원격 매니페스트 URL (커스텀 오버라이드 가능)
     pub manifest_url: String,
+    /// 마지막으로 받은 원격 매니페스트 (읽기 락에서 갱신하므로 내부 가변성 사용)
+    manifest_cache: std::sync::Mutex<Option<ManifestCache>>,
 }
 
 #[allow(dead_code)]
@@ -438,9 +683,12 @@ impl ExtensionManager {
             discovered: HashMap::new(),
             enabled: HashSet::new(),
             state_path,
+            disabled_reason: HashMap::new(),
+            zip_errors: HashMap::new(),
             extension_config: HashMap::new(),
             config_path,
             manifest_url: DEFAULT_MANIFEST_URL.to_string(),
+            manifest_cache: std::sync::Mutex::new(None),
         };
         mgr.load_state();
         mgr.load_extension_config();
@@ -463,6 +711,19 @@ impl ExtensionManager {
         ext_id.replace('-', "_")
     }
 
+    /// 폴더명으로 쓸 수 있는 ext_id인지 (빈 값, `.`/`..`, 경로 구분자 거부)
+    fn is_path_safe_id(ext_id: &str) -> bool {
+        !ext_id.is_empty()
+            && ext_id != "."
+            && !ext_id.contains("..")
+            && !ext_id.contains(['/', '\\'])
+    }
+
+    /// 마지막 `discover`에서 압축 해제하지 못한 zip 목록 (파일명 → 에러 메시지)
+    pub fn zip_errors(&self) -> &HashMap<String, String> {
+        &self.zip_errors
+    }
+
     /// ext_id에 대응하는 디스크 상의 디렉토리를 반환합니다.
     ///
     /// 탐색 순서:
@@ -507,7 +768,7 @@ impl ExtensionManager {
             return Ok(found);
         }
 
-
+        self.zip_errors.clear();
 
         let entries = std::fs::read_dir(&self.extensions_dir)
             .with_context(|| {
@@ -541,6 +802,8 @@ impl ExtensionManager {
                                 "Failed to extract zip extension {}: {}",
                                 path.display(), e
                             );
+                            let name = entry.file_name().to_string_lossy().to_string();
+                            self.zip_errors.insert(name, format!("{:#}", e));
                         }
                     }
                 }
@@ -590,11 +853,17 @@ impl ExtensionManager {
         let newly_extracted = self.rescan_extracted()?;
         found.extend(newly_extracted);
 
+        // 저장된 enabled 목록이 디스크 상태와 어긋났으면 (의존 익스텐션 삭제/마이그레이션) 정리
+        self.disable_broken_dependents();
+
         tracing::info!("Extension discovery complete: {} found", found.len());
         Ok(found)
     }
 
-    /// `.zip` 파일을 같은 이름의 폴더로 압축 해제.
+    /// `.zip` 파일을 익스텐션 폴더로 압축 해제.
+    ///
+    /// 폴더명은 zip 안의 `manifest.json`에 선언된 `id`를 따르며 (없으면 파일명),
+    /// 파일명과 id가 달라도 `mount(id)`가 찾는 위치에 풀리도록 합니다.
     /// 이미 폴더가 있으면 None 반환 (스킵).
     fn extract_zip_extension(&self, zip_path: &std::path::Path) -> Result<Option<String>> {
         let stem = zip_path
@@ -602,7 +871,29 @@ impl ExtensionManager {
             .and_then(|s| s.to_str())
             .ok_or_else(|| anyhow::anyhow!("Invalid zip filename: {}", zip_path.display()))?;
 
-        let dir_name = Self::python_safe_dir_name(stem);
+        let file = std::fs::File::open(zip_path)
+            .with_context(|| format!("Failed to open zip: {}", zip_path.display()))?;
+        let mut archive = zip::ZipArchive::new(file)
+            .with_context(|| format!("Failed to read zip archive: {}", zip_path.display()))?;
+        Self::ensure_safe_archive(&mut archive, &zip_path.display().to_string())?;
+
+        let label = zip_path.display().to_string();
+        let ext_id = match Self::read_zip_manifest(&mut archive, &label)? {
+            Some(manifest) if manifest.id != stem => {
+                tracing::info!(
+                    "Zip '{}' declares extension id '{}' — extracting under the id",
+                    zip_path.display(), manifest.id
+                );
+                manifest.id
+            }
+            _ => stem.to_string(),
+        };
+        // id(또는 파일명)가 경로로 쓰일 수 없는 값이면 거부 — `.`이면 extensions/ 자체를 가리킴
+        if !Self::is_path_safe_id(&ext_id) {
+            return Err(ExtensionError::id_mismatch(&ext_id, stem).into());
+        }
+
+        let dir_name = Self::python_safe_dir_name(&ext_id);
         let dest = self.extensions_dir.join(&dir_name);
         if dest.is_dir() {
             // 이미 추출된 폴더 존재 → zip 파일 삭제 후 스킵
@@ -612,16 +903,46 @@ impl ExtensionManager {
             return Ok(None);
         }
 
-        let file = std::fs::File::open(zip_path)
-            .with_context(|| format!("Failed to open zip: {}", zip_path.display()))?;
-        let mut archive = zip::ZipArchive::new(file)
-            .with_context(|| format!("Failed to read zip archive: {}", zip_path.display()))?;
+        Self::extract_archive(&mut archive, &dest, &zip_path.display().to_string())?;
 
+        // 성공 후 zip 파일 제거
+        if let Err(e) = std::fs::remove_file(zip_path) {
+            tracing::warn!("Failed to remove zip after extraction: {}", e);
+        }
+
+        tracing::info!("Extracted zip extension '{}' to {}", ext_id, dest.display());
+        Ok(Some(ext_id))
+    }
+
+    /// 모든 엔트리가 압축 해제 대상 폴더 안에 머무는지 확인.
+    /// `..`·절대 경로 등 `enclosed_name()`이 거부하는 엔트리가 하나라도 있으면
+    /// 일부만 풀린 익스텐션이 남지 않도록 추출 전에 `unsafe_archive`로 실패합니다.
+    fn ensure_safe_archive<R: std::io::Read + std::io::Seek>(
+        archive: &mut zip::ZipArchive<R>,
+        label: &str,
+    ) -> Result<()> {
+        for i in 0..archive.len() {
+            let zip_file = archive.by_index(i)?;
+            if zip_file.enclosed_name().is_none() {
+                return Err(ExtensionError::unsafe_archive(label, zip_file.name()).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// zip 아카이브를 `dest` 아래로 압축 해제. 안전하지 않은 엔트리는 에러.
+    fn extract_archive<R: std::io::Read + std::io::Seek>(
+        archive: &mut zip::ZipArchive<R>,
+        dest: &std::path::Path,
+        label: &str,
+    ) -> Result<()> {
         for i in 0..archive.len() {
             let mut zip_file = archive.by_index(i)?;
             let outpath = match zip_file.enclosed_name() {
                 Some(p) => dest.join(p),
-                None => continue,
+                None => {
+                    return Err(ExtensionError::unsafe_archive(label, zip_file.name()).into());
+                }
             };
             if zip_file.is_dir() {
                 std::fs::create_dir_all(&outpath)?;
@@ -635,14 +956,26 @@ impl ExtensionManager {
                     .with_context(|| format!("Failed to write {}", outpath.display()))?;
             }
         }
+        Ok(())
+    }
 
-        // 성공 후 zip 파일 제거
-        if let Err(e) = std::fs::remove_file(zip_path) {
-            tracing::warn!("Failed to remove zip after extraction: {}", e);
-        }
-
-        tracing::info!("Extracted zip extension '{}' to {}", stem, dest.display());
-        Ok(Some(stem.to_string()))
+    /// zip 루트의 `manifest.json`을 읽어 파싱. 루트에 매니페스트가 없으면 None,
+    /// 파싱할 수 없으면 해당 zip에 대한 `invalid_manifest` 에러.
+    fn read_zip_manifest<R: std::io::Read + std::io::Seek>(
+        archive: &mut zip::ZipArchive<R>,
+        label: &str,
+    ) -> Result<Option<ExtensionManifest>> {
+        let mut entry = match archive.by_name("manifest.json") {
+            Ok(entry) => entry,
+            Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+            Err(e) => return Err(e).context("Failed to read manifest.json from zip"),
+        };
+        let mut content = String::new();
+        std::io::Read::read_to_string(&mut entry, &mut content)
+            .context("Failed to read manifest.json from zip")?;
+        let manifest = serde_json::from_str(&content)
+            .map_err(|e| ExtensionError::invalid_manifest(label, &e.to_string()))?;
+        Ok(Some(manifest))
     }
 
     /// 방금 추출된 폴더들의 manifest를 로드하여 discovered에 추가 (내부용)
@@ -740,10 +1073,20 @@ impl ExtensionManager {
             }
         }
 
+        self.disable_broken_dependents();
+
         tracing::info!("Rescan complete: {} new extension(s)", newly_found.len());
         Ok(newly_found)
     }
 
+    /// `install_from_url`이 돌려준 익스텐션을 디스크 재조회 없이 바로 마운트.
+    /// 이미 존재하면 매니페스트를 교체.
+    pub fn mount_installed(&mut self, ext: DiscoveredExtension) {
+        tracing::info!("Mounted extension: {} v{}", ext.manifest.name, ext.manifest.version);
+        self.discovered.insert(ext.manifest.id.clone(), ext);
+        self.disable_broken_dependents();
+    }
+
     /// 단일 익스텐션을 핫 마운트 (디스크에서 로드 → discovered에 추가).
     /// 이미 존재하면 매니페스트를 리로드.
     pub fn mount(&mut self, ext_id: &str) -> Result<()> {
@@ -761,11 +1104,7 @@ impl ExtensionManager {
             return Err(ExtensionError::id_mismatch(&manifest.id, ext_id).into());
         }
 
-        tracing::info!("Mounted extension: {} v{}", manifest.name, manifest.version);
-        self.discovered.insert(
-            ext_id.to_string(),
-            DiscoveredExtension { manifest, dir: ext_path },
-        );
+        self.mount_installed(DiscoveredExtension { manifest, dir: ext_path });
         Ok(())
     }
 
@@ -817,6 +1156,54 @@ impl ExtensionManager {
             .collect()
     }
 
+    /// 활성 익스텐션의 익스텐션 의존성을 다시 검증해, 깨진 것은 사유와 함께 `force_disable`.
+    ///
+    /// 의존 익스텐션이 디스크에서 사라졌거나, 교체된 버전이 요구사항을 더 이상 충족하지 않으면
+    /// 저장된 enabled 목록을 그대로 믿을 수 없다. 비활성화가 연쇄되도록 더 없을 때까지 반복.
+    /// 반환: 강제 비활성화된 익스텐션 ID 목록
+    fn disable_broken_dependents(&mut self) -> Vec<String> {
+        let mut disabled = Vec::new();
+        loop {
+            let broken = self.discovered.values()
+                .filter(|ext| self.enabled.contains(&ext.manifest.id))
+                .find_map(|ext| {
+                    ext.manifest.dependencies.iter()
+                        .find_map(|(dep, req)| self.dependency_break_reason(dep, req))
+                        .map(|reason| (ext.manifest.id.clone(), reason))
+                });
+            match broken {
+                Some((ext_id, reason)) => {
+                    self.force_disable(&ext_id, &reason);
+                    disabled.push(ext_id);
+                }
+                None => return disabled,
+            }
+        }
+    }
+
+    /// 활성 익스텐션의 익스텐션 의존성 하나가 깨졌으면 그 사유
+    ///
+    /// discovered에 없는 키는 이름만 선언(`*`)됐거나 enabled 목록에 남아 있을 때만
+    /// 사라진 익스텐션으로 본다 — 그 밖의 키는 컴포넌트 의존성이라 여기서 검사하지 않음.
+    fn dependency_break_reason(&self, dep: &str, version_req: &str) -> Option<String> {
+        match self.discovered.get(dep) {
+            Some(_) if !self.enabled.contains(dep) => {
+                Some(format!("dependency '{}' disabled", dep))
+            }
+            Some(dep_ext) if !Self::version_satisfies(&dep_ext.manifest.version, version_req) => {
+                Some(format!(
+                    "dependency '{}' {} no longer satisfies {}",
+                    dep, dep_ext.manifest.version, version_req
+                ))
+            }
+            Some(_) => None,
+            None if version_req == "*" || self.enabled.contains(dep) => {
+                Some(format!("dependency '{}' removed", dep))
+            }
+            None => None,
+        }
+    }
+
     /// 인스턴스의 extension_data에서 이 익스텐션이 선언한 instance_fields를
     /// 하나라도 truthy 값으로 사용하는 인스턴스 이름 목록.
     fn instances_using_ext(
@@ -871,6 +1258,12 @@ impl ExtensionManager {
             return Err(ExtensionError::not_found(ext_id).into());
         }
 
+        let core_version = installed_versions
+            .get(CORE_COMPONENT_KEY)
+            .map(String::as_str)
+            .unwrap_or(env!("CARGO_PKG_VERSION"));
+        Self::check_min_app_version(&self.discovered[ext_id].manifest, core_version)?;
+
         let deps = self.discovered[ext_id].manifest.dependencies.clone();
         for (dep_key, version_req) in &deps {
             if let Some(dep_ext) = self.discovered.get(dep_key) {
@@ -880,21 +1273,14 @@ impl ExtensionManager {
                         ExtensionError::dependency_not_enabled(ext_id, dep_key).into()
                     );
                 }
-                // 버전 검증 ("*"면 스킵)
-                if version_req != "*" {
-                    let min_clean = version_req.trim_start_matches(">=").trim();
-                    let satisfied = match (SemVer::parse(&dep_ext.manifest.version), SemVer::parse(min_clean)) {
-                        (Some(iv), Some(rv)) => iv >= rv,
-                        _ => false,
-                    };
-                    if !satisfied {
-                        return Err(
-                            ExtensionError::component_version_unsatisfied(
-                                ext_id, dep_key, version_req,
-                                Some(&dep_ext.manifest.version),
-                            ).into()
-                        );
-                    }
+                // 버전 검증 ("*"는 항상 충족)
+                if !Self::version_satisfies(&dep_ext.manifest.version, version_req) {
+                    return Err(
+                        ExtensionError::component_version_unsatisfied(
+                            ext_id, dep_key, version_req,
+                            Some(&dep_ext.manifest.version),
+                        ).into()
+                    );
                 }
             } else {
                 // ── 비-익스텐션 컴포넌트 의존성 ──
@@ -904,14 +1290,8 @@ impl ExtensionManager {
                         ExtensionError::dependency_missing(ext_id, dep_key).into()
                     );
                 }
-                let min_clean = version_req.trim_start_matches(">=").trim();
                 let installed = installed_versions.get(dep_key);
-                let satisfied = installed.is_some_and(|v| {
-                    match (SemVer::parse(v), SemVer::parse(min_clean)) {
-                        (Some(iv), Some(rv)) => iv >= rv,
-                        _ => false,
-                    }
-                });
+                let satisfied = installed.is_some_and(|v| Self::version_satisfies(v, version_req));
                 if !satisfied {
                     return Err(
                         ExtensionError::component_version_unsatisfied(
@@ -924,6 +1304,7 @@ impl ExtensionManager {
         }
 
         self.enabled.insert(ext_id.to_string());
+        self.disabled_reason.remove(ext_id);
         self.save_state();
         tracing::info!("Extension enabled: {}", ext_id);
         Ok(())
@@ -959,10 +1340,19 @@ impl ExtensionManager {
     }
 
     /// 강제 비활성화 (인스턴스/의존성 무시) — 내부 마이그레이션/관리용
-    pub fn force_disable(&mut self, ext_id: &str) {
+    ///
+    /// `reason`은 영속화되어 `list()`의 `disabled_reason`으로 노출되므로,
+    /// 사용자가 익스텐션이 꺼진 이유를 알 수 있도록 구체적으로 적습니다.
+    pub fn force_disable(&mut self, ext_id: &str, reason: &str) {
         self.enabled.remove(ext_id);
+        self.disabled_reason.insert(ext_id.to_string(), reason.to_string());
         self.save_state();
-        tracing::warn!("Extension force-disabled: {}", ext_id);
+        tracing::warn!("Extension force-disabled: {} ({})", ext_id, reason);
+    }
+
+    /// 강제 비활성화 사유 (없으면 None)
+    pub fn disabled_reason(&self, ext_id: &str) -> Option<&str> {
+        self.disabled_reason.get(ext_id).map(String::as_str)
     }
 
     /// 익스텐션 제거 — 비활성화 후 디렉토리 삭제
@@ -991,6 +1381,7 @@ impl ExtensionManager {
         if self.extension_config.remove(ext_id).is_some() {
             self.save_extension_config();
         }
+        self.disabled_reason.remove(ext_id);
         self.save_state();
         tracing::info!("Extension removed: {}", ext_id);
         Ok(())
@@ -1047,6 +1438,7 @@ impl ExtensionManager {
                     instance_fields: m.instance_fields.clone(),
                     config_fields: m.config_fields.clone(),
                     has_icon,
+                    disabled_reason: self.disabled_reason.get(&m.id).cloned(),
                 }
             })
             .collect()
@@ -1066,49 +1458,157 @@ impl ExtensionManager {
         result
     }
 
-    /// 조건 문자열 평가: "instance.ext_data.<key>" → ext_data[key] == true
+    /// 조건 문자열 평가
+    ///
+    /// 지원 문법:
+    /// - `instance.ext_data.<key>` — 값의 truthiness (bool, 0이 아닌 수, 비어 있지 않은 문자열)
+    /// - `instance.ext_data.<key> <op> <literal>` — `op`는 `==`, `!=`, `>`, `<`
+    ///   - 리터럴은 JSON 값(`true`, `2`, `"bridge"`, `null`)이며, 따옴표 없는 단어는 문자열로 취급
+    ///   - bool 리터럴과의 비교는 truthiness 기준 (`docker_enabled == false`는 키가 없어도 참)
+    ///   - `>`/`<`는 양쪽이 모두 수(또는 수로 해석되는 문자열)일 때만 참
+    /// - `!<condition>` — 부정
+    ///
+    /// 해석할 수 없는 조건은 경고 후 false로 평가합니다.
     pub fn evaluate_condition(
         condition: &str,
         ext_data: &HashMap<String, Value>,
     ) -> bool {
-        // "instance.ext_data.<key>" 패턴
-        if let Some(key) = condition.strip_prefix("instance.ext_data.") {
-            match ext_data.get(key) {
-                Some(Value::Bool(b)) => *b,
-                Some(Value::Number(n)) => n.as_f64().map(|v| v != 0.0).unwrap_or(false),
-                Some(Value::String(s)) => !s.is_empty(),
-                _ => false,
+        match Self::eval_condition_expr(condition.trim(), ext_data) {
+            Some(result) => result,
+            None => {
+                tracing::warn!("Unknown condition pattern: {}", condition);
+                false
             }
-        } else {
-            tracing::warn!("Unknown condition pattern: {}", condition);
-            false
         }
     }
 
-    /// hook context에 extension_config를 주입합니다.
-    /// 이미 존재하지 않는 경우에만 추가합니다.
-    fn inject_extension_config(&self, mut context: Value) -> Value {
-        if let Some(obj) = context.as_object_mut() {
-            if !obj.contains_key("extension_config") {
-                obj.insert(
-                    "extension_config".to_string(),
-                    self.extension_config_as_value(),
-                );
-            }
+    /// 조건식 평가 — 문법 오류면 None
+    fn eval_condition_expr(expr: &str, ext_data: &HashMap<String, Value>) -> Option<bool> {
+        if let Some(inner) = expr.strip_prefix('!') {
+            return Self::eval_condition_expr(inner.trim_start(), ext_data).map(|b| !b);
+        }
+
+        let Some((lhs, op, rhs)) = Self::split_condition(expr) else {
+            let key = Self::condition_key(expr)?;
+            return Some(Self::is_truthy(ext_data.get(key)));
+        };
+
+        let value = ext_data.get(Self::condition_key(lhs)?);
+        let rhs = rhs.trim();
+        if rhs.is_empty() {
+            return None;
+        }
+        let literal = serde_json::from_str::<Value>(rhs)
+            .unwrap_or_else(|_| Value::String(rhs.trim_matches('\'').to_string()));
+
+        Some(match op {
+            "==" => Self::condition_equals(value, &literal),
+            "!=" => !Self::condition_equals(value, &literal),
+            ">" | "<" => match (value.and_then(Self::condition_number), Self::condition_number(&literal)) {
+                (Some(v), Some(l)) if op == ">" => v > l,
+                (Some(v), Some(l)) => v < l,
+                _ => false,
+            },
+            _ => unreachable!("split_condition only yields known operators"),
+        })
+    }
+
+    /// 첫 비교 연산자를 기준으로 `(lhs, op, rhs)` 분리
+    fn split_condition(expr: &str) -> Option<(&str, &'static str, &str)> {
+        for (i, _) in expr.char_indices() {
+            let rest = &expr[i..];
+            let op = if rest.starts_with("==") {
+                "=="
+            } else if rest.starts_with("!=") {
+                "!="
+            } else if rest.starts_with('>') {
+                ">"
+            } else if rest.starts_with('<') {
+                "<"
+            } else {
+                continue;
+            };
+            return Some((&expr[..i], op, &rest[op.len()..]));
+        }
+        None
+    }
+
+    /// `instance.ext_data.<key>` → `<key>` (공백이 섞인 키는 거부)
+    fn condition_key(lhs: &str) -> Option<&str> {
+        let key = lhs.trim().strip_prefix("instance.ext_data.")?;
+        (!key.is_empty() && !key.contains(char::is_whitespace)).then_some(key)
+    }
+
+    fn is_truthy(value: Option<&Value>) -> bool {
+        match value {
+            Some(Value::Bool(b)) => *b,
+            Some(Value::Number(n)) => n.as_f64().map(|v| v != 0.0).unwrap_or(false),
+            Some(Value::String(s)) => !s.is_empty(),
+            _ => false,
+        }
+    }
+
+    fn condition_number(value: &Value) -> Option<f64> {
+        match value {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    fn condition_equals(value: Option<&Value>, literal: &Value) -> bool {
+        match literal {
+            Value::Bool(b) => Self::is_truthy(value) == *b,
+            Value::Null => matches!(value, None | Some(Value::Null)),
+            Value::Number(_) => match value.and_then(Self::condition_number) {
+                Some(v) => Some(v) == Self::condition_number(literal),
+                None => false,
+            },
+            _ => value == Some(literal),
+        }
+    }
+
+    /// 지정된 hook의 현재 활성 바인딩을 소유한 스냅샷으로 고정합니다.
+    ///
+    /// 스냅샷은 매니저를 빌리지 않으므로, 호출자는 `RwLock` 읽기 잠금을 잡은 채로
+    /// 스냅샷만 만든 뒤 잠금을 풀고 `dispatch*`를 실행할 수 있습니다.
+    /// 이후 `rescan`/`mount`/`enable`/`disable`이 실행되어도 진행 중인 디스패치의
+    /// hook 집합은 바뀌지 않습니다 — 변경은 다음 스냅샷부터 반영됩니다.
+    pub fn snapshot_hooks(&self, hook_name: &str) -> HookSnapshot {
+        let targets = self
+            .hooks_for(hook_name)
+            .into_iter()
+            .map(|(ext, binding)| HookTarget {
+                ext_id: ext.manifest.id.clone(),
+                instance_fields: ext.manifest.instance_fields.clone(),
+                module_path: ext
+                    .manifest
+                    .python_modules
+                    .get(&binding.module)
+                    .map(|rel_path| ext.dir.join(rel_path).to_string_lossy().to_string()),
+                binding: binding.clone(),
+            })
+            .collect();
+        HookSnapshot {
+            hook_name: hook_name.to_string(),
+            targets,
+            extension_config: self.extension_config_as_value(),
         }
-        context
     }
 
     /// Hook 디스패치: 조건 평가 → run_plugin 호출 → handled 체크
     ///
     /// 반환: Vec<(ext_id, Result<Value>)>
     /// handled=true가 나오면 이후 익스텐션은 스킵 (chain-of-responsibility)
+    ///
+    /// 시작 시점의 `snapshot_hooks` 결과로 실행합니다. 장시간 hook을 `RwLock` 아래에서
+    /// 호출하면 그동안 rescan/설치가 막히므로, 그런 경우 스냅샷을 직접 만들어 잠금 밖에서 실행하세요.
     pub async fn dispatch_hook(
         &self,
         hook_name: &str,
         context: Value,
     ) -> Vec<(String, Result<Value>)> {
-        self.dispatch_hook_timed(hook_name, context, crate::plugin::DEFAULT_PLUGIN_TIMEOUT_SECS).await
+        self.snapshot_hooks(hook_name).dispatch(context).await
     }
 
     /// 타임아웃 지정 가능한 hook 디스패치 (server.list_enrich 등 빠른 반환이 필요한 hook용)
@@ -1118,208 +1618,53 @@ impl ExtensionManager {
         context: Value,
         timeout_secs: u64,
     ) -> Vec<(String, Result<Value>)> {
-        let hooks = self.hooks_for(hook_name);
+        self.snapshot_hooks(hook_name).dispatch_timed(context, timeout_secs).await
+    }
+
+    /// Hook 디스패치 + 진행률 콜백 (server.post_create 등 장시간 hook용)
+    pub async fn dispatch_hook_with_progress<F>(
+        &self,
+        hook_name: &str,
+        context: Value,
+        on_progress: F,
+    ) -> Vec<(String, Result<Value>)>
+    where
+        F: Fn(crate::plugin::ExtensionProgress) + Send + Sync + 'static,
+    {
+        let hooks = self.snapshot_hooks(hook_name);
         if hooks.is_empty() {
+            tracing::warn!("dispatch_hook_with_progress('{}') — no hooks registered (enabled: {:?})", hook_name, self.enabled);
             return Vec::new();
         }
+        hooks.dispatch_with_progress(context, on_progress).await
+    }
 
-        // extension_config를 context에 자동 주입
-        let context = self.inject_extension_config(context);
-
-        let ext_data: HashMap<String, Value> = context
-            .get("extension_data")
-            .and_then(|v| serde_json::from_value(v.clone()).ok())
-            .unwrap_or_default();
+    /// 해당 config 섹션명을 관할하는 활성 익스텐션이 있는지
+    pub fn should_parse_config_section(&self, section: &str) -> bool {
+        self.discovered.values().any(|ext| {
+            self.enabled.contains(&ext.manifest.id)
+                && ext.manifest.module_config_section.as_deref() == Some(section)
+        })
+    }
 
-        let mut results = Vec::new();
+    /// 인스턴스 `extension_data`를 익스텐션이 선언한 `instance_fields` 타입과 대조합니다.
+    ///
+    /// 발견되지 않은 익스텐션이면 빈 목록. hook 디스패치 전에도 같은 검사를 수행하여
+    /// 잘못된 값이 Python까지 전달되지 않도록 합니다.
+    pub fn validate_instance_data(&self, ext_id: &str, data: &HashMap<String, Value>) -> Vec<FieldError> {
+        self.discovered
+            .get(ext_id)
+            .map(|ext| validate_fields(&ext.manifest.instance_fields, data))
+            .unwrap_or_default()
+    }
 
-        for (ext, binding) in hooks {
-            // 조건 평가
-            if let Some(ref cond) = binding.condition {
-                if !Self::evaluate_condition(cond, &ext_data) {
-                    continue;
-                }
-            }
-
-            // Python 모듈 절대 경로 해석
-            let module_file = match ext.manifest.python_modules.get(&binding.module) {
-                Some(rel_path) => ext.dir.join(rel_path),
-                None => {
-                    tracing::error!(
-                        "Extension '{}' hook '{}' references unknown module '{}'",
-                        ext.manifest.id,
-                        hook_name,
-                        binding.module
-                    );
-                    results.push((
-                        ext.manifest.id.clone(),
-                        Err(anyhow::anyhow!(
-                            "Unknown python module: {}",
-                            binding.module
-                        )),
-                    ));
-                    continue;
-                }
-            };
-
-            let module_path = module_file.to_string_lossy().to_string();
-            tracing::debug!(
-                "Dispatching hook '{}' → ext '{}' → {}::{}",
-                hook_name,
-                ext.manifest.id,
-                binding.module,
-                binding.function
-            );
-
-            let result = crate::plugin::run_plugin_with_timeout(
-                &module_path,
-                &binding.function,
-                context.clone(),
-                timeout_secs,
-            )
-            .await;
-
-            match &result {
-                Ok(val) => {
-                    tracing::debug!(
-                        "Hook '{}' ext '{}' returned: {}",
-                        hook_name,
-                        ext.manifest.id,
-                        serde_json::to_string(val).unwrap_or_default()
-                    );
-                    results.push((ext.manifest.id.clone(), Ok(val.clone())));
-
-                    // handled=true → chain 종료
-                    if val
-                        .get("handled")
-                        .and_then(|h| h.as_bool())
-                        == Some(true)
-                    {
-                        tracing::debug!(
-                            "Hook '{}' handled by extension '{}', skipping remaining",
-                            hook_name,
-                            ext.manifest.id
-                        );
-                        break;
-                    }
-                }
-                Err(e) => {
-                    tracing::error!(
-                        "Hook '{}' ext '{}' failed: {}",
-                        hook_name,
-                        ext.manifest.id,
-                        e
-                    );
-                    results.push((
-                        ext.manifest.id.clone(),
-                        Err(anyhow::anyhow!("Hook dispatch failed: {}", e)),
-                    ));
-                    // 에러 시 graceful degradation — 기본 동작 진행을 위해 계속
-                }
-            }
-        }
-
-        results
-    }
-
-    /// Hook 디스패치 + 진행률 콜백 (server.post_create 등 장시간 hook용)
-    pub async fn dispatch_hook_with_progress<F>(
-        &self,
-        hook_name: &str,
-        context: Value,
-        on_progress: F,
-    ) -> Vec<(String, Result<Value>)>
-    where
-        F: Fn(crate::plugin::ExtensionProgress) + Send + 'static,
-    {
-        let hooks = self.hooks_for(hook_name);
-        if hooks.is_empty() {
-            tracing::warn!("dispatch_hook_with_progress('{}') — no hooks registered (enabled: {:?})", hook_name, self.enabled);
-            return Vec::new();
-        }
-
-        // extension_config를 context에 자동 주입
-        let context = self.inject_extension_config(context);
-
-        let ext_data: HashMap<String, Value> = context
-            .get("extension_data")
-            .and_then(|v| serde_json::from_value(v.clone()).ok())
-            .unwrap_or_default();
-
-        tracing::info!("dispatch_hook_with_progress('{}') — {} hook(s), ext_data keys: {:?}", hook_name, hooks.len(), ext_data.keys().collect::<Vec<_>>());
-
-        let mut results = Vec::new();
-
-        for (ext, binding) in hooks {
-            if let Some(ref cond) = binding.condition {
-                if !Self::evaluate_condition(cond, &ext_data) {
-                    tracing::warn!("Hook '{}' from '{}' skipped: condition '{}' evaluated to false (ext_data: {:?})", hook_name, ext.manifest.id, cond, ext_data);
-                    continue;
-                }
-            }
-
-            let module_file = match ext.manifest.python_modules.get(&binding.module) {
-                Some(rel_path) => ext.dir.join(rel_path),
-                None => {
-                    results.push((
-                        ext.manifest.id.clone(),
-                        Err(anyhow::anyhow!(
-                            "Unknown python module: {}",
-                            binding.module
-                        )),
-                    ));
-                    continue;
-                }
-            };
-
-            let module_path = module_file.to_string_lossy().to_string();
-
-            let result = crate::plugin::run_plugin_with_progress_and_timeout(
-                &module_path,
-                &binding.function,
-                context.clone(),
-                on_progress,
-                1800, // 30분 — SteamCMD/다운로드 등 장시간 프로비저닝 허용
-            )
-            .await;
-
-            match &result {
-                Ok(val) => {
-                    results.push((ext.manifest.id.clone(), Ok(val.clone())));
-                    if val.get("handled").and_then(|h| h.as_bool()) == Some(true) {
-                        break;
-                    }
-                }
-                Err(e) => {
-                    results.push((
-                        ext.manifest.id.clone(),
-                        Err(anyhow::anyhow!("Hook dispatch failed: {}", e)),
-                    ));
-                }
-            }
-
-            // progress 콜백은 한 번만 소비 가능하므로 첫 번째 익스텐션만 progress 지원
-            break;
-        }
-
-        results
-    }
-
-    /// 해당 config 섹션명을 관할하는 활성 익스텐션이 있는지
-    pub fn should_parse_config_section(&self, section: &str) -> bool {
-        self.discovered.values().any(|ext| {
-            self.enabled.contains(&ext.manifest.id)
-                && ext.manifest.module_config_section.as_deref() == Some(section)
-        })
-    }
-
-    /// 활성 익스텐션의 instance_fields를 합산
-    pub fn all_instance_fields(&self) -> HashMap<String, FieldDef> {
-        let mut fields = HashMap::new();
-        for ext in self.discovered.values() {
-            if self.enabled.contains(&ext.manifest.id) {
-                for (k, v) in &ext.manifest.instance_fields {
-                    fields.insert(k.clone(), v.clone());
+    /// 활성 익스텐션의 instance_fields를 합산
+    pub fn all_instance_fields(&self) -> HashMap<String, FieldDef> {
+        let mut fields = HashMap::new();
+        for ext in self.discovered.values() {
+            if self.enabled.contains(&ext.manifest.id) {
+                for (k, v) in &ext.manifest.instance_fields {
+                    fields.insert(k.clone(), v.clone());
                 }
             }
         }
@@ -1357,27 +1702,73 @@ impl ExtensionManager {
     /// 원격 매니페스트에서 가용 익스텐션 목록을 페치합니다.
     ///
     /// `manifest_url`(GitHub raw URL)에서 JSON을 가져와 `Vec<RemoteExtensionInfo>`로 반환합니다.
+    /// 이전 응답의 ETag를 `If-None-Match`로 보내고, 304면 캐시된 목록을 그대로 돌려줍니다.
+    /// HTTP 오류·JSON 파싱 실패·지원하지 않는 `schema_version`은 `ExtensionError`로 보고합니다.
     pub async fn fetch_manifest(&self) -> Result<Vec<RemoteExtensionInfo>> {
         tracing::debug!("Fetching extension manifest from: {}", self.manifest_url);
 
-        let response = reqwest::get(&self.manifest_url)
+        let cached = self
+            .manifest_cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+            .filter(|c| c.url == self.manifest_url);
+
+        let client = reqwest::Client::builder()
+            .timeout(MANIFEST_FETCH_TIMEOUT)
+            .build()
+            .context("Failed to build HTTP client")?;
+        let mut request = client.get(&self.manifest_url);
+        if let Some(ref c) = cached {
+            request = request.header(reqwest::header::IF_NONE_MATCH, c.etag.as_str());
+        }
+
+        let response = request
+            .send()
             .await
             .with_context(|| format!("Failed to fetch manifest from {}", self.manifest_url))?;
 
-        if !response.status().is_success() {
-            return Err(anyhow::anyhow!(
-                "Manifest fetch failed: HTTP {}",
-                response.status()
-            ));
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(c) = cached {
+                tracing::debug!("Extension manifest not modified (ETag {})", c.etag);
+                return Ok(c.extensions);
+            }
         }
+        if status != reqwest::StatusCode::OK {
+            return Err(ExtensionError::registry_http_error(&self.manifest_url, status.as_u16()).into());
+        }
+
+        let etag = response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
 
-        let manifest_resp: ExtensionManifestResponse = response
-            .json()
+        let body = response
+            .text()
             .await
-            .context("Failed to parse manifest response")?;
+            .with_context(|| format!("Failed to read manifest response from {}", self.manifest_url))?;
+        let manifest_resp: ExtensionManifestResponse = serde_json::from_str(&body)
+            .map_err(|e| ExtensionError::registry_parse_error(&self.manifest_url, &e.to_string()))?;
+
+        if manifest_resp.schema_version > SUPPORTED_MANIFEST_SCHEMA_VERSION {
+            return Err(ExtensionError::registry_schema_unsupported(
+                &self.manifest_url,
+                manifest_resp.schema_version,
+            ).into());
+        }
 
         let list = manifest_resp.into_list();
         tracing::info!("Fetched {} extension(s) from manifest", list.len());
+
+        let mut cache = self.manifest_cache.lock().unwrap_or_else(|e| e.into_inner());
+        *cache = etag.map(|etag| ManifestCache {
+            url: self.manifest_url.clone(),
+            etag,
+            extensions: list.clone(),
+        });
+
         Ok(list)
     }
 
@@ -1414,6 +1805,145 @@ impl ExtensionManager {
         updates
     }
 
+    /// 매니페스트의 `min_app_version`이 `core_version`으로 충족되는지 검사
+    fn check_min_app_version(manifest: &ExtensionManifest, core_version: &str) -> Result<()> {
+        let Some(required) = manifest.min_app_version.as_deref() else {
+            return Ok(());
+        };
+        if Self::version_satisfies(core_version, required) {
+            return Ok(());
+        }
+        Err(ExtensionError::app_version_too_old(&manifest.id, required, core_version).into())
+    }
+
+    /// 버전 요구사항 검사 — `*`, `>=1.0.0`, `>1.0.0`, `<=2.0.0`, `<2.0.0`, `=1.2.3`과
+    /// 쉼표로 묶은 범위(`>=1.0.0, <2.0.0`)를 지원합니다.
+    /// 연산자 없는 버전은 기존 동작대로 최소 버전(`>=`)으로 취급합니다.
+    pub fn version_satisfies(version: &str, requirement: &str) -> bool {
+        requirement.split(',').map(str::trim).all(|part| {
+            if part.is_empty() || part == "*" {
+                return true;
+            }
+            let (op, required) = [">=", "<=", ">", "<", "="]
+                .iter()
+                .find_map(|op| part.strip_prefix(op).map(|rest| (*op, rest.trim())))
+                .unwrap_or((">=", part));
+            let (Some(v), Some(r)) = (SemVer::parse(version), SemVer::parse(required)) else {
+                return false;
+            };
+            match op {
+                ">=" => v >= r,
+                "<=" => v <= r,
+                ">" => v > r,
+                "<" => v < r,
+                _ => v == r,
+            }
+        })
+    }
+
+    /// 원격 매니페스트 기준으로 업데이트 가능한 모든 익스텐션을 의존성 순서대로 설치합니다.
+    ///
+    /// - 업데이트 대상끼리는 의존 대상이 먼저 설치되도록 정렬 (순환 의존은 `dependency_cycle`)
+    /// - 원격 항목이 요구하는 익스텐션 버전이 (앞선 업데이트 후에도) 충족되지 않으면 스킵
+    /// - 새 버전이 활성 익스텐션의 버전 요구사항을 깨면 `update_breaks_dependent`로 스킵
+    /// - 설치에 성공한 익스텐션은 즉시 다시 마운트
+    ///
+    /// 반환: 업데이트 대상별 (ext_id, 결과) — 처리 순서대로
+    pub async fn update_all(&mut self, remote: &[RemoteExtensionInfo]) -> Vec<(String, Result<()>)> {
+        let updates = self.check_updates_against(remote);
+        let targets: HashMap<&str, &RemoteExtensionInfo> = updates
+            .iter()
+            .filter_map(|u| remote.iter().find(|r| r.id == u.id))
+            .map(|r| (r.id.as_str(), r))
+            .collect();
+        let (order, cyclic) = Self::update_order(&targets);
+
+        let mut results = Vec::new();
+        for id in order {
+            let result = self.update_one(targets[id.as_str()]).await;
+            match &result {
+                Ok(()) => tracing::info!("Extension '{}' updated to {}", id, targets[id.as_str()].version),
+                Err(e) => tracing::warn!("Extension '{}' update skipped/failed: {}", id, e),
+            }
+            results.push((id, result));
+        }
+        for id in &cyclic {
+            tracing::warn!("Extension '{}' update skipped: circular dependency", id);
+            results.push((id.clone(), Err(ExtensionError::dependency_cycle(id, &cyclic).into())));
+        }
+        results
+    }
+
+    /// 업데이트 대상의 설치 순서 (의존 대상 우선, 같은 단계는 ID순) — 순환에 걸린 ID는 따로 반환
+    fn update_order(targets: &HashMap<&str, &RemoteExtensionInfo>) -> (Vec<String>, Vec<String>) {
+        let mut pending: std::collections::BTreeMap<&str, HashSet<&str>> = targets
+            .iter()
+            .map(|(id, info)| {
+                let deps = info
+                    .dependencies
+                    .keys()
+                    .map(String::as_str)
+                    .filter(|dep| dep != id && targets.contains_key(dep))
+                    .collect();
+                (*id, deps)
+            })
+            .collect();
+
+        let mut order = Vec::new();
+        loop {
+            let ready: Vec<&str> = pending
+                .iter()
+                .filter(|(_, deps)| deps.is_empty())
+                .map(|(id, _)| *id)
+                .collect();
+            if ready.is_empty() {
+                break;
+            }
+            for id in ready {
+                pending.remove(id);
+                for deps in pending.values_mut() {
+                    deps.remove(id);
+                }
+                order.push(id.to_string());
+            }
+        }
+        (order, pending.into_keys().map(str::to_string).collect())
+    }
+
+    /// 단일 익스텐션 업데이트 — 의존성 검사 후 설치 및 재마운트
+    async fn update_one(&mut self, info: &RemoteExtensionInfo) -> Result<()> {
+        // 새 버전이 요구하는 익스텐션 의존성 (비-익스텐션 컴포넌트는 enable 시 검증)
+        for (dep, required) in &info.dependencies {
+            if let Some(dep_ext) = self.discovered.get(dep) {
+                if !Self::version_satisfies(&dep_ext.manifest.version, required) {
+                    return Err(ExtensionError::update_dependency_unsatisfied(
+                        &info.id, dep, required, &dep_ext.manifest.version,
+                    ).into());
+                }
+            }
+        }
+
+        // 이 익스텐션에 의존하는 활성 익스텐션의 버전 요구사항
+        for ext in self.discovered.values() {
+            if ext.manifest.id == info.id || !self.enabled.contains(&ext.manifest.id) {
+                continue;
+            }
+            if let Some(required) = ext.manifest.dependencies.get(&info.id) {
+                if !Self::version_satisfies(&info.version, required) {
+                    return Err(ExtensionError::update_breaks_dependent(
+                        &info.id, &info.version, &ext.manifest.id, required,
+                    ).into());
+                }
+            }
+        }
+
+        let installed = self
+            .install_from_url(&info.id, &info.download_url, info.sha256.as_deref())
+            .await?;
+        self.mount_installed(installed);
+        Ok(())
+    }
+
     /// 버전 문자열 비교 (updater 크레이트의 SemVer를 사용, 폴백 포함)
     ///
     /// 기존 업데이터와 동일한 `SemVer` 타입을 사용하여 동작을 보장합니다.
@@ -1427,18 +1957,38 @@ impl ExtensionManager {
     /// 원격에서 zip을 다운로드하여 extensions/ 폴더에 설치합니다.
     ///
     /// `download_url`에서 zip 파일을 받아 `extensions/{ext_id}/`에 압축을 풉니다.
-    /// `expected_sha256`가 주어지면 다운로드 후 무결성을 검증합니다 (미구현, v0.2 예정).
+    /// 본문은 임시 파일로 스트리밍하면서 SHA-256을 계산하므로 아카이브 전체를 메모리에 올리지 않습니다.
+    /// `expected_sha256`가 주어지면 압축 해제 전에 비교하고,
+    /// 다르면 `checksum_mismatch` 에러로 중단합니다.
+    ///
+    /// 아카이브 루트의 `manifest.json` id가 `ext_id`와 다르면 `id_mismatch`로 거부하며,
+    /// 성공 시 설치된 익스텐션을 반환하므로 `mount_installed`로 바로 마운트할 수 있습니다.
     pub async fn install_from_url(
         &self,
         ext_id: &str,
         download_url: &str,
-        _expected_sha256: Option<&str>,
-    ) -> Result<()> {
+        expected_sha256: Option<&str>,
+    ) -> Result<DiscoveredExtension> {
+        self.install_from_url_with_progress(ext_id, download_url, expected_sha256, &|_| {})
+            .await
+    }
+
+    /// `install_from_url`과 같지만 진행 상태를 `on_progress`로 보고합니다.
+    ///
+    /// 다운로드 중에는 청크마다 `Downloading`(수신/전체 바이트)을 보내고,
+    /// 이후 `Verifying` → `Extracting` → `Done` 순서로 단계 전환을 알립니다.
+    /// 실패하면 해당 단계에서 멈추고 `Done`은 전달되지 않습니다.
+    pub async fn install_from_url_with_progress(
+        &self,
+        ext_id: &str,
+        download_url: &str,
+        expected_sha256: Option<&str>,
+        on_progress: &(dyn Fn(InstallProgress) + Send + Sync),
+    ) -> Result<DiscoveredExtension> {
         tracing::info!("Installing extension '{}' from {}", ext_id, download_url);
 
-        // sha256 검증은 v0.2에서 sha2 크레이트 도입 후 구현 예정
         // 다운로드
-        let response = reqwest::get(download_url)
+        let mut response = reqwest::get(download_url)
             .await
             .with_context(|| format!("Failed to download extension from {}", download_url))?;
 
@@ -1449,15 +1999,67 @@ impl ExtensionManager {
             ));
         }
 
-        let bytes = response
-            .bytes()
+        let total_bytes = response.content_length().unwrap_or(0);
+        let mut bytes_received = 0u64;
+        let report = |phase: InstallPhase, bytes_received: u64| {
+            on_progress(InstallProgress {
+                ext_id: ext_id.to_string(),
+                phase,
+                bytes_received,
+                total_bytes,
+            });
+        };
+        report(InstallPhase::Downloading, 0);
+
+        // 청크 단위로 임시 파일에 기록하며 해시 계산 — 임시 파일은 drop 시 삭제됨
+        use sha2::{Digest, Sha256};
+        let mut staged = tempfile::tempfile().context("Failed to create temporary download file")?;
+        let mut hasher = Sha256::new();
+        while let Some(chunk) = response
+            .chunk()
             .await
-            .context("Failed to read download response body")?;
+            .context("Failed to read download response body")?
+        {
+            hasher.update(&chunk);
+            std::io::Write::write_all(&mut staged, &chunk)
+                .context("Failed to write temporary download file")?;
+            bytes_received += chunk.len() as u64;
+            report(InstallPhase::Downloading, bytes_received);
+        }
 
-        // 임시 zip 파일로 저장
-        let zip_path = self.extensions_dir.join(format!("{}.zip", ext_id));
-        std::fs::write(&zip_path, &bytes)
-            .with_context(|| format!("Failed to write download to {}", zip_path.display()))?;
+        // 무결성 검증 — 검증 전에는 extensions/에 아무것도 쓰지 않음
+        report(InstallPhase::Verifying, bytes_received);
+        match expected_sha256 {
+            Some(expected) => {
+                let actual = hex::encode(hasher.finalize());
+                if !actual.eq_ignore_ascii_case(expected.trim()) {
+                    return Err(ExtensionError::checksum_mismatch(ext_id, expected, &actual).into());
+                }
+                tracing::debug!("Extension '{}' checksum verified: {}", ext_id, actual);
+            }
+            None => {
+                tracing::warn!(
+                    "Installing extension '{}' without sha256 — download is unverified",
+                    ext_id
+                );
+            }
+        }
+
+        // 아카이브 안전성 검증 — 기존 설치를 지우기 전에 수행
+        report(InstallPhase::Extracting, bytes_received);
+        std::io::Seek::rewind(&mut staged).context("Failed to rewind temporary download file")?;
+        let mut archive = zip::ZipArchive::new(std::io::BufReader::new(staged))
+            .context("Failed to read downloaded zip archive")?;
+        Self::ensure_safe_archive(&mut archive, download_url)?;
+
+        // id/디렉토리 일관성 검증 (mount와 같은 보장) — 기존 설치를 지우기 전에 수행
+        let manifest = Self::read_zip_manifest(&mut archive, download_url)?.ok_or_else(|| {
+            ExtensionError::manifest_not_found(&format!("{}!manifest.json", download_url))
+        })?;
+        if manifest.id != ext_id || !Self::is_path_safe_id(ext_id) {
+            return Err(ExtensionError::id_mismatch(&manifest.id, ext_id).into());
+        }
+        Self::check_min_app_version(&manifest, env!("CARGO_PKG_VERSION"))?;
 
         // 압축 해제: Python import와 호환되는 디렉토리명 사용 (하이픈→언더스코어)
         // 기존 폴더가 있으면 먼저 제거 (하이픈/언더스코어 버전 모두 확인)
@@ -1468,33 +2070,11 @@ impl ExtensionManager {
                 .with_context(|| format!("Failed to remove existing extension dir: {}", dest.display()))?;
         }
 
+        Self::extract_archive(&mut archive, &dest, download_url)?;
 
-        let file = std::fs::File::open(&zip_path)?;
-        let mut archive = zip::ZipArchive::new(file)
-            .context("Failed to read downloaded zip archive")?;
-
-        for i in 0..archive.len() {
-            let mut zip_file = archive.by_index(i)?;
-            let outpath = match zip_file.enclosed_name() {
-                Some(p) => dest.join(p),
-                None => continue,
-            };
-            if zip_file.is_dir() {
-                std::fs::create_dir_all(&outpath)?;
-            } else {
-                if let Some(parent) = outpath.parent() {
-                    std::fs::create_dir_all(parent)?;
-                }
-                let mut outfile = std::fs::File::create(&outpath)?;
-                std::io::copy(&mut zip_file, &mut outfile)?;
-            }
-        }
-
-        // 임시 zip 삭제
-        let _ = std::fs::remove_file(&zip_path);
-
+        report(InstallPhase::Done, bytes_received);
         tracing::info!("Extension '{}' installed successfully", ext_id);
-        Ok(())
+        Ok(DiscoveredExtension { manifest, dir: dest })
     }
 
     /// i18n JSON 로드
@@ -1603,8 +2183,16 @@ impl ExtensionManager {
         }
     }
 
-    /// enabled 목록 영속화
+    /// 강제 비활성화 사유 파일 경로 (state 파일과 같은 디렉토리)
+    ///
+    /// state 파일은 Python 플러그인 러너도 문자열 배열로 읽으므로 형식을 바꾸지 않고 별도 파일을 둔다.
+    fn disabled_reason_path(&self) -> PathBuf {
+        self.state_path.with_file_name("extensions_disabled_reasons.json")
+    }
+
+    /// enabled 목록 + 강제 비활성화 사유 영속화
     fn save_state(&self) {
+        self.save_disabled_reasons();
         let enabled_list: Vec<&str> = self.enabled.iter().map(|s| s.as_str()).collect();
         let json = match serde_json::to_string_pretty(&enabled_list) {
             Ok(j) => j,
@@ -1620,50 +2208,360 @@ impl ExtensionManager {
             }
         }
 
-        if let Err(e) = std::fs::write(&self.state_path, json) {
-            tracing::error!(
-                "Failed to save extension state to {}: {}",
-                self.state_path.display(),
-                e
-            );
-        }
-    }
+        if let Err(e) = std::fs::write(&self.state_path, json) {
+            tracing::error!(
+                "Failed to save extension state to {}: {}",
+                self.state_path.display(),
+                e
+            );
+        }
+    }
+
+    fn save_disabled_reasons(&self) {
+        let path = self.disabled_reason_path();
+        if self.disabled_reason.is_empty() {
+            if path.exists() {
+                if let Err(e) = std::fs::remove_file(&path) {
+                    tracing::warn!("Failed to remove {}: {}", path.display(), e);
+                }
+            }
+            return;
+        }
+        let result = serde_json::to_string_pretty(&self.disabled_reason)
+            .map_err(anyhow::Error::from)
+            .and_then(|json| {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(&path, json).map_err(anyhow::Error::from)
+            });
+        if let Err(e) = result {
+            tracing::error!("Failed to save extension disabled reasons to {}: {}", path.display(), e);
+        }
+    }
+
+    /// 저장된 enabled 목록 + 강제 비활성화 사유 로드
+    fn load_state(&mut self) {
+        let reason_path = self.disabled_reason_path();
+        if let Ok(content) = std::fs::read_to_string(&reason_path) {
+            match serde_json::from_str::<HashMap<String, String>>(&content) {
+                Ok(reasons) => self.disabled_reason = reasons,
+                Err(e) => tracing::warn!(
+                    "Failed to parse extension disabled reasons {}: {}",
+                    reason_path.display(),
+                    e
+                ),
+            }
+        }
+
+        if !self.state_path.exists() {
+            return;
+        }
+        match std::fs::read_to_string(&self.state_path) {
+            Ok(content) => {
+                match serde_json::from_str::<Vec<String>>(&content) {
+                    Ok(list) => {
+                        self.enabled = list.into_iter().collect();
+                        tracing::info!(
+                            "Loaded extension state: {} enabled",
+                            self.enabled.len()
+                        );
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            "Failed to parse extension state {}: {}",
+                            self.state_path.display(),
+                            e
+                        );
+                    }
+                }
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to read extension state {}: {}",
+                    self.state_path.display(),
+                    e
+                );
+            }
+        }
+    }
+}
+// ═══════════════════════════════════════════════════════════════
+//  Hook 스냅샷
+// ═══════════════════════════════════════════════════════════════
+
+/// 스냅샷에 고정된 단일 hook 대상
+#[derive(Debug, Clone)]
+struct HookTarget {
+    ext_id: String,
+    /// `extension_data` 타입 검증용 선언
+    instance_fields: HashMap<String, FieldDef>,
+    binding: HookBinding,
+    /// 해석된 Python 모듈 절대 경로 (`python_modules`에 키가 없으면 None)
+    module_path: Option<String>,
+}
+
+/// `ExtensionManager::snapshot_hooks`로 고정한 hook 실행 계획.
+///
+/// 매니저 상태를 참조하지 않으므로 잠금 없이 디스패치할 수 있고,
+/// 실행 도중 익스텐션이 rescan/비활성화되어도 대상 목록이 바뀌지 않습니다.
+#[derive(Debug, Clone)]
+pub struct HookSnapshot {
+    hook_name: String,
+    targets: Vec<HookTarget>,
+    /// 스냅샷 시점의 extensionConfig (context에 자동 주입)
+    extension_config: Value,
+}
+
+impl HookSnapshot {
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// context에 `extension_data`가 있으면 대상 익스텐션의 `instance_fields` 타입을 검증
+    fn check_instance_data(
+        target: &HookTarget,
+        context: &Value,
+        ext_data: &HashMap<String, Value>,
+    ) -> Result<()> {
+        if !context.get("extension_data").is_some_and(Value::is_object) {
+            return Ok(());
+        }
+        let errors = validate_fields(&target.instance_fields, ext_data);
+        if errors.is_empty() {
+            return Ok(());
+        }
+        Err(ExtensionError::invalid_instance_data(&target.ext_id, &errors).into())
+    }
+
+    /// hook context에 extension_config를 주입합니다.
+    /// 이미 존재하지 않는 경우에만 추가합니다.
+    fn inject_extension_config(&self, mut context: Value) -> Value {
+        if let Some(obj) = context.as_object_mut() {
+            if !obj.contains_key("extension_config") {
+                obj.insert("extension_config".to_string(), self.extension_config.clone());
+            }
+        }
+        context
+    }
+
+    /// 기본 타임아웃으로 디스패치 (`ExtensionManager::dispatch_hook` 참고)
+    pub async fn dispatch(&self, context: Value) -> Vec<(String, Result<Value>)> {
+        self.dispatch_timed(context, crate::plugin::DEFAULT_PLUGIN_TIMEOUT_SECS).await
+    }
+
+    /// 타임아웃을 지정해 디스패치 — 조건 평가 → run_plugin 호출 → handled 체크
+    ///
+    /// 바인딩에 `timeout_secs`가 있으면 그 값을, 없으면 `timeout_secs` 인자를 사용합니다.
+    pub async fn dispatch_timed(
+        &self,
+        context: Value,
+        timeout_secs: u64,
+    ) -> Vec<(String, Result<Value>)> {
+        if self.targets.is_empty() {
+            return Vec::new();
+        }
+        let hook_name = self.hook_name.as_str();
+
+        // extension_config를 context에 자동 주입
+        let context = self.inject_extension_config(context);
+
+        let ext_data: HashMap<String, Value> = context
+            .get("extension_data")
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .unwrap_or_default();
+
+        let mut results = Vec::new();
+
+        for target in &self.targets {
+            let binding = &target.binding;
+            // 조건 평가
+            if let Some(ref cond) = binding.condition {
+                if !ExtensionManager::evaluate_condition(cond, &ext_data) {
+                    continue;
+                }
+            }
+
+            // extension_data 타입 검증 — 불일치는 Python 실행 전에 에러로 보고
+            if let Err(e) = Self::check_instance_data(target, &context, &ext_data) {
+                tracing::warn!("Hook '{}' ext '{}' skipped: {}", hook_name, target.ext_id, e);
+                results.push((target.ext_id.clone(), Err(e)));
+                continue;
+            }
+
+            // Python 모듈 절대 경로 (스냅샷 시점에 해석됨)
+            let module_path = match &target.module_path {
+                Some(path) => path,
+                None => {
+                    tracing::error!(
+                        "Extension '{}' hook '{}' references unknown module '{}'",
+                        target.ext_id,
+                        hook_name,
+                        binding.module
+                    );
+                    results.push((
+                        target.ext_id.clone(),
+                        Err(anyhow::anyhow!(
+                            "Unknown python module: {}",
+                            binding.module
+                        )),
+                    ));
+                    continue;
+                }
+            };
+
+            tracing::debug!(
+                "Dispatching hook '{}' → ext '{}' → {}::{}",
+                hook_name,
+                target.ext_id,
+                binding.module,
+                binding.function
+            );
+
+            let result = crate::plugin::run_plugin_with_timeout(
+                module_path,
+                &binding.function,
+                context.clone(),
+                binding.timeout_secs.unwrap_or(timeout_secs),
+            )
+            .await;
+
+            match &result {
+                Ok(val) => {
+                    tracing::debug!(
+                        "Hook '{}' ext '{}' returned: {}",
+                        hook_name,
+                        target.ext_id,
+                        serde_json::to_string(val).unwrap_or_default()
+                    );
+                    results.push((target.ext_id.clone(), Ok(val.clone())));
+
+                    // handled=true → chain 종료
+                    if val
+                        .get("handled")
+                        .and_then(|h| h.as_bool())
+                        == Some(true)
+                    {
+                        tracing::debug!(
+                            "Hook '{}' handled by extension '{}', skipping remaining",
+                            hook_name,
+                            target.ext_id
+                        );
+                        break;
+                    }
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "Hook '{}' ext '{}' failed: {}",
+                        hook_name,
+                        target.ext_id,
+                        e
+                    );
+                    results.push((
+                        target.ext_id.clone(),
+                        Err(anyhow::anyhow!("Hook dispatch failed: {}", e)),
+                    ));
+                    // 에러 시 graceful degradation — 기본 동작 진행을 위해 계속
+                }
+            }
+        }
+
+        results
+    }
+
+    /// 진행률 콜백과 함께 디스패치 (server.post_create 등 장시간 hook용)
+    ///
+    /// 콜백은 `Arc`로 공유되어 매칭되는 모든 익스텐션 호출에 전달되며,
+    /// `dispatch_timed`와 마찬가지로 `handled=true`가 나오면 이후 익스텐션은 스킵합니다.
+    pub async fn dispatch_with_progress<F>(
+        &self,
+        context: Value,
+        on_progress: F,
+    ) -> Vec<(String, Result<Value>)>
+    where
+        F: Fn(crate::plugin::ExtensionProgress) + Send + Sync + 'static,
+    {
+        if self.targets.is_empty() {
+            return Vec::new();
+        }
+        let hook_name = self.hook_name.as_str();
+
+        // extension_config를 context에 자동 주입
+        let context = self.inject_extension_config(context);
+
+        let ext_data: HashMap<String, Value> = context
+            .get("extension_data")
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .unwrap_or_default();
+
+        tracing::info!("dispatch_hook_with_progress('{}') — {} hook(s), ext_data keys: {:?}", hook_name, self.targets.len(), ext_data.keys().collect::<Vec<_>>());
+
+        let on_progress = std::sync::Arc::new(on_progress);
+        let mut results = Vec::new();
+
+        for target in &self.targets {
+            let binding = &target.binding;
+            if let Some(ref cond) = binding.condition {
+                if !ExtensionManager::evaluate_condition(cond, &ext_data) {
+                    tracing::warn!("Hook '{}' from '{}' skipped: condition '{}' evaluated to false (ext_data: {:?})", hook_name, target.ext_id, cond, ext_data);
+                    continue;
+                }
+            }
+
+            if let Err(e) = Self::check_instance_data(target, &context, &ext_data) {
+                tracing::warn!("Hook '{}' ext '{}' skipped: {}", hook_name, target.ext_id, e);
+                results.push((target.ext_id.clone(), Err(e)));
+                continue;
+            }
+
+            let module_path = match &target.module_path {
+                Some(path) => path,
+                None => {
+                    results.push((
+                        target.ext_id.clone(),
+                        Err(anyhow::anyhow!(
+                            "Unknown python module: {}",
+                            binding.module
+                        )),
+                    ));
+                    continue;
+                }
+            };
+
+            let on_progress = on_progress.clone();
+            let result = crate::plugin::run_plugin_with_progress_and_timeout(
+                module_path,
+                &binding.function,
+                context.clone(),
+                move |prog| on_progress(prog),
+                binding.timeout_secs.unwrap_or(PROGRESS_HOOK_TIMEOUT_SECS),
+            )
+            .await;
 
-    /// 저장된 enabled 목록 로드
-    fn load_state(&mut self) {
-        if !self.state_path.exists() {
-            return;
-        }
-        match std::fs::read_to_string(&self.state_path) {
-            Ok(content) => {
-                match serde_json::from_str::<Vec<String>>(&content) {
-                    Ok(list) => {
-                        self.enabled = list.into_iter().collect();
-                        tracing::info!(
-                            "Loaded extension state: {} enabled",
-                            self.enabled.len()
-                        );
-                    }
-                    Err(e) => {
-                        tracing::warn!(
-                            "Failed to parse extension state {}: {}",
-                            self.state_path.display(),
-                            e
-                        );
+            match &result {
+                Ok(val) => {
+                    results.push((target.ext_id.clone(), Ok(val.clone())));
+                    // handled=true → chain 종료
+                    if val.get("handled").and_then(|h| h.as_bool()) == Some(true) {
+                        break;
                     }
                 }
-            }
-            Err(e) => {
-                tracing::warn!(
-                    "Failed to read extension state {}: {}",
-                    self.state_path.display(),
-                    e
-                );
+                Err(e) => {
+                    tracing::error!("Hook '{}' ext '{}' failed: {}", hook_name, target.ext_id, e);
+                    results.push((
+                        target.ext_id.clone(),
+                        Err(anyhow::anyhow!("Hook dispatch failed: {}", e)),
+                    ));
+                    // 에러 시 graceful degradation — 다음 익스텐션 계속
+                }
             }
         }
+
+        results
     }
 }
 
+
 // ═══════════════════════════════════════════════════════════════
 //  Tests
 // ═══════════════════════════════════════════════════════════════
@@ -1717,6 +2615,97 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_evaluate_condition_equality() {
+        let ext_data: HashMap<String, Value> = serde_json::from_value(json!({
+            "docker_enabled": false,
+            "network": "bridge",
+            "cpu_limit": 2,
+        }))
+        .unwrap();
+        let eval = |c: &str| ExtensionManager::evaluate_condition(c, &ext_data);
+
+        assert!(eval("instance.ext_data.docker_enabled == false"));
+        assert!(!eval("instance.ext_data.docker_enabled == true"));
+        // bool 비교는 truthiness 기준 — 키가 없으면 false와 같음
+        assert!(eval("instance.ext_data.missing == false"));
+        assert!(eval("instance.ext_data.network == \"bridge\""));
+        assert!(eval("instance.ext_data.network == bridge"));
+        assert!(eval("instance.ext_data.cpu_limit == 2.0"));
+        assert!(eval("instance.ext_data.missing == null"));
+    }
+
+    #[test]
+    fn test_evaluate_condition_inequality() {
+        let ext_data: HashMap<String, Value> = serde_json::from_value(json!({
+            "network": "bridge",
+            "cpu_limit": 2,
+        }))
+        .unwrap();
+        let eval = |c: &str| ExtensionManager::evaluate_condition(c, &ext_data);
+
+        assert!(eval("instance.ext_data.network != host"));
+        assert!(!eval("instance.ext_data.network != 'bridge'"));
+        assert!(!eval("instance.ext_data.cpu_limit != 2"));
+        assert!(eval("instance.ext_data.missing != 2"));
+    }
+
+    #[test]
+    fn test_evaluate_condition_greater_than() {
+        let ext_data: HashMap<String, Value> = serde_json::from_value(json!({
+            "cpu_limit": 4,
+            "memory": "2048",
+            "network": "bridge",
+        }))
+        .unwrap();
+        let eval = |c: &str| ExtensionManager::evaluate_condition(c, &ext_data);
+
+        assert!(eval("instance.ext_data.cpu_limit > 2"));
+        assert!(!eval("instance.ext_data.cpu_limit > 4"));
+        assert!(eval("instance.ext_data.memory>1024"));
+        // 수가 아닌 값/없는 키는 비교 불가 → false
+        assert!(!eval("instance.ext_data.network > 1"));
+        assert!(!eval("instance.ext_data.missing > 0"));
+    }
+
+    #[test]
+    fn test_evaluate_condition_less_than() {
+        let ext_data: HashMap<String, Value> = serde_json::from_value(json!({ "cpu_limit": 1.5 })).unwrap();
+        let eval = |c: &str| ExtensionManager::evaluate_condition(c, &ext_data);
+
+        assert!(eval("instance.ext_data.cpu_limit < 2"));
+        assert!(!eval("instance.ext_data.cpu_limit < 1.5"));
+        assert!(!eval("instance.ext_data.missing < 10"));
+    }
+
+    #[test]
+    fn test_evaluate_condition_negation() {
+        let ext_data: HashMap<String, Value> = serde_json::from_value(json!({
+            "docker_enabled": true,
+            "cpu_limit": 4,
+        }))
+        .unwrap();
+        let eval = |c: &str| ExtensionManager::evaluate_condition(c, &ext_data);
+
+        assert!(!eval("!instance.ext_data.docker_enabled"));
+        assert!(eval("! instance.ext_data.missing"));
+        assert!(eval("!instance.ext_data.cpu_limit < 2"));
+        assert!(eval("!!instance.ext_data.docker_enabled"));
+        // 문법 오류는 부정해도 false
+        assert!(!eval("!some.other.pattern"));
+    }
+
+    #[test]
+    fn test_evaluate_condition_malformed_is_false() {
+        let ext_data: HashMap<String, Value> = serde_json::from_value(json!({ "cpu_limit": 4 })).unwrap();
+        let eval = |c: &str| ExtensionManager::evaluate_condition(c, &ext_data);
+
+        assert!(!eval("instance.ext_data.cpu_limit >"));
+        assert!(!eval("instance.ext_data. > 1"));
+        assert!(!eval("instance.ext_data.cpu limit > 1"));
+        assert!(!eval("other.cpu_limit > 1"));
+    }
+
     #[test]
     fn test_manifest_deserialization() {
         let json = json!({
@@ -2027,8 +3016,94 @@ mod tests {
         mgr.enable("test_ext").unwrap();
         assert!(mgr.is_enabled("test_ext"));
 
-        mgr.force_disable("test_ext");
+        mgr.force_disable("test_ext", "dependency 'steamcmd' removed");
         assert!(!mgr.is_enabled("test_ext"));
+        assert_eq!(
+            mgr.list()[0].disabled_reason.as_deref(),
+            Some("dependency 'steamcmd' removed")
+        );
+
+        // 재시작 후에도 사유 유지
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+        assert_eq!(mgr.disabled_reason("test_ext"), Some("dependency 'steamcmd' removed"));
+
+        // 다시 활성화하면 사유 제거
+        mgr.enable("test_ext").unwrap();
+        assert_eq!(mgr.list()[0].disabled_reason, None);
+        let mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        assert_eq!(mgr.disabled_reason("test_ext"), None);
+    }
+
+    /// 의존 익스텐션이 디스크에서 삭제되면 다음 discover에서 의존하는 쪽을 사유와 함께 비활성화
+    #[test]
+    fn test_removed_dependency_force_disables_dependents() {
+        let tmp = tempfile::tempdir().unwrap();
+        let write = |id: &str, manifest: Value| {
+            let dir = tmp.path().join(id);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join("manifest.json"), manifest.to_string()).unwrap();
+        };
+        write("steamcmd", json!({ "id": "steamcmd", "name": "SteamCMD", "version": "1.0.0" }));
+        write("docker", json!({
+            "id": "docker", "name": "Docker", "version": "1.0.0", "dependencies": ["steamcmd"]
+        }));
+        write("compose", json!({
+            "id": "compose", "name": "Compose", "version": "1.0.0", "dependencies": { "docker": ">=1.0.0" }
+        }));
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+        for id in ["steamcmd", "docker", "compose"] {
+            mgr.enable(id).unwrap();
+        }
+
+        // 재시작 전에 의존 대상 폴더가 지워진 상황 (수동 삭제/마이그레이션)
+        std::fs::remove_dir_all(tmp.path().join("steamcmd")).unwrap();
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+
+        assert!(!mgr.is_enabled("docker"));
+        assert_eq!(mgr.disabled_reason("docker"), Some("dependency 'steamcmd' removed"));
+        // 연쇄 비활성화
+        assert!(!mgr.is_enabled("compose"));
+        assert_eq!(mgr.disabled_reason("compose"), Some("dependency 'docker' disabled"));
+
+        // 사유는 영속화되어 재시작 후에도 노출
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+        let docker = mgr.list().into_iter().find(|e| e.id == "docker").unwrap();
+        assert_eq!(docker.disabled_reason.as_deref(), Some("dependency 'steamcmd' removed"));
+    }
+
+    /// 의존 익스텐션이 요구 버전을 벗어난 버전으로 교체되면 rescan에서 비활성화
+    #[test]
+    fn test_migrated_dependency_version_force_disables_dependents() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base_dir = tmp.path().join("base");
+        let addon_dir = tmp.path().join("addon");
+        std::fs::create_dir_all(&base_dir).unwrap();
+        std::fs::create_dir_all(&addon_dir).unwrap();
+        std::fs::write(base_dir.join("manifest.json"), json!({
+            "id": "base", "name": "Base", "version": "1.2.0"
+        }).to_string()).unwrap();
+        std::fs::write(addon_dir.join("manifest.json"), json!({
+            "id": "addon", "name": "Addon", "version": "1.0.0", "dependencies": { "base": ">=1.0.0, <2.0.0" }
+        }).to_string()).unwrap();
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+        mgr.enable("base").unwrap();
+        mgr.enable("addon").unwrap();
+
+        std::fs::write(base_dir.join("manifest.json"), json!({
+            "id": "base", "name": "Base", "version": "2.0.0"
+        }).to_string()).unwrap();
+        mgr.rescan().unwrap();
+
+        assert!(mgr.is_enabled("base"));
+        assert!(!mgr.is_enabled("addon"));
+        assert_eq!(mgr.disabled_reason("addon"), Some("dependency 'base' 2.0.0 no longer satisfies >=1.0.0, <2.0.0"));
     }
 
     // ═══════════════════════════════════════════════════════════════
@@ -2087,7 +3162,8 @@ mod tests {
                 },
                 "server.post_stop": {
                     "module": "compose_manager",
-                    "function": "post_stop"
+                    "function": "post_stop",
+                    "timeout_secs": 300
                 }
             },
             "instance_fields": {
@@ -2115,6 +3191,8 @@ mod tests {
         assert_eq!(manifest.hooks.len(), 2);
         assert!(manifest.hooks.contains_key("server.pre_start"));
         assert!(manifest.hooks.contains_key("server.post_stop"));
+        assert_eq!(manifest.hooks["server.pre_start"].timeout_secs, None);
+        assert_eq!(manifest.hooks["server.post_stop"].timeout_secs, Some(300));
         assert_eq!(manifest.python_modules.len(), 2);
         assert_eq!(manifest.instance_fields.len(), 2);
         assert!(manifest.gui.is_some());
@@ -2188,98 +3266,331 @@ mod tests {
 
         let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
         mgr.discover().unwrap();
-        assert_eq!(mgr.list().len(), 1);
+        assert_eq!(mgr.list().len(), 1);
+
+        let no_instances: Vec<(&str, &HashMap<String, Value>)> = vec![];
+        mgr.remove("removable", &no_instances).unwrap();
+        assert!(mgr.list().is_empty());
+        assert!(!ext_dir.exists(), "Extension directory should be deleted");
+    }
+
+    /// 삭제 — 의존하는 익스텐션이 있으면 실패
+    #[test]
+    fn test_remove_blocked_by_active_dependent() {
+        let tmp = tempfile::tempdir().unwrap();
+        let parent_dir = tmp.path().join("parent");
+        std::fs::create_dir_all(&parent_dir).unwrap();
+        std::fs::write(parent_dir.join("manifest.json"),
+            r#"{"id":"parent","name":"Parent","version":"0.1.0"}"#).unwrap();
+
+        let child_dir = tmp.path().join("child");
+        std::fs::create_dir_all(&child_dir).unwrap();
+        std::fs::write(child_dir.join("manifest.json"),
+            r#"{"id":"child","name":"Child","version":"0.1.0","dependencies":["parent"]}"#).unwrap();
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+        mgr.enable("parent").unwrap();
+        mgr.enable("child").unwrap();
+
+        let no_instances: Vec<(&str, &HashMap<String, Value>)> = vec![];
+        let result = mgr.remove("parent", &no_instances);
+        assert!(result.is_err(), "Cannot remove parent while child depends on it");
+    }
+
+    /// list() 결과 검증 — enabled 상태, hooks, instance_fields 정확히 반영
+    #[test]
+    fn test_list_reflects_extension_state() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ext_dir = tmp.path().join("ext_a");
+        std::fs::create_dir_all(&ext_dir).unwrap();
+        std::fs::write(ext_dir.join("manifest.json"), json!({
+            "id": "ext_a",
+            "name": "Extension A",
+            "version": "1.2.3",
+            "description": "Test extension",
+            "author": "Tester",
+            "hooks": { "server.pre_start": { "module": "m", "function": "f" } },
+            "instance_fields": { "my_flag": { "type": "boolean", "default": false } }
+        }).to_string()).unwrap();
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+
+        let list = mgr.list();
+        assert_eq!(list.len(), 1);
+        let item = &list[0];
+        assert_eq!(item.id, "ext_a");
+        assert_eq!(item.version, "1.2.3");
+        assert!(!item.enabled, "Initially disabled");
+        assert_eq!(item.hooks, vec!["server.pre_start"]);
+        assert!(item.instance_fields.contains_key("my_flag"));
+
+        mgr.enable("ext_a").unwrap();
+        let list = mgr.list();
+        assert!(list[0].enabled, "Should be enabled after enable()");
+    }
+
+    /// hooks_for — 비활성 익스텐션의 hook은 반환되지 않아야 함
+    #[test]
+    fn test_hooks_for_only_returns_enabled() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ext_dir = tmp.path().join("hook_ext");
+        std::fs::create_dir_all(&ext_dir).unwrap();
+        std::fs::write(ext_dir.join("manifest.json"), json!({
+            "id": "hook_ext", "name": "Hook Ext", "version": "0.1.0",
+            "hooks": { "server.pre_start": { "module": "m", "function": "f" } },
+            "python_modules": { "m": "m.py" }
+        }).to_string()).unwrap();
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+
+        // 비활성 → hooks_for 비어있음
+        assert!(mgr.hooks_for("server.pre_start").is_empty());
+
+        // 활성화 → hooks_for에 포함
+        mgr.enable("hook_ext").unwrap();
+        let hooks = mgr.hooks_for("server.pre_start");
+        assert_eq!(hooks.len(), 1);
+        assert_eq!(hooks[0].0.manifest.id, "hook_ext");
+        assert_eq!(hooks[0].1.function, "f");
+        assert_eq!(hooks[0].1.timeout_secs, None);
+
+        // 존재하지 않는 hook 이름
+        assert!(mgr.hooks_for("nonexistent.hook").is_empty());
+    }
+
+    #[test]
+    fn test_validate_instance_data_checks_declared_types() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ext_dir = tmp.path().join("docker");
+        std::fs::create_dir_all(&ext_dir).unwrap();
+        std::fs::write(ext_dir.join("manifest.json"), json!({
+            "id": "docker", "name": "Docker", "version": "1.0.0",
+            "instance_fields": {
+                "docker_enabled": { "type": "boolean", "default": false },
+                "docker_image": { "type": "string" },
+                "docker_cpu_limit": { "type": "number", "optional": true },
+                "docker_replicas": { "type": "integer", "optional": true }
+            }
+        }).to_string()).unwrap();
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+        let data = |v: Value| -> HashMap<String, Value> { serde_json::from_value(v).unwrap() };
+
+        // 모든 타입 일치 + optional/default 필드 생략
+        assert!(mgr.validate_instance_data("docker", &data(json!({ "docker_image": "nginx" }))).is_empty());
+        assert!(mgr.validate_instance_data("docker", &data(json!({
+            "docker_enabled": true, "docker_image": "nginx",
+            "docker_cpu_limit": 1.5, "docker_replicas": 2.0,
+        }))).is_empty());
+
+        let errors = mgr.validate_instance_data("docker", &data(json!({
+            "docker_enabled": "yes",
+            "docker_cpu_limit": "fast",
+            "docker_replicas": 1.5,
+        })));
+        assert_eq!(
+            errors,
+            vec![
+                FieldError { field: "docker_cpu_limit".into(), expected: "number".into(), found: "string".into() },
+                FieldError { field: "docker_enabled".into(), expected: "boolean".into(), found: "string".into() },
+                FieldError { field: "docker_image".into(), expected: "string".into(), found: "missing".into() },
+                FieldError { field: "docker_replicas".into(), expected: "integer".into(), found: "number".into() },
+            ]
+        );
+
+        assert!(mgr.validate_instance_data("unknown", &data(json!({ "x": 1 }))).is_empty());
+    }
+
+    /// hook 디스패치 전 extension_data 타입 검증 — Python 실행 없이 명확한 에러
+    #[tokio::test]
+    async fn test_dispatch_rejects_mistyped_extension_data() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ext_dir = tmp.path().join("hook_ext");
+        std::fs::create_dir_all(&ext_dir).unwrap();
+        std::fs::write(ext_dir.join("manifest.json"), json!({
+            "id": "hook_ext", "name": "Hook Ext", "version": "0.1.0",
+            "hooks": { "server.pre_start": { "module": "missing", "function": "f" } },
+            "instance_fields": { "hook_enabled": { "type": "boolean", "default": false } }
+        }).to_string()).unwrap();
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+        mgr.enable("hook_ext").unwrap();
+
+        let results = mgr
+            .dispatch_hook("server.pre_start", json!({ "extension_data": { "hook_enabled": "true" } }))
+            .await;
+        assert_eq!(results.len(), 1);
+        let err = results[0].1.as_ref().unwrap_err();
+        assert_eq!(error_code_of(err), "invalid_instance_data");
+        assert!(err.to_string().contains("hook_enabled"));
+
+        // 올바른 타입이면 검증을 통과해 모듈 해석 단계까지 진행
+        let results = mgr
+            .dispatch_hook("server.pre_start", json!({ "extension_data": { "hook_enabled": true } }))
+            .await;
+        assert!(results[0].1.as_ref().unwrap_err().to_string().contains("Unknown python module"));
+    }
+
+    /// `hook.py`의 `run(config)`을 server.pre_start에 바인딩한 익스텐션 생성 (실제 Python 실행용)
+    fn write_python_hook_ext(root: &std::path::Path, id: &str, run_body: &str, timeout_secs: Option<u64>) {
+        let dir = root.join(id);
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut binding = json!({ "module": "hook", "function": "run" });
+        if let Some(secs) = timeout_secs {
+            binding["timeout_secs"] = json!(secs);
+        }
+        std::fs::write(dir.join("manifest.json"), json!({
+            "id": id, "name": id, "version": "0.1.0",
+            "python_modules": { "hook": "hook.py" },
+            "hooks": { "server.pre_start": binding }
+        }).to_string()).unwrap();
+        std::fs::write(dir.join("hook.py"), format!(
+            "import json, sys, time\n\ndef run(config):\n{}\n\n\
+             if __name__ == \"__main__\":\n    print(json.dumps(run(json.loads(sys.stdin.read() or \"{{}}\"))))\n",
+            run_body
+        )).unwrap();
+    }
+
+    /// 바인딩의 timeout_secs가 디스패치 기본값보다 우선
+    #[tokio::test]
+    async fn test_dispatch_uses_binding_timeout() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_python_hook_ext(tmp.path(), "slow_ext", "    time.sleep(10)\n    return {}", Some(1));
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+        mgr.enable("slow_ext").unwrap();
+
+        let started = std::time::Instant::now();
+        let results = mgr.dispatch_hook_timed("server.pre_start", json!({}), 60).await;
+        assert_eq!(results.len(), 1);
+        let err = results[0].1.as_ref().unwrap_err().to_string();
+        assert!(err.contains("timed out after 1s"), "{}", err);
+        assert!(started.elapsed() < std::time::Duration::from_secs(10));
+    }
+
+    /// 같은 hook에 바인딩된 익스텐션 둘 — 모두 실행되고 진행률 콜백도 양쪽에서 받음
+    #[tokio::test]
+    async fn test_dispatch_with_progress_runs_every_extension() {
+        let tmp = tempfile::tempdir().unwrap();
+        for id in ["ext_a", "ext_b"] {
+            let body = format!(
+                "    print('PROGRESS:' + json.dumps({{'message': '{}'}}), file=sys.stderr)\n    return {{'ext': '{}'}}",
+                id, id
+            );
+            write_python_hook_ext(tmp.path(), id, &body, None);
+        }
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+        mgr.enable("ext_a").unwrap();
+        mgr.enable("ext_b").unwrap();
 
-        let no_instances: Vec<(&str, &HashMap<String, Value>)> = vec![];
-        mgr.remove("removable", &no_instances).unwrap();
-        assert!(mgr.list().is_empty());
-        assert!(!ext_dir.exists(), "Extension directory should be deleted");
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let results = mgr
+            .snapshot_hooks("server.pre_start")
+            .dispatch_with_progress(json!({}), move |prog| {
+                sink.lock().unwrap().push(prog.message.unwrap_or_default());
+            })
+            .await;
+
+        let mut ran: Vec<_> = results.iter()
+            .map(|(id, r)| (id.clone(), r.as_ref().unwrap()["ext"].as_str().unwrap().to_string()))
+            .collect();
+        ran.sort();
+        assert_eq!(ran, vec![("ext_a".into(), "ext_a".into()), ("ext_b".into(), "ext_b".into())]);
+
+        let mut seen = seen.lock().unwrap().clone();
+        seen.sort();
+        assert_eq!(seen, vec!["ext_a", "ext_b"]);
     }
 
-    /// 삭제 — 의존하는 익스텐션이 있으면 실패
-    #[test]
-    fn test_remove_blocked_by_active_dependent() {
+    /// 진행률 디스패치도 handled=true가 나오면 나머지 익스텐션을 스킵
+    #[tokio::test]
+    async fn test_dispatch_with_progress_stops_after_handled() {
         let tmp = tempfile::tempdir().unwrap();
-        let parent_dir = tmp.path().join("parent");
-        std::fs::create_dir_all(&parent_dir).unwrap();
-        std::fs::write(parent_dir.join("manifest.json"),
-            r#"{"id":"parent","name":"Parent","version":"0.1.0"}"#).unwrap();
-
-        let child_dir = tmp.path().join("child");
-        std::fs::create_dir_all(&child_dir).unwrap();
-        std::fs::write(child_dir.join("manifest.json"),
-            r#"{"id":"child","name":"Child","version":"0.1.0","dependencies":["parent"]}"#).unwrap();
+        for id in ["ext_a", "ext_b"] {
+            write_python_hook_ext(tmp.path(), id, "    return {'handled': True}", None);
+        }
 
         let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
         mgr.discover().unwrap();
-        mgr.enable("parent").unwrap();
-        mgr.enable("child").unwrap();
+        mgr.enable("ext_a").unwrap();
+        mgr.enable("ext_b").unwrap();
 
-        let no_instances: Vec<(&str, &HashMap<String, Value>)> = vec![];
-        let result = mgr.remove("parent", &no_instances);
-        assert!(result.is_err(), "Cannot remove parent while child depends on it");
+        let results = mgr
+            .snapshot_hooks("server.pre_start")
+            .dispatch_with_progress(json!({}), |_| {})
+            .await;
+        assert_eq!(results.len(), 1, "{:?}", results);
+        assert_eq!(results[0].1.as_ref().unwrap()["handled"], true);
     }
 
-    /// list() 결과 검증 — enabled 상태, hooks, instance_fields 정확히 반영
+    /// timeout_secs: 0은 매니페스트 로드 시 거부
     #[test]
-    fn test_list_reflects_extension_state() {
+    fn test_manifest_rejects_zero_timeout() {
+        let result = serde_json::from_value::<ExtensionManifest>(json!({
+            "id": "zero", "name": "Zero", "version": "0.1.0",
+            "hooks": { "server.pre_start": { "module": "m", "function": "f", "timeout_secs": 0 } }
+        }));
+        assert!(result.unwrap_err().to_string().contains("timeout_secs must be at least 1"));
+
         let tmp = tempfile::tempdir().unwrap();
-        let ext_dir = tmp.path().join("ext_a");
+        let ext_dir = tmp.path().join("zero");
         std::fs::create_dir_all(&ext_dir).unwrap();
         std::fs::write(ext_dir.join("manifest.json"), json!({
-            "id": "ext_a",
-            "name": "Extension A",
-            "version": "1.2.3",
-            "description": "Test extension",
-            "author": "Tester",
-            "hooks": { "server.pre_start": { "module": "m", "function": "f" } },
-            "instance_fields": { "my_flag": { "type": "boolean", "default": false } }
+            "id": "zero", "name": "Zero", "version": "0.1.0",
+            "hooks": { "server.pre_start": { "module": "m", "function": "f", "timeout_secs": 0 } }
         }).to_string()).unwrap();
 
         let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
         mgr.discover().unwrap();
-
-        let list = mgr.list();
-        assert_eq!(list.len(), 1);
-        let item = &list[0];
-        assert_eq!(item.id, "ext_a");
-        assert_eq!(item.version, "1.2.3");
-        assert!(!item.enabled, "Initially disabled");
-        assert_eq!(item.hooks, vec!["server.pre_start"]);
-        assert!(item.instance_fields.contains_key("my_flag"));
-
-        mgr.enable("ext_a").unwrap();
-        let list = mgr.list();
-        assert!(list[0].enabled, "Should be enabled after enable()");
+        assert!(mgr.enable("zero").is_err(), "잘못된 매니페스트는 발견되지 않아야 함");
     }
 
-    /// hooks_for — 비활성 익스텐션의 hook은 반환되지 않아야 함
-    #[test]
-    fn test_hooks_for_only_returns_enabled() {
+    /// 디스패치 도중 rescan/disable — 진행 중인 디스패치는 스냅샷 시점의 hook 집합으로 실행
+    #[tokio::test]
+    async fn test_rescan_during_dispatch_uses_snapshot() {
         let tmp = tempfile::tempdir().unwrap();
         let ext_dir = tmp.path().join("hook_ext");
         std::fs::create_dir_all(&ext_dir).unwrap();
+        // python_modules에 없는 모듈 → Python 실행 없이 ext_id가 담긴 Err 결과
         std::fs::write(ext_dir.join("manifest.json"), json!({
             "id": "hook_ext", "name": "Hook Ext", "version": "0.1.0",
-            "hooks": { "server.pre_start": { "module": "m", "function": "f" } },
-            "python_modules": { "m": "m.py" }
+            "hooks": { "server.pre_start": { "module": "missing", "function": "f" } }
         }).to_string()).unwrap();
 
         let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
         mgr.discover().unwrap();
+        mgr.enable("hook_ext").unwrap();
+        let mgr = std::sync::Arc::new(tokio::sync::RwLock::new(mgr));
 
-        // 비활성 → hooks_for 비어있음
-        assert!(mgr.hooks_for("server.pre_start").is_empty());
+        let hooks = mgr.read().await.snapshot_hooks("server.pre_start");
 
-        // 활성화 → hooks_for에 포함
-        mgr.enable("hook_ext").unwrap();
-        let hooks = mgr.hooks_for("server.pre_start");
-        assert_eq!(hooks.len(), 1);
-        assert_eq!(hooks[0].0.manifest.id, "hook_ext");
-        assert_eq!(hooks[0].1.function, "f");
+        // 스냅샷 이후에는 읽기 잠금이 풀려 있으므로 쓰기 잠금을 바로 얻을 수 있어야 함
+        {
+            let mut guard = tokio::time::timeout(std::time::Duration::from_secs(1), mgr.write())
+                .await
+                .expect("snapshot must not hold the manager lock");
+            guard.disable("hook_ext", &[]).unwrap();
+            std::fs::remove_dir_all(&ext_dir).unwrap();
+            guard.rescan().unwrap();
+        }
 
-        // 존재하지 않는 hook 이름
-        assert!(mgr.hooks_for("nonexistent.hook").is_empty());
+        let results = hooks.dispatch(json!({})).await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, "hook_ext");
+        assert!(results[0].1.as_ref().unwrap_err().to_string().contains("missing"));
+
+        // 새 스냅샷에는 변경이 반영됨
+        assert!(mgr.read().await.snapshot_hooks("server.pre_start").is_empty());
+        assert!(mgr.read().await.dispatch_hook("server.pre_start", json!({})).await.is_empty());
     }
 
     /// should_parse_config_section — module_config_section 매칭
@@ -2467,6 +3778,82 @@ mod tests {
         assert!(!zip_path.exists(), "Zip file should be removed after extraction");
     }
 
+    /// zip 파일명과 manifest id가 다르면 id 이름의 폴더로 추출되어 mount 가능해야 함
+    #[test]
+    fn test_discover_zip_stem_differs_from_manifest_id() {
+        let tmp = tempfile::tempdir().unwrap();
+
+        let zip_path = tmp.path().join("foo.zip");
+        let manifest_content = r#"{"id":"bar","name":"Bar Extension","version":"0.1.0"}"#;
+
+        let file = std::fs::File::create(&zip_path).unwrap();
+        let mut zip_writer = zip::ZipWriter::new(file);
+        let options = zip::write::FileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        zip_writer.start_file("manifest.json", options).unwrap();
+        std::io::Write::write_all(&mut zip_writer, manifest_content.as_bytes()).unwrap();
+        zip_writer.finish().unwrap();
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        let found = mgr.discover().unwrap();
+        assert_eq!(found, vec!["bar".to_string()]);
+        assert!(tmp.path().join("bar").join("manifest.json").exists());
+        assert!(!tmp.path().join("foo").exists(), "Should not extract under the zip stem");
+
+        // 언마운트 후 id로 다시 마운트할 수 있어야 함
+        mgr.unmount("bar", &[]).unwrap();
+        mgr.mount("bar").unwrap();
+    }
+
+    fn write_manifest_zip(path: &std::path::Path, manifest: &str) {
+        let file = std::fs::File::create(path).unwrap();
+        let mut zip_writer = zip::ZipWriter::new(file);
+        let options = zip::write::FileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        zip_writer.start_file("manifest.json", options).unwrap();
+        std::io::Write::write_all(&mut zip_writer, manifest.as_bytes()).unwrap();
+        zip_writer.finish().unwrap();
+    }
+
+    /// `.`/`..` id는 extensions/ 자체나 상위를 가리키므로 거부하고 zip을 남겨둬야 함
+    #[test]
+    fn test_zip_extraction_rejects_dot_ids() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+
+        for (file, id) in [("dot.zip", "."), ("dotdot.zip", ".."), ("..zip", ".")] {
+            let zip_path = tmp.path().join(file);
+            write_manifest_zip(&zip_path, &format!(r#"{{"id":"{}","name":"X","version":"0.1.0"}}"#, id));
+            let err = mgr.extract_zip_extension(&zip_path).unwrap_err();
+            assert_eq!(error_code_of(&err), "id_mismatch", "{}", file);
+            assert!(zip_path.exists(), "{} should be left in place", file);
+        }
+    }
+
+    /// 한 zip의 manifest.json이 깨져 있어도 다른 zip은 추출되고, 실패는 해당 zip에 기록되어야 함
+    #[test]
+    fn test_discover_reports_malformed_zip_manifest_per_entry() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_manifest_zip(&tmp.path().join("broken.zip"), "{ not json");
+        write_manifest_zip(
+            &tmp.path().join("good.zip"),
+            r#"{"id":"good","name":"Good","version":"0.1.0"}"#,
+        );
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        let found = mgr.discover().unwrap();
+        assert_eq!(found, vec!["good".to_string()]);
+        assert!(tmp.path().join("good").join("manifest.json").exists());
+        assert!(tmp.path().join("broken.zip").exists());
+
+        let errors = mgr.zip_errors();
+        assert_eq!(errors.len(), 1);
+        assert!(errors["broken.zip"].contains("Invalid manifest.json"), "{}", errors["broken.zip"]);
+
+        let err = mgr.extract_zip_extension(&tmp.path().join("broken.zip")).unwrap_err();
+        assert_eq!(error_code_of(&err), "invalid_manifest");
+    }
+
     // ── 컴포넌트 버전 의존성(dependencies) 테스트 ──
 
     #[test]
@@ -2516,6 +3903,51 @@ mod tests {
         assert!(err_msg.contains("0.2.0"), "Error should mention installed version");
     }
 
+    #[test]
+    fn test_enable_checks_min_app_version() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ext_dir = tmp.path().join("my_ext");
+        std::fs::create_dir_all(&ext_dir).unwrap();
+        std::fs::write(ext_dir.join("manifest.json"), json!({
+            "id": "my_ext",
+            "name": "My Extension",
+            "version": "1.0.0",
+            "min_app_version": "0.4.0"
+        }).to_string()).unwrap();
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+
+        let old_core = HashMap::from([("saba-core".to_string(), "0.3.9".to_string())]);
+        let err = mgr.enable_with_versions("my_ext", &old_core).unwrap_err();
+        assert_eq!(error_code_of(&err), "app_version_too_old");
+        assert!(err.to_string().contains("0.3.9"));
+        assert!(!mgr.enabled_set().contains("my_ext"));
+
+        let new_core = HashMap::from([("saba-core".to_string(), "0.4.0".to_string())]);
+        mgr.enable_with_versions("my_ext", &new_core).unwrap();
+        assert!(mgr.enabled_set().contains("my_ext"));
+    }
+
+    #[test]
+    fn test_enable_min_app_version_defaults_to_running_core() {
+        let tmp = tempfile::tempdir().unwrap();
+        for (id, min) in [("future_ext", "999.0.0"), ("current_ext", env!("CARGO_PKG_VERSION"))] {
+            let ext_dir = tmp.path().join(id);
+            std::fs::create_dir_all(&ext_dir).unwrap();
+            std::fs::write(ext_dir.join("manifest.json"), json!({
+                "id": id, "name": id, "version": "1.0.0", "min_app_version": min
+            }).to_string()).unwrap();
+        }
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+
+        let err = mgr.enable("future_ext").unwrap_err();
+        assert_eq!(error_code_of(&err), "app_version_too_old");
+        mgr.enable("current_ext").unwrap();
+    }
+
     #[test]
     fn test_enable_with_component_not_installed() {
         let tmp = tempfile::tempdir().unwrap();
@@ -2913,6 +4345,403 @@ mod tests {
         assert_eq!(manifest.i18n_dir.as_deref(), Some("i18n/"));
     }
 
+    // ── 원격 매니페스트 페치 테스트 ──
+
+    /// 순서대로 (status, etag, body) 응답을 돌려주는 1회성 HTTP 서버.
+    /// 받은 요청의 If-None-Match 헤더 값을 함께 기록합니다.
+    async fn spawn_test_server(
+        responses: Vec<(u16, Option<&'static str>, Vec<u8>)>,
+    ) -> (String, std::sync::Arc<std::sync::Mutex<Vec<Option<String>>>>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/manifest.json", listener.local_addr().unwrap());
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen_srv = seen.clone();
+
+        tokio::spawn(async move {
+            for (status, etag, body) in responses {
+                let (mut sock, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 4096];
+                let n = sock.read(&mut buf).await.unwrap();
+                let req = String::from_utf8_lossy(&buf[..n]).to_string();
+                let inm = req
+                    .lines()
+                    .find(|l| l.to_ascii_lowercase().starts_with("if-none-match:"))
+                    .map(|l| l.split_once(':').unwrap().1.trim().to_string());
+                seen_srv.lock().unwrap().push(inm);
+
+                let mut head = format!(
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n",
+                    status,
+                    body.len()
+                );
+                if let Some(tag) = etag {
+                    head.push_str(&format!("ETag: {}\r\n", tag));
+                }
+                head.push_str("\r\n");
+                sock.write_all(head.as_bytes()).await.unwrap();
+                sock.write_all(&body).await.unwrap();
+                let _ = sock.shutdown().await;
+            }
+        });
+
+        (url, seen)
+    }
+
+    fn manifest_body(schema_version: u32) -> Vec<u8> {
+        json!({
+            "schema_version": schema_version,
+            "extensions": {
+                "docker": { "name": "Docker Isolation", "version": "0.2.0" }
+            }
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    fn error_code_of(err: &anyhow::Error) -> String {
+        err.downcast_ref::<ExtensionError>()
+            .map(|e| e.error_code.clone())
+            .unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn test_fetch_manifest_uses_etag_cache() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (url, seen) = spawn_test_server(vec![
+            (200, Some("\"v1\""), manifest_body(1)),
+            (304, None, Vec::new()),
+        ])
+        .await;
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.set_manifest_url(&url);
+
+        let first = mgr.fetch_manifest().await.unwrap();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].id, "docker");
+
+        let second = mgr.fetch_manifest().await.unwrap();
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].version, "0.2.0");
+
+        let seen = seen.lock().unwrap().clone();
+        assert_eq!(seen, vec![None, Some("\"v1\"".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_manifest_http_error_is_typed() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (url, _) = spawn_test_server(vec![(503, None, Vec::new())]).await;
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.set_manifest_url(&url);
+
+        let err = mgr.fetch_manifest().await.unwrap_err();
+        assert_eq!(error_code_of(&err), "registry_http_error");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_manifest_malformed_json_is_typed() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (url, _) = spawn_test_server(vec![(200, None, b"{not json".to_vec())]).await;
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.set_manifest_url(&url);
+
+        let err = mgr.fetch_manifest().await.unwrap_err();
+        assert_eq!(error_code_of(&err), "registry_parse_error");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_manifest_rejects_future_schema() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (url, _) = spawn_test_server(vec![(200, None, manifest_body(2))]).await;
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.set_manifest_url(&url);
+
+        let err = mgr.fetch_manifest().await.unwrap_err();
+        assert_eq!(error_code_of(&err), "registry_schema_unsupported");
+    }
+
+    fn extension_zip_bytes(manifest: &str) -> Vec<u8> {
+        let mut cursor = std::io::Cursor::new(Vec::new());
+        {
+            let mut zip_writer = zip::ZipWriter::new(&mut cursor);
+            let options = zip::write::FileOptions::default()
+                .compression_method(zip::CompressionMethod::Stored);
+            zip_writer.start_file("manifest.json", options).unwrap();
+            std::io::Write::write_all(&mut zip_writer, manifest.as_bytes()).unwrap();
+            zip_writer.finish().unwrap();
+        }
+        cursor.into_inner()
+    }
+
+    #[tokio::test]
+    async fn test_install_from_url_verifies_sha256() {
+        use sha2::{Digest, Sha256};
+
+        let tmp = tempfile::tempdir().unwrap();
+        let zip = extension_zip_bytes(r#"{"id":"remote_ext","name":"Remote","version":"0.1.0"}"#);
+        let sha = hex::encode(Sha256::digest(&zip));
+        let (url, _) = spawn_test_server(vec![(200, None, zip)]).await;
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        let installed = mgr
+            .install_from_url("remote_ext", &url, Some(&sha.to_uppercase()))
+            .await
+            .unwrap();
+        assert!(tmp.path().join("remote_ext").join("manifest.json").exists());
+        assert_eq!(installed.manifest.id, "remote_ext");
+        assert_eq!(installed.dir, tmp.path().join("remote_ext"));
+
+        // 재스캔 없이 바로 마운트/활성화 가능
+        mgr.mount_installed(installed);
+        mgr.enable("remote_ext").unwrap();
+        assert!(mgr.list().iter().any(|e| e.id == "remote_ext" && e.enabled));
+    }
+
+    #[test]
+    fn test_version_satisfies_requirements() {
+        assert!(ExtensionManager::version_satisfies("1.2.0", "*"));
+        assert!(ExtensionManager::version_satisfies("1.2.0", "1.0.0"), "bare version is a minimum");
+        assert!(ExtensionManager::version_satisfies("1.2.0", ">=1.2.0"));
+        assert!(!ExtensionManager::version_satisfies("1.2.0", ">1.2.0"));
+        assert!(ExtensionManager::version_satisfies("1.2.0", "<2.0.0"));
+        assert!(!ExtensionManager::version_satisfies("2.0.0", "<2.0.0"));
+        assert!(ExtensionManager::version_satisfies("2.0.0", "<=2.0.0"));
+        assert!(ExtensionManager::version_satisfies("1.2.3", "=1.2.3"));
+        assert!(ExtensionManager::version_satisfies("1.5.0", ">=1.0.0, <2.0.0"));
+        assert!(!ExtensionManager::version_satisfies("2.1.0", ">=1.0.0, <2.0.0"));
+        assert!(!ExtensionManager::version_satisfies("garbage", ">=1.0.0"));
+    }
+
+    fn write_local_extension(root: &std::path::Path, id: &str, version: &str, deps: Value) {
+        let dir = root.join(id);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("manifest.json"), json!({
+            "id": id, "name": id, "version": version, "dependencies": deps,
+        }).to_string()).unwrap();
+    }
+
+    fn remote_extension(id: &str, version: &str, url: &str, deps: Value) -> RemoteExtensionInfo {
+        serde_json::from_value(json!({
+            "id": id, "name": id, "version": version,
+            "download_url": url, "dependencies": deps,
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_update_all_installs_dependencies_first() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_local_extension(tmp.path(), "ext_a", "1.0.0", json!({}));
+        write_local_extension(tmp.path(), "ext_b", "1.0.0", json!({ "ext_a": ">=1.0.0" }));
+        // 서버는 요청 순서대로 응답 — ext_b가 먼저 요청되면 id_mismatch로 실패
+        let (url, _) = spawn_test_server(vec![
+            (200, None, extension_zip_bytes(r#"{"id":"ext_a","name":"A","version":"2.0.0"}"#)),
+            (200, None, extension_zip_bytes(
+                r#"{"id":"ext_b","name":"B","version":"2.0.0","dependencies":{"ext_a":">=2.0.0"}}"#,
+            )),
+        ])
+        .await;
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+        mgr.enable("ext_a").unwrap();
+        mgr.enable("ext_b").unwrap();
+
+        let remote = vec![
+            remote_extension("ext_b", "2.0.0", &url, json!({ "ext_a": ">=2.0.0" })),
+            remote_extension("ext_a", "2.0.0", &url, json!({})),
+        ];
+        let results = mgr.update_all(&remote).await;
+
+        let ids: Vec<&str> = results.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["ext_a", "ext_b"]);
+        assert!(results.iter().all(|(_, r)| r.is_ok()), "{:?}", results);
+        // 재스캔 없이 새 버전으로 마운트됨
+        let versions: HashMap<String, String> =
+            mgr.list().into_iter().map(|e| (e.id, e.version)).collect();
+        assert_eq!(versions["ext_a"], "2.0.0");
+        assert_eq!(versions["ext_b"], "2.0.0");
+    }
+
+    #[tokio::test]
+    async fn test_update_all_skips_updates_breaking_enabled_dependents() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_local_extension(tmp.path(), "ext_a", "1.0.0", json!({}));
+        write_local_extension(tmp.path(), "ext_b", "1.0.0", json!({ "ext_a": "*" }));
+        write_local_extension(tmp.path(), "ext_c", "1.0.0", json!({ "ext_a": "<2.0.0" }));
+
+        let mut mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.discover().unwrap();
+        for id in ["ext_a", "ext_b", "ext_c"] {
+            mgr.enable(id).unwrap();
+        }
+
+        // 다운로드까지 가면 연결 실패 — 두 업데이트 모두 그 전에 스킵되어야 함
+        let url = "http://127.0.0.1:9/unreachable.zip";
+        let remote = vec![
+            remote_extension("ext_a", "2.0.0", url, json!({})),
+            remote_extension("ext_b", "2.0.0", url, json!({ "ext_a": ">=2.0.0" })),
+        ];
+        let results = mgr.update_all(&remote).await;
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "ext_a");
+        assert_eq!(error_code_of(results[0].1.as_ref().unwrap_err()), "update_breaks_dependent");
+        assert_eq!(results[1].0, "ext_b");
+        assert_eq!(
+            error_code_of(results[1].1.as_ref().unwrap_err()),
+            "update_dependency_unsatisfied"
+        );
+        assert!(mgr.list().iter().all(|e| e.version == "1.0.0"));
+    }
+
+    #[tokio::test]
+    async fn test_install_from_url_rejects_newer_min_app_version() {
+        let tmp = tempfile::tempdir().unwrap();
+        let zip = extension_zip_bytes(
+            r#"{"id":"remote_ext","name":"Remote","version":"0.1.0","min_app_version":"999.0.0"}"#,
+        );
+        let (url, _) = spawn_test_server(vec![(200, None, zip)]).await;
+
+        let mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        let err = mgr.install_from_url("remote_ext", &url, None).await.unwrap_err();
+        assert_eq!(error_code_of(&err), "app_version_too_old");
+        assert!(!tmp.path().join("remote_ext").exists());
+    }
+
+    #[tokio::test]
+    async fn test_install_from_url_rejects_manifest_id_mismatch() {
+        let tmp = tempfile::tempdir().unwrap();
+        let previous = tmp.path().join("remote_ext");
+        std::fs::create_dir_all(&previous).unwrap();
+        std::fs::write(previous.join("manifest.json"), "{}").unwrap();
+        let zip = extension_zip_bytes(r#"{"id":"other_ext","name":"Other","version":"0.1.0"}"#);
+        let (url, _) = spawn_test_server(vec![(200, None, zip)]).await;
+
+        let mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        let err = mgr.install_from_url("remote_ext", &url, None).await.unwrap_err();
+        assert_eq!(error_code_of(&err), "id_mismatch");
+        // 기존 설치는 건드리지 않음
+        assert_eq!(std::fs::read_to_string(previous.join("manifest.json")).unwrap(), "{}");
+        assert!(!tmp.path().join("other_ext").exists());
+    }
+
+    #[tokio::test]
+    async fn test_install_from_url_rejects_checksum_mismatch() {
+        let tmp = tempfile::tempdir().unwrap();
+        let zip = extension_zip_bytes(r#"{"id":"remote_ext","name":"Remote","version":"0.1.0"}"#);
+        let (url, _) = spawn_test_server(vec![(200, None, zip)]).await;
+
+        let mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        let err = mgr
+            .install_from_url("remote_ext", &url, Some(&"0".repeat(64)))
+            .await
+            .unwrap_err();
+        assert_eq!(error_code_of(&err), "checksum_mismatch");
+        assert!(!tmp.path().join("remote_ext").exists());
+        assert!(!tmp.path().join("remote_ext.zip").exists());
+    }
+
+    #[tokio::test]
+    async fn test_install_from_url_with_progress_reports_phases() {
+        let tmp = tempfile::tempdir().unwrap();
+        let zip = extension_zip_bytes(r#"{"id":"remote_ext","name":"Remote","version":"0.1.0"}"#);
+        let size = zip.len() as u64;
+        let (url, _) = spawn_test_server(vec![(200, None, zip)]).await;
+
+        let events = std::sync::Mutex::new(Vec::new());
+        let mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.install_from_url_with_progress("remote_ext", &url, None, &|p| {
+            events.lock().unwrap().push(p)
+        })
+        .await
+        .unwrap();
+
+        let events = events.into_inner().unwrap();
+        let mut phases: Vec<InstallPhase> = events.iter().map(|p| p.phase).collect();
+        phases.dedup();
+        assert_eq!(
+            phases,
+            vec![
+                InstallPhase::Downloading,
+                InstallPhase::Verifying,
+                InstallPhase::Extracting,
+                InstallPhase::Done,
+            ]
+        );
+        let last = events.last().unwrap();
+        assert_eq!(last.ext_id, "remote_ext");
+        assert_eq!(last.bytes_received, size);
+        assert_eq!(last.total_bytes, size);
+    }
+
+    #[tokio::test]
+    async fn test_install_from_url_with_progress_stops_before_done_on_mismatch() {
+        let tmp = tempfile::tempdir().unwrap();
+        let zip = extension_zip_bytes(r#"{"id":"remote_ext","name":"Remote","version":"0.1.0"}"#);
+        let (url, _) = spawn_test_server(vec![(200, None, zip)]).await;
+
+        let events = std::sync::Mutex::new(Vec::new());
+        let mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        mgr.install_from_url_with_progress("remote_ext", &url, Some(&"0".repeat(64)), &|p| {
+            events.lock().unwrap().push(p.phase)
+        })
+        .await
+        .unwrap_err();
+
+        let events = events.into_inner().unwrap();
+        assert_eq!(events.last(), Some(&InstallPhase::Verifying));
+        assert!(!events.contains(&InstallPhase::Done));
+    }
+
+    fn traversal_zip_bytes() -> Vec<u8> {
+        let mut cursor = std::io::Cursor::new(Vec::new());
+        {
+            let mut zip_writer = zip::ZipWriter::new(&mut cursor);
+            let options = zip::write::FileOptions::default()
+                .compression_method(zip::CompressionMethod::Stored);
+            zip_writer.start_file("manifest.json", options).unwrap();
+            std::io::Write::write_all(
+                &mut zip_writer,
+                br#"{"id":"evil","name":"Evil","version":"0.1.0"}"#,
+            )
+            .unwrap();
+            zip_writer.start_file("../escaped.txt", options).unwrap();
+            std::io::Write::write_all(&mut zip_writer, b"pwned").unwrap();
+            zip_writer.finish().unwrap();
+        }
+        cursor.into_inner()
+    }
+
+    #[test]
+    fn test_discover_rejects_path_traversal_zip() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ext_root = tmp.path().join("extensions");
+        std::fs::create_dir_all(&ext_root).unwrap();
+        let zip_path = ext_root.join("evil.zip");
+        std::fs::write(&zip_path, traversal_zip_bytes()).unwrap();
+
+        let mgr = ExtensionManager::new_isolated(ext_root.to_str().unwrap());
+        let err = mgr.extract_zip_extension(&zip_path).unwrap_err();
+        assert_eq!(error_code_of(&err), "unsafe_archive");
+        assert!(!ext_root.join("evil").exists(), "Nothing should be extracted");
+        assert!(!tmp.path().join("escaped.txt").exists());
+    }
+
+    #[tokio::test]
+    async fn test_install_from_url_rejects_path_traversal_zip() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (url, _) = spawn_test_server(vec![(200, None, traversal_zip_bytes())]).await;
+
+        let mgr = ExtensionManager::new_isolated(tmp.path().to_str().unwrap());
+        let err = mgr.install_from_url("evil", &url, None).await.unwrap_err();
+        assert_eq!(error_code_of(&err), "unsafe_archive");
+        assert!(!tmp.path().join("evil").exists());
+        assert!(!tmp.path().join("evil.zip").exists());
+    }
+
     /// music manifest — gui.builtin, dependencies: [], i18n_dir: null
     #[test]
     fn test_parse_music_manifest() {
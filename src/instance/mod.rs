@@ -286,7 +286,8 @@ impl InstanceStore {
                 sorted.push(loaded.remove(pos));
             }
         }
-        // order에 없는 인스턴스는 뒤에 추가
+        // order에 없는 인스턴스는 뒤에 추가 — read_dir 순서에 의존하지 않도록 이름/ID 순 정렬
+        loaded.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
         sorted.extend(loaded);
 
         // order 동기화 (새 인스턴스 포함)
@@ -551,6 +552,25 @@ mod tests {
         assert_eq!(store2.list()[1].id, id_a);
     }
 
+    #[test]
+    fn test_load_orders_unlisted_instances_by_name() {
+        let tmp = TempDir::new().unwrap();
+        let instances_dir = tmp.path().join("instances");
+
+        let mut store = InstanceStore::new(instances_dir.to_str().unwrap());
+        store.load().unwrap();
+        for name in ["charlie", "alpha", "bravo"] {
+            store.add(make_test_instance(name, "minecraft")).unwrap();
+        }
+
+        // order.json이 없으면 디렉토리 순회 순서와 무관하게 이름순
+        fs::remove_file(instances_dir.join("order.json")).unwrap();
+        let mut store2 = InstanceStore::new(instances_dir.to_str().unwrap());
+        store2.load().unwrap();
+        let names: Vec<&str> = store2.list().iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec!["alpha", "bravo", "charlie"]);
+    }
+
     #[test]
     fn test_directory_structure() {
         let tmp = TempDir::new().unwrap();
@@ -21,7 +21,20 @@ pub async fn daemon_shutdown(
     (StatusCode::OK, Json(json!({"ok": true, "message": "Shutdown initiated"}))).into_response()
 }
 
+/// 클라이언트 고정 키 최대 길이
+const MAX_CLIENT_KEY_LEN: usize = 128;
+
+/// 고정 키는 URL 경로(`/api/client/:id/...`)에 그대로 쓰이므로 안전한 문자만 허용
+fn is_valid_client_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.len() <= MAX_CLIENT_KEY_LEN
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
 /// POST /api/client/register — 클라이언트(GUI/CLI) 등록
+///
+/// Body: `{ "kind": "gui" | "cli", "client_key"?: string }`
+/// `client_key`를 주면 같은 키의 기존 등록을 대체합니다 (재접속 시 중복 방지).
 pub async fn client_register(
     State(state): State<IPCServer>,
     Json(payload): Json<serde_json::Value>,
@@ -35,7 +48,23 @@ pub async fn client_register(
         _ => ClientKind::Gui,
     };
 
-    let client_id = state.client_registry.register(kind.clone()).await;
+    let client_key = payload.get("client_key").and_then(|v| v.as_str());
+    if let Some(key) = client_key {
+        if !is_valid_client_key(key) {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({
+                    "error": format!(
+                        "client_key must be 1-{} characters of [A-Za-z0-9._-]",
+                        MAX_CLIENT_KEY_LEN
+                    )
+                })),
+            )
+                .into_response();
+        }
+    }
+
+    let client_id = state.client_registry.register(kind.clone(), client_key).await;
     let count = state.client_registry.count().await;
     tracing::info!("[Heartbeat] Active clients: {}", count);
 
@@ -87,7 +116,7 @@ pub async fn client_unregister(
         // shutdown=true(완전 종료)일 때만 봇 프로세스 정리
         if wants_shutdown {
             if let Some(pid) = client.bot_pid {
-                kill_bot_pid(pid);
+                kill_bot_pid(pid).await.report("[Heartbeat]");
             }
         }
         let count = state.client_registry.count().await;
@@ -114,35 +143,109 @@ pub async fn client_unregister(
     }
 }
 
+/// 정상 종료 요청 후 봇이 스스로 종료하기를 기다리는 시간
+const BOT_KILL_GRACE: std::time::Duration = std::time::Duration::from_millis(1500);
+/// 강제 종료 후 프로세스 소멸을 확인하는 시간
+const BOT_KILL_CONFIRM: std::time::Duration = std::time::Duration::from_millis(500);
+/// 종료 확인 폴링 간격
+const BOT_KILL_POLL: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// `kill_bot_pid` 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BotKillOutcome {
+    pub pid: u32,
+    /// 프로세스가 실제로 종료되었는지
+    pub exited: bool,
+    /// 유예 시간 내 종료되지 않아 강제 종료(SIGKILL / taskkill /F)로 승격했는지
+    pub escalated: bool,
+}
+
+impl BotKillOutcome {
+    /// 호출 경로 태그(`[Watchdog]`, `[Shutdown]` 등)와 함께 승격/잔존 여부를 로깅
+    pub fn report(&self, tag: &str) {
+        if self.escalated {
+            tracing::warn!("{} Bot process {} ignored graceful termination — force-killed", tag, self.pid);
+        }
+        if !self.exited {
+            tracing::error!("{} Bot process {} is still running after force kill", tag, self.pid);
+        }
+    }
+}
+
 /// 특정 PID의 봇 프로세스를 종료
-pub fn kill_bot_pid(pid: u32) {
+///
+/// 정상 종료(SIGTERM / taskkill)를 먼저 요청하고, 유예 시간 내 종료되지 않으면
+/// 강제 종료로 승격합니다.
+pub async fn kill_bot_pid(pid: u32) -> BotKillOutcome {
     tracing::info!("[Heartbeat] Killing bot process PID: {}", pid);
 
+    if !bot_process_alive(pid) {
+        return BotKillOutcome { pid, exited: true, escalated: false };
+    }
+
+    // 정상 종료 요청이 전달되지 않았으면 (예: 콘솔 프로세스에 대한 taskkill) 바로 강제 종료
+    if request_bot_terminate(pid) && wait_for_bot_exit(pid, BOT_KILL_GRACE).await {
+        return BotKillOutcome { pid, exited: true, escalated: false };
+    }
+
+    if let Err(e) = crate::supervisor::process::force_kill_pid(pid) {
+        tracing::warn!("[Heartbeat] Force kill of bot process {} failed: {}", pid, e);
+    }
+    let exited = wait_for_bot_exit(pid, BOT_KILL_CONFIRM).await;
+    BotKillOutcome { pid, exited, escalated: true }
+}
+
+/// 정상 종료 요청 — 전달에 성공하면 true
+fn request_bot_terminate(pid: u32) -> bool {
     #[cfg(target_os = "windows")]
     {
-        let _ = std::process::Command::new("taskkill")
-            .args(["/PID", &pid.to_string(), "/F"])
+        std::process::Command::new("taskkill")
+            .args(["/PID", &pid.to_string()])
             .stdin(std::process::Stdio::null())
             .stdout(std::process::Stdio::null())
             .stderr(std::process::Stdio::null())
-            .status();
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false)
     }
 
     #[cfg(not(target_os = "windows"))]
     {
-        let _ = std::process::Command::new("kill")
-            .args(["-9", &pid.to_string()])
-            .stdin(std::process::Stdio::null())
-            .stdout(std::process::Stdio::null())
-            .stderr(std::process::Stdio::null())
-            .status();
+        use nix::sys::signal::{self, Signal};
+        use nix::unistd::Pid;
+        signal::kill(Pid::from_raw(pid as i32), Signal::SIGTERM).is_ok()
+    }
+}
+
+/// `timeout` 안에 프로세스가 종료되면 true
+async fn wait_for_bot_exit(pid: u32, timeout: std::time::Duration) -> bool {
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        if !bot_process_alive(pid) {
+            return true;
+        }
+        if tokio::time::Instant::now() >= deadline {
+            return false;
+        }
+        tokio::time::sleep(BOT_KILL_POLL).await;
+    }
+}
+
+/// 좀비(종료됐지만 부모가 회수하지 않은) 프로세스는 종료된 것으로 취급
+fn bot_process_alive(pid: u32) -> bool {
+    let pid = sysinfo::Pid::from_u32(pid);
+    let mut sys = sysinfo::System::new();
+    if !sys.refresh_process_specifics(pid, sysinfo::ProcessRefreshKind::new()) {
+        return false;
     }
+    sys.process(pid)
+        .map(|p| p.status() != sysinfo::ProcessStatus::Zombie)
+        .unwrap_or(false)
 }
 
 /// 백그라운드 태스크에서 호출 — 만료 클라이언트 정리 및 고아 봇 프로세스 종료
 pub async fn reap_expired_clients(registry: &ClientRegistry) {
-    let timeout = std::time::Duration::from_secs(90);
-    let expired = registry.reap_expired(timeout).await;
+    let expired = registry.reap_expired(registry.config().client_timeout()).await;
 
     for (id, client) in &expired {
         tracing::warn!(
@@ -151,7 +254,7 @@ pub async fn reap_expired_clients(registry: &ClientRegistry) {
             client.kind
         );
         if let Some(pid) = client.bot_pid {
-            kill_bot_pid(pid);
+            kill_bot_pid(pid).await.report("[Heartbeat]");
         }
     }
 
@@ -28,7 +28,8 @@ fn extension_err_response(
     if let Some(ext_err) = err.downcast_ref::<ExtensionError>() {
         let status = match ext_err.error_code.as_str() {
             "not_found" | "not_mounted" | "manifest_not_found" => StatusCode::NOT_FOUND,
-            "dependency_missing" | "dependency_not_enabled" | "component_version_unsatisfied" => {
+            "dependency_missing" | "dependency_not_enabled" | "component_version_unsatisfied"
+            | "app_version_too_old" => {
                 StatusCode::UNPROCESSABLE_ENTITY
             }
             "has_dependents" | "in_use" => StatusCode::CONFLICT,
@@ -317,6 +318,10 @@ pub async fn fetch_manifest(
         Err(e) => Json(json!({
             "success": false,
             "error": e.to_string(),
+            "error_code": e
+                .downcast_ref::<ExtensionError>()
+                .map(|ext_err| ext_err.error_code.as_str())
+                .unwrap_or("manifest_fetch_failed"),
             "extensions": [],
             "updates": [],
         })),
@@ -375,23 +380,20 @@ pub async fn install_extension(
 
     let mgr = state.extension_manager.write().await;
     match mgr.install_from_url(&ext_id, &download_url, sha256.as_deref()).await {
-        Ok(()) => {
-            // 설치 완료 후 마운트 (write lock 재획득)
+        Ok(installed) => {
+            // 설치된 매니페스트로 바로 마운트 — 재스캔 없이 곧바로 enable 가능
             drop(mgr);
-            let mut mgr = state.extension_manager.write().await;
-            if let Err(e) = mgr.mount(&ext_id) {
-                tracing::warn!("Extension '{}' installed but mount failed: {} — try rescan", ext_id, e);
-                // mount 실패 시 rescan으로 폴백
-                if let Err(e2) = mgr.rescan() {
-                    tracing::error!("Extension '{}' rescan also failed: {}", ext_id, e2);
-                }
-            }
-            Json(json!({ "success": true, "id": ext_id }))
+            let version = installed.manifest.version.clone();
+            state.extension_manager.write().await.mount_installed(installed);
+            Json(json!({ "success": true, "id": ext_id, "version": version }))
         }
         Err(e) => Json(json!({
             "success": false,
             "error": e.to_string(),
-            "error_code": "install_failed",
+            "error_code": e
+                .downcast_ref::<ExtensionError>()
+                .map(|ext_err| ext_err.error_code.as_str())
+                .unwrap_or("install_failed"),
         })),
     }
 }
@@ -420,6 +422,44 @@ pub async fn check_extension_updates(
     }
 }
 
+/// POST /api/extensions/update-all — 업데이트 가능한 익스텐션을 의존성 순서대로 일괄 설치
+pub async fn update_all_extensions(
+    State(state): State<IPCServer>,
+) -> Json<serde_json::Value> {
+    let remote = match state.extension_manager.read().await.fetch_manifest().await {
+        Ok(remote) => remote,
+        Err(e) => return Json(json!({
+            "success": false,
+            "error": format!("Manifest fetch failed: {}", e),
+            "error_code": "manifest_fetch_failed",
+        })),
+    };
+
+    let results = state.extension_manager.write().await.update_all(&remote).await;
+    let updated = results.iter().filter(|(_, r)| r.is_ok()).count();
+    let results: Vec<serde_json::Value> = results
+        .into_iter()
+        .map(|(id, result)| match result {
+            Ok(()) => json!({ "id": id, "success": true }),
+            Err(e) => json!({
+                "id": id,
+                "success": false,
+                "error": e.to_string(),
+                "error_code": e
+                    .downcast_ref::<ExtensionError>()
+                    .map(|ext_err| ext_err.error_code.as_str())
+                    .unwrap_or("install_failed"),
+            }),
+        })
+        .collect();
+
+    Json(json!({
+        "success": updated == results.len(),
+        "updated": updated,
+        "results": results,
+    }))
+}
+
 /// DELETE /api/extensions/:id — 익스텐션 제거 (비활성화 + 디렉토리 삭제)
 pub async fn remove_extension(
     State(state): State<IPCServer>,
@@ -316,10 +316,9 @@ pub async fn create_instance(
                     let tracker_done = tracker.clone();
                     let name_done = inst_clone.name.clone();
                     tokio::spawn(async move {
-                        let mgr = ext_mgr.read().await;
-                        let results = mgr.dispatch_hook_with_progress(
-                            "server.post_create", ctx, on_progress,
-                        ).await;
+                        // 스냅샷만 잠금 아래에서 만들고, 장시간 프로비저닝 동안에는 잠금을 풀어 둔다
+                        let hooks = ext_mgr.read().await.snapshot_hooks("server.post_create");
+                        let results = hooks.dispatch_with_progress(ctx, on_progress).await;
                         // 완료 또는 에러 상태 기록 (Err variant + Python success:false 모두 체크)
                         let err = results.iter().find_map(|(_, r)| match r {
                             Err(e) => Some(e.to_string()),
@@ -1,14 +1,14 @@
 ﻿use axum::{
     extract::{Path, State},
-    http::StatusCode,
+    http::{header, HeaderMap, StatusCode},
     response::IntoResponse,
     Json,
 };
 use serde_json::json;
 
 use super::super::{
-    IPCServer, ExtensionInfo, ExtensionListResponse, PortConflictInfo, ProtocolsInfo, ServerInfo, ServerListResponse,
-    ServerStartRequest, ServerStopRequest,
+    IPCServer, ExtensionInfo, ExtensionListResponse, ModuleIconCache, PortConflictInfo, ProtocolsInfo, ServerInfo,
+    ServerListResponse, ServerStartRequest, ServerStopRequest,
 };
 
 /// GET /health - 데몬 활성 확인용 경량 ping
@@ -21,6 +21,8 @@ pub async fn health_check() -> impl IntoResponse {
 }
 
 /// GET /api/servers - 모든 서버 목록 (인스턴스 기반)
+///
+/// 행 순서는 인스턴스 저장소 순서(order.json, 사용자 재정렬 반영)를 그대로 따릅니다.
 pub async fn list_servers(State(state): State<IPCServer>) -> impl IntoResponse {
     // ── Phase 1: supervisor lock을 최소 시간만 잡고 필요한 데이터 복사 ──
     struct InstanceSnapshot {
@@ -109,8 +111,8 @@ pub async fn list_servers(State(state): State<IPCServer>) -> impl IntoResponse {
                     "instance_dir": &instance.instance_dir,
                     "process_patterns": &instance.process_patterns,
                 });
-                let mgr = ext_mgr.read().await;
-                let results = mgr.dispatch_hook_timed("server.list_enrich", ctx, 10).await;
+                let hooks = ext_mgr.read().await.snapshot_hooks("server.list_enrich");
+                let results = hooks.dispatch_timed(ctx, 10).await;
                 // 첫 번째 성공 응답을 캐시 (handled 여부 무관)
                 let result = results.into_iter()
                     .find_map(|(_id, r)| r.ok())
@@ -223,8 +225,8 @@ pub async fn list_servers(State(state): State<IPCServer>) -> impl IntoResponse {
 
     // ── 포트 충돌 계산: 모든 서버 간 포트 겹침을 검사하여 per-server 정보 채움 ──
     {
-        // 포트 → (server_index, port_type) 매핑
-        let mut port_map: std::collections::HashMap<u16, Vec<(usize, &str)>> = std::collections::HashMap::new();
+        // 포트 → (server_index, port_type) 매핑 — 폴링마다 충돌 목록 순서가 같도록 포트 순 정렬
+        let mut port_map: std::collections::BTreeMap<u16, Vec<(usize, &str)>> = std::collections::BTreeMap::new();
         for (idx, srv) in servers.iter().enumerate() {
             for (p, pt) in [
                 (srv.port, "port"),
@@ -265,52 +267,100 @@ pub async fn list_servers(State(state): State<IPCServer>) -> impl IntoResponse {
     Json(ServerListResponse { servers, port_conflict_stops })
 }
 
+/// 로드된 모듈 목록을 API 응답 형식으로 변환 (아이콘은 `ModuleIconCache` 경유)
+fn module_infos(
+    modules: Vec<crate::supervisor::module_loader::LoadedModule>,
+    icon_cache: &ModuleIconCache,
+) -> Vec<ExtensionInfo> {
+    modules
+        .into_iter()
+        .map(|m| {
+            let icon_base64 = m.metadata.icon.as_ref().and_then(|icon_file| {
+                icon_cache.data_url(&std::path::Path::new(&m.path).join(icon_file))
+            });
+
+            ExtensionInfo {
+                name: m.metadata.name,
+                version: m.metadata.version,
+                description: m.metadata.description,
+                path: m.path,
+                executable_path: m.metadata.executable_path,
+                icon: icon_base64,
+                interaction_mode: m.metadata.interaction_mode,
+                protocols: m.metadata.protocols_supported.map(|supported| {
+                    ProtocolsInfo {
+                        supported,
+                        default: m.metadata.protocols_default,
+                    }
+                }),
+                settings: m.metadata.settings,
+                commands: m.metadata.commands,
+                syntax_highlight: m.metadata.syntax_highlight,
+                dir_signatures: m.metadata.dir_signatures,
+                install: m.metadata.install,
+                module_aliases: m.metadata.module_aliases,
+                command_aliases: m.metadata.command_aliases,
+            }
+        })
+        .collect()
+}
+
+/// 모듈 목록 ETag — 이름/버전/경로와 module.toml·아이콘 mtime으로 계산 (파일 내용은 읽지 않음)
+fn modules_etag(modules: &[crate::supervisor::module_loader::LoadedModule]) -> String {
+    use std::hash::{Hash, Hasher};
+
+    let mtime = |path: std::path::PathBuf| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    let mut entries: Vec<_> = modules
+        .iter()
+        .map(|m| {
+            let dir = std::path::Path::new(&m.path);
+            (
+                &m.metadata.name,
+                &m.metadata.version,
+                &m.path,
+                mtime(dir.join("module.toml")),
+                m.metadata.icon.as_ref().and_then(|icon| mtime(dir.join(icon))),
+            )
+        })
+        .collect();
+    entries.sort();
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    entries.hash(&mut hasher);
+    format!("\"{:016x}\"", hasher.finish())
+}
+
+/// `If-None-Match`가 현재 ETag(또는 `*`)를 포함하는지
+fn etag_matches(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(|tag| tag.trim().trim_start_matches("W/"))
+        .any(|tag| tag == etag || tag == "*")
+}
+
 /// GET /api/modules - 모든 모듈 목록
-pub async fn list_modules(State(state): State<IPCServer>) -> impl IntoResponse {
-    let supervisor = state.supervisor.read().await;
+///
+/// 응답에 `ETag`를 붙이고, `If-None-Match`가 일치하면 본문 없이 `304`를 반환합니다.
+pub async fn list_modules(State(state): State<IPCServer>, headers: HeaderMap) -> impl IntoResponse {
+    // 아이콘 처리는 supervisor 잠금을 놓은 뒤에 수행
+    let modules = state.supervisor.read().await.list_modules();
 
-    match supervisor.list_modules() {
+    match modules {
         Ok(modules) => {
-            let module_infos: Vec<ExtensionInfo> = modules
-                .into_iter()
-                .map(|m| {
-                    // 아이콘 파일을 base64로 인코딩
-                    let icon_base64 = m.metadata.icon.as_ref().and_then(|icon_file| {
-                        let icon_path = std::path::Path::new(&m.path).join(icon_file);
-                        std::fs::read(&icon_path).ok().map(|data| {
-                            use base64::{engine::general_purpose, Engine as _};
-                            format!(
-                                "data:image/png;base64,{}",
-                                general_purpose::STANDARD.encode(&data)
-                            )
-                        })
-                    });
-
-                    ExtensionInfo {
-                        name: m.metadata.name,
-                        version: m.metadata.version,
-                        description: m.metadata.description,
-                        path: m.path,
-                        executable_path: m.metadata.executable_path,
-                        icon: icon_base64,
-                        interaction_mode: m.metadata.interaction_mode,
-                        protocols: m.metadata.protocols_supported.map(|supported| {
-                            ProtocolsInfo {
-                                supported,
-                                default: m.metadata.protocols_default,
-                            }
-                        }),
-                        settings: m.metadata.settings,
-                        commands: m.metadata.commands,
-                        syntax_highlight: m.metadata.syntax_highlight,
-                        dir_signatures: m.metadata.dir_signatures,
-                        install: m.metadata.install,
-                        module_aliases: m.metadata.module_aliases,
-                        command_aliases: m.metadata.command_aliases,
-                    }
-                })
-                .collect();
-            (StatusCode::OK, Json(ExtensionListResponse { modules: module_infos })).into_response()
+            let etag = modules_etag(&modules);
+            if etag_matches(&headers, &etag) {
+                return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response();
+            }
+            let module_infos = module_infos(modules, &state.module_icon_cache);
+            (
+                StatusCode::OK,
+                [(header::ETAG, etag)],
+                Json(ExtensionListResponse { modules: module_infos }),
+            )
+                .into_response()
         }
         Err(e) => {
             let error = json!({ "error": format!("Failed to list modules: {}", e) });
@@ -321,54 +371,23 @@ pub async fn list_modules(State(state): State<IPCServer>) -> impl IntoResponse {
 
 /// POST /api/modules/refresh - 모듈 캐시를 새로고침하고 다시 발견
 pub async fn refresh_modules(State(state): State<IPCServer>) -> impl IntoResponse {
-    let supervisor = state.supervisor.read().await;
+    let modules = state.supervisor.read().await.refresh_modules();
+    state.module_icon_cache.clear();
 
-    match supervisor.refresh_modules() {
+    match modules {
         Ok(modules) => {
-            let module_infos: Vec<ExtensionInfo> = modules
-                .into_iter()
-                .map(|m| {
-                    // 아이콘 파일을 base64로 인코딩
-                    let icon_base64 = m.metadata.icon.as_ref().and_then(|icon_file| {
-                        let icon_path = std::path::Path::new(&m.path).join(icon_file);
-                        std::fs::read(&icon_path).ok().map(|data| {
-                            use base64::{engine::general_purpose, Engine as _};
-                            format!(
-                                "data:image/png;base64,{}",
-                                general_purpose::STANDARD.encode(&data)
-                            )
-                        })
-                    });
-
-                    ExtensionInfo {
-                        name: m.metadata.name,
-                        version: m.metadata.version,
-                        description: m.metadata.description,
-                        path: m.path,
-                        executable_path: m.metadata.executable_path,
-                        icon: icon_base64,
-                        interaction_mode: m.metadata.interaction_mode,
-                        protocols: m.metadata.protocols_supported.map(|supported| {
-                            ProtocolsInfo {
-                                supported,
-                                default: m.metadata.protocols_default,
-                            }
-                        }),
-                        settings: m.metadata.settings,
-                        commands: m.metadata.commands,
-                        syntax_highlight: m.metadata.syntax_highlight,
-                        dir_signatures: m.metadata.dir_signatures,
-                        install: m.metadata.install,
-                        module_aliases: m.metadata.module_aliases,
-                        command_aliases: m.metadata.command_aliases,
-                    }
-                })
-                .collect();
+            let etag = modules_etag(&modules);
+            let module_infos = module_infos(modules, &state.module_icon_cache);
             tracing::info!(
                 "Module cache refreshed. Found {} modules",
                 module_infos.len()
             );
-            (StatusCode::OK, Json(ExtensionListResponse { modules: module_infos })).into_response()
+            (
+                StatusCode::OK,
+                [(header::ETAG, etag)],
+                Json(ExtensionListResponse { modules: module_infos }),
+            )
+                .into_response()
         }
         Err(e) => {
             let error = json!({ "error": format!("Failed to refresh modules: {}", e) });
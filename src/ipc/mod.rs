@@ -44,6 +44,8 @@ pub struct ClientRegistry {
     had_clients_ever: Arc<RwLock<bool>>,
     /// 마지막 클라이언트가 사라진 시점 (None = 아직 클라이언트 있음)
     last_client_lost_at: Arc<RwLock<Option<std::time::Instant>>>,
+    /// 하트비트 만료 / 워치독 타이밍
+    config: crate::config::HeartbeatConfig,
 }
 
 impl Default for ClientRegistry {
@@ -52,6 +54,7 @@ impl Default for ClientRegistry {
             inner: Arc::new(RwLock::new(HashMap::new())),
             had_clients_ever: Arc::new(RwLock::new(false)),
             last_client_lost_at: Arc::new(RwLock::new(None)),
+            config: crate::config::HeartbeatConfig::default(),
         }
     }
 }
@@ -61,15 +64,37 @@ impl ClientRegistry {
         Self::default()
     }
 
-    /// 새 클라이언트 등록, client_id 반환
-    pub async fn register(&self, kind: ClientKind) -> String {
-        let id = uuid::Uuid::new_v4().to_string();
+    /// 지정한 타이밍 설정으로 레지스트리 생성
+    pub fn with_config(config: crate::config::HeartbeatConfig) -> Self {
+        Self { config, ..Self::default() }
+    }
+
+    /// 하트비트 만료 / 워치독 타이밍 설정
+    pub fn config(&self) -> &crate::config::HeartbeatConfig {
+        &self.config
+    }
+
+    /// 클라이언트 등록, client_id 반환
+    ///
+    /// `client_key`(설치별 고정 키)를 주면 그 값을 client_id로 사용하고,
+    /// 같은 키로 등록된 기존 항목(크래시 후 재접속 전의 유령 항목)을 대체합니다.
+    /// 기존 항목의 bot_pid는 재접속 후에도 정리 대상이 되도록 이어받습니다.
+    pub async fn register(&self, kind: ClientKind, client_key: Option<&str>) -> String {
+        let id = match client_key {
+            Some(key) => key.to_string(),
+            None => uuid::Uuid::new_v4().to_string(),
+        };
         let mut map = self.inner.write().await;
-        tracing::info!("[Heartbeat] Client registered: {} ({:?})", id, kind);
+        let previous = map.remove(&id);
+        if previous.is_some() {
+            tracing::info!("[Heartbeat] Client re-registered, replacing stale entry: {} ({:?})", id, kind);
+        } else {
+            tracing::info!("[Heartbeat] Client registered: {} ({:?})", id, kind);
+        }
         map.insert(id.clone(), RegisteredClient {
             kind,
             last_heartbeat: std::time::Instant::now(),
-            bot_pid: None,
+            bot_pid: previous.and_then(|c| c.bot_pid),
         });
         // 클라이언트가 등록되면 "한 번이라도 연결됨" 플래그 세팅, lost 타임스탬프 해제
         *self.had_clients_ever.write().await = true;
@@ -419,6 +444,49 @@ impl ExtensionStatusCache {
     }
 }
 
+// ── Module Icon Cache ───────────────────────────────────────
+
+/// 모듈 아이콘 data URL 캐시 — 파일 경로별로 (mtime, 인코딩 결과)를 보관하여
+/// `/api/modules` 폴링마다 PNG를 다시 읽고 base64 인코딩하지 않도록 함
+#[derive(Debug, Clone, Default)]
+pub struct ModuleIconCache {
+    inner: Arc<std::sync::Mutex<HashMap<std::path::PathBuf, (std::time::SystemTime, String)>>>,
+}
+
+impl ModuleIconCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 아이콘 파일의 data URL — mtime이 캐시와 같으면 재사용, 바뀌었으면 다시 인코딩.
+    /// 파일을 읽을 수 없으면 None.
+    pub fn data_url(&self, path: &std::path::Path) -> Option<String> {
+        let mtime = std::fs::metadata(path).and_then(|m| m.modified()).ok()?;
+        if let Some((cached_mtime, url)) = self.inner.lock().ok()?.get(path) {
+            if *cached_mtime == mtime {
+                return Some(url.clone());
+            }
+        }
+
+        let data = std::fs::read(path).ok()?;
+        let url = {
+            use base64::{engine::general_purpose, Engine as _};
+            format!("data:image/png;base64,{}", general_purpose::STANDARD.encode(&data))
+        };
+        if let Ok(mut map) = self.inner.lock() {
+            map.insert(path.to_path_buf(), (mtime, url.clone()));
+        }
+        Some(url)
+    }
+
+    /// 전체 캐시 무효화 (모듈 새로고침 시)
+    pub fn clear(&self) {
+        if let Ok(mut map) = self.inner.lock() {
+            map.clear();
+        }
+    }
+}
+
 /// 익스텐션 초기화 상태 추적
 #[derive(Clone)]
 pub struct ExtensionInitTracker {
@@ -512,6 +580,8 @@ pub struct IPCServer {
     pub extension_status_cache: ExtensionStatusCache,
     /// 익스텐션 초기화(daemon.startup) 진행 상태
     pub extension_init_tracker: ExtensionInitTracker,
+    /// 모듈 아이콘 base64 캐시 (list_modules 폴링 가속)
+    pub module_icon_cache: ModuleIconCache,
     /// 인스턴스별 RCON 연결 풀 (연결 재사용)
     pub rcon_pool: Arc<crate::protocol::rcon_pool::RconPool>,
     /// Graceful shutdown 토큰 — 취소 시 데몬 전체 종료
@@ -558,6 +628,7 @@ impl IPCServer {
             extension_manager,
             extension_status_cache: ExtensionStatusCache::new(30), // 30초 TTL (Docker/WSL 지연 대비)
             extension_init_tracker: ExtensionInitTracker::new(),
+            module_icon_cache: ModuleIconCache::new(),
             rcon_pool: Arc::new(crate::protocol::rcon_pool::RconPool::new()),
             shutdown_token: tokio_util::sync::CancellationToken::new(),
             ext_process_manager: handlers::ext_process::new_ext_process_manager(),
@@ -566,6 +637,12 @@ impl IPCServer {
         }
     }
 
+    /// 하트비트/워치독 타이밍 설정 적용 (기존 클라이언트가 없는 기동 직후에만 호출)
+    pub fn with_heartbeat_config(mut self, config: crate::config::HeartbeatConfig) -> Self {
+        self.client_registry = ClientRegistry::with_config(config);
+        self
+    }
+
     pub async fn start(self) -> Result<()> {
         tracing::info!("IPC HTTP server starting on {}", self.listen_addr);
 
@@ -643,6 +720,7 @@ impl IPCServer {
             .route("/api/extensions/rescan", post(handlers::extension::rescan_extensions))
             .route("/api/extensions/manifest", get(handlers::extension::fetch_manifest))
             .route("/api/extensions/updates", get(handlers::extension::check_extension_updates))
+            .route("/api/extensions/update-all", post(handlers::extension::update_all_extensions))
             .route("/api/extensions/:id/enable", post(handlers::extension::enable_extension))
             .route("/api/extensions/:id/disable", post(handlers::extension::disable_extension))
             .route("/api/extensions/:id/mount", post(handlers::extension::mount_extension))
@@ -733,6 +811,80 @@ mod tests {
         assert!(response["success"].as_bool().unwrap());
     }
 
+    #[tokio::test]
+    async fn test_client_register_with_key_replaces_stale_entry() {
+        let registry = ClientRegistry::new();
+        let id = registry.register(ClientKind::Gui, Some("gui-install-a")).await;
+        assert_eq!(id, "gui-install-a");
+        assert!(registry.heartbeat(&id, Some(4242)).await);
+
+        // 크래시 후 같은 키로 재등록 → 중복 없이 대체, bot_pid 유지
+        let again = registry.register(ClientKind::Gui, Some("gui-install-a")).await;
+        assert_eq!(again, id);
+        assert_eq!(registry.count().await, 1);
+        let all = registry.reap_expired(std::time::Duration::ZERO).await;
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].1.bot_pid, Some(4242));
+
+        // 키 없이 등록하면 매번 새 항목
+        let a = registry.register(ClientKind::Cli, None).await;
+        let b = registry.register(ClientKind::Cli, None).await;
+        assert_ne!(a, b);
+        assert_eq!(registry.count().await, 2);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn test_kill_bot_pid_terminates_gracefully() {
+        let mut child = std::process::Command::new("sleep").arg("30").spawn().unwrap();
+        let outcome = kill_bot_pid(child.id()).await;
+        assert!(outcome.exited);
+        assert!(!outcome.escalated);
+        let _ = child.wait();
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn test_kill_bot_pid_escalates_when_sigterm_is_ignored() {
+        // 무시된 시그널은 exec 후에도 유지 → sleep이 SIGTERM을 무시
+        let mut child = std::process::Command::new("sh")
+            .args(["-c", "trap '' TERM; exec sleep 30"])
+            .spawn()
+            .unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        let outcome = kill_bot_pid(child.id()).await;
+        assert!(outcome.exited);
+        assert!(outcome.escalated);
+        let _ = child.wait();
+    }
+
+    #[test]
+    fn test_module_icon_cache_reencodes_only_on_mtime_change() {
+        let tmp = tempfile::tempdir().unwrap();
+        let icon = tmp.path().join("icon.png");
+        std::fs::write(&icon, b"first").unwrap();
+        let cache = ModuleIconCache::new();
+
+        let first = cache.data_url(&icon).unwrap();
+        assert_eq!(first, "data:image/png;base64,Zmlyc3Q=");
+
+        // 내용이 바뀌어도 mtime이 같으면 캐시 사용
+        let mtime = std::fs::metadata(&icon).unwrap().modified().unwrap();
+        std::fs::write(&icon, b"other").unwrap();
+        std::fs::File::options().write(true).open(&icon).unwrap().set_modified(mtime).unwrap();
+        assert_eq!(cache.data_url(&icon).unwrap(), first);
+
+        // mtime이 바뀌면 다시 인코딩
+        let later = mtime + std::time::Duration::from_secs(5);
+        std::fs::File::options().write(true).open(&icon).unwrap().set_modified(later).unwrap();
+        assert_eq!(cache.data_url(&icon).unwrap(), "data:image/png;base64,b3RoZXI=");
+
+        // clear 후에는 현재 파일 기준으로 재계산, 없는 파일은 None
+        cache.clear();
+        assert_eq!(cache.data_url(&icon).unwrap(), "data:image/png;base64,b3RoZXI=");
+        assert!(cache.data_url(&tmp.path().join("missing.png")).is_none());
+    }
+
     #[test]
     fn test_module_info_serialization_with_commands() {
         let module_info = ExtensionInfo {
@@ -4,26 +4,33 @@
 //! 파일 적용(apply)은 별도 업데이터 프로세스에서 처리합니다.
 //!
 //! ## 엔드포인트
+//! - `GET  /api/updates`                     — 캐시된 업데이트 상태 조회 (`/status` 별칭)
 //! - `GET  /api/updates/status`              — 캐시된 업데이트 상태 조회
 //! - `POST /api/updates/check`               — 업데이트 확인 (GitHub API 호출)
 //! - `POST /api/updates/download`            — 선택 컴포넌트 다운로드
 //! - `POST /api/updates/apply`               — 업데이터 exe 스폰하여 적용
 //! - `GET  /api/updates/config`              — 업데이트 설정 조회
 //! - `PUT  /api/updates/config`              — 업데이트 설정 변경
+//! - `POST /api/install/run`                 — 미설치 컴포넌트 일괄 설치
+//! - `POST /api/install/component/:key`      — 단일 컴포넌트 설치 (버전 지정 가능)
+//! - `GET  /api/install/progress`            — 설치 진행률 조회
+//! - `POST /api/install/cancel`              — 진행 중인 설치 취소
 
 use axum::{
-    extract::State,
+    extract::{Path, State},
     response::IntoResponse,
     routing::{get, post, put},
     Json, Router,
 };
 use serde::Deserialize;
 use serde_json::{json, Value};
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use saba_chan_updater_lib::{
-    Component, DownloadProgress, UpdateConfig, UpdateManager,
+    ApplyExitCode, Component, ComponentVersion, DownloadProgress, InstallProgress, ReleaseChannel, UpdateConfig,
+    UpdateManager, UpdateStatus, UpdaterError,
 };
 
 // ═══════════════════════════════════════════════════════
@@ -36,6 +43,17 @@ pub struct UpdateState {
     pub manager: Arc<RwLock<UpdateManager>>,
     /// 다운로드 진행률 (Manager 잠금 없이 폴링 가능)
     pub download_progress: Arc<std::sync::Mutex<DownloadProgress>>,
+    /// 설치 진행률 (Manager 잠금 없이 폴링 가능)
+    pub install_progress: Arc<std::sync::Mutex<Option<InstallProgress>>>,
+    /// 설치 취소 플래그 (Manager 잠금 없이 취소 가능)
+    pub install_cancel: Arc<std::sync::atomic::AtomicBool>,
+    /// 마지막으로 관측한 업데이트 상태 (확인 중 Manager가 잠겨 있어도 조회 가능)
+    ///
+    /// Manager를 오래 잠그는 요청이 시작될 때와 확인이 끝날 때만 갱신하며,
+    /// 폴링은 `Arc`만 복제하므로 릴리즈 노트까지 깊은 복사하지 않습니다.
+    pub status_snapshot: Arc<std::sync::Mutex<Arc<UpdateStatus>>>,
+    /// 업데이트 확인 진행 중 플래그 — 중복 확인 요청을 즉시 거절
+    pub check_running: Arc<std::sync::atomic::AtomicBool>,
     /// 모듈/익스텐션 업데이트 적용 후 핫로드를 위한 참조
     pub supervisor: Option<Arc<RwLock<crate::supervisor::Supervisor>>>,
     pub extension_manager: Option<Arc<RwLock<crate::extension::ExtensionManager>>>,
@@ -48,10 +66,17 @@ impl UpdateState {
         let modules_dir = resolve_modules_dir();
         let mgr = UpdateManager::new(cfg, &modules_dir);
         let progress = mgr.download_progress.clone();
+        let install_progress = mgr.install_progress.clone();
+        let install_cancel = mgr.install_cancel.clone();
+        let status_snapshot = Arc::new(std::sync::Mutex::new(Arc::new(mgr.status().clone())));
         let manager = Arc::new(RwLock::new(mgr));
         Self {
             manager,
             download_progress: progress,
+            install_progress,
+            install_cancel,
+            status_snapshot,
+            check_running: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             supervisor: None,
             extension_manager: None,
         }
@@ -67,6 +92,39 @@ impl UpdateState {
         self.extension_manager = Some(extension_manager);
         self
     }
+
+    /// 상태 스냅샷 교체
+    fn store_snapshot(&self, status: Arc<UpdateStatus>) {
+        *self.status_snapshot.lock().unwrap_or_else(|e| e.into_inner()) = status;
+    }
+
+    /// Manager 쓰기 잠금 획득 — 잠금을 오래 쥘 작업 전에 현재 상태를 스냅샷으로 남겨
+    /// 그동안의 상태 폴링이 최신에 가까운 값을 돌려주도록 함
+    async fn write_manager(&self) -> tokio::sync::RwLockWriteGuard<'_, UpdateManager> {
+        let mgr = self.manager.write().await;
+        self.store_snapshot(Arc::new(mgr.status().clone()));
+        mgr
+    }
+}
+
+/// 업데이트 확인 진행 플래그 — 핸들러가 끝나거나 (클라이언트 연결 종료로) 취소·패닉되어도
+/// `Drop`에서 해제되어 이후 확인이 `check_in_progress`로 막히지 않음
+struct CheckGuard(Arc<std::sync::atomic::AtomicBool>);
+
+impl CheckGuard {
+    /// 다른 확인이 진행 중이면 None
+    fn acquire(flag: &Arc<std::sync::atomic::AtomicBool>) -> Option<Self> {
+        if flag.swap(true, Ordering::SeqCst) {
+            return None;
+        }
+        Some(Self(flag.clone()))
+    }
+}
+
+impl Drop for CheckGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
 }
 
 impl Default for UpdateState {
@@ -82,6 +140,7 @@ impl Default for UpdateState {
 /// `/api/updates/*` 라우트를 포함하는 axum Router 생성
 pub fn updates_router(state: UpdateState) -> Router {
     Router::new()
+        .route("/api/updates", get(get_status))
         .route("/api/updates/status", get(get_status))
         .route("/api/updates/check", post(check_updates))
         .route("/api/updates/download", post(download_components))
@@ -90,6 +149,11 @@ pub fn updates_router(state: UpdateState) -> Router {
         .route("/api/updates/integrity", get(check_integrity))
         .route("/api/updates/config", get(get_config))
         .route("/api/updates/config", put(set_config))
+        .route("/api/install/run", post(run_install))
+        .route("/api/install/component/:key", post(install_component))
+        .route("/api/install/component/:key/reinstall", post(reinstall_component))
+        .route("/api/install/progress", get(get_install_progress))
+        .route("/api/install/cancel", post(cancel_install))
         .with_state(state)
 }
 
@@ -97,30 +161,46 @@ pub fn updates_router(state: UpdateState) -> Router {
 // 핸들러
 // ═══════════════════════════════════════════════════════
 
-/// GET /api/updates/status — 캐시된 상태 반환 (GitHub API 호출 없음)
+/// GET /api/updates, /api/updates/status — 캐시된 상태 반환 (GitHub API 호출 없음)
 ///
 /// Locales 컴포넌트는 사용자에게 비표시 — 백그라운드 자동 다운로드/적용 대상이므로
 /// 응답의 `components`, `updates_available` 에서 제외합니다.
+/// 확인/다운로드로 Manager가 잠겨 있으면 기다리지 않고 마지막 스냅샷을 반환합니다.
 async fn get_status(
     State(state): State<UpdateState>,
 ) -> impl IntoResponse {
-    let mgr = state.manager.read().await;
-    let status = mgr.get_status();
+    match state.manager.try_read() {
+        Ok(mgr) => status_response(mgr.status(), false),
+        Err(_) => {
+            let snapshot = state.status_snapshot.lock().unwrap_or_else(|e| e.into_inner()).clone();
+            status_response(&snapshot, state.check_running.load(Ordering::SeqCst))
+        }
+    }
+}
+
+/// 상태 응답 JSON — `checking`이면 스냅샷 값과 관계없이 확인 중으로 표시
+/// GUI에 보내는 컴포넌트 항목 (status/check 응답 공용)
+fn component_json(c: &ComponentVersion) -> Value {
+    json!({
+        "component": c.component.manifest_key(),
+        "display_name": c.component.display_name(),
+        "current_version": c.current_version,
+        "latest_version": c.latest_version,
+        "update_available": c.update_available,
+        "downloaded": c.downloaded,
+        "installed": c.installed,
+        "warning": c.warning,
+        "release_notes": c.release_notes,
+        "published_at": c.published_at,
+    })
+}
 
+fn status_response(status: &UpdateStatus, checking: bool) -> Json<Value> {
     // Locales는 UI에 표시하지 않음 — 백그라운드 자동 적용 대상
     let components: Vec<Value> = status.components.iter()
         .filter(|c| !matches!(c.component, Component::Locales))
-        .map(|c| {
-            json!({
-                "component": c.component.manifest_key(),
-                "display_name": c.component.display_name(),
-                "current_version": c.current_version,
-                "latest_version": c.latest_version,
-                "update_available": c.update_available,
-                "downloaded": c.downloaded,
-                "installed": c.installed,
-            })
-        }).collect();
+        .map(component_json)
+        .collect();
 
     let visible_update_count = status.components.iter()
         .filter(|c| c.update_available && !matches!(c.component, Component::Locales))
@@ -130,7 +210,7 @@ async fn get_status(
         "ok": true,
         "last_check": status.last_check,
         "next_check": status.next_check,
-        "checking": status.checking,
+        "checking": status.checking || checking,
         "error": status.error,
         "updates_available": visible_update_count,
         "components": components,
@@ -144,10 +224,26 @@ async fn get_status(
 async fn check_updates(
     State(state): State<UpdateState>,
 ) -> impl IntoResponse {
+    // 이미 확인 중이면 Manager 잠금을 기다리지 않고 즉시 반환
+    let Some(check_guard) = CheckGuard::acquire(&state.check_running) else {
+        return Json(json!({
+            "ok": false,
+            "error": "An update check is already in progress",
+            "code": "check_in_progress",
+            "checking": true,
+        }));
+    };
     let result = {
         let mut mgr = state.manager.write().await;
-        mgr.check_for_updates().await
+        let result = mgr.check_for_updates().await.map(Arc::new);
+        let snapshot = match result {
+            Ok(ref status) => status.clone(),
+            Err(_) => Arc::new(mgr.status().clone()),
+        };
+        state.store_snapshot(snapshot);
+        result
     };
+    drop(check_guard);
 
     match result {
         Ok(status) => {
@@ -165,18 +261,8 @@ async fn check_updates(
             // Locales를 제외한 "사용자에게 보이는" 컴포넌트 목록
             let components: Vec<Value> = status.components.iter()
                 .filter(|c| !matches!(c.component, Component::Locales))
-                .map(|c| {
-                    json!({
-                        "component": c.component.manifest_key(),
-                        "display_name": c.component.display_name(),
-                        "current_version": c.current_version,
-                        "latest_version": c.latest_version,
-                        "update_available": c.update_available,
-                        "downloaded": c.downloaded,
-                        "installed": c.installed,
-                        "release_notes": c.release_notes,
-                    })
-                }).collect();
+                .map(component_json)
+                .collect();
 
             let update_names: Vec<String> = status.components.iter()
                 .filter(|c| c.update_available && !matches!(c.component, Component::Locales))
@@ -192,9 +278,16 @@ async fn check_updates(
             }))
         }
         Err(e) => {
+            // 오프라인이어도 이미 다운로드된 업데이트는 적용 가능 — GUI가 적용 버튼을 유지하도록 전달
+            let staged_updates = match &e {
+                UpdaterError::Offline { staged_updates } => *staged_updates,
+                _ => 0,
+            };
             Json(json!({
                 "ok": false,
                 "error": e.to_string(),
+                "code": e.code(),
+                "staged_updates": staged_updates,
             }))
         }
     }
@@ -241,22 +334,25 @@ async fn silent_apply_locales(manager: &Arc<RwLock<UpdateManager>>) {
 /// POST /api/updates/download — 선택한 컴포넌트 다운로드
 ///
 /// Body: `{ "components": ["gui", "cli", "module-minecraft"] }`
-/// 비어있으면 업데이트 가능한 전체를 다운로드
+/// 비어있으면 업데이트 가능한 전체를 다운로드 — `{ "exclude": ["gui"] }`로 일부 제외 가능
 #[derive(Deserialize)]
 struct DownloadRequest {
     /// 다운로드할 컴포넌트 키 목록. 비어있으면 전체.
     #[serde(default)]
     components: Vec<String>,
+    /// 전체 다운로드 시 제외할 컴포넌트 키 목록 (`components`가 비어있을 때만 사용)
+    #[serde(default)]
+    exclude: Vec<String>,
 }
 
 async fn download_components(
     State(state): State<UpdateState>,
     Json(body): Json<DownloadRequest>,
 ) -> impl IntoResponse {
-    let mut mgr = state.manager.write().await;
+    let mut mgr = state.write_manager().await;
 
     // 아직 체크하지 않았으면 먼저 체크
-    if mgr.get_status().components.is_empty() {
+    if mgr.status().components.is_empty() {
         if let Err(e) = mgr.check_for_updates().await {
             return Json(json!({
                 "ok": false,
@@ -266,8 +362,8 @@ async fn download_components(
     }
 
     if body.components.is_empty() {
-        // 전체 다운로드
-        match mgr.download_available_updates().await {
+        // 전체 다운로드 (제외 목록 반영)
+        match mgr.download_available_updates_except(&body.exclude).await {
             Ok(downloaded) => {
                 // 업데이터 --apply 모드를 위해 매니페스트 저장
                 if let Err(e) = mgr.save_pending_manifest() {
@@ -283,6 +379,7 @@ async fn download_components(
                 Json(json!({
                     "ok": false,
                     "error": e.to_string(),
+                    "code": e.code(),
                 }))
             }
         }
@@ -304,6 +401,7 @@ async fn download_components(
                     errors.push(json!({
                         "component": key,
                         "error": e.to_string(),
+                        "code": e.code(),
                     }));
                 }
             }
@@ -342,23 +440,164 @@ async fn get_download_progress(
     }))
 }
 
+/// POST /api/install/run — 미설치 컴포넌트 일괄 설치
+///
+/// Body: `{ "components": ["cli", "gui"] }` (선택, 비어있으면 전체)
+#[derive(Deserialize)]
+struct InstallRunRequest {
+    #[serde(default)]
+    components: Vec<String>,
+}
+
+async fn run_install(
+    State(state): State<UpdateState>,
+    body: Option<Json<InstallRunRequest>>,
+) -> impl IntoResponse {
+    let filter = body
+        .map(|Json(b)| b.components)
+        .filter(|c| !c.is_empty());
+
+    // 계획 수립에만 Manager를 잠그고, 다운로드·압축 해제 동안에는 잠금 없이 진행
+    let prepared = state.write_manager().await.prepare_install(filter, false).await;
+    match prepared {
+        Ok(job) => {
+            let progress = job.run().await;
+            Json(json!({
+                "ok": progress.errors.is_empty(),
+                "progress": progress,
+            }))
+        }
+        Err(e) => Json(json!({
+            "ok": false,
+            "error": e.to_string(),
+            "code": e.code(),
+        })),
+    }
+}
+
+/// POST /api/install/component/:key — 단일 컴포넌트 설치
+///
+/// Body: `{ "version": "1.2.0" }` (선택, 지정하면 해당 버전으로 설치/롤백)
+#[derive(Deserialize)]
+struct InstallComponentRequest {
+    #[serde(default)]
+    version: Option<String>,
+}
+
+async fn install_component(
+    State(state): State<UpdateState>,
+    Path(key): Path<String>,
+    body: Option<Json<InstallComponentRequest>>,
+) -> impl IntoResponse {
+    let version = body
+        .and_then(|Json(b)| b.version)
+        .filter(|v| !v.trim().is_empty());
+    let component = Component::from_manifest_key(&key);
+
+    let result = match version {
+        Some(version) => install_component_version(&state, &component, &version).await,
+        None => state.write_manager().await.install_component(&component).await
+            .map(|dir| format!("{} installed to {}", component.display_name(), dir)),
+    };
+    match result {
+        Ok(message) => Json(json!({
+            "ok": true,
+            "message": message,
+        })),
+        Err(e) => Json(json!({
+            "ok": false,
+            "error": e.to_string(),
+            "code": e.code(),
+        })),
+    }
+}
+
+/// 버전 지정 설치 — 준비와 반영에만 Manager를 잠그고, 다운로드 중에는 잠금을 풀어
+/// 상태 조회·확인 요청이 다운로드 시간 동안 막히지 않게 함
+async fn install_component_version(
+    state: &UpdateState,
+    component: &Component,
+    version: &str,
+) -> Result<String, UpdaterError> {
+    let job = state.write_manager().await.prepare_component_version(component, version).await?;
+    job.download().await?;
+    state.write_manager().await.finish_component_version(job).await
+}
+
+/// POST /api/install/component/:key/reinstall — 설치 여부와 관계없이 다시 설치 (손상된 설치 복구)
+async fn reinstall_component(
+    State(state): State<UpdateState>,
+    Path(key): Path<String>,
+) -> impl IntoResponse {
+    let component = Component::from_manifest_key(&key);
+    let mut mgr = state.write_manager().await;
+    match mgr.reinstall_component(&component).await {
+        Ok(message) => Json(json!({
+            "ok": true,
+            "message": message,
+        })),
+        Err(e) => Json(json!({
+            "ok": false,
+            "error": e.to_string(),
+            "code": e.code(),
+        })),
+    }
+}
+
+/// GET /api/install/progress — 설치 진행률 조회
+///
+/// 설치는 Manager 잠금 밖에서 진행되므로 공유 핸들에서 직접 읽음.
+async fn get_install_progress(
+    State(state): State<UpdateState>,
+) -> impl IntoResponse {
+    let progress = state.install_progress.lock().unwrap().clone();
+    Json(json!({
+        "ok": true,
+        "progress": progress,
+    }))
+}
+
+/// POST /api/install/cancel — 진행 중인 설치 취소 요청
+///
+/// 이미 설치된 컴포넌트는 유지되며, 설치 응답의 `cancelled`가 true로 표시됨.
+async fn cancel_install(
+    State(state): State<UpdateState>,
+) -> impl IntoResponse {
+    let running = state
+        .install_progress
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|p| !p.complete);
+    if running {
+        state.install_cancel.store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+    Json(json!({
+        "ok": true,
+        "cancelled": running,
+    }))
+}
+
 /// POST /api/updates/apply — 다운로드된 업데이트 적용
 ///
 /// - 모듈: 데몬이 직접 적용 (파일 교체)
 /// - 데몬/GUI/CLI: 업데이터 exe를 스폰하여 적용 (응답에 requires_updater: true)
 ///
-/// Body: `{ "components": ["module-minecraft", "saba-core"] }` (선택 적용, 비어있으면 전체)
+/// Body: `{ "components": ["module-minecraft", "saba-core"], "force": false }`
+/// (선택 적용, 비어있으면 전체 / `force`면 충족되지 않은 `requires`를 무시)
 #[derive(Deserialize)]
 struct ApplyRequest {
     #[serde(default)]
     components: Vec<String>,
+    #[serde(default)]
+    force: bool,
 }
 
 async fn apply_updates(
     State(state): State<UpdateState>,
     Json(body): Json<ApplyRequest>,
 ) -> impl IntoResponse {
-    let mut mgr = state.manager.write().await;
+    let mut mgr = state.write_manager().await;
 
     // 적용 대상 분류
     let pending = mgr.get_pending_components();
@@ -371,6 +610,23 @@ async fn apply_updates(
             .collect()
     };
 
+    // 의존성 사전 확인 — 충족되지 않은 컴포넌트는 force가 아니면 적용 대상에서 제외
+    let target_keys: Vec<String> = targets.iter().map(Component::manifest_key).collect();
+    let mut blocked = mgr.check_apply_dependencies(&target_keys);
+    if body.force {
+        for issue in blocked.iter().flat_map(|check| &check.issues) {
+            tracing::warn!("[Updates] ⚠ FORCED despite unmet dependency: {}", issue.message);
+        }
+        let forced: Vec<String> = blocked.iter().map(|check| check.component.clone()).collect();
+        mgr.acknowledge_dependencies(&forced);
+        blocked.clear();
+    } else {
+        for issue in blocked.iter().flat_map(|check| &check.issues) {
+            tracing::warn!("[Updates] Blocked by unmet dependency: {}", issue.message);
+        }
+        targets.retain(|c| !blocked.iter().any(|check| check.component == c.manifest_key()));
+    }
+
     // 적용 우선순위에 따라 정렬:
     // Updater → 모듈/익스텐션/Locales → DiscordBot → CoreDaemon → 인터페이스
     targets.sort_by_key(|comp| match comp {
@@ -390,17 +646,25 @@ async fn apply_updates(
     // 데몬이 일부만 적용하다 중간에 죽는 문제를 방지하기 위해
     // **모든** 컴포넌트를 업데이터 exe에 위임한다.
     // 업데이터가 프로세스 종료 후 올바른 순서로 일괄 적용.
-    let any_needs_restart = targets.iter().any(|c| matches!(c,
-        Component::Gui | Component::Updater | Component::CoreDaemon
-    ));
+    let any_needs_restart = targets.iter().any(Component::requires_restart);
 
     if any_needs_restart {
         // 전부 업데이터에 위임 — 데몬은 아무것도 직접 적용하지 않음
         needs_updater = targets.iter().map(|c| c.manifest_key()).collect();
     } else {
         // GUI/데몬 재시작 불필요 → 데몬이 직접 적용
+        // 다른 적용 작업(Locales 적용, 포그라운드 업데이터 등)이 진행 중이면 거부
+        let _lease = match mgr.begin_apply() {
+            Ok(lease) => lease,
+            Err(e) => {
+                return Json(json!({
+                    "ok": false,
+                    "error": e.to_string(),
+                }));
+            }
+        };
         for comp in &targets {
-            match mgr.apply_single_component(comp).await {
+            match mgr.apply_single_component_leased(comp).await {
                 Ok(result) if result.success => {
                     applied.push(comp.display_name());
                 }
@@ -465,12 +729,17 @@ async fn apply_updates(
         }
     }
 
+    // 업데이터 위임(GUI/데몬 재시작)이 필요하면 재시작 필요로 간주
+    let exit_code = ApplyExitCode::from_outcome(errors.len() + blocked.len(), !needs_updater.is_empty());
+
     Json(json!({
-        "ok": errors.is_empty(),
+        "ok": errors.is_empty() && blocked.is_empty(),
         "applied": applied,
         "needs_updater": needs_updater,
         "requires_updater": !needs_updater.is_empty(),
         "errors": errors,
+        "blocked": blocked,
+        "exit_code": exit_code.code(),
     }))
 }
 
@@ -478,7 +747,7 @@ async fn apply_updates(
 async fn check_integrity(
     State(state): State<UpdateState>,
 ) -> impl IntoResponse {
-    let mut mgr = state.manager.write().await;
+    let mut mgr = state.write_manager().await;
 
     let report = match mgr.verify_integrity().await {
         Ok(r) => r,
@@ -520,9 +789,11 @@ async fn get_config(
 ) -> impl IntoResponse {
     let mgr = state.manager.read().await;
     let config = mgr.get_config();
+    // 토큰 값은 config 직렬화에서 빠지므로 설정 여부만 알려줌
     Json(json!({
         "ok": true,
         "config": config,
+        "github_token_set": config.effective_github_token().is_some(),
     }))
 }
 
@@ -556,12 +827,67 @@ async fn set_config(
     if let Some(v) = body.get("include_prerelease").and_then(|v| v.as_bool()) {
         cfg.include_prerelease = v;
     }
+    // 채널을 명시하면 deprecated `include_prerelease`보다 우선하도록 해제
+    if let Some(channel) = body.get("channel")
+        .and_then(|v| serde_json::from_value::<ReleaseChannel>(v.clone()).ok())
+    {
+        cfg.channel = channel;
+        cfg.include_prerelease = false;
+    }
     if let Some(v) = body.get("install_root").and_then(|v| v.as_str()) {
         cfg.install_root = Some(v.to_string());
     }
     if let Some(v) = body.get("api_base_url").and_then(|v| v.as_str()) {
         cfg.api_base_url = Some(v.to_string());
     }
+    // null 또는 빈 문자열이면 공개 github.com 사용
+    if let Some(v) = body.get("github_host") {
+        cfg.github_host = v.as_str().map(str::trim).filter(|s| !s.is_empty()).map(String::from);
+    }
+    // null 또는 빈 문자열이면 토큰 해제 (SABA_GITHUB_TOKEN 환경변수는 그대로 적용)
+    if let Some(v) = body.get("github_token") {
+        cfg.github_token = v.as_str().map(str::trim).filter(|s| !s.is_empty()).map(String::from);
+    }
+    if let Some(map) = body.get("api_base_overrides").and_then(|v| v.as_object()) {
+        cfg.api_base_overrides = map
+            .iter()
+            .filter_map(|(repo, url)| url.as_str().map(|u| (repo.clone(), u.to_string())))
+            .collect();
+    }
+    // null 또는 0이면 백그라운드 다운로드 대역폭 제한 해제
+    if let Some(v) = body.get("max_download_kbps") {
+        cfg.max_download_kbps = v.as_u64().filter(|k| *k > 0).map(|k| k as u32);
+    }
+    // null 또는 빈 문자열이면 로컬 릴리즈 소스 해제 (GitHub 사용)
+    if let Some(v) = body.get("local_release_dir") {
+        cfg.local_release_dir = v.as_str().map(str::trim).filter(|s| !s.is_empty()).map(String::from);
+    }
+    // null 또는 빈 문자열이면 업데이트 알림 웹훅 해제
+    if let Some(v) = body.get("webhook_url") {
+        cfg.webhook_url = v.as_str().map(str::trim).filter(|s| !s.is_empty()).map(String::from);
+    }
+    if let Some(map) = body.get("pinned").and_then(|v| v.as_object()) {
+        cfg.pinned = map
+            .iter()
+            .filter_map(|(key, version)| version.as_str().map(|v| (key.clone(), v.to_string())))
+            .collect();
+    }
+    if let Some(v) = body.get("release_scan_depth").and_then(|v| v.as_u64()) {
+        cfg.release_scan_depth = (v as u32).max(1);
+    }
+    if let Some(v) = body.get("staging_max_age_days").and_then(|v| v.as_u64()) {
+        cfg.staging_max_age_days = v as u32;
+    }
+    if let Some(v) = body.get("modules_dir") {
+        cfg.modules_dir = v.as_str().map(str::trim).filter(|s| !s.is_empty()).map(String::from);
+    }
+    if let Some(v) = body.get("extensions_dir") {
+        cfg.extensions_dir = v.as_str().map(str::trim).filter(|s| !s.is_empty()).map(String::from);
+    }
+    // null 또는 빈 문자열이면 기본 후보 경로에서 업데이터 탐색
+    if let Some(v) = body.get("updater_path") {
+        cfg.updater_path = v.as_str().map(str::trim).filter(|s| !s.is_empty()).map(String::from);
+    }
 
     mgr.update_config(cfg.clone());
 
@@ -589,3 +915,88 @@ fn resolve_modules_dir() -> String {
 }
 
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicBool;
+
+    fn component(component: Component, notes: Option<&str>, published_at: Option<&str>) -> ComponentVersion {
+        ComponentVersion {
+            component,
+            current_version: "1.0.0".into(),
+            latest_version: Some("1.1.0".into()),
+            update_available: true,
+            download_url: None,
+            asset_name: None,
+            release_notes: notes.map(str::to_string),
+            published_at: published_at.map(str::to_string),
+            downloaded: false,
+            downloaded_path: None,
+            installed: true,
+            warning: None,
+            requires: None,
+        }
+    }
+
+    #[test]
+    fn status_response_includes_release_notes_and_published_at() {
+        let status = UpdateStatus {
+            last_check: None,
+            next_check: None,
+            components: vec![
+                component(Component::CoreDaemon, Some("- fixed crash"), Some("2026-10-15T09:00:00Z")),
+                component(Component::Gui, None, None),
+                component(Component::Locales, Some("hidden"), None),
+            ],
+            checking: false,
+            error: None,
+        };
+
+        let Json(body) = status_response(&status, false);
+        let components = body["components"].as_array().unwrap();
+        assert_eq!(components.len(), 2, "Locales는 제외");
+        assert_eq!(components[0]["release_notes"], "- fixed crash");
+        assert_eq!(components[0]["published_at"], "2026-10-15T09:00:00Z");
+        // 값이 없어도 키는 null로 항상 존재
+        assert!(components[1]["release_notes"].is_null());
+        assert!(components[1].as_object().unwrap().contains_key("published_at"));
+        assert_eq!(body["updates_available"], 2);
+    }
+
+    #[test]
+    fn check_guard_rejects_concurrent_check_and_releases_on_drop() {
+        let flag = Arc::new(AtomicBool::new(false));
+        let guard = CheckGuard::acquire(&flag).unwrap();
+        assert!(CheckGuard::acquire(&flag).is_none());
+        drop(guard);
+        assert!(!flag.load(Ordering::SeqCst));
+        assert!(CheckGuard::acquire(&flag).is_some());
+    }
+
+    #[tokio::test]
+    async fn check_guard_released_when_handler_future_is_dropped_or_panics() {
+        let flag = Arc::new(AtomicBool::new(false));
+
+        // 클라이언트 연결 종료 → 핸들러 future가 중간에 drop됨
+        let held = flag.clone();
+        let task = tokio::spawn(async move {
+            let _guard = CheckGuard::acquire(&held).unwrap();
+            std::future::pending::<()>().await;
+        });
+        while !flag.load(Ordering::SeqCst) {
+            tokio::task::yield_now().await;
+        }
+        task.abort();
+        assert!(task.await.unwrap_err().is_cancelled());
+        assert!(!flag.load(Ordering::SeqCst));
+
+        let held = flag.clone();
+        let result = tokio::spawn(async move {
+            let _guard = CheckGuard::acquire(&held).unwrap();
+            panic!("check failed");
+        }).await;
+        assert!(result.unwrap_err().is_panic());
+        assert!(!flag.load(Ordering::SeqCst));
+    }
+}
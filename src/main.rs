@@ -20,8 +20,6 @@ use tokio::sync::RwLock;
 use saba_chan_updater_lib::constants;
 /// 프로세스 모니터링 폴링 간격 (초)
 const MONITOR_INTERVAL_SECS: u64 = 2;
-/// 하트비트 reaper 간격 (초)
-const HEARTBEAT_REAPER_INTERVAL_SECS: u64 = 30;
 /// 모니터 연속 실패 허용 횟수
 const MONITOR_MAX_CONSECUTIVE_ERRORS: u32 = 10;
 
@@ -110,9 +108,15 @@ async fn main() -> anyhow::Result<()> {
     // 서버(GitHub)에서 매니페스트를 가져와 설치된 컴포넌트의 SHA256을 검증
     run_integrity_check_on_startup().await;
 
-    // Load config
-    let cfg = config::GlobalConfig::load().ok();
-    let _ = &cfg; // 향후 설정 참조를 위해 유지
+    // Load config — 오버라이드가 잘못되었으면 경고 후 기본값 사용
+    let cfg = match config::GlobalConfig::load() {
+        Ok(cfg) => cfg,
+        Err(e) => {
+            tracing::warn!("Invalid config override, falling back to defaults: {}", e);
+            config::GlobalConfig::default()
+        }
+    };
+    tracing::info!("[Heartbeat] Timing: {:?}", cfg.heartbeat);
 
     // Initialize supervisor with module loader
     // 모듈 경로: %APPDATA%/saba-chan/modules (환경 변수 오버라이드 가능)
@@ -138,7 +142,8 @@ async fn main() -> anyhow::Result<()> {
         .and_then(|p| p.parse::<u16>().ok())
         .unwrap_or(constants::DEFAULT_IPC_PORT);
     let ipc_addr = format!("127.0.0.1:{}", ipc_port);
-    let ipc_server = ipc::IPCServer::new(supervisor.clone(), &ipc_addr, daemon_log_buffer.clone());
+    let ipc_server = ipc::IPCServer::new(supervisor.clone(), &ipc_addr, daemon_log_buffer.clone())
+        .with_heartbeat_config(cfg.heartbeat.clone());
 
     // Supervisor에 ExtensionManager 연결
     {
@@ -257,8 +262,9 @@ async fn main() -> anyhow::Result<()> {
         }
     });
 
-    // Heartbeat reaper 태스크 — 30초마다 만료 클라이언트 확인, 봇 프로세스 정리
+    // Heartbeat reaper 태스크 — reaper_interval_secs마다 만료 클라이언트 확인, 봇 프로세스 정리
     let registry_reaper = client_registry.clone();
+    let reaper_interval_secs = client_registry.config().reaper_interval_secs;
     let reaper_cancel = shutdown_token.clone();
     tokio::spawn(async move {
         loop {
@@ -267,7 +273,7 @@ async fn main() -> anyhow::Result<()> {
                     tracing::info!("Heartbeat reaper shutting down");
                     break;
                 }
-                _ = tokio::time::sleep(tokio::time::Duration::from_secs(HEARTBEAT_REAPER_INTERVAL_SECS)) => {}
+                _ = tokio::time::sleep(tokio::time::Duration::from_secs(reaper_interval_secs)) => {}
             }
             ipc::reap_expired_clients(&registry_reaper).await;
 
@@ -280,9 +286,9 @@ async fn main() -> anyhow::Result<()> {
 
     // ── Renderer Watchdog 태스크 ──────────────────────────────────
     // 렌더러(GUI/CLI) 프로세스가 패닉 등으로 전부 끊기면:
-    //   1. 15초 대기 (자연 재접속 기회)
-    //   2. GUI → CLI 순으로 재기동 시도  
-    //   3. 재기동 후 60초 내 재접속 없으면 코어 데몬 자체 종료
+    //   1. grace_period_secs 대기 (자연 재접속 기회, 기본 15초)
+    //   2. GUI → CLI 순으로 재기동 시도 (최대 max_restart_attempts회)
+    //   3. 재기동 후 restart_wait_secs 내 재접속 없으면 코어 데몬 자체 종료
     //
     // ⚠️ daemon-only 모드에서는 렌더러가 없으므로 watchdog를 건너뜀
     //    → 메모리 절약 및 불필요한 자살 방지
@@ -299,11 +305,7 @@ async fn main() -> anyhow::Result<()> {
     let registry_watchdog = client_registry.clone();
     let watchdog_cancel = shutdown_token.clone();
     tokio::spawn(async move {
-        const CHECK_INTERVAL_SECS: u64 = 5;
-        const GRACE_PERIOD_SECS: u64 = 15;
-        const RESTART_WAIT_SECS: u64 = 60;
-        const MAX_RESTART_ATTEMPTS: u32 = 2;
-
+        let timing = registry_watchdog.config().clone();
         let mut restart_attempts: u32 = 0;
 
         loop {
@@ -312,7 +314,7 @@ async fn main() -> anyhow::Result<()> {
                     tracing::info!("[Watchdog] Shutting down");
                     break;
                 }
-                _ = tokio::time::sleep(tokio::time::Duration::from_secs(CHECK_INTERVAL_SECS)) => {}
+                _ = tokio::time::sleep(tokio::time::Duration::from_secs(timing.watchdog_check_interval_secs)) => {}
             }
 
             // 아직 클라이언트가 연결된 적 없으면 무시 (데몬 첫 기동 시)
@@ -338,12 +340,12 @@ async fn main() -> anyhow::Result<()> {
             let elapsed = std::time::Instant::now().duration_since(lost_at);
 
             // 유예 기간 내라면 자연 재접속 대기
-            if elapsed < std::time::Duration::from_secs(GRACE_PERIOD_SECS) {
+            if elapsed < std::time::Duration::from_secs(timing.grace_period_secs) {
                 continue;
             }
 
             // 재기동 시도 횟수 초과 → 자살
-            if restart_attempts >= MAX_RESTART_ATTEMPTS {
+            if restart_attempts >= timing.max_restart_attempts {
                 tracing::error!(
                     "[Watchdog] All {} restart attempts failed. No renderer reconnected within timeout.",
                     restart_attempts
@@ -355,7 +357,7 @@ async fn main() -> anyhow::Result<()> {
                 for (id, client) in &all {
                     tracing::info!("[Watchdog] Cleanup client {} ({:?})", id, client.kind);
                     if let Some(pid) = client.bot_pid {
-                        ipc::kill_bot_pid(pid);
+                        ipc::kill_bot_pid(pid).await.report("[Watchdog]");
                     }
                 }
                 watchdog_cancel.cancel();
@@ -366,14 +368,14 @@ async fn main() -> anyhow::Result<()> {
             restart_attempts += 1;
             tracing::warn!(
                 "[Watchdog] Attempting renderer restart (attempt {}/{})",
-                restart_attempts, MAX_RESTART_ATTEMPTS
+                restart_attempts, timing.max_restart_attempts
             );
 
             let restarted = try_restart_renderer().await;
             if restarted {
-                tracing::info!("[Watchdog] Renderer process launched, waiting {}s for reconnection...", RESTART_WAIT_SECS);
+                tracing::info!("[Watchdog] Renderer process launched, waiting {}s for reconnection...", timing.restart_wait_secs);
                 // 재기동 후 재접속 대기
-                let deadline = std::time::Instant::now() + std::time::Duration::from_secs(RESTART_WAIT_SECS);
+                let deadline = std::time::Instant::now() + std::time::Duration::from_secs(timing.restart_wait_secs);
                 loop {
                     tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
                     if registry_watchdog.has_clients().await {
@@ -381,7 +383,7 @@ async fn main() -> anyhow::Result<()> {
                         break;
                     }
                     if std::time::Instant::now() >= deadline {
-                        tracing::warn!("[Watchdog] Renderer did not reconnect within {}s", RESTART_WAIT_SECS);
+                        tracing::warn!("[Watchdog] Renderer did not reconnect within {}s", timing.restart_wait_secs);
                         break;
                     }
                 }
@@ -440,7 +442,7 @@ async fn main() -> anyhow::Result<()> {
         for (id, client) in &all {
             tracing::info!("[Shutdown] Cleaning client {} ({:?})", id, client.kind);
             if let Some(pid) = client.bot_pid {
-                ipc::kill_bot_pid(pid);
+                ipc::kill_bot_pid(pid).await.report("[Shutdown]");
             }
         }
 
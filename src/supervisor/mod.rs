@@ -320,13 +320,13 @@ impl Supervisor {
         // ── Extension hook: server.pre_start ──
         if let Some(ref ext_mgr) = self.extension_manager {
             let instance_dir = self.instance_store.instance_dir(&instance.id);
-            let results = ext_mgr.read().await
-                .dispatch_hook("server.pre_start", json!({
-                    "instance_id": instance.id.clone(),
-                    "instance_dir": instance_dir.to_string_lossy(),
-                    "module": module_name,
-                    "extension_data": &instance.extension_data,
-                })).await;
+            let hooks = ext_mgr.read().await.snapshot_hooks("server.pre_start");
+            let results = hooks.dispatch(json!({
+                "instance_id": instance.id.clone(),
+                "instance_dir": instance_dir.to_string_lossy(),
+                "module": module_name,
+                "extension_data": &instance.extension_data,
+            })).await;
 
             for (ext_id, result) in &results {
                 if let Ok(val) = result {
@@ -562,13 +562,13 @@ impl Supervisor {
         // ── Extension hook: server.post_stop ──
         if let Some(ref ext_mgr) = self.extension_manager {
             let instance_dir = self.instance_store.instance_dir(&instance.id);
-            let results = ext_mgr.read().await
-                .dispatch_hook("server.post_stop", json!({
-                    "instance_id": instance.id.clone(),
-                    "instance_dir": instance_dir.to_string_lossy(),
-                    "module": module_name,
-                    "extension_data": &instance.extension_data,
-                })).await;
+            let hooks = ext_mgr.read().await.snapshot_hooks("server.post_stop");
+            let results = hooks.dispatch(json!({
+                "instance_id": instance.id.clone(),
+                "instance_dir": instance_dir.to_string_lossy(),
+                "module": module_name,
+                "extension_data": &instance.extension_data,
+            })).await;
 
             for (_ext_id, result) in &results {
                 if let Ok(val) = result {
@@ -755,14 +755,14 @@ impl Supervisor {
             let process_patterns = self.module_loader.get_module(module_name)
                 .map(|m| m.metadata.process_patterns.clone())
                 .unwrap_or_default();
-            let results = ext_mgr.read().await
-                .dispatch_hook("server.status", json!({
-                    "instance_id": instance.id.clone(),
-                    "instance_dir": instance_dir.to_string_lossy(),
-                    "module": module_name,
-                    "extension_data": &instance.extension_data,
-                    "process_patterns": process_patterns,
-                })).await;
+            let hooks = ext_mgr.read().await.snapshot_hooks("server.status");
+            let results = hooks.dispatch(json!({
+                "instance_id": instance.id.clone(),
+                "instance_dir": instance_dir.to_string_lossy(),
+                "module": module_name,
+                "extension_data": &instance.extension_data,
+                "process_patterns": process_patterns,
+            })).await;
 
             for (_ext_id, result) in &results {
                 if let Ok(val) = result {
@@ -1028,8 +1028,8 @@ impl Supervisor {
                 "managed": true,
                 "extension_data": &instance.extension_data,
             });
-            let mgr = ext_mgr.read().await;
-            let results = mgr.dispatch_hook("server.pre_start", ctx).await;
+            let hooks = ext_mgr.read().await.snapshot_hooks("server.pre_start");
+            let results = hooks.dispatch(ctx).await;
             for (ext_id, result) in &results {
                 if let Ok(val) = result {
                     if val.get("handled").and_then(|h| h.as_bool()) == Some(true) {
@@ -753,6 +753,8 @@ impl ModuleLoader {
     }
 
     /// 모듈 디렉터리에서 모든 사용 가능한 모듈 발견 (ZIP 및 폴더 모두 지원)
+    ///
+    /// `read_dir` 순서는 플랫폼/파일시스템마다 다르므로 모듈 이름(동명이면 경로) 순으로 정렬해 반환합니다.
     pub fn discover_modules(&self) -> Result<Vec<LoadedModule>> {
         // 캐시 확인
         if let Some(modules) = self.cached_modules.read().unwrap().as_ref() {
@@ -814,6 +816,9 @@ impl ModuleLoader {
             }
         }
 
+        modules.sort_by(|a, b| {
+            a.metadata.name.cmp(&b.metadata.name).then_with(|| a.path.cmp(&b.path))
+        });
         *self.cached_modules.write().unwrap() = Some(modules.clone());
         Ok(modules)
     }
@@ -913,6 +918,28 @@ mod tests {
         assert!(modules.is_empty());
     }
 
+    #[test]
+    fn test_discover_modules_sorted_by_name() {
+        let tmp = tempfile::tempdir().unwrap();
+        // 디렉터리 이름과 모듈 이름 순서를 일부러 어긋나게 구성
+        for (dir, name) in [("a-dir", "zomboid"), ("m-dir", "minecraft"), ("z-dir", "ark")] {
+            let module_dir = tmp.path().join(dir);
+            std::fs::create_dir_all(&module_dir).unwrap();
+            std::fs::write(
+                module_dir.join("module.toml"),
+                format!("[module]\nname = \"{}\"\nversion = \"1.0.0\"\nentry = \"lifecycle.py\"\n", name),
+            )
+            .unwrap();
+        }
+
+        let loader = ModuleLoader::new(tmp.path().to_str().unwrap());
+        let names: Vec<String> = loader.discover_modules().unwrap()
+            .into_iter()
+            .map(|m| m.metadata.name)
+            .collect();
+        assert_eq!(names, vec!["ark", "minecraft", "zomboid"]);
+    }
+
     #[test]
     fn test_parse_module_toml_minimal() {
         let toml = r#"
@@ -506,6 +506,60 @@ async fn test_ipc_module_metadata_contains_required_fields() {
     server_task.abort();
 }
 
+#[tokio::test]
+async fn test_ipc_module_list_honors_if_none_match() {
+    let (base_url, _sup, server_task) = boot_ipc().await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .get(format!("{}/api/modules", base_url))
+        .send().await.unwrap();
+    assert_eq!(resp.status(), 200);
+    let etag = resp.headers()[reqwest::header::ETAG].to_str().unwrap().to_string();
+
+    // 같은 ETag → 본문 없는 304
+    let resp = client
+        .get(format!("{}/api/modules", base_url))
+        .header(reqwest::header::IF_NONE_MATCH, &etag)
+        .send().await.unwrap();
+    assert_eq!(resp.status(), 304);
+    assert_eq!(resp.headers()[reqwest::header::ETAG], etag.as_str());
+    assert!(resp.bytes().await.unwrap().is_empty());
+
+    // 다른 ETag → 전체 목록
+    let resp = client
+        .get(format!("{}/api/modules", base_url))
+        .header(reqwest::header::IF_NONE_MATCH, "\"stale\"")
+        .send().await.unwrap();
+    assert_eq!(resp.status(), 200);
+    let json: Value = resp.json().await.unwrap();
+    assert!(json["modules"].is_array());
+
+    server_task.abort();
+}
+
+#[tokio::test]
+async fn test_ipc_updates_root_returns_status() {
+    let (base_url, _sup, server_task) = boot_ipc().await;
+    let client = reqwest::Client::new();
+
+    let root: Value = client
+        .get(format!("{}/api/updates", base_url))
+        .send().await.unwrap()
+        .json().await.unwrap();
+    let status: Value = client
+        .get(format!("{}/api/updates/status", base_url))
+        .send().await.unwrap()
+        .json().await.unwrap();
+
+    assert_eq!(root["ok"], true);
+    assert!(root["components"].is_array());
+    assert_eq!(root["checking"], status["checking"]);
+    assert_eq!(root["updates_available"], status["updates_available"]);
+
+    server_task.abort();
+}
+
 // ═══════════════════════════════════════════════════════
 // 8. 인스턴스 생성·삭제·재생성 독립성 검증
 //    네이티브 인스턴스는 모듈당 1개 제한이므로,
@@ -834,10 +888,12 @@ async fn test_client_unregister_full_shutdown_kills_bot() {
     assert_eq!(unreg_resp.status(), reqwest::StatusCode::OK);
 
     // 5. 봇(더미 프로세스)이 종료되어야 한다
+    //    더미는 테스트 프로세스의 자식이라 종료 후 좀비로 남으므로 kill -0 대신 회수로 확인
     sleep(Duration::from_millis(1000)).await;
     assert!(
-        !is_process_alive(dummy_pid),
-        "Bot process should be killed when shutdown=true (full shutdown)"
+        dummy.try_wait().unwrap().is_some(),
+        "Bot process {} should be killed when shutdown=true (full shutdown)",
+        dummy_pid
     );
 
     // 정리
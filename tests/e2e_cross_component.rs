@@ -104,6 +104,7 @@ async fn boot_e2e_ipc() -> (
         .join(format!("saba-e2e-data-{}", pick_free_port()));
     fs::create_dir_all(&data_dir).expect("create data dir");
     std::env::set_var("SABA_DATA_DIR", data_dir.to_str().unwrap());
+    std::env::set_var("SABA_STAGING_DIR", data_dir.join("updates").to_str().unwrap());
 
     let modules_dir = setup_e2e_modules_dir();
     let instances_dir = std::env::temp_dir()
@@ -29,7 +29,7 @@ use tempfile::TempDir;
 
 use saba_chan_updater_lib::{
     Component,
-    UpdateConfig, UpdateManager,
+    UpdateConfig, UpdateManager, UpdaterError,
 };
 use saba_chan_updater_lib::github::{
     GitHubClient, ReleaseManifest,
@@ -224,6 +224,7 @@ fn create_test_manager(
         include_prerelease: false,
         install_root: Some(tmpdir.path().to_string_lossy().to_string()),
         api_base_url: None,
+        ..UpdateConfig::default()
     };
 
     UpdateManager::new(config, &modules_dir.to_string_lossy())
@@ -368,6 +369,7 @@ fn test_update_config_serialization() {
         include_prerelease: true,
         install_root: Some("/opt/saba".into()),
         api_base_url: None,
+        ..UpdateConfig::default()
     };
 
     let json = serde_json::to_string(&cfg).unwrap();
@@ -433,12 +435,19 @@ fn test_manifest_serialization_roundtrip() {
         version: "1.0.0".into(),
         asset: Some("gui.zip".into()),
         asset_linux: None,
+        assets: Vec::new(),
         sha256: Some("abcdef1234".into()),
+        mirrors: Vec::new(),
+        signature: None,
         install_dir: Some(".".into()),
         requires: None,
+        patch_from: HashMap::new(),
+        binary_sha256: None,
+        size: None,
     });
 
     let manifest = ReleaseManifest {
+        manifest_schema_version: 1,
         release_version: "1.0.0".into(),
         components,
     };
@@ -814,6 +823,84 @@ async fn test_fresh_install_simulation() {
     println!("✓ Fresh install simulation: CLI + GUI + Module all installed from mock server");
 }
 
+/// fresh_install — 병렬 다운로드 중 한 컴포넌트가 실패해도 나머지는 설치됨
+#[tokio::test]
+async fn test_fresh_install_isolates_component_failures() {
+    let tmp = TempDir::new().unwrap();
+
+    let mut alpha_files = HashMap::new();
+    alpha_files.insert("module.toml", b"name = \"alpha\"\nversion = \"1.0.0\"\n" as &[u8]);
+    let mut gamma_files = HashMap::new();
+    gamma_files.insert("module.toml", b"name = \"gamma\"\nversion = \"1.0.0\"\n" as &[u8]);
+
+    let mut assets = HashMap::new();
+    assets.insert("isolation-module-alpha.zip".to_string(), create_test_zip(&alpha_files));
+    assets.insert("isolation-module-broken.zip".to_string(), b"not a zip archive".to_vec());
+    assets.insert("isolation-module-gamma.zip".to_string(), create_test_zip(&gamma_files));
+
+    let manifest = create_test_manifest("1.0.0", vec![
+        ("module-alpha", "1.0.0", "isolation-module-alpha.zip", Some("modules/alpha")),
+        ("module-broken", "1.0.0", "isolation-module-broken.zip", Some("modules/broken")),
+        ("module-gamma", "1.0.0", "isolation-module-gamma.zip", Some("modules/gamma")),
+    ]);
+    let (addr, _handle) = start_mock_github_server(manifest, assets).await;
+
+    let mut mgr = create_test_manager(&tmp, "test", "saba-chan");
+    let mut config = mgr.get_config();
+    config.api_base_url = Some(format!("http://{}", addr));
+    mgr.update_config(config);
+
+    let progress = mgr.fresh_install(None).await.unwrap();
+
+    assert!(progress.complete);
+    assert_eq!(progress.total, 3);
+    assert_eq!(progress.done, 2, "done counts completed extractions: {:?}", progress);
+    assert_eq!(progress.installed_components.len(), 2);
+    assert_eq!(progress.errors.len(), 1, "only the broken module fails: {:?}", progress.errors);
+    assert!(progress.errors[0].contains("broken"), "{}", progress.errors[0]);
+
+    let modules = tmp.path().join("modules");
+    assert!(modules.join("alpha").join("module.toml").exists());
+    assert!(modules.join("gamma").join("module.toml").exists());
+}
+
+/// prepare_install — 계획 수립 후에는 Manager 잠금 없이 설치가 진행됨
+#[tokio::test]
+async fn test_prepared_install_runs_without_manager_lock() {
+    let tmp = TempDir::new().unwrap();
+
+    let mut alpha_files = HashMap::new();
+    alpha_files.insert("module.toml", b"name = \"alpha\"\nversion = \"1.0.0\"\n" as &[u8]);
+    let mut assets = HashMap::new();
+    assets.insert("prepared-module-alpha.zip".to_string(), create_test_zip(&alpha_files));
+    let manifest = create_test_manifest("1.0.0", vec![
+        ("module-alpha", "1.0.0", "prepared-module-alpha.zip", Some("modules/alpha")),
+    ]);
+    let (addr, _handle) = start_mock_github_server(manifest, assets).await;
+
+    let mut mgr = create_test_manager(&tmp, "test", "saba-chan");
+    let mut config = mgr.get_config();
+    config.api_base_url = Some(format!("http://{}", addr));
+    mgr.update_config(config);
+    let manager = Arc::new(RwLock::new(mgr));
+
+    let job = manager.write().await.prepare_install(None, false).await.unwrap();
+
+    // 설치 작업이 진행되는 동안 Manager는 잠기지 않지만, 다른 적용은 독점권에 막힘
+    {
+        let mgr = manager.try_read().expect("manager must not be locked by the install");
+        let err = mgr.begin_apply().unwrap_err();
+        assert!(matches!(err.downcast_ref::<UpdaterError>(), Some(UpdaterError::ApplyInProgress)), "{}", err);
+    }
+
+    let progress = job.run().await;
+    assert!(progress.complete);
+    assert_eq!(progress.installed_components.len(), 1, "{:?}", progress);
+    assert!(tmp.path().join("modules").join("alpha").join("module.toml").exists());
+    assert_eq!(manager.read().await.get_install_progress().unwrap().done, 1);
+    assert!(manager.read().await.begin_apply().is_ok(), "lease is released when the job finishes");
+}
+
 // ═══════════════════════════════════════════════════════
 // 9. 데몬 IPC API 직접 호출 테스트 (Axum tower::ServiceExt)
 // ═══════════════════════════════════════════════════════
@@ -1096,7 +1183,9 @@ async fn test_apply_without_downloaded_returns_empty() {
     let mut mgr = create_test_manager(&tmp, "test", "repo");
 
     let result = mgr.apply_updates().await.unwrap();
-    assert!(result.is_empty(), "Should apply nothing if nothing downloaded");
+    assert!(result.results.is_empty(), "Should apply nothing if nothing downloaded");
+    assert!(result.daemon_restart_script.is_none());
+    assert!(result.self_update_components.is_empty());
 }
 
 #[tokio::test]
@@ -5,25 +5,52 @@
 //!
 //! ## 실행
 //! ```text
-//! saba-chan-updater --apply [--relaunch <exe> [extra...]]
+//! saba-chan-updater --apply [<component>...] [--install-root <path>] [--manifest <path>]
+//!                   [--relaunch-delay <ms>] [--relaunch <exe> [extra...]]
 //! ```
 //!
+//! 인자 해석은 `parse_apply_args` 참고. 컴포넌트 키를 지정하면 apply-targets.json 대신 그 목록을 적용하고,
+//! `--install-root`를 지정하면 exe 위치 대신 그 경로를 install_root로 사용합니다.
+//!
+//! `--manifest`로 다른 곳(빌드 서버 등)에서 준비한 pending 매니페스트를 지정하면
+//! 그 디렉터리 기준으로 스테이징 파일을 찾아 매니페스트의 전체 컴포넌트를 적용합니다.
+//!
+//! `--relaunch-delay`는 재실행 전 대기 시간(기본 2000ms)으로, 백신 검사가 느린 환경에서 늘립니다.
+//! 재실행된 프로세스는 install_root를 작업 디렉터리로, 업데이터의 환경 변수를 그대로 물려받습니다.
+//!
+//! ## 종료 코드 (`ApplyExitCode`)
+//! - `0` — 적용 완료 또는 적용할 항목 없음
+//! - `10` — 적용 완료, 재시작 필요 (데몬/GUI/업데이터 교체)
+//! - `20` — 일부(또는 전체) 컴포넌트 적용 실패
+//! - `30` — 네트워크 오류
+//!
+//! 같은 값이 업데이트 완료 마커(`update-complete.json`)의 `exit_code` 필드에 기록됩니다.
+//!
 //! ## 데이터 소스
 //! - `%APPDATA%/saba-chan/updates/apply-targets.json` — 적용 대상 컴포넌트
 //! - `%APPDATA%/saba-chan/updates/pending.json` — 다운로드 파일 위치
 //! - `%APPDATA%/saba-chan/settings.json` — 언어 설정
 //!
+//! ## 이벤트
+//! - `apply:progress` — 적용 단계/진행률 (`ApplyProgressEvent`)
+//! - `worker:event` — 백그라운드 워커 이벤트 (`WorkerEvent`, `{"event": "check_started", ...}`)
+//!
 //! ## 설계 원칙
-//! - install_root는 자기 exe 경로에서 자동 추론 (CLI 인자 불필요)
-//! - 적용 대상은 apply-targets.json에서 읽음 (CLI 인자 불필요)
+//! - install_root는 자기 exe 경로에서 자동 추론 (`--install-root`는 개발 빌드 등 예외용)
+//! - 적용 대상은 apply-targets.json에서 읽음 (명령줄 컴포넌트 지정은 예외용)
 //! - 테마는 CSS `data-theme="auto"` + `prefers-color-scheme` 미디어 쿼리로 자동 처리
 
-use saba_chan_updater_lib::{UpdateManager, UpdateCompletionMarker};
+use saba_chan_updater_lib::{
+    ApplyExitCode, ApplyModeConfig, ApplyPhase, ApplyProgress, BackgroundWorker, ChangelogEntry, Component,
+    ComponentSummary, ErrorPayload, RelaunchOptions, UpdateManager, UpdateCompletionMarker, UpdatePlan, UpdaterError,
+    WorkerEvent, WorkerStatus, parse_apply_args,
+};
 use serde::Serialize;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicI32, Ordering};
 use std::sync::Arc;
 use tauri::{AppHandle, Emitter, Manager};
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
 use saba_chan_updater_lib::constants;
 
 pub mod config;
@@ -34,11 +61,41 @@ pub mod config;
 
 type ManagerState = Arc<RwLock<UpdateManager>>;
 
-/// 재실행 설정 (Tauri managed state)
+/// 프로세스 종료 시 사용할 종료 코드 (`start_apply`가 결과에 따라 설정)
+static APPLY_EXIT_CODE: AtomicI32 = AtomicI32::new(0);
+
+/// 재실행·매니페스트 설정 (Tauri managed state)
 #[derive(Debug, Clone, Default)]
 struct ApplyConfig {
     relaunch_exe: Option<String>,
     relaunch_extra: Vec<String>,
+    /// 재실행 대기 시간(`--relaunch-delay`)과 작업 디렉터리(install_root)
+    relaunch: RelaunchOptions,
+    /// `--manifest`로 지정한 pending 매니페스트 (None이면 staging의 pending.json)
+    manifest_path: Option<PathBuf>,
+    /// 명령줄로 지정한 적용 대상 컴포넌트 (비어 있으면 apply-targets.json)
+    components: Vec<String>,
+}
+
+impl ApplyConfig {
+    /// 적용할 pending 매니페스트 로드
+    fn load_manifest(&self, mgr: &mut UpdateManager) -> anyhow::Result<usize> {
+        match self.manifest_path {
+            Some(ref path) => mgr.load_pending_manifest_from(path),
+            None => mgr.load_pending_manifest(),
+        }
+    }
+
+    /// 적용 대상 — 명령줄 컴포넌트 > 지정한 매니페스트는 전체 적용 > apply-targets.json
+    fn apply_targets(&self, mgr: &UpdateManager) -> Vec<String> {
+        if !self.components.is_empty() {
+            return self.components.clone();
+        }
+        if self.manifest_path.is_some() {
+            return Vec::new();
+        }
+        mgr.load_updater_apply_targets().unwrap_or_default()
+    }
 }
 
 /// Apply 진행 이벤트 페이로드
@@ -65,88 +122,162 @@ async fn get_apply_mode(config: tauri::State<'_, ApplyConfig>) -> Result<serde_j
     }))
 }
 
+/// 적용 전 의존성 사전 확인 — 확인 대화상자용 (충족되지 않는 `requires` 목록)
+#[tauri::command]
+async fn check_apply_dependencies(
+    apply_config: tauri::State<'_, ApplyConfig>,
+    manager: tauri::State<'_, ManagerState>,
+) -> Result<serde_json::Value, String> {
+    let mut mgr = manager.write().await;
+    if mgr.status().components.is_empty() {
+        apply_config.load_manifest(&mut mgr).map_err(|e| format!("Failed to load manifest: {}", e))?;
+    }
+    let targets = apply_config.apply_targets(&mgr);
+    let blocked = mgr.check_apply_dependencies(&targets);
+    Ok(serde_json::json!({ "ok": blocked.is_empty(), "blocked": blocked }))
+}
+
+/// 적용 대기 중인 업데이트의 통합 릴리즈 노트 — "새로운 기능" 패널용
+#[tauri::command]
+async fn get_pending_changelog(
+    apply_config: tauri::State<'_, ApplyConfig>,
+    manager: tauri::State<'_, ManagerState>,
+) -> Result<Vec<ChangelogEntry>, String> {
+    let mut mgr = manager.write().await;
+    if mgr.status().components.is_empty() {
+        apply_config.load_manifest(&mut mgr).map_err(|e| format!("Failed to load manifest: {}", e))?;
+    }
+    Ok(mgr.get_pending_changelog())
+}
+
+/// 적용 중단 처리 — 종료 코드 기록, error 이벤트 발행 후 프론트엔드용 `{code, message}` 반환
+fn fail_apply(app: &AppHandle, context: &str, err: UpdaterError) -> ErrorPayload {
+    APPLY_EXIT_CODE.store(ApplyExitCode::from_updater_error(&err).code(), Ordering::SeqCst);
+    let mut payload = ErrorPayload::from(&err);
+    payload.message = format!("{}: {}", context, payload.message);
+    emit_progress(app, "error", &payload.message, 0, &[]);
+    payload
+}
+
 /// Apply 실행 — 매니페스트 로드 → 파일 적용 → 완료 마커 → 재실행
+///
+/// `force`가 true면 충족되지 않은 의존성이 있어도 경고만 남기고 적용.
+/// 실패 시 `{code, message, recoverable}`를 반환하여 프론트엔드가 에러 종류별로 분기할 수 있다.
 #[tauri::command]
 async fn start_apply(
     app: AppHandle,
     apply_config: tauri::State<'_, ApplyConfig>,
     manager: tauri::State<'_, ManagerState>,
-) -> Result<Vec<String>, String> {
+    force: Option<bool>,
+) -> Result<Vec<String>, ErrorPayload> {
+    let force = force.unwrap_or(false);
+    // 매니페스트 로드부터 완료까지 적용 독점권 유지 — 그동안 백그라운드 워커 작업은 거절됨
+    let _lease = manager.read().await.begin_apply()
+        .map_err(|e| fail_apply(&app, "Apply failed", e.into()))?;
+
     // 1. 매니페스트 로드
     emit_progress(&app, "manifest", "Loading manifest...", 10, &[]);
 
     let count = {
         let mut mgr = manager.write().await;
-        mgr.load_pending_manifest()
-            .map_err(|e| {
-                let msg = format!("Failed to load manifest: {}", e);
-                emit_progress(&app, "error", &msg, 0, &[]);
-                msg
-            })?
+        apply_config.load_manifest(&mut mgr)
+            .map_err(|e| fail_apply(&app, "Failed to load manifest", e.into()))?
     };
 
     emit_progress(&app, "manifest", &format!("{} components ready", count), 25, &[]);
     tokio::time::sleep(std::time::Duration::from_millis(400)).await;
 
-    // 2. apply-targets.json에서 적용 대상 결정
+    // 2. 적용 대상 결정 (명령줄 컴포넌트 > --manifest면 전체 > apply-targets.json)
     let mut mgr = manager.write().await;
-    let target_keys: Vec<String> = mgr
-        .load_updater_apply_targets()
-        .unwrap_or_default();
+    let target_keys: Vec<String> = apply_config.apply_targets(&mgr);
 
     tracing::info!("[Apply] Targets: {:?}", target_keys);
 
     // 3. 적용
     let mut applied = Vec::new();
+    let mut failed = 0usize;
+    let mut restart_needed = false;
 
     if target_keys.is_empty() {
         // apply-targets.json이 없거나 비어있으면 전체 적용
-        let total = mgr.get_pending_components().len();
-        emit_progress(&app, "applying", &format!("Applying {} components...", total), 50, &[]);
+        // 매니저가 보고하는 실제 단계/진행률을 30~90% 구간에 매핑
+        let on_progress = |progress: ApplyProgress| {
+            if matches!(progress.phase, ApplyPhase::Completed | ApplyPhase::Failed) {
+                return;
+            }
+            let pct = 30 + i32::from(progress.percent()) * 60 / 100;
+            emit_progress(&app, "applying", &progress.message, pct, &[]);
+        };
 
-        match mgr.apply_updates().await {
-            Ok(a) => applied = a,
-            Err(e) => {
-                let msg = format!("Apply failed: {}", e);
-                emit_progress(&app, "error", &msg, 0, &[]);
-                return Err(msg);
+        match mgr.apply_components_leased(&[], force, Some(&on_progress)).await {
+            Ok(result) => {
+                restart_needed = result.restart_needed();
+                failed = result.results.iter().filter(|r| !r.success).count() + result.blocked.len();
+                applied = result.applied_names();
             }
+            Err(e) => return Err(fail_apply(&app, "Apply failed", e)),
         }
     } else {
-        // 개별 컴포넌트 순차 적용 (진행률 이벤트 발행)
+        // 개별 컴포넌트 순차 적용 (진행률 이벤트 발행) — 위에서 잡은 lease로 루프 전체가 하나의 적용 작업
+        // 의존성이 충족되지 않은 대상은 force가 아니면 건너뜀
+        let blocked = mgr.check_apply_dependencies(&target_keys);
+        for check in &blocked {
+            for issue in &check.issues {
+                if force {
+                    tracing::warn!("[Apply] ⚠ FORCED despite unmet dependency: {}", issue.message);
+                } else {
+                    tracing::warn!("[Apply] {} blocked: {}", check.component, issue.message);
+                }
+            }
+        }
         let total = target_keys.len();
         for (i, key) in target_keys.iter().enumerate() {
+            if !force && blocked.iter().any(|b| &b.component == key) {
+                failed += 1;
+                continue;
+            }
             let pct = 30 + ((i as i32) * 60 / std::cmp::max(total as i32, 1));
             emit_progress(&app, "applying",
                 &format!("Applying {} ({}/{})...", key, i + 1, total), pct, &applied);
 
-            match mgr.apply_single_component(
+            match mgr.apply_single_component_leased(
                 &saba_chan_updater_lib::Component::from_manifest_key(key),
             ).await {
                 Ok(result) if result.success => {
                     tracing::info!("[Apply] {} ✓", key);
+                    restart_needed |= result.restart_needed;
                     applied.push(key.clone());
                 }
                 Ok(result) => {
                     tracing::warn!("[Apply] {} failed: {}", key, result.message);
+                    failed += 1;
                 }
                 Err(e) => {
                     tracing::error!("[Apply] {} error: {}", key, e);
+                    failed += 1;
                 }
             }
         }
     }
 
+    let exit_code = ApplyExitCode::from_outcome(failed, restart_needed);
+    APPLY_EXIT_CODE.store(exit_code.code(), Ordering::SeqCst);
+    tracing::info!("[Apply] Exit code: {} ({:?})", exit_code.code(), exit_code);
+
     // 4. 완료 마커 저장
     if !applied.is_empty() {
         let marker = UpdateCompletionMarker::success(applied.clone());
         let marker = UpdateCompletionMarker {
             message: Some(format!("{} updates applied: {}", applied.len(), applied.join(", "))),
+            exit_code: exit_code.code(),
             ..marker
         };
         marker.save().ok();
     }
-    mgr.clear_pending_manifest();
+    // 지정한 매니페스트(외부 번들)는 그대로 두고, 로컬 staging의 대기 목록도 건드리지 않음
+    if apply_config.manifest_path.is_none() {
+        mgr.clear_pending_manifest();
+    }
 
     emit_progress(&app, "complete", &{
         if applied.is_empty() {
@@ -161,30 +292,97 @@ async fn start_apply(
     // 5. GUI 재실행 → 업데이터 종료
     let relaunch_exe = apply_config.relaunch_exe.clone();
     let relaunch_extra = apply_config.relaunch_extra.clone();
+    let relaunch = apply_config.relaunch.clone();
     let app_handle = app.clone();
 
     tauri::async_runtime::spawn(async move {
-        tokio::time::sleep(std::time::Duration::from_millis(2000)).await;
+        tokio::time::sleep(relaunch.delay).await;
 
         if let Some(ref cmd) = relaunch_exe {
             wait_for_exe(cmd).await;
-            launch_with_retry(cmd, &relaunch_extra).await;
+            launch_with_retry(cmd, &relaunch_extra, &relaunch).await;
         } else {
             // --relaunch 미지정 시 GUI exe 자동 추론
-            if let Some(gui_path) = resolve_gui_exe() {
+            if let Some(gui_path) = relaunch.working_dir.as_deref().and_then(resolve_gui_exe) {
                 let cmd = gui_path.to_string_lossy().to_string();
                 wait_for_exe(&cmd).await;
-                launch_with_retry(&cmd, &[]).await;
+                launch_with_retry(&cmd, &[], &relaunch).await;
             }
         }
 
         tokio::time::sleep(std::time::Duration::from_millis(500)).await;
-        app_handle.exit(0);
+        app_handle.exit(APPLY_EXIT_CODE.load(Ordering::SeqCst));
     });
 
     Ok(applied)
 }
 
+/// 설치 상태 점검 — installed-manifest.json과 실제 파일의 불일치 목록 (health check 패널용)
+#[tauri::command]
+async fn verify_installed(manager: tauri::State<'_, ManagerState>) -> Result<serde_json::Value, String> {
+    let mgr = manager.read().await;
+    let issues: Vec<serde_json::Value> = mgr.verify_installed().into_iter()
+        .map(|(component, issue)| serde_json::json!({ "component": component, "issue": issue }))
+        .collect();
+    Ok(serde_json::json!({ "ok": issues.is_empty(), "issues": issues }))
+}
+
+/// 손상된 컴포넌트 재설치 ("문제 해결" 복구용) — 설치 여부와 관계없이 백업 후 새로 설치
+#[tauri::command]
+async fn reinstall_component(
+    manager: tauri::State<'_, ManagerState>,
+    key: String,
+) -> Result<String, ErrorPayload> {
+    let mut mgr = manager.write().await;
+    mgr.reinstall_component(&saba_chan_updater_lib::Component::from_manifest_key(&key))
+        .await
+        .map_err(|e| ErrorPayload::from(&e))
+}
+
+/// 단일 컴포넌트 상태 조회 — 전체 상태(릴리즈 노트 포함) 대신 요약만 반환
+///
+/// 상태에 없는 컴포넌트면 `null`, 알 수 없는 키면 에러.
+#[tauri::command]
+async fn get_component_status(
+    apply_config: tauri::State<'_, ApplyConfig>,
+    manager: tauri::State<'_, ManagerState>,
+    key: String,
+) -> Result<Option<ComponentSummary>, String> {
+    let component = Component::try_from_manifest_key(&key)
+        .ok_or_else(|| format!("Unknown component: {}", key))?;
+    let mut mgr = manager.write().await;
+    if mgr.status().components.is_empty() {
+        apply_config.load_manifest(&mut mgr).map_err(|e| format!("Failed to load manifest: {}", e))?;
+    }
+    Ok(mgr.component_status(&component).as_ref().map(ComponentSummary::from))
+}
+
+/// 업데이트 사전 점검 — 다운로드 전 확인 화면용 (총 다운로드 크기, 건너뛸 항목, 의존성, 재시작 여부)
+///
+/// 아직 체크하지 않았으면 먼저 업데이트를 확인한다.
+#[tauri::command]
+async fn get_update_plan(manager: tauri::State<'_, ManagerState>) -> Result<UpdatePlan, ErrorPayload> {
+    let mut mgr = manager.write().await;
+    if mgr.status().components.is_empty() {
+        mgr.check_for_updates().await.map_err(|e| ErrorPayload::from(&e))?;
+    }
+    Ok(mgr.plan_update().await)
+}
+
+/// 백그라운드 워커 상태 — "확인 중…"/"다운로드 중…" 표시용 (`busy`, `current_task`, `pending_tasks`)
+#[tauri::command]
+async fn get_worker_status(
+    worker: tauri::State<'_, BackgroundWorker>,
+) -> Result<WorkerStatus, String> {
+    Ok(worker.get_status().await)
+}
+
+/// 백그라운드 업데이트 확인 요청 — 결과는 워커 상태와 이벤트로 전달
+#[tauri::command]
+async fn check_updates_in_background(worker: tauri::State<'_, BackgroundWorker>) -> Result<(), String> {
+    worker.check_now().await
+}
+
 /// 언어 설정 조회 — settings.json → 시스템 로케일 → "en"
 #[tauri::command]
 async fn get_preferred_language() -> Result<String, String> {
@@ -237,6 +435,23 @@ fn emit_progress(app: &AppHandle, step: &str, message: &str, percent: i32, appli
     }).ok();
 }
 
+/// 워커 이벤트를 `worker:event`로 프론트엔드에 전달 — 밀려서 놓친 이벤트는 건너뛰고 계속 전달
+fn spawn_worker_event_pump(app: AppHandle, mut events: broadcast::Receiver<WorkerEvent>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    app.emit("worker:event", &event).ok();
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("[Worker] Event pump lagged, {} event(s) dropped", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
 /// settings.json에서 키 값 읽기
 fn load_setting(key: &str) -> Option<String> {
     let path = constants::resolve_settings_path();
@@ -256,8 +471,7 @@ fn resolve_install_root_from_exe() -> Option<PathBuf> {
 }
 
 /// GUI exe 경로 추론 (install_root/saba-chan-gui.exe)
-fn resolve_gui_exe() -> Option<PathBuf> {
-    let root = resolve_install_root_from_exe()?;
+fn resolve_gui_exe(root: &std::path::Path) -> Option<PathBuf> {
     let name = if cfg!(windows) { "saba-chan-gui.exe" } else { "saba-chan-gui" };
     let path = root.join(name);
     if path.exists() { Some(path) } else { None }
@@ -282,9 +496,9 @@ async fn wait_for_exe(cmd: &str) {
 }
 
 /// 프로세스 재실행 (최대 3회 재시도)
-async fn launch_with_retry(cmd: &str, extra_args: &[String]) {
+async fn launch_with_retry(cmd: &str, extra_args: &[String], options: &RelaunchOptions) {
     for attempt in 0..3 {
-        match spawn_detached(cmd, extra_args) {
+        match spawn_detached(cmd, extra_args, options) {
             Ok(()) => {
                 tracing::info!("[Apply] Relaunch succeeded (attempt {})", attempt + 1);
                 return;
@@ -298,27 +512,12 @@ async fn launch_with_retry(cmd: &str, extra_args: &[String]) {
     tracing::error!("[Apply] All relaunch attempts failed for: {}", cmd);
 }
 
-/// 완전히 분리된 자식 프로세스 생성
-fn spawn_detached(cmd: &str, extra_args: &[String]) -> Result<(), String> {
-    tracing::info!("[Apply] Spawning: {} {:?}", cmd, extra_args);
-
-    let mut command = std::process::Command::new(cmd);
-    for arg in extra_args {
-        command.arg(arg);
-    }
-    command.arg("--after-update");
-
-    #[cfg(target_os = "windows")]
-    {
-        use std::os::windows::process::CommandExt;
-        // DETACHED_PROCESS | CREATE_BREAKAWAY_FROM_JOB | CREATE_NEW_PROCESS_GROUP
-        command.creation_flags(0x00000008 | 0x01000000 | 0x00000200);
-    }
+/// 완전히 분리된 자식 프로세스 생성 (작업 디렉터리 = install_root, 환경 변수 유지)
+fn spawn_detached(cmd: &str, extra_args: &[String], options: &RelaunchOptions) -> Result<(), String> {
+    tracing::info!("[Apply] Spawning: {} {:?} (cwd: {:?})", cmd, extra_args, options.working_dir);
 
-    command
-        .stdin(std::process::Stdio::null())
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::null())
+    let args = saba_chan_updater_lib::relaunch_args(extra_args, true);
+    saba_chan_updater_lib::relaunch_command(std::path::Path::new(cmd), &args, options)
         .spawn()
         .map_err(|e| format!("Failed to spawn: {}", e))?;
 
@@ -337,12 +536,19 @@ pub fn run() {
     if !args.iter().any(|a| a == "--apply") {
         eprintln!("사바쨩 업데이터 — 업데이트 적용 전용");
         eprintln!();
-        eprintln!("사용법: saba-chan-updater --apply [--relaunch <exe> [extra...]]");
+        eprintln!("사용법: saba-chan-updater --apply [<component>...] [--install-root <path>] [--manifest <path>] [--relaunch-delay <ms>] [--relaunch <exe> [extra...]]");
         eprintln!();
         eprintln!("이 프로그램은 메인 GUI에서 자동으로 실행됩니다.");
         eprintln!("직접 실행할 필요가 없습니다.");
         std::process::exit(1);
     }
+    let ApplyModeConfig { install_root, manifest_path, relaunch_delay, relaunch_exe, relaunch_extra, components } = match parse_apply_args(&args) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
 
     tracing_subscriber::fmt()
         .with_writer({
@@ -364,35 +570,27 @@ pub fn run() {
         .with_ansi(false)
         .init();
 
-    // 인자 파싱: --apply [--relaunch <exe> [extra...]]
-    let apply_pos = args.iter().position(|a| a == "--apply").unwrap();
-    let after_apply = &args[apply_pos + 1..];
-
-    let relaunch_pos = after_apply.iter().position(|a| a == "--relaunch");
-    let (relaunch_exe, relaunch_extra) = match relaunch_pos {
-        Some(pos) => {
-            let rest = &after_apply[pos + 1..];
-            if rest.is_empty() {
-                (None, Vec::new())
-            } else {
-                (Some(rest[0].clone()), rest[1..].to_vec())
-            }
-        }
-        None => (None, Vec::new()),
-    };
-
-    tracing::info!("[Apply] Relaunch: {:?} {:?}", relaunch_exe, relaunch_extra);
+    tracing::info!("[Apply] Relaunch: {:?} {:?} (delay {:?})", relaunch_exe, relaunch_extra, relaunch_delay);
+    if let Some(ref path) = manifest_path {
+        tracing::info!("[Apply] Manifest: {}", path.display());
+    }
+    if !components.is_empty() {
+        tracing::info!("[Apply] Components (from args): {:?}", components);
+    }
 
     // GUI 프로세스 종료 대기
     std::thread::sleep(std::time::Duration::from_millis(500));
 
-    let apply_config = ApplyConfig { relaunch_exe, relaunch_extra };
+    // install_root: --install-root 또는 exe 위치에서 자동 추론 — 재실행 작업 디렉터리로도 사용
+    let root_from_args = install_root.is_some();
+    let install_root = install_root.or_else(resolve_install_root_from_exe);
+    let relaunch = RelaunchOptions { delay: relaunch_delay, working_dir: install_root.clone() };
+    let apply_config = ApplyConfig { relaunch_exe, relaunch_extra, relaunch, manifest_path, components };
 
-    // install_root: exe 위치에서 자동 추론
     let mut cfg = config::load_config_for_gui();
-    if let Some(root) = resolve_install_root_from_exe() {
+    if let Some(root) = install_root {
         let root_str = root.to_string_lossy().to_string();
-        tracing::info!("[Apply] Install root (from exe): {}", root_str);
+        tracing::info!("[Apply] Install root ({}): {}", if root_from_args { "from args" } else { "from exe" }, root_str);
         cfg.install_root = Some(root_str);
     }
 
@@ -404,6 +602,16 @@ pub fn run() {
         .manage(manager)
         .manage(apply_config)
         .setup(|app| {
+            // 백그라운드 워커 — setup은 런타임 밖에서 실행되므로 Tauri의 tokio 런타임에 들어가서 스폰.
+            // 워커 이벤트는 폴링 없이 바로 반응할 수 있도록 `worker:event`로 전달.
+            // `start_apply`가 적용 lease를 쥔 동안에는 워커가 체크·다운로드 작업을 거절한다
+            let worker = {
+                let _runtime = tauri::async_runtime::handle().inner().enter();
+                BackgroundWorker::spawn(app.state::<ManagerState>().inner().clone())
+            };
+            spawn_worker_event_pump(app.handle().clone(), worker.subscribe());
+            app.manage(worker);
+
             if let Some(win) = app.get_webview_window("main") {
                 // 사용자 언어에 맞는 타이틀
                 let title = match load_setting("language").as_deref() {
@@ -439,7 +647,21 @@ pub fn run() {
             get_preferred_language,
             get_theme,
             check_after_update,
+            verify_installed,
+            check_apply_dependencies,
+            reinstall_component,
+            get_pending_changelog,
+            get_component_status,
+            get_update_plan,
+            get_worker_status,
+            check_updates_in_background,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| {
+            // 창을 직접 닫아 종료하는 경우에도 적용 결과의 종료 코드를 유지
+            if let tauri::RunEvent::Exit = event {
+                std::process::exit(APPLY_EXIT_CODE.load(Ordering::SeqCst));
+            }
+        });
 }
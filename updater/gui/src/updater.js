@@ -967,9 +967,11 @@ function enterApplyMode(mode) {
     });
 
     // apply 실행
+    // 실패 시 { code, message, recoverable } 객체가 전달됨
     invoke('start_apply').catch(err => {
-        showRing('error', tr('applyFailed'), String(err));
+        const message = err?.message ?? String(err);
+        showRing('error', tr('applyFailed'), message);
         hideProgress();
-        showToast(tr('applyStartFailed', { error: err }), 'error', 8000);
+        showToast(tr('applyStartFailed', { error: message }), 'error', 8000);
     });
 }
@@ -33,6 +33,12 @@ pub const GITHUB_MODULES_REPO: &str = "saba-chan-modules";
 /// GitHub 익스텐션 저장소 이름
 pub const GITHUB_EXTENSIONS_REPO: &str = "saba-chan-extensions";
 
+/// 릴리즈 에셋 서명 검증용 ed25519 공개키 (hex)
+///
+/// 릴리즈 빌드 시 `SABA_SIGNING_PUBKEY` 환경변수로 주입된다.
+/// 비어 있으면 `UpdateConfig.trusted_pubkey`가 지정된 경우에만 서명을 검증한다.
+pub const TRUSTED_SIGNING_PUBKEY: Option<&str> = option_env!("SABA_SIGNING_PUBKEY");
+
 // ══════════════════════════════════════════════════════
 //  네트워크 상수
 // ══════════════════════════════════════════════════════
@@ -188,7 +194,14 @@ pub fn resolve_discord_bot_dir() -> PathBuf {
 }
 
 /// 스테이징 디렉터리 (업데이트 다운로드 임시 파일)
+///
+/// `SABA_STAGING_DIR` 환경 변수로 오버라이드 가능.
 pub fn resolve_staging_dir() -> PathBuf {
+    if let Ok(p) = std::env::var("SABA_STAGING_DIR") {
+        if !p.is_empty() {
+            return PathBuf::from(p);
+        }
+    }
     #[cfg(target_os = "windows")]
     {
         resolve_data_dir().join("updates")
@@ -0,0 +1,201 @@
+//! 바이너리 델타 패치 (bsdiff `BSDIFF40` 포맷)
+//!
+//! 큰 바이너리(GUI/코어 데몬 등)의 작은 변경을 위해 전체 에셋 대신
+//! 설치된 바이너리에 적용할 패치만 내려받을 때 사용합니다.
+//!
+//! ## 포맷
+//! - 헤더 32바이트: `"BSDIFF40"`, 제어 블록 길이, diff 블록 길이, 결과 파일 크기
+//! - 제어/diff/extra 블록: 각각 bzip2 압축
+//! - 제어 블록은 `(diff 길이, extra 길이, 이전 파일 seek)` 트리플의 반복
+
+use anyhow::Result;
+use std::io::Read;
+
+const MAGIC: &[u8; 8] = b"BSDIFF40";
+const HEADER_LEN: usize = 32;
+/// 패치 결과 크기 상한 (손상된 헤더로 인한 과도한 할당 방지)
+const MAX_OUTPUT_SIZE: i64 = 1 << 30;
+
+/// bsdiff의 부호-크기 방식 64비트 정수 디코딩
+fn offtin(buf: &[u8]) -> i64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&buf[..8]);
+    let negative = bytes[7] & 0x80 != 0;
+    bytes[7] &= 0x7f;
+    let value = i64::from_le_bytes(bytes);
+    if negative { -value } else { value }
+}
+
+/// 패치 블록에서 정확히 `len` 바이트 읽기
+fn read_block(reader: &mut impl Read, len: usize, what: &str) -> Result<Vec<u8>> {
+    let mut buf = vec![0u8; len];
+    reader.read_exact(&mut buf)
+        .map_err(|e| anyhow::anyhow!("Corrupt patch: truncated {} block ({})", what, e))?;
+    Ok(buf)
+}
+
+/// `old`에 bsdiff 패치를 적용하여 새 파일 내용을 반환
+pub fn apply_bsdiff_patch(old: &[u8], patch: &[u8]) -> Result<Vec<u8>> {
+    if patch.len() < HEADER_LEN || &patch[..8] != MAGIC {
+        anyhow::bail!("Not a BSDIFF40 patch");
+    }
+    let ctrl_len = offtin(&patch[8..16]);
+    let diff_len = offtin(&patch[16..24]);
+    let new_size = offtin(&patch[24..32]);
+    if ctrl_len < 0 || diff_len < 0 || !(0..=MAX_OUTPUT_SIZE).contains(&new_size) {
+        anyhow::bail!("Corrupt patch: invalid header");
+    }
+    let (ctrl_len, diff_len) = (ctrl_len as usize, diff_len as usize);
+    let body = &patch[HEADER_LEN..];
+    if body.len() < ctrl_len.saturating_add(diff_len) {
+        anyhow::bail!("Corrupt patch: block lengths exceed patch size");
+    }
+
+    let mut ctrl = bzip2::read::BzDecoder::new(&body[..ctrl_len]);
+    let mut diff = bzip2::read::BzDecoder::new(&body[ctrl_len..ctrl_len + diff_len]);
+    let mut extra = bzip2::read::BzDecoder::new(&body[ctrl_len + diff_len..]);
+
+    let new_size = new_size as usize;
+    let mut new = Vec::with_capacity(new_size);
+    let mut old_pos: i64 = 0;
+
+    while new.len() < new_size {
+        let triple = read_block(&mut ctrl, 24, "control")?;
+        let add_len = offtin(&triple[0..8]);
+        let copy_len = offtin(&triple[8..16]);
+        let seek = offtin(&triple[16..24]);
+        if add_len < 0 || copy_len < 0 {
+            anyhow::bail!("Corrupt patch: negative control length");
+        }
+        let (add_len, copy_len) = (add_len as usize, copy_len as usize);
+
+        // diff 블록: 이전 파일의 바이트에 더함
+        if new.len() + add_len > new_size {
+            anyhow::bail!("Corrupt patch: diff overruns output");
+        }
+        let delta = read_block(&mut diff, add_len, "diff")?;
+        for (i, d) in delta.into_iter().enumerate() {
+            let pos = old_pos.checked_add(i as i64)
+                .ok_or_else(|| anyhow::anyhow!("Corrupt patch: old offset overflow"))?;
+            let base = if pos >= 0 && (pos as usize) < old.len() { old[pos as usize] } else { 0 };
+            new.push(base.wrapping_add(d));
+        }
+        old_pos = old_pos.checked_add(add_len as i64)
+            .ok_or_else(|| anyhow::anyhow!("Corrupt patch: old offset overflow"))?;
+
+        // extra 블록: 그대로 복사
+        if new.len() + copy_len > new_size {
+            anyhow::bail!("Corrupt patch: extra overruns output");
+        }
+        new.extend(read_block(&mut extra, copy_len, "extra")?);
+        old_pos = old_pos.checked_add(seek)
+            .ok_or_else(|| anyhow::anyhow!("Corrupt patch: old offset overflow"))?;
+    }
+
+    Ok(new)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn offtout(value: i64) -> [u8; 8] {
+        let mut bytes = value.unsigned_abs().to_le_bytes();
+        if value < 0 {
+            bytes[7] |= 0x80;
+        }
+        bytes
+    }
+
+    fn bz(data: &[u8]) -> Vec<u8> {
+        let mut enc = bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::best());
+        enc.write_all(data).unwrap();
+        enc.finish().unwrap()
+    }
+
+    /// 테스트용 단순 패치 생성 — 겹치는 구간은 diff, 나머지는 extra로 인코딩
+    pub(crate) fn make_patch(old: &[u8], new: &[u8]) -> Vec<u8> {
+        let add_len = old.len().min(new.len());
+        let diff: Vec<u8> = (0..add_len).map(|i| new[i].wrapping_sub(old[i])).collect();
+        let extra = &new[add_len..];
+
+        let mut ctrl = Vec::new();
+        ctrl.extend(offtout(add_len as i64));
+        ctrl.extend(offtout(extra.len() as i64));
+        ctrl.extend(offtout(0));
+
+        let (ctrl, diff, extra) = (bz(&ctrl), bz(&diff), bz(extra));
+        let mut patch = MAGIC.to_vec();
+        patch.extend(offtout(ctrl.len() as i64));
+        patch.extend(offtout(diff.len() as i64));
+        patch.extend(offtout(new.len() as i64));
+        patch.extend(ctrl);
+        patch.extend(diff);
+        patch.extend(extra);
+        patch
+    }
+
+    #[test]
+    fn offtin_roundtrip() {
+        for v in [0i64, 1, 255, 1 << 40, -1, -300] {
+            assert_eq!(offtin(&offtout(v)), v);
+        }
+    }
+
+    #[test]
+    fn apply_patch_roundtrip() {
+        let old = b"saba-chan gui v1.0.0 binary".to_vec();
+        let new = b"saba-chan gui v1.0.1 binary with extra bytes".to_vec();
+        assert_eq!(apply_bsdiff_patch(&old, &make_patch(&old, &new)).unwrap(), new);
+
+        // 결과가 더 짧은 경우
+        let shorter = b"saba".to_vec();
+        assert_eq!(apply_bsdiff_patch(&old, &make_patch(&old, &shorter)).unwrap(), shorter);
+    }
+
+    #[test]
+    fn rejects_corrupt_patch() {
+        assert!(apply_bsdiff_patch(b"old", b"not a patch").is_err());
+
+        // 헤더의 결과 크기가 제어 블록보다 크면 잘린 패치로 간주
+        let mut patch = make_patch(b"old", b"new!");
+        patch[24] += 8;
+        assert!(apply_bsdiff_patch(b"old", &patch).is_err());
+    }
+
+    /// 제어 트리플 목록으로 패치 구성 (diff/extra는 0 바이트로 채움)
+    fn patch_from_controls(controls: &[(i64, i64, i64)]) -> Vec<u8> {
+        let mut ctrl = Vec::new();
+        let (mut diff_len, mut extra_len) = (0, 0);
+        for &(add, copy, seek) in controls {
+            ctrl.extend(offtout(add));
+            ctrl.extend(offtout(copy));
+            ctrl.extend(offtout(seek));
+            diff_len += add as usize;
+            extra_len += copy as usize;
+        }
+        let (ctrl, diff, extra) = (bz(&ctrl), bz(&vec![0; diff_len]), bz(&vec![0; extra_len]));
+        let mut patch = MAGIC.to_vec();
+        patch.extend(offtout(ctrl.len() as i64));
+        patch.extend(offtout(diff.len() as i64));
+        patch.extend(offtout((diff_len + extra_len) as i64));
+        patch.extend(ctrl);
+        patch.extend(diff);
+        patch.extend(extra);
+        patch
+    }
+
+    #[test]
+    fn rejects_seek_overflow() {
+        // seek 누적이 i64 범위를 넘으면 패닉 대신 손상된 패치로 거부
+        let patch = patch_from_controls(&[(1, 0, i64::MAX), (1, 0, 0)]);
+        let err = apply_bsdiff_patch(b"old", &patch).unwrap_err();
+        assert!(err.to_string().starts_with("Corrupt patch"), "{}", err);
+
+        // seek 직후 diff 오프셋 계산에서 넘치는 경우
+        let patch = patch_from_controls(&[(1, 0, i64::MAX - 1), (2, 0, 0)]);
+        let err = apply_bsdiff_patch(b"old", &patch).unwrap_err();
+        assert!(err.to_string().starts_with("Corrupt patch"), "{}", err);
+    }
+}
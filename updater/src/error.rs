@@ -51,6 +51,54 @@ pub enum UpdaterError {
     ConfigError {
         message: String,
     },
+    /// 다른 적용 작업이 진행 중
+    ApplyInProgress,
+    /// 이 빌드가 이해하지 못하는 manifest 스키마 (업데이터가 너무 오래됨)
+    UnsupportedManifest {
+        schema_version: u32,
+        supported: u32,
+    },
+    /// GitHub API rate limit (403/429) — `retry_after_secs` 후에 다시 시도해야 함
+    RateLimited {
+        retry_after_secs: u64,
+    },
+    /// 릴리즈 서버에 연결할 수 없음 (체크 전 연결 확인 실패)
+    /// `staged_updates`는 `pending.json`에서 복원한, 오프라인으로도 적용 가능한 업데이트 수
+    Offline {
+        staged_updates: usize,
+    },
+    /// 받은 파일이 압축 파일로 열리지 않음 (CDN 오류 페이지가 200으로 저장된 경우 등)
+    CorruptDownload {
+        asset: String,
+        message: String,
+    },
+    /// 다른 업데이터 프로세스가 스테이징 디렉터리 잠금을 잡고 있음 (`updater.lock`)
+    AlreadyRunning {
+        pid: u32,
+    },
+    /// 리포를 찾을 수 없거나 접근 권한이 없음 (릴리즈 목록 404)
+    /// `authenticated`는 요청에 `github_token`을 실어 보냈는지 여부
+    RepoUnavailable {
+        repo: String,
+        authenticated: bool,
+    },
+    /// 업데이터 실행파일을 찾을 수 없음 (`searched`는 확인한 경로 목록)
+    UpdaterNotFound {
+        searched: Vec<String>,
+    },
+    /// 현재 OS/아키텍처용 릴리즈 에셋이 없음 (다른 플랫폼용 에셋만 있음)
+    NoAssetForPlatform {
+        component: String,
+        platform: String,
+    },
+    /// 탐색한 릴리즈 어디에도 `manifest.json`이 없음 (`scanned_tags`는 확인한 릴리즈 태그)
+    ManifestNotFound {
+        scanned_tags: Vec<String>,
+    },
+    /// 실행 중인 바이너리(데몬/GUI/업데이터)는 제자리 설치 불가 — 업데이터 재시작 경로로 적용해야 함
+    RequiresRestart {
+        component: String,
+    },
     /// 알 수 없는 오류
     Unknown {
         message: String,
@@ -76,6 +124,9 @@ impl fmt::Display for UpdaterError {
                     component, downloaded_bytes, total_bytes
                 )
             }
+            UpdaterError::FileSystemError { operation, path, message } if path.is_empty() => {
+                write!(f, "File system error during {}: {}", operation, message)
+            }
             UpdaterError::FileSystemError { operation, path, message } => {
                 write!(f, "File system error during {} on '{}': {}", operation, path, message)
             }
@@ -89,6 +140,46 @@ impl fmt::Display for UpdaterError {
             UpdaterError::ConfigError { message } => {
                 write!(f, "Configuration error: {}", message)
             }
+            UpdaterError::ApplyInProgress => {
+                write!(f, "Another apply operation is already in progress")
+            }
+            UpdaterError::UnsupportedManifest { schema_version, supported } => {
+                write!(
+                    f,
+                    "Updater too old, please update: manifest schema {} is newer than supported schema {}",
+                    schema_version, supported
+                )
+            }
+            UpdaterError::RateLimited { retry_after_secs } => {
+                write!(f, "Rate limited by GitHub API: retry after {}s", retry_after_secs)
+            }
+            UpdaterError::Offline { staged_updates } => {
+                write!(f, "Offline: update server unreachable ({} staged updates available)", staged_updates)
+            }
+            UpdaterError::CorruptDownload { asset, message } => {
+                write!(f, "Downloaded {} is not a valid archive: {}", asset, message)
+            }
+            UpdaterError::AlreadyRunning { pid } => {
+                write!(f, "Another updater process is already running (pid {})", pid)
+            }
+            UpdaterError::RepoUnavailable { repo, authenticated: true } => {
+                write!(f, "Repository {} not found or not accessible with the configured token", repo)
+            }
+            UpdaterError::RepoUnavailable { repo, authenticated: false } => {
+                write!(f, "Repository {} not found (private repositories require github_token)", repo)
+            }
+            UpdaterError::UpdaterNotFound { searched } => {
+                write!(f, "Updater executable not found (searched: {})", searched.join(", "))
+            }
+            UpdaterError::NoAssetForPlatform { component, platform } => {
+                write!(f, "No release asset of {} for platform {}", component, platform)
+            }
+            UpdaterError::ManifestNotFound { scanned_tags } => {
+                write!(f, "No manifest.json in scanned releases: {}", scanned_tags.join(", "))
+            }
+            UpdaterError::RequiresRestart { component } => {
+                write!(f, "{} is running and must be applied by the updater with a restart", component)
+            }
             UpdaterError::Unknown { message } => {
                 write!(f, "Unknown error: {}", message)
             }
@@ -98,7 +189,78 @@ impl fmt::Display for UpdaterError {
 
 impl std::error::Error for UpdaterError {}
 
+/// `UpdateManager` 공개 API의 결과 타입
+pub type UpdaterResult<T> = std::result::Result<T, UpdaterError>;
+
+/// 내부 anyhow 에러를 분류 — 체인에 담긴 `UpdaterError`/reqwest/IO 에러를 우선 사용
+impl From<anyhow::Error> for UpdaterError {
+    fn from(err: anyhow::Error) -> Self {
+        // 가장 바깥 context를 작업 이름으로 사용 (context가 없으면 기본값)
+        let operation = |cause: &dyn std::error::Error, fallback: &str| {
+            let outer = err.to_string();
+            if outer == cause.to_string() { fallback.to_string() } else { outer }
+        };
+        for cause in err.chain() {
+            if let Some(e) = cause.downcast_ref::<UpdaterError>() {
+                return e.clone();
+            }
+            if let Some(e) = cause.downcast_ref::<reqwest::Error>() {
+                return UpdaterError::from_reqwest(e, &operation(e, "request"));
+            }
+            if let Some(e) = cause.downcast_ref::<std::io::Error>() {
+                return UpdaterError::from_io(e, &operation(e, "file operation"), "");
+            }
+        }
+        UpdaterError::Unknown { message: format!("{:#}", err) }
+    }
+}
+
+/// 프론트엔드 전달용 에러 형식 (`{code, message, recoverable}`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorPayload {
+    /// 에러 종류 (`UpdaterError::code`)
+    pub code: String,
+    /// 사용자 표시용 메시지
+    pub message: String,
+    pub recoverable: bool,
+}
+
+impl From<&UpdaterError> for ErrorPayload {
+    fn from(err: &UpdaterError) -> Self {
+        Self {
+            code: err.code().to_string(),
+            message: err.to_string(),
+            recoverable: err.is_recoverable(),
+        }
+    }
+}
+
 impl UpdaterError {
+    /// 프론트엔드가 분기할 수 있는 에러 코드 (snake_case)
+    pub fn code(&self) -> &'static str {
+        match self {
+            UpdaterError::NetworkError { .. } => "network_error",
+            UpdaterError::Timeout { .. } => "timeout",
+            UpdaterError::ApiError { .. } => "api_error",
+            UpdaterError::DownloadInterrupted { .. } => "download_interrupted",
+            UpdaterError::FileSystemError { .. } => "file_system_error",
+            UpdaterError::ValidationError { .. } => "validation_error",
+            UpdaterError::ConfigError { .. } => "config_error",
+            UpdaterError::ApplyInProgress => "apply_in_progress",
+            UpdaterError::UnsupportedManifest { .. } => "unsupported_manifest",
+            UpdaterError::RateLimited { .. } => "rate_limited",
+            UpdaterError::Offline { .. } => "offline",
+            UpdaterError::CorruptDownload { .. } => "corrupt_download",
+            UpdaterError::AlreadyRunning { .. } => "already_running",
+            UpdaterError::RepoUnavailable { .. } => "repo_unavailable",
+            UpdaterError::UpdaterNotFound { .. } => "updater_not_found",
+            UpdaterError::NoAssetForPlatform { .. } => "no_asset_for_platform",
+            UpdaterError::ManifestNotFound { .. } => "manifest_not_found",
+            UpdaterError::RequiresRestart { .. } => "requires_restart",
+            UpdaterError::Unknown { .. } => "unknown",
+        }
+    }
+
     /// 복구 가능한 에러인지 확인
     pub fn is_recoverable(&self) -> bool {
         match self {
@@ -112,12 +274,27 @@ impl UpdaterError {
             UpdaterError::FileSystemError { .. } => false,
             UpdaterError::ValidationError { .. } => true, // 재다운로드로 복구 가능
             UpdaterError::ConfigError { .. } => false,
+            UpdaterError::ApplyInProgress => true, // 진행 중인 적용이 끝나면 재시도 가능
+            UpdaterError::UnsupportedManifest { .. } => false, // 업데이터 자체를 갱신해야 함
+            UpdaterError::RateLimited { .. } => true,
+            UpdaterError::Offline { .. } => true,
+            UpdaterError::CorruptDownload { .. } => true, // 재다운로드로 복구 가능
+            UpdaterError::AlreadyRunning { .. } => true, // 다른 프로세스가 끝나면 재시도 가능
+            UpdaterError::RepoUnavailable { .. } => false, // 토큰/리포 설정을 고쳐야 함
+            UpdaterError::UpdaterNotFound { .. } => false, // 업데이터 재설치 또는 updater_path 설정 필요
+            UpdaterError::NoAssetForPlatform { .. } => false, // 해당 플랫폼 빌드가 릴리즈되어야 함
+            UpdaterError::ManifestNotFound { .. } => false, // 릴리즈에 manifest.json이 올라와야 함
+            UpdaterError::RequiresRestart { .. } => false, // 다운로드 후 업데이터 적용 경로를 사용해야 함
             UpdaterError::Unknown { .. } => false,
         }
     }
 
     /// 권장 재시도 대기 시간
     pub fn retry_delay(&self, attempt: u32) -> Duration {
+        // 서버가 알려준 대기 시간은 백오프 없이 그대로 따름
+        if let UpdaterError::RateLimited { retry_after_secs } = self {
+            return Duration::from_secs(*retry_after_secs);
+        }
         let base_delay = match self {
             UpdaterError::NetworkError { .. } => Duration::from_secs(2),
             UpdaterError::Timeout { .. } => Duration::from_secs(5),
@@ -172,6 +349,45 @@ impl UpdaterError {
             UpdaterError::ConfigError { message } => {
                 format!("설정 오류: {}", message)
             }
+            UpdaterError::ApplyInProgress => {
+                "이미 업데이트를 적용하는 중입니다. 잠시 후 다시 시도해주세요.".to_string()
+            }
+            UpdaterError::UnsupportedManifest { .. } => {
+                "업데이터가 너무 오래되었습니다. 업데이터를 최신 버전으로 업데이트해주세요.".to_string()
+            }
+            UpdaterError::RateLimited { retry_after_secs } => {
+                format!("GitHub 요청 한도를 초과했습니다. 약 {}초 후에 다시 확인합니다.", retry_after_secs)
+            }
+            UpdaterError::Offline { staged_updates: 0 } => {
+                "오프라인 상태입니다. 인터넷 연결을 확인해주세요.".to_string()
+            }
+            UpdaterError::Offline { staged_updates } => {
+                format!("오프라인 상태입니다. 이미 다운로드된 업데이트 {}개는 적용할 수 있습니다.", staged_updates)
+            }
+            UpdaterError::CorruptDownload { .. } => {
+                "다운로드한 파일이 손상되었습니다. 다시 다운로드해주세요.".to_string()
+            }
+            UpdaterError::AlreadyRunning { .. } => {
+                "다른 업데이터가 이미 실행 중입니다. 해당 작업이 끝난 후 다시 시도해주세요.".to_string()
+            }
+            UpdaterError::RepoUnavailable { repo, authenticated: true } => {
+                format!("{} 리포에 접근할 수 없습니다. 토큰에 이 리포 읽기 권한이 있는지 확인해주세요.", repo)
+            }
+            UpdaterError::RepoUnavailable { repo, authenticated: false } => {
+                format!("{} 리포를 찾을 수 없습니다. 비공개 리포라면 GitHub 토큰을 설정해주세요.", repo)
+            }
+            UpdaterError::UpdaterNotFound { .. } => {
+                "업데이터 실행파일을 찾을 수 없습니다. 업데이터를 다시 설치하거나 설정에서 업데이터 경로를 지정해주세요.".to_string()
+            }
+            UpdaterError::NoAssetForPlatform { component, platform } => {
+                format!("{}의 새 버전에 이 플랫폼({})용 파일이 없습니다. 해당 플랫폼 빌드가 배포될 때까지 기다려주세요.", component, platform)
+            }
+            UpdaterError::ManifestNotFound { scanned_tags } => {
+                format!("릴리즈에 업데이트 정보(manifest.json)가 없어 업데이트를 확인할 수 없습니다. (확인한 릴리즈: {})", scanned_tags.join(", "))
+            }
+            UpdaterError::RequiresRestart { component } => {
+                format!("{}은(는) 실행 중이라 바로 설치할 수 없습니다. 업데이트로 다운로드한 뒤 적용해주세요.", component)
+            }
             UpdaterError::Unknown { message } => {
                 format!("오류가 발생했습니다: {}", message)
             }
@@ -337,6 +553,28 @@ impl NetworkChecker {
         }
     }
 
+    /// 지정한 엔드포인트만 확인하는 체커
+    pub fn with_endpoints(endpoints: Vec<String>, timeout: Duration) -> Self {
+        Self { endpoints, timeout }
+    }
+
+    /// 빠른 도달 가능성 확인 — 엔드포인트 호스트에 TCP 연결만 시도 (DNS 조회 포함 `timeout` 이내)
+    ///
+    /// HTTP 응답을 기다리지 않으므로 오프라인일 때 DNS 타임아웃까지 기다리지 않고 바로 판단한다.
+    pub async fn is_reachable(&self) -> bool {
+        for endpoint in &self.endpoints {
+            let Ok(url) = reqwest::Url::parse(endpoint) else { continue };
+            let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+                continue;
+            };
+            let connect = tokio::net::TcpStream::connect((host, port));
+            if let Ok(Ok(_)) = tokio::time::timeout(self.timeout, connect).await {
+                return true;
+            }
+        }
+        false
+    }
+
     /// 네트워크 연결 상태 확인
     pub async fn check_connectivity(&self) -> bool {
         let client = reqwest::Client::builder()
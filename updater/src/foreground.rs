@@ -9,9 +9,10 @@
 //! - **Saba-Core 적용**: 데몬 중지 → 적용 → 재시작
 //! - **GUI/CLI 적용**: 업데이터가 셀프 업데이트 수행
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use serde::{Deserialize, Serialize};
 
@@ -42,6 +43,17 @@ pub struct ApplyProgress {
     pub message: String,
 }
 
+impl ApplyProgress {
+    /// 전체 진행률 (0~100) — 적용을 마친 컴포넌트 수 기준
+    pub fn percent(&self) -> u8 {
+        match self.phase {
+            ApplyPhase::Completed => 100,
+            _ if self.total == 0 => 0,
+            _ => (self.done.min(self.total) * 100 / self.total) as u8,
+        }
+    }
+}
+
 /// 적용 단계
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ApplyPhase {
@@ -51,6 +63,10 @@ pub enum ApplyPhase {
     WaitingForProcesses,
     /// 파일 적용 중
     Applying,
+    /// 컴포넌트 파일 추출/교체 중
+    Extracting { component: String },
+    /// 설치 매니페스트 기록 중
+    WritingManifest,
     /// 재시작 중
     Restarting,
     /// 완료
@@ -59,6 +75,157 @@ pub enum ApplyPhase {
     Failed,
 }
 
+/// 적용 후 재시작 설정
+#[derive(Debug, Clone)]
+pub struct RelaunchOptions {
+    /// 재시작 전 대기 시간 — 백신 검사가 느린 환경에서는 늘려서 교체된 exe가 풀릴 때까지 기다림
+    pub delay: Duration,
+    /// 자식 프로세스의 작업 디렉터리 (None이면 실행 파일이 있는 디렉터리 = install_root)
+    pub working_dir: Option<PathBuf>,
+}
+
+impl RelaunchOptions {
+    pub const DEFAULT_DELAY: Duration = Duration::from_millis(2000);
+}
+
+impl Default for RelaunchOptions {
+    fn default() -> Self {
+        Self { delay: Self::DEFAULT_DELAY, working_dir: None }
+    }
+}
+
+/// 업데이트 후 재시작 시 넘기는 플래그
+pub const AFTER_UPDATE_FLAG: &str = "--after-update";
+
+/// 재시작 인자 조립 — 호출자가 이미 `--after-update`를 넘긴 경우 한 번만 남김
+pub fn relaunch_args(extra: &[String], after_update: bool) -> Vec<String> {
+    let mut args = Vec::with_capacity(extra.len() + 1);
+    let mut has_flag = false;
+    for arg in extra {
+        if arg == AFTER_UPDATE_FLAG {
+            if has_flag {
+                continue;
+            }
+            has_flag = true;
+        }
+        args.push(arg.clone());
+    }
+    if after_update && !has_flag {
+        args.push(AFTER_UPDATE_FLAG.to_string());
+    }
+    args
+}
+
+/// 업데이터 apply 모드 설정 (`parse_apply_args` 결과)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApplyModeConfig {
+    /// `--install-root`로 지정한 설치 경로 (None이면 업데이터 exe 위치에서 추론)
+    pub install_root: Option<PathBuf>,
+    /// `--manifest`로 지정한 pending 매니페스트
+    pub manifest_path: Option<PathBuf>,
+    /// `--relaunch-delay` (미지정 시 `RelaunchOptions::DEFAULT_DELAY`)
+    pub relaunch_delay: Duration,
+    /// `--relaunch` 바로 뒤의 재실행 파일
+    pub relaunch_exe: Option<String>,
+    /// 재실행 파일 뒤의 나머지 인자 (그대로 전달)
+    pub relaunch_extra: Vec<String>,
+    /// `--relaunch` 앞의 위치 인자 — 적용할 컴포넌트 키 (비어 있으면 apply-targets.json)
+    pub components: Vec<String>,
+}
+
+/// 업데이터 자체 옵션 — `--relaunch` 앞뒤 어디에 있어도 업데이터가 소비
+const APPLY_VALUE_FLAGS: [&str; 3] = ["--install-root", "--manifest", "--relaunch-delay"];
+
+/// `--apply [<component>...] [--install-root <path>] [--manifest <path>] [--relaunch-delay <ms>] [--relaunch <exe> [extra...]]` 파싱
+///
+/// - 값이 필요한 옵션은 순서와 관계없이 인식하며, 값이 없거나 다른 옵션(`--…`)이면 에러
+/// - 같은 옵션을 두 번 지정하거나 `--relaunch` 뒤에 실행 파일이 없으면 에러
+/// - `--relaunch <exe>` 뒤의 나머지 인자는 재실행 인자로 그대로 전달
+/// - 셸에서 잘못 이스케이프되어 남은 경로 양끝의 `"`는 제거 (공백이 포함된 경로 지원)
+pub fn parse_apply_args(args: &[String]) -> Result<ApplyModeConfig, String> {
+    let apply_pos = args.iter().position(|a| a == "--apply")
+        .ok_or_else(|| "--apply 인자가 필요합니다.".to_string())?;
+
+    let mut values: [Option<String>; 3] = Default::default();
+    let mut relaunch: Option<(String, Vec<String>)> = None;
+    let mut components = Vec::new();
+
+    let mut rest = args[apply_pos + 1..].iter();
+    while let Some(arg) = rest.next() {
+        if let Some(idx) = APPLY_VALUE_FLAGS.iter().position(|f| f == arg) {
+            let value = rest.next()
+                .filter(|v| !v.starts_with("--"))
+                .ok_or_else(|| format!("{} 뒤에 값이 필요합니다.", arg))?;
+            if values[idx].replace(value.clone()).is_some() {
+                return Err(format!("{} 인자가 중복되었습니다.", arg));
+            }
+        } else if arg == "--relaunch" {
+            if relaunch.is_some() {
+                return Err("--relaunch 인자가 중복되었습니다.".to_string());
+            }
+            let exe = rest.next()
+                .filter(|v| !v.starts_with("--"))
+                .ok_or_else(|| "--relaunch 뒤에 실행 파일 경로가 필요합니다.".to_string())?;
+            relaunch = Some((unquote(exe), Vec::new()));
+        } else if let Some((_, extra)) = relaunch.as_mut() {
+            extra.push(arg.clone());
+        } else if arg.starts_with("--") {
+            return Err(format!("알 수 없는 인자: {}", arg));
+        } else {
+            components.push(arg.clone());
+        }
+    }
+
+    let [install_root, manifest_path, relaunch_delay] = values;
+    let relaunch_delay = match relaunch_delay {
+        Some(ms) => Duration::from_millis(ms.parse::<u64>()
+            .map_err(|_| format!("--relaunch-delay 값이 올바르지 않습니다 (ms): {}", ms))?),
+        None => RelaunchOptions::DEFAULT_DELAY,
+    };
+    let (relaunch_exe, relaunch_extra) = match relaunch {
+        Some((exe, extra)) => (Some(exe), extra),
+        None => (None, Vec::new()),
+    };
+
+    Ok(ApplyModeConfig {
+        install_root: install_root.map(|p| PathBuf::from(unquote(&p))),
+        manifest_path: manifest_path.map(|p| PathBuf::from(unquote(&p))),
+        relaunch_delay,
+        relaunch_exe,
+        relaunch_extra,
+        components,
+    })
+}
+
+/// 경로 양끝에 남은 큰따옴표 제거
+fn unquote(value: &str) -> String {
+    value.trim_start_matches('"').trim_end_matches('"').to_string()
+}
+
+/// 분리된 재시작 명령 생성
+///
+/// 작업 디렉터리를 install_root로 맞추고, 설정 디렉터리 등을 가리키는 현재 환경 변수를
+/// 그대로 넘겨 재시작된 GUI/CLI가 업데이트 전과 같은 환경에서 실행되도록 합니다.
+pub fn relaunch_command(exe: &Path, args: &[String], options: &RelaunchOptions) -> Command {
+    let mut cmd = Command::new(exe);
+    cmd.args(args).envs(std::env::vars_os());
+    if let Some(dir) = options.working_dir.as_deref().or_else(|| exe.parent().filter(|p| !p.as_os_str().is_empty())) {
+        cmd.current_dir(dir);
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        // DETACHED_PROCESS | CREATE_BREAKAWAY_FROM_JOB | CREATE_NEW_PROCESS_GROUP
+        cmd.creation_flags(0x00000008 | 0x01000000 | 0x00000200);
+    }
+
+    cmd.stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null());
+    cmd
+}
+
 /// 포그라운드 적용 매니저
 pub struct ForegroundApplier {
     manager: Arc<RwLock<UpdateManager>>,
@@ -66,6 +233,8 @@ pub struct ForegroundApplier {
     gui_executable: Option<PathBuf>,
     /// CLI 실행 파일 경로
     cli_executable: Option<PathBuf>,
+    /// 재시작 대기 시간/작업 디렉터리
+    relaunch_options: RelaunchOptions,
     /// 현재 진행 상태
     progress: Arc<RwLock<Option<ApplyProgress>>>,
 }
@@ -76,6 +245,7 @@ impl ForegroundApplier {
             manager,
             gui_executable: None,
             cli_executable: None,
+            relaunch_options: RelaunchOptions::default(),
             progress: Arc::new(RwLock::new(None)),
         }
     }
@@ -90,6 +260,11 @@ impl ForegroundApplier {
         self.cli_executable = Some(path);
     }
 
+    /// 재시작 대기 시간/작업 디렉터리 설정
+    pub fn set_relaunch_options(&mut self, options: RelaunchOptions) {
+        self.relaunch_options = options;
+    }
+
     /// 적용 준비 상태 확인
     pub async fn prepare(&self) -> ApplyPreparation {
         let mgr = self.manager.read().await;
@@ -135,7 +310,13 @@ impl ForegroundApplier {
     }
 
     /// 모듈/익스텐션만 적용 (프로세스 중단 불필요)
-    pub async fn apply_modules_only(&self) -> Result<Vec<String>, String> {
+    ///
+    /// 한 모듈이 실패해도 나머지를 계속 적용하며, 실패를 포함한 모듈별 결과를 반환합니다.
+    pub async fn apply_modules_only(&self) -> Result<Vec<ApplyComponentResult>, String> {
+        let mut mgr = self.manager.write().await;
+        // 대상 순회 전체에 걸쳐 다른 적용 작업이 끼어들지 않도록 lease 유지
+        let _lease = mgr.begin_apply().map_err(|e| e.to_string())?;
+
         self.update_progress(ApplyProgress {
             phase: ApplyPhase::Applying,
             current_component: None,
@@ -144,8 +325,7 @@ impl ForegroundApplier {
             message: "모듈/익스텐션 업데이트 적용 중...".to_string(),
         }).await;
 
-        let mut applied = Vec::new();
-        let mut mgr = self.manager.write().await;
+        let mut results = Vec::new();
 
         let targets: Vec<Component> = mgr
             .get_pending_components()
@@ -164,28 +344,43 @@ impl ForegroundApplier {
                 message: format!("{} 적용 중...", target.display_name()),
             }).await;
 
-            match mgr.apply_single_component(target).await {
-                Ok(result) if result.success => {
-                    applied.push(target.display_name());
-                }
+            let result = match mgr.apply_single_component_leased(target).await {
                 Ok(result) => {
-                    tracing::warn!("[Apply] {} failed: {}", target.display_name(), result.message);
+                    if !result.success {
+                        tracing::warn!("[Apply] {} failed: {}", target.display_name(), result.message);
+                    }
+                    result
                 }
                 Err(e) => {
                     tracing::error!("[Apply] {} error: {}", target.display_name(), e);
+                    ApplyComponentResult {
+                        component: target.manifest_key(),
+                        success: false,
+                        message: e.to_string(),
+                        stopped_processes: Vec::new(),
+                        restart_needed: false,
+                    }
                 }
-            }
+            };
+            results.push(result);
         }
 
+        let succeeded = results.iter().filter(|r| r.success).count();
+        let failed = results.len() - succeeded;
+        let message = if failed == 0 {
+            format!("{} 모듈/익스텐션 업데이트 완료", succeeded)
+        } else {
+            format!("{} 모듈/익스텐션 업데이트 완료, {} 실패", succeeded, failed)
+        };
         self.update_progress(ApplyProgress {
             phase: ApplyPhase::Completed,
             current_component: None,
             total,
-            done: applied.len(),
-            message: format!("{} 모듈/익스텐션 업데이트 완료", applied.len()),
+            done: succeeded,
+            message,
         }).await;
 
-        Ok(applied)
+        Ok(results)
     }
 
     /// 전체 적용 (GUI/CLI 종료 필요)
@@ -237,25 +432,13 @@ impl ForegroundApplier {
             message: "업데이트 적용 중...".to_string(),
         }).await;
 
-        let result = mgr.apply_updates().await.map_err(|e| e.to_string())?;
-
-        let apply_result = ApplyResult {
-            results: result.iter().map(|name| ApplyComponentResult {
-                component: name.clone(),
-                success: true,
-                message: format!("{} 업데이트 완료", name),
-                stopped_processes: Vec::new(),
-                restart_needed: false,
-            }).collect(),
-            daemon_restart_script: None,
-            self_update_components: Vec::new(),
-        };
+        let apply_result = mgr.apply_updates().await.map_err(|e| e.to_string())?;
 
         self.update_progress(ApplyProgress {
             phase: ApplyPhase::Completed,
             current_component: None,
-            total: result.len(),
-            done: result.len(),
+            total: apply_result.results.len(),
+            done: apply_result.results.len(),
             message: "업데이트 적용 완료!".to_string(),
         }).await;
 
@@ -267,20 +450,9 @@ impl ForegroundApplier {
         let exe = self.gui_executable.as_ref()
             .ok_or("GUI 실행 파일이 설정되지 않았습니다")?;
 
-        let mut cmd = Command::new(exe);
-        if after_update {
-            cmd.arg("--after-update");
-        }
-
-        #[cfg(target_os = "windows")]
-        {
-            use std::os::windows::process::CommandExt;
-            cmd.creation_flags(0x00000008 | 0x01000000 | 0x00000200);
-        }
-
-        cmd.stdin(std::process::Stdio::null())
-            .stdout(std::process::Stdio::null())
-            .stderr(std::process::Stdio::null())
+        let args = relaunch_args(&[], after_update);
+        std::thread::sleep(self.relaunch_options.delay);
+        relaunch_command(exe, &args, &self.relaunch_options)
             .spawn()
             .map_err(|e| format!("GUI 재시작 실패: {}", e))?;
 
@@ -292,21 +464,9 @@ impl ForegroundApplier {
         let exe = self.cli_executable.as_ref()
             .ok_or("CLI 실행 파일이 설정되지 않았습니다")?;
 
-        let mut cmd = Command::new(exe);
-        cmd.args(args);
-        if after_update {
-            cmd.arg("--after-update");
-        }
-
-        #[cfg(target_os = "windows")]
-        {
-            use std::os::windows::process::CommandExt;
-            cmd.creation_flags(0x00000008 | 0x01000000 | 0x00000200);
-        }
-
-        cmd.stdin(std::process::Stdio::null())
-            .stdout(std::process::Stdio::null())
-            .stderr(std::process::Stdio::null())
+        let args = relaunch_args(args, after_update);
+        std::thread::sleep(self.relaunch_options.delay);
+        relaunch_command(exe, &args, &self.relaunch_options)
             .spawn()
             .map_err(|e| format!("CLI 재시작 실패: {}", e))?;
 
@@ -394,37 +554,35 @@ impl SelfUpdater {
 pub struct ProcessChecker;
 
 impl ProcessChecker {
-    /// 프로세스 이름으로 실행 중인지 확인
-    #[cfg(target_os = "windows")]
+    /// 프로세스 이름으로 실행 중인지 확인 (Windows/Linux/macOS 공통)
+    ///
+    /// 프로세스 이름 또는 실행파일 이름이 일치하면 실행 중으로 판단합니다.
+    /// Linux의 프로세스 이름(comm)은 15자로 잘리므로 실행파일 경로도 함께 비교합니다.
+    /// 현재 프로세스 자신은 제외합니다.
     pub fn is_running(process_name: &str) -> bool {
-        use std::process::Command;
-        
-        let output = Command::new("tasklist")
-            .args(["/FI", &format!("IMAGENAME eq {}", process_name)])
-            .output();
-
-        match output {
-            Ok(output) => {
-                let stdout = String::from_utf8_lossy(&output.stdout);
-                stdout.contains(process_name)
-            }
-            Err(_) => false,
-        }
+        let mut sys = sysinfo::System::new();
+        sys.refresh_processes();
+        let own_pid = sysinfo::Pid::from_u32(std::process::id());
+
+        sys.processes().iter()
+            .filter(|(pid, _)| **pid != own_pid)
+            .any(|(_, process)| Self::process_matches(process, process_name))
     }
 
-    #[cfg(not(target_os = "windows"))]
-    pub fn is_running(process_name: &str) -> bool {
-        use std::process::Command;
-        
-        let output = Command::new("pgrep")
-            .arg("-x")
-            .arg(process_name)
-            .output();
-
-        match output {
-            Ok(output) => output.status.success(),
-            Err(_) => false,
-        }
+    fn process_matches(process: &sysinfo::Process, process_name: &str) -> bool {
+        let eq = |name: &str| {
+            if cfg!(target_os = "windows") {
+                name.eq_ignore_ascii_case(process_name)
+            } else {
+                name == process_name
+            }
+        };
+        eq(process.name())
+            || process.exe()
+                .and_then(|p| p.file_name())
+                .and_then(|n| n.to_str())
+                .map(eq)
+                .unwrap_or(false)
     }
 
     /// 프로세스 종료 대기
@@ -442,6 +600,28 @@ impl ProcessChecker {
         false
     }
 
+    /// 프로세스가 실행 중이면 종료를 기다리고, 시간 내에 종료되지 않으면 에러를 반환
+    ///
+    /// 실행 중인(메모리 매핑된) 실행파일을 덮어써 손상시키는 것을 막기 위해
+    /// 바이너리 교체 직전에 호출합니다.
+    /// 실제로 종료를 기다려야 했으면 `true`, 처음부터 실행 중이 아니었으면 `false`를 반환합니다.
+    pub async fn ensure_exited(process_name: &str, timeout_secs: u64) -> anyhow::Result<bool> {
+        if !Self::is_running(process_name) {
+            return Ok(false);
+        }
+        tracing::info!("[Updater] Waiting for {} to exit before applying update...", process_name);
+        if Self::wait_for_exit(process_name, timeout_secs).await {
+            tracing::info!("[Updater] {} exited, continuing update", process_name);
+            Ok(true)
+        } else {
+            anyhow::bail!(
+                "Process still running: {} did not exit within {}s; close it and retry the update",
+                process_name,
+                timeout_secs
+            )
+        }
+    }
+
     /// 사바쨩 GUI가 실행 중인지 확인
     pub fn is_gui_running() -> bool {
         #[cfg(target_os = "windows")]
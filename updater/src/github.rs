@@ -6,6 +6,11 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::platform::{AssetMatch, Platform};
+use crate::source::ReleaseSource;
 
 /// GitHub Release 응답 (필요한 필드만)
 #[derive(Debug, Clone, Deserialize)]
@@ -20,6 +25,36 @@ pub struct GitHubRelease {
     pub assets: Vec<GitHubAsset>,
 }
 
+/// 업데이트 릴리즈 채널
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReleaseChannel {
+    /// 정식 릴리즈만
+    #[default]
+    Stable,
+    /// 정식 릴리즈 + `-beta` 태그의 프리릴리즈
+    Beta,
+    /// 모든 프리릴리즈 포함
+    Edge,
+}
+
+impl ReleaseChannel {
+    /// 릴리즈가 이 채널의 업데이트 후보인지 (draft는 항상 제외)
+    pub fn accepts(self, release: &GitHubRelease) -> bool {
+        if release.draft {
+            return false;
+        }
+        if !release.prerelease {
+            return true;
+        }
+        match self {
+            ReleaseChannel::Stable => false,
+            ReleaseChannel::Beta => release.tag_name.to_ascii_lowercase().contains("-beta"),
+            ReleaseChannel::Edge => true,
+        }
+    }
+}
+
 /// GitHub Release Asset
 #[derive(Debug, Clone, Deserialize)]
 pub struct GitHubAsset {
@@ -29,15 +64,42 @@ pub struct GitHubAsset {
     pub content_type: Option<String>,
 }
 
+/// 이 빌드가 해석할 수 있는 최대 manifest 스키마 버전
+pub const SUPPORTED_MANIFEST_SCHEMA_VERSION: u32 = 1;
+
+fn default_manifest_schema_version() -> u32 {
+    1
+}
+
 /// manifest.json — 릴리스에 포함되는 컴포넌트별 버전 매핑
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReleaseManifest {
+    /// manifest 형식 버전 (생략 시 1)
+    #[serde(default = "default_manifest_schema_version")]
+    pub manifest_schema_version: u32,
     /// 릴리스 전체 버전
     pub release_version: String,
     /// 컴포넌트별 정보
     pub components: HashMap<String, ComponentInfo>,
 }
 
+impl ReleaseManifest {
+    /// manifest JSON을 파싱하고 스키마 버전을 검증
+    ///
+    /// 이 빌드보다 새로운 스키마는 에셋을 잘못 매핑할 수 있으므로
+    /// `UpdaterError::UnsupportedManifest`로 거부한다.
+    pub fn parse(json: &str) -> Result<Self> {
+        let manifest: Self = serde_json::from_str(json)?;
+        if manifest.manifest_schema_version > SUPPORTED_MANIFEST_SCHEMA_VERSION {
+            return Err(anyhow::anyhow!(crate::UpdaterError::UnsupportedManifest {
+                schema_version: manifest.manifest_schema_version,
+                supported: SUPPORTED_MANIFEST_SCHEMA_VERSION,
+            }));
+        }
+        Ok(manifest)
+    }
+}
+
 /// manifest.json 내 각 컴포넌트 정보
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComponentInfo {
@@ -48,14 +110,37 @@ pub struct ComponentInfo {
     /// Linux용 릴리스 에셋 파일명 (None이면 asset 필드를 폴백 사용)
     #[serde(default)]
     pub asset_linux: Option<String>,
+    /// 선택: 플랫폼별 에셋 파일명 목록 — 이름의 OS/아키텍처 토큰으로 현재 플랫폼용을 선택
+    /// 예: `["saba-core-windows-x64.zip", "saba-core-linux-x64.tar.gz", "saba-core-macos-arm64.zip"]`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assets: Vec<String>,
     /// 선택: 에셋 SHA256 해시
     pub sha256: Option<String>,
+    /// 선택: 대체 다운로드 URL 목록 (기본 URL 실패 시 순서대로 시도)
+    /// GitHub CDN이 차단된 지역을 위한 자체 호스팅 미러
+    #[serde(default)]
+    pub mirrors: Vec<String>,
+    /// 선택: ed25519 서명 파일 — URL 또는 같은 릴리즈의 에셋 이름
+    /// 서명 대상은 에셋의 SHA256 다이제스트(소문자 hex 텍스트)
+    /// 생략하면 `{asset}.sig` 에셋이 있을 때 그것을 사용
+    #[serde(default)]
+    pub signature: Option<String>,
     /// 선택: 설치 디렉터리 (install_root 기준 상대경로)
     pub install_dir: Option<String>,
     /// 버전 의존성: 이 컴포넌트가 요구하는 다른 컴포넌트의 최소 버전
     /// 예: { "saba-core": ">=0.3.0" } — GUI 0.3.0은 CoreDaemon 0.3.0 이상 필요
     #[serde(default)]
     pub requires: Option<HashMap<String, String>>,
+    /// 선택: 델타 패치 — 이전 버전 → 패치 URL 또는 같은 릴리즈의 에셋 이름
+    /// 로컬 버전이 키와 일치하면 전체 에셋 대신 설치된 바이너리에 bsdiff 패치를 적용한다
+    #[serde(default)]
+    pub patch_from: HashMap<String, String>,
+    /// 선택: 패치 적용 후 주 바이너리의 SHA256 (없으면 패치를 사용하지 않음)
+    #[serde(default)]
+    pub binary_sha256: Option<String>,
+    /// 선택: 에셋 크기 (바이트) — 생략하면 릴리즈 에셋 목록 또는 HEAD 요청으로 확인
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
 }
 
 /// 여러 릴리즈를 횡단 탐색하여 결정된 컴포넌트의 최적 다운로드 소스
@@ -78,8 +163,40 @@ pub struct ResolvedComponent {
     pub install_dir: Option<String>,
     /// SHA256 해시
     pub sha256: Option<String>,
+    /// 대체 다운로드 URL (manifest의 mirrors)
+    #[serde(default)]
+    pub mirrors: Vec<String>,
+    /// 서명 파일 다운로드 URL (없으면 서명 미제공)
+    #[serde(default)]
+    pub signature_url: Option<String>,
     /// 의존성 정보
     pub requires: Option<HashMap<String, String>>,
+    /// 델타 패치: 이전 버전 → 패치 다운로드 URL
+    #[serde(default)]
+    pub patch_from: HashMap<String, String>,
+    /// 패치 적용 후 주 바이너리의 SHA256
+    #[serde(default)]
+    pub binary_sha256: Option<String>,
+    /// 에셋 크기 (바이트, None이면 알 수 없음) — 다운로드 전 총 크기 표시용
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+}
+
+/// walk-back 탐색 시 한 번에 가져오는 릴리즈 수
+const RELEASES_PER_PAGE: u32 = 30;
+
+/// GitHub REST API 기본 베이스 URL
+pub const DEFAULT_API_BASE: &str = "https://api.github.com";
+
+/// 공개 GitHub 호스트
+pub const DEFAULT_GITHUB_HOST: &str = "github.com";
+
+/// walk-back 탐색 시 추가 페이지 요청 설정
+#[derive(Debug, Clone, Copy)]
+struct ReleasePaging {
+    per_page: u32,
+    /// 탐색할 최대 릴리즈 수 (UpdateConfig.release_scan_depth)
+    max_releases: usize,
 }
 
 /// GitHub API 클라이언트
@@ -90,6 +207,15 @@ pub struct GitHubClient {
     /// API 베이스 URL (기본: "https://api.github.com")
     /// 로컬 mock 서버 테스트 시 "http://127.0.0.1:9876" 등으로 오버라이드
     base_url: String,
+    /// 로컬 릴리즈 디렉터리 (설정되면 HTTP 대신 파일 시스템에서 읽음)
+    local_dir: Option<PathBuf>,
+    /// 릴리즈 소스 (설정되면 릴리즈 목록·manifest 조회를 위임)
+    source: Option<Arc<dyn ReleaseSource>>,
+    /// GitHub Enterprise 웹 베이스 (예: "https://ghe.example.com")
+    /// 설정되면 github.com 에셋 URL을 이 호스트로 재작성해서 다운로드
+    enterprise_web_base: Option<String>,
+    /// API 요청에 `Authorization: Bearer`로 보낼 토큰 (비공개 리포 조회용)
+    token: Option<String>,
 }
 
 impl GitHubClient {
@@ -111,29 +237,190 @@ impl GitHubClient {
             http,
             base_url: base_url
                 .filter(|s| !s.trim().is_empty())
-                .unwrap_or("https://api.github.com")
+                .unwrap_or(DEFAULT_API_BASE)
                 .trim_end_matches('/')
                 .to_string(),
+            local_dir: None,
+            source: None,
+            enterprise_web_base: None,
+            token: None,
+        }
+    }
+
+    /// API 요청에 토큰을 실어 보내는 클라이언트 (빈 토큰은 무시)
+    pub fn with_token(mut self, token: Option<String>) -> Self {
+        self.token = token.filter(|t| !t.trim().is_empty());
+        self
+    }
+
+    /// GitHub API GET 요청 (토큰이 있으면 인증 헤더 포함)
+    fn api_get(&self, url: &str) -> reqwest::RequestBuilder {
+        let request = self.http
+            .get(url)
+            .header("Accept", "application/vnd.github+json");
+        match self.token {
+            Some(ref token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    /// GitHub 호스트 기준 생성자 — Enterprise면 `https://<host>/api/v3`를 API로 사용하고
+    /// 에셋 다운로드도 같은 호스트로 보낸다. `github.com`이면 `new`와 같다.
+    pub fn with_host(owner: &str, repo: &str, host: &str) -> Self {
+        let mut client = Self::with_base_url(owner, repo, Some(&host_api_base(host)));
+        client.enterprise_web_base = enterprise_host(host).map(|h| host_web_base(&h));
+        client
+    }
+
+    /// 다운로드에 사용할 에셋 URL (Enterprise 호스트면 github.com URL을 재작성)
+    fn asset_url(&self, asset: &GitHubAsset) -> String {
+        match self.enterprise_web_base {
+            Some(ref web_base) => rewrite_asset_url(&asset.browser_download_url, web_base),
+            None => asset.browser_download_url.clone(),
+        }
+    }
+
+    /// 에셋 크기 — HEAD 요청의 `Content-Length` (서버가 알려주지 않으면 None)
+    ///
+    /// GitHub 릴리즈 에셋은 CDN으로 리다이렉트되며, 리다이렉트를 따라간 최종 응답의 크기를 쓴다.
+    pub async fn asset_size(&self, url: &str) -> Option<u64> {
+        if let Some(path) = file_url_path(url) {
+            return std::fs::metadata(path).ok().map(|m| m.len());
         }
+        let url = match self.enterprise_web_base {
+            Some(ref web_base) => rewrite_asset_url(url, web_base),
+            None => url.to_string(),
+        };
+        let response = self.http
+            .head(&url)
+            .timeout(std::time::Duration::from_secs(10))
+            .send()
+            .await
+            .ok()?;
+        if !response.status().is_success() {
+            tracing::debug!("[GitHub] HEAD {} returned {}", url, response.status());
+            return None;
+        }
+        response.headers()
+            .get(reqwest::header::CONTENT_LENGTH)?
+            .to_str().ok()?
+            .parse().ok()
+            .filter(|size| *size > 0)
+    }
+
+    /// 릴리즈 목록과 manifest 조회를 `source`에 위임하는 생성자
+    ///
+    /// walk-back 탐색 로직은 그대로 사용하고 전송 계층만 교체한다.
+    pub fn with_source(owner: &str, repo: &str, source: Arc<dyn ReleaseSource>) -> Self {
+        let mut client = Self::with_base_url(owner, repo, None);
+        client.source = Some(source);
+        client
+    }
+
+    /// 로컬 디렉터리를 릴리즈 소스로 사용하는 생성자 (오프라인/에어갭 설치용)
+    ///
+    /// `dir/manifest.json`과 같은 디렉터리의 에셋 파일들을 하나의 릴리즈로 취급한다.
+    /// 디렉터리나 manifest.json이 없으면 릴리즈가 없는 리포로 간주한다.
+    pub fn local(owner: &str, repo: &str, dir: &Path) -> Self {
+        let mut client = Self::with_base_url(owner, repo, None);
+        client.base_url = file_url(dir);
+        client.local_dir = Some(dir.to_path_buf());
+        client
+    }
+
+    /// 로컬 릴리즈 디렉터리 (HTTP 소스면 None)
+    pub fn local_dir(&self) -> Option<&Path> {
+        self.local_dir.as_deref()
+    }
+
+    /// 로컬 디렉터리를 단일 릴리즈로 변환
+    ///
+    /// 태그는 manifest.json의 `release_version`(없으면 `version`)에서 가져오고,
+    /// 디렉터리의 모든 파일을 `file://` URL 에셋으로 노출한다.
+    fn local_release(dir: &Path) -> Result<Option<GitHubRelease>> {
+        let manifest_path = dir.join("manifest.json");
+        if !manifest_path.is_file() {
+            tracing::debug!("[Updater] No manifest.json in local release dir {}", dir.display());
+            return Ok(None);
+        }
+
+        let manifest: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&manifest_path)?)?;
+        let version = match manifest.get("release_version")
+            .or_else(|| manifest.get("version"))
+            .and_then(|v| v.as_str())
+        {
+            Some(v) => v.trim_start_matches('v').to_string(),
+            None => {
+                tracing::warn!(
+                    "[Updater] Local manifest {} has no release_version — ignoring",
+                    manifest_path.display()
+                );
+                return Ok(None);
+            }
+        };
+
+        let mut assets = Vec::new();
+        for entry in std::fs::read_dir(dir)?.flatten() {
+            let path = entry.path();
+            let meta = match entry.metadata() {
+                Ok(m) if m.is_file() => m,
+                _ => continue,
+            };
+            assets.push(GitHubAsset {
+                name: entry.file_name().to_string_lossy().to_string(),
+                size: meta.len(),
+                browser_download_url: file_url(&path),
+                content_type: None,
+            });
+        }
+        assets.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(Some(GitHubRelease {
+            tag_name: format!("v{}", version),
+            name: Some(format!("Local release {}", version)),
+            body: None,
+            prerelease: false,
+            draft: false,
+            published_at: None,
+            html_url: file_url(dir),
+            assets,
+        }))
     }
 
     /// 모든 릴리스 가져오기 (최대 per_page개)
     pub async fn fetch_releases(&self, per_page: u32) -> Result<Vec<GitHubRelease>> {
+        self.fetch_releases_page(per_page, 1).await
+    }
+
+    /// 릴리스 목록의 특정 페이지 가져오기 (GitHub `page` 파라미터, 1부터 시작)
+    pub async fn fetch_releases_page(&self, per_page: u32, page: u32) -> Result<Vec<GitHubRelease>> {
+        if let Some(ref source) = self.source {
+            return source.fetch_releases(&self.repo, per_page, page).await;
+        }
+        if let Some(ref dir) = self.local_dir {
+            if page > 1 {
+                return Ok(Vec::new());
+            }
+            return Ok(Self::local_release(dir)?.into_iter().collect());
+        }
+
         let url = format!(
-            "{}/repos/{}/{}/releases?per_page={}",
-            self.base_url, self.owner, self.repo, per_page
+            "{}/repos/{}/{}/releases?per_page={}&page={}",
+            self.base_url, self.owner, self.repo, per_page, page
         );
 
-        let response = self.http
-            .get(&url)
-            .header("Accept", "application/vnd.github+json")
-            .send()
-            .await?;
+        let response = self.api_get(&url).send().await?;
 
+        // 릴리즈가 없는 리포는 빈 목록(200)을 주므로, 목록 조회의 404는
+        // 리포가 없거나 비공개라서 이 요청으로는 볼 수 없다는 뜻
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(anyhow::anyhow!(crate::UpdaterError::RepoUnavailable {
+                repo: format!("{}/{}", self.owner, self.repo),
+                authenticated: self.token.is_some(),
+            }));
+        }
         if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            anyhow::bail!("GitHub API error ({}): {}", status, body);
+            return Err(self.api_error(response).await);
         }
 
         let releases: Vec<GitHubRelease> = response.json().await?;
@@ -142,46 +429,52 @@ impl GitHubClient {
 
     /// 최신 릴리스 가져오기 (프리릴리스 제외)
     pub async fn fetch_latest_release(&self) -> Result<GitHubRelease> {
+        if let Some(ref source) = self.source {
+            return source.fetch_releases(&self.repo, RELEASES_PER_PAGE, 1).await?
+                .into_iter()
+                .find(|r| !r.draft && !r.prerelease)
+                .ok_or_else(|| anyhow::anyhow!("No release found for {}", self.repo));
+        }
+        if let Some(ref dir) = self.local_dir {
+            return Self::local_release(dir)?.ok_or_else(|| anyhow::anyhow!(
+                "No release found in local release dir {}", dir.display()
+            ));
+        }
+
         let url = format!(
             "{}/repos/{}/{}/releases/latest",
             self.base_url, self.owner, self.repo
         );
 
-        let response = self.http
-            .get(&url)
-            .header("Accept", "application/vnd.github+json")
-            .send()
-            .await?;
+        let response = self.api_get(&url).send().await?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            anyhow::bail!("GitHub API error ({}): {}", status, body);
+            return Err(self.api_error(response).await);
         }
 
         let release: GitHubRelease = response.json().await?;
         Ok(release)
     }
 
-    /// 릴리스 에셋에서 manifest.json 다운로드 및 파싱
-    pub async fn fetch_manifest(&self, release: &GitHubRelease) -> Result<ReleaseManifest> {
-        let manifest_asset = release.assets.iter()
-            .find(|a| a.name == "manifest.json")
-            .ok_or_else(|| anyhow::anyhow!(
-                "Release '{}' does not contain manifest.json", release.tag_name
-            ))?;
-
-        let response = self.http
-            .get(&manifest_asset.browser_download_url)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            anyhow::bail!("Failed to download manifest.json: {}", response.status());
+    /// 실패한 API 응답을 에러로 변환 — rate limit이면 `UpdaterError::RateLimited`
+    async fn api_error(&self, response: reqwest::Response) -> anyhow::Error {
+        let status = response.status();
+        if let Some(retry_after_secs) = rate_limit_wait(status, response.headers(), crate::unix_now_secs()) {
+            tracing::warn!(
+                "[Updater] GitHub rate limit hit for {}/{} ({}) — pausing until {}",
+                self.owner, self.repo, status,
+                crate::format_unix_timestamp(crate::unix_now_secs() + retry_after_secs)
+            );
+            return anyhow::anyhow!(crate::UpdaterError::RateLimited { retry_after_secs });
         }
+        let body = response.text().await.unwrap_or_default();
+        anyhow::anyhow!("GitHub API error ({}): {}", status, body)
+    }
 
-        let manifest: ReleaseManifest = response.json().await?;
-        Ok(manifest)
+    /// 릴리스 에셋에서 manifest.json 다운로드 및 파싱 (스키마 버전 검증 포함)
+    pub async fn fetch_manifest(&self, release: &GitHubRelease) -> Result<ReleaseManifest> {
+        let text = self.fetch_manifest_raw(release).await?;
+        ReleaseManifest::parse(&text)
     }
 
     /// 릴리스 에셋에서 manifest.json을 raw JSON 텍스트로 다운로드
@@ -193,8 +486,15 @@ impl GitHubClient {
                 "Release '{}' does not contain manifest.json", release.tag_name
             ))?;
 
+        if let Some(ref source) = self.source {
+            return source.fetch_manifest(&self.repo, release).await;
+        }
+        if let Some(path) = file_url_path(&manifest_asset.browser_download_url) {
+            return Ok(std::fs::read_to_string(path)?);
+        }
+
         let response = self.http
-            .get(&manifest_asset.browser_download_url)
+            .get(self.asset_url(manifest_asset))
             .send()
             .await?;
 
@@ -207,11 +507,17 @@ impl GitHubClient {
     }
 
     /// 에셋 바이너리 다운로드 → Vec<u8>
+    ///
+    /// 전체 본문을 메모리에 올리므로 작은 에셋 전용. 컴포넌트 zip은 `download_asset_to_file` 사용.
     pub async fn download_asset(&self, asset: &GitHubAsset) -> Result<Vec<u8>> {
         tracing::info!("Downloading asset: {} ({} bytes)", asset.name, asset.size);
 
+        if let Some(path) = file_url_path(&asset.browser_download_url) {
+            return Ok(std::fs::read(path)?);
+        }
+
         let response = self.http
-            .get(&asset.browser_download_url)
+            .get(self.asset_url(asset))
             .send()
             .await?;
 
@@ -231,8 +537,16 @@ impl GitHubClient {
     ) -> Result<()> {
         tracing::info!("Downloading {} → {}", asset.name, dest.display());
 
+        if let Some(path) = file_url_path(&asset.browser_download_url) {
+            if let Some(parent) = dest.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::copy(path, dest)?;
+            return Ok(());
+        }
+
         let response = self.http
-            .get(&asset.browser_download_url)
+            .get(self.asset_url(asset))
             .send()
             .await?;
 
@@ -240,13 +554,13 @@ impl GitHubClient {
             anyhow::bail!("Failed to download {}: {}", asset.name, response.status());
         }
 
-        let bytes = response.bytes().await?;
         if let Some(parent) = dest.parent() {
             std::fs::create_dir_all(parent)?;
         }
-        std::fs::write(dest, &bytes)?;
+        // 본문을 청크 단위로 파일에 바로 기록 — 에셋 전체를 메모리에 올리지 않음
+        let received = crate::UpdateManager::stream_to_file(response, dest, None, None).await?;
 
-        tracing::info!("Downloaded {} ({} bytes)", asset.name, bytes.len());
+        tracing::info!("Downloaded {} ({} bytes)", asset.name, received);
         Ok(())
     }
 
@@ -260,26 +574,90 @@ impl GitHubClient {
     ///
     /// ## 효율성
     /// - releases 목록은 1회 API 호출로 전부 가져옴
+    ///   (`resolve_components_with_depth`는 미해결 컴포넌트가 있을 때만 다음 페이지 요청)
     /// - manifest는 필요한 릴리즈만 선별적으로 다운로드
     /// - 이미 resolved된 컴포넌트는 건너뜀
     pub async fn resolve_components_across_releases(
         &self,
         releases: &[GitHubRelease],
-        include_prerelease: bool,
+        channel: ReleaseChannel,
     ) -> Result<(ReleaseManifest, HashMap<String, ResolvedComponent>)> {
+        let mut releases = releases.to_vec();
+        self.resolve_walk_back(&mut releases, channel, None).await
+    }
 
-        // draft 제외, prerelease 옵션 적용, 최신순 정렬된 릴리즈 필터
-        let valid_releases: Vec<&GitHubRelease> = releases.iter()
-            .filter(|r| !r.draft)
-            .filter(|r| include_prerelease || !r.prerelease)
-            .collect();
+    /// 최대 `scan_depth`개 릴리즈까지 페이지를 넘겨가며 컴포넌트 소스를 결정
+    ///
+    /// 첫 페이지로 모든 컴포넌트가 해결되면 추가 요청을 하지 않으며,
+    /// 미해결 컴포넌트가 남아 있을 때만 다음 페이지를 가져옵니다.
+    /// 반환값의 세 번째 항목은 실제로 가져온 전체 릴리즈 목록입니다.
+    pub async fn resolve_components_with_depth(
+        &self,
+        channel: ReleaseChannel,
+        scan_depth: u32,
+    ) -> Result<(ReleaseManifest, HashMap<String, ResolvedComponent>, Vec<GitHubRelease>)> {
+        self.resolve_components_paged(channel, scan_depth, RELEASES_PER_PAGE).await
+    }
+
+    pub(crate) async fn resolve_components_paged(
+        &self,
+        channel: ReleaseChannel,
+        scan_depth: u32,
+        per_page: u32,
+    ) -> Result<(ReleaseManifest, HashMap<String, ResolvedComponent>, Vec<GitHubRelease>)> {
+        let scan_depth = scan_depth.max(1);
+        let per_page = per_page.clamp(1, scan_depth);
+        let mut releases = self.fetch_releases_page(per_page, 1).await?;
+        releases.truncate(scan_depth as usize);
+
+        let paging = ReleasePaging { per_page, max_releases: scan_depth as usize };
+        let (manifest, resolved) = self
+            .resolve_walk_back(&mut releases, channel, Some(paging))
+            .await?;
+        Ok((manifest, resolved, releases))
+    }
+
+    /// walk-back 탐색 본체 — `paging`이 있으면 목록 끝에서 다음 페이지를 이어 받음
+    async fn resolve_walk_back(
+        &self,
+        releases: &mut Vec<GitHubRelease>,
+        channel: ReleaseChannel,
+        paging: Option<ReleasePaging>,
+    ) -> Result<(ReleaseManifest, HashMap<String, ResolvedComponent>)> {
+        let is_candidate = |r: &GitHubRelease| channel.accepts(r);
 
-        if valid_releases.is_empty() {
-            anyhow::bail!("No suitable releases found");
+        // draft 제외, 릴리즈 채널 필터 적용, 최신순 정렬된 릴리즈 중 첫 번째
+        let first_idx = releases.iter()
+            .position(is_candidate)
+            .ok_or_else(|| anyhow::anyhow!("No suitable releases found"))?;
+
+        // manifest.json이 없는 릴리즈는 기준으로 삼을 수 없음 — 있는 릴리즈까지 거슬러 올라감
+        // (어디에도 없으면 "업데이트 없음"으로 보이지 않도록 별도 에러)
+        let has_manifest = |r: &GitHubRelease| r.assets.iter().any(|a| a.name == "manifest.json");
+        let latest_idx = match releases.iter()
+            .enumerate()
+            .skip(first_idx)
+            .find(|(_, r)| is_candidate(r) && has_manifest(r))
+        {
+            Some((idx, _)) => idx,
+            None => {
+                let scanned_tags = releases.iter()
+                    .filter(|r| is_candidate(r))
+                    .map(|r| r.tag_name.clone())
+                    .collect();
+                return Err(anyhow::anyhow!(crate::UpdaterError::ManifestNotFound { scanned_tags }));
+            }
+        };
+        if latest_idx != first_idx {
+            tracing::warn!(
+                "[Resolver] 최신 릴리즈 {}에 manifest.json 없음, {} 기준으로 탐색",
+                releases[first_idx].tag_name, releases[latest_idx].tag_name
+            );
         }
 
         // ── 1단계: 최신 릴리즈의 manifest에서 최신 버전 맵 구축 ──
-        let latest_release = valid_releases[0];
+        let latest_release = releases[latest_idx].clone();
+        let latest_release = &latest_release;
         let latest_manifest = self.fetch_manifest(latest_release).await?;
 
         // 컴포넌트 키 → 최신 버전 (manifest 전체에서)
@@ -292,23 +670,8 @@ impl GitHubClient {
         let mut resolved: HashMap<String, ResolvedComponent> = HashMap::new();
 
         for (key, info) in &latest_manifest.components {
-            let effective_asset = if cfg!(target_os = "windows") {
-                info.asset.as_ref()
-            } else {
-                info.asset_linux.as_ref().or(info.asset.as_ref())
-            };
-            if let Some(asset_name) = effective_asset {
-                if let Some(asset) = latest_release.assets.iter().find(|a| &a.name == asset_name) {
-                    resolved.insert(key.clone(), ResolvedComponent {
-                        latest_version: info.version.clone(),
-                        source_release_tag: latest_release.tag_name.clone(),
-                        download_url: asset.browser_download_url.clone(),
-                        asset_name: asset_name.clone(),
-                        install_dir: info.install_dir.clone(),
-                        sha256: info.sha256.clone(),
-                        requires: info.requires.clone(),
-                    });
-                }
+            if let Some(rc) = resolve_in_release(info, latest_release) {
+                resolved.insert(key.clone(), rc);
             }
         }
 
@@ -324,15 +687,48 @@ impl GitHubClient {
                 unresolved_keys.len(), unresolved_keys
             );
 
-            // 이전 릴리즈를 순회하며 에셋 탐색
-            for older_release in valid_releases.iter().skip(1) {
+            // 이전 릴리즈를 순회하며 에셋 탐색 (목록이 끝나면 필요할 때만 다음 페이지)
+            let mut idx = latest_idx + 1;
+            let mut exhausted = false;
+            loop {
                 if unresolved_keys.iter().all(|k| resolved.contains_key(k)) {
                     break; // 모두 해결됨
                 }
+                if idx >= releases.len() {
+                    let Some(ref paging) = paging else { break };
+                    if exhausted
+                        || releases.len() >= paging.max_releases
+                        || !releases.len().is_multiple_of(paging.per_page as usize)
+                    {
+                        break; // 깊이 상한 도달 또는 마지막 페이지
+                    }
+                    let page = (releases.len() / paging.per_page as usize) as u32 + 1;
+                    tracing::info!("[Resolver] 미해결 컴포넌트 탐색을 위해 릴리즈 페이지 {} 요청", page);
+                    let mut next = match self.fetch_releases_page(paging.per_page, page).await {
+                        Ok(next) => next,
+                        Err(e) => {
+                            tracing::warn!("[Resolver] 릴리즈 페이지 {} 로드 실패: {}", page, e);
+                            break;
+                        }
+                    };
+                    if next.is_empty() {
+                        break;
+                    }
+                    exhausted = next.len() < paging.per_page as usize;
+                    next.truncate(paging.max_releases - releases.len());
+                    releases.extend(next);
+                    continue;
+                }
+
+                let older_release = releases[idx].clone();
+                let older_release = &older_release;
+                idx += 1;
+                if !is_candidate(older_release) {
+                    continue;
+                }
 
                 // 이 릴리즈에 manifest.json이 있는지 먼저 확인 (에셋 목록으로)
-                let has_manifest = older_release.assets.iter().any(|a| a.name == "manifest.json");
-                if !has_manifest {
+                if !has_manifest(older_release) {
                     continue;
                 }
 
@@ -357,27 +753,12 @@ impl GitHubClient {
                         let target_ver = &target_versions[key];
                         if &info.version == target_ver {
                             // 에셋이 있는지 확인 (플랫폼별 선택)
-                            let effective_asset = if cfg!(target_os = "windows") {
-                                info.asset.as_ref()
-                            } else {
-                                info.asset_linux.as_ref().or(info.asset.as_ref())
-                            };
-                            if let Some(asset_name) = effective_asset {
-                                if let Some(asset) = older_release.assets.iter().find(|a| &a.name == asset_name) {
-                                    tracing::info!(
-                                        "[Resolver] {} v{} → 릴리즈 {} 에서 발견",
-                                        key, info.version, older_release.tag_name
-                                    );
-                                    resolved.insert(key.clone(), ResolvedComponent {
-                                        latest_version: info.version.clone(),
-                                        source_release_tag: older_release.tag_name.clone(),
-                                        download_url: asset.browser_download_url.clone(),
-                                        asset_name: asset_name.clone(),
-                                        install_dir: info.install_dir.clone(),
-                                        sha256: info.sha256.clone(),
-                                        requires: info.requires.clone(),
-                                    });
-                                }
+                            if let Some(rc) = resolve_in_release(info, older_release) {
+                                tracing::info!(
+                                    "[Resolver] {} v{} → 릴리즈 {} 에서 발견",
+                                    key, info.version, older_release.tag_name
+                                );
+                                resolved.insert(key.clone(), rc);
                             }
                         }
                         // 정확히 target_ver와 일치하지 않더라도,
@@ -389,18 +770,224 @@ impl GitHubClient {
             }
 
             // 여전히 미해결 컴포넌트 로깅
-            for key in &unresolved_keys {
-                if !resolved.contains_key(key) {
-                    tracing::warn!(
-                        "[Resolver] {} v{} → 에셋을 포함한 릴리즈를 찾지 못함",
-                        key, target_versions[key]
-                    );
-                }
+            let still_unresolved: Vec<&String> = unresolved_keys.iter()
+                .filter(|k| !resolved.contains_key(*k))
+                .collect();
+            for key in &still_unresolved {
+                tracing::warn!(
+                    "[Resolver] {} v{} → 에셋을 포함한 릴리즈를 찾지 못함",
+                    key, target_versions[*key]
+                );
+            }
+            if !still_unresolved.is_empty() {
+                tracing::warn!(
+                    "[Resolver] 릴리즈 {}개 탐색 후에도 미해결: {:?}",
+                    releases.len(), still_unresolved
+                );
             }
         }
 
         Ok((latest_manifest, resolved))
     }
+
+    /// 특정 버전의 컴포넌트 에셋을 포함한 릴리즈 탐색 (롤백/특정 버전 설치용)
+    ///
+    /// 사용자가 버전을 명시했으므로 릴리즈 채널 필터는 적용하지 않고 draft만 제외한다.
+    /// 최신순으로 순회하며 manifest의 버전이 일치하고 에셋이 실제로 있는 첫 릴리즈를 반환한다.
+    pub async fn resolve_component_version(
+        &self,
+        releases: &[GitHubRelease],
+        key: &str,
+        version: &str,
+    ) -> Option<(ComponentInfo, ResolvedComponent)> {
+        let wanted = version.trim_start_matches('v');
+        for release in releases.iter().filter(|r| !r.draft) {
+            if !release.assets.iter().any(|a| a.name == "manifest.json") {
+                continue;
+            }
+            let manifest = match self.fetch_manifest(release).await {
+                Ok(m) => m,
+                Err(e) => {
+                    tracing::warn!("[Resolver] {} manifest 로드 실패: {}", release.tag_name, e);
+                    continue;
+                }
+            };
+            let Some(info) = manifest.components.get(key) else {
+                continue;
+            };
+            if info.version.trim_start_matches('v') != wanted {
+                continue;
+            }
+            if let Some(rc) = resolve_in_release(info, release) {
+                tracing::info!("[Resolver] {} v{} → 릴리즈 {} 에서 발견", key, wanted, release.tag_name);
+                return Some((info.clone(), rc));
+            }
+        }
+        None
+    }
+}
+
+impl ComponentInfo {
+    /// manifest에 선언된 에셋 중 `platform`용 선택 — `available`을 통과한 이름만 후보
+    ///
+    /// 후보 순서는 `asset_linux`(Linux에서만), `assets`, `asset`이며 같은 적합도면 앞선 것을 쓴다.
+    pub fn select_asset(&self, platform: &Platform, available: impl Fn(&str) -> bool) -> AssetMatch<&str> {
+        let linux_asset = self.asset_linux.as_deref().filter(|_| platform.os == "linux");
+        let candidates = linux_asset.into_iter()
+            .chain(self.assets.iter().map(String::as_str))
+            .chain(self.asset.as_deref())
+            .filter(|name| available(name));
+        platform.select(candidates, |name| name)
+    }
+}
+
+/// 릴리즈에 현재 플랫폼용 컴포넌트 에셋이 있으면 다운로드 소스로 변환
+fn resolve_in_release(info: &ComponentInfo, release: &GitHubRelease) -> Option<ResolvedComponent> {
+    let has_asset = |name: &str| release.assets.iter().any(|a| a.name == name);
+    let asset_name = &info.select_asset(&Platform::current(), has_asset).found()?.to_string();
+    let asset = release.assets.iter().find(|a| &a.name == asset_name)?;
+    Some(ResolvedComponent {
+        latest_version: info.version.clone(),
+        source_release_tag: release.tag_name.clone(),
+        download_url: asset.browser_download_url.clone(),
+        asset_name: asset_name.clone(),
+        install_dir: info.install_dir.clone(),
+        sha256: info.sha256.clone(),
+        mirrors: info.mirrors.clone(),
+        signature_url: signature_url(info, release, asset_name),
+        requires: info.requires.clone(),
+        patch_from: patch_urls(info, release),
+        binary_sha256: info.binary_sha256.clone(),
+        // GitHub API 에셋 목록의 크기 (로컬/주입 소스는 0일 수 있음)
+        size: info.size.or(Some(asset.size).filter(|size| *size > 0)),
+    })
+}
+
+/// 컴포넌트의 서명 파일 URL 결정
+///
+/// manifest의 `signature`가 URL이면 그대로, 에셋 이름이면 해당 릴리즈 에셋 URL,
+/// 지정이 없으면 `{asset}.sig` 에셋을 찾는다.
+fn signature_url(info: &ComponentInfo, release: &GitHubRelease, asset_name: &str) -> Option<String> {
+    let find_asset = |name: &str| {
+        release.assets.iter()
+            .find(|a| a.name == name)
+            .map(|a| a.browser_download_url.clone())
+    };
+    match info.signature.as_deref() {
+        Some(sig) if sig.starts_with("http://") || sig.starts_with("https://") || sig.starts_with("file://") => {
+            Some(sig.to_string())
+        }
+        Some(sig) => find_asset(sig),
+        None => find_asset(&format!("{}.sig", asset_name)),
+    }
+}
+
+/// 델타 패치 URL 결정 — 값이 URL이면 그대로, 에셋 이름이면 해당 릴리즈 에셋 URL
+///
+/// 릴리즈에서 찾을 수 없는 패치는 제외한다.
+fn patch_urls(info: &ComponentInfo, release: &GitHubRelease) -> HashMap<String, String> {
+    info.patch_from.iter()
+        .filter_map(|(from, patch)| {
+            let url = if patch.contains("://") {
+                Some(patch.clone())
+            } else {
+                release.assets.iter()
+                    .find(|a| &a.name == patch)
+                    .map(|a| a.browser_download_url.clone())
+            };
+            url.map(|u| (from.trim_start_matches('v').to_string(), u))
+        })
+        .collect()
+}
+
+/// 403/429 응답이 rate limit이면 다시 시도하기까지 기다릴 초를 반환
+///
+/// `Retry-After`(secondary rate limit) → 남은 요청이 0일 때 `X-RateLimit-Reset` 순으로 확인하고,
+/// 헤더 없는 429는 60초로 본다. 권한 문제 등 rate limit이 아닌 403은 None.
+pub(crate) fn rate_limit_wait(status: reqwest::StatusCode, headers: &reqwest::header::HeaderMap, now_unix: u64) -> Option<u64> {
+    if status != reqwest::StatusCode::FORBIDDEN && status != reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).map(str::trim);
+
+    if let Some(secs) = header("retry-after").and_then(|v| v.parse::<u64>().ok()) {
+        return Some(secs);
+    }
+    if header("x-ratelimit-remaining") == Some("0") {
+        if let Some(reset) = header("x-ratelimit-reset").and_then(|v| v.parse::<u64>().ok()) {
+            return Some(reset.saturating_sub(now_unix).max(1));
+        }
+    }
+    (status == reqwest::StatusCode::TOO_MANY_REQUESTS).then_some(60)
+}
+
+/// Enterprise 호스트 정규화 — 스킴/끝 슬래시를 제거하고, 비어 있거나 공개 github.com이면 None
+pub fn enterprise_host(host: &str) -> Option<String> {
+    let host = host.trim().trim_end_matches('/');
+    let bare = host.split_once("://").map_or(host, |(_, rest)| rest);
+    if bare.is_empty()
+        || bare.eq_ignore_ascii_case(DEFAULT_GITHUB_HOST)
+        || bare.eq_ignore_ascii_case("api.github.com")
+    {
+        return None;
+    }
+    Some(host.to_string())
+}
+
+/// GitHub 호스트의 웹 베이스 URL (`ghe.example.com` → `https://ghe.example.com`, 스킴이 있으면 유지)
+pub fn host_web_base(host: &str) -> String {
+    let host = host.trim().trim_end_matches('/');
+    if host.contains("://") {
+        host.to_string()
+    } else {
+        format!("https://{}", host)
+    }
+}
+
+/// GitHub 호스트의 REST API 베이스 URL
+/// (공개 github.com은 `https://api.github.com`, Enterprise는 `https://<host>/api/v3`)
+pub fn host_api_base(host: &str) -> String {
+    match enterprise_host(host) {
+        Some(host) => format!("{}/api/v3", host_web_base(&host)),
+        None => DEFAULT_API_BASE.to_string(),
+    }
+}
+
+/// 공개 github.com 에셋 URL을 `web_base` 호스트로 재작성 (다른 호스트의 URL은 그대로)
+///
+/// 매니페스트 미러나 하드코딩된 `https://github.com/{owner}/{repo}/releases/download/...` 주소를
+/// Enterprise 서버의 같은 경로로 보낸다.
+pub fn rewrite_asset_url(url: &str, web_base: &str) -> String {
+    let Some((_, rest)) = url.split_once("://") else {
+        return url.to_string();
+    };
+    let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    if authority.eq_ignore_ascii_case(DEFAULT_GITHUB_HOST) {
+        format!("{}{}", web_base.trim_end_matches('/'), path)
+    } else {
+        url.to_string()
+    }
+}
+
+/// 로컬 경로 → `file://` URL (Windows 경로는 `file:///C:/...` 형태)
+pub fn file_url(path: &Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    if path.starts_with('/') {
+        format!("file://{}", path)
+    } else {
+        format!("file:///{}", path)
+    }
+}
+
+/// `file://` URL → 로컬 경로 (다른 스킴이면 None)
+pub fn file_url_path(url: &str) -> Option<PathBuf> {
+    let rest = url.strip_prefix("file://")?;
+    // "file:///C:/..." → "C:/..."
+    let bytes = rest.as_bytes();
+    if bytes.len() >= 3 && bytes[0] == b'/' && bytes[1].is_ascii_alphabetic() && bytes[2] == b':' {
+        return Some(PathBuf::from(&rest[1..]));
+    }
+    Some(PathBuf::from(rest))
 }
 
 #[cfg(test)]
@@ -501,7 +1088,12 @@ mod tests {
             asset_name: "saba-core-windows-x64.zip".to_string(),
             install_dir: Some(".".to_string()),
             sha256: None,
+            mirrors: vec!["https://mirror.example.com/daemon.zip".to_string()],
+            signature_url: None,
             requires: None,
+            patch_from: HashMap::new(),
+            binary_sha256: None,
+            size: None,
         };
 
         let json = serde_json::to_string(&rc).unwrap();
@@ -509,5 +1101,301 @@ mod tests {
         assert_eq!(deserialized.latest_version, "0.4.0");
         assert_eq!(deserialized.source_release_tag, "v0.4.0");
         assert_eq!(deserialized.asset_name, "saba-core-windows-x64.zip");
+        assert_eq!(deserialized.mirrors.len(), 1);
+    }
+
+    fn release_with_assets(names: &[&str]) -> GitHubRelease {
+        GitHubRelease {
+            tag_name: "v0.4.0".to_string(),
+            name: None,
+            body: None,
+            prerelease: false,
+            draft: false,
+            published_at: None,
+            html_url: String::new(),
+            assets: names.iter().map(|n| GitHubAsset {
+                name: n.to_string(),
+                size: 0,
+                browser_download_url: format!("https://dl.example.com/{}", n),
+                content_type: None,
+            }).collect(),
+        }
+    }
+
+    fn info_with_signature(signature: Option<&str>) -> ComponentInfo {
+        ComponentInfo {
+            version: "0.4.0".to_string(),
+            asset: Some("core.zip".to_string()),
+            asset_linux: None,
+            assets: Vec::new(),
+            sha256: None,
+            mirrors: Vec::new(),
+            signature: signature.map(String::from),
+            install_dir: None,
+            requires: None,
+            patch_from: HashMap::new(),
+            binary_sha256: None,
+            size: None,
+        }
+    }
+
+    #[test]
+    fn component_asset_selected_for_platform() {
+        let linux = Platform { os: "linux", arch: "x86_64" };
+        let windows = Platform { os: "windows", arch: "x86_64" };
+        let macos = Platform { os: "macos", arch: "aarch64" };
+
+        // 기존 asset/asset_linux 구성: asset_linux는 Linux에서만 후보
+        let mut info = info_with_signature(None);
+        info.asset = Some("saba-core-windows-x64.zip".to_string());
+        assert_eq!(info.select_asset(&linux, |_| true), AssetMatch::WrongPlatform);
+        assert_eq!(info.select_asset(&windows, |_| true), AssetMatch::Found("saba-core-windows-x64.zip"));
+        info.asset_linux = Some("saba-core-linux-x64.tar.gz".to_string());
+        assert_eq!(info.select_asset(&linux, |_| true), AssetMatch::Found("saba-core-linux-x64.tar.gz"));
+        assert_eq!(info.select_asset(&macos, |_| true), AssetMatch::WrongPlatform);
+
+        // assets 목록에서 가장 구체적인 것 — 릴리즈에 없는 이름은 후보 아님
+        info.assets = vec!["saba-core-macos-arm64.zip".to_string(), "saba-core-macos.zip".to_string()];
+        assert_eq!(info.select_asset(&macos, |_| true), AssetMatch::Found("saba-core-macos-arm64.zip"));
+        assert_eq!(
+            info.select_asset(&macos, |n| n != "saba-core-macos-arm64.zip"),
+            AssetMatch::Found("saba-core-macos.zip")
+        );
+        assert_eq!(info.select_asset(&macos, |_| false), AssetMatch::Missing);
+
+        // 이 플랫폼용 에셋이 없으면 릴리즈에서 해석하지 않음
+        let release = release_with_assets(&["saba-core-windows-x64.zip"]);
+        let mut windows_only = info_with_signature(None);
+        windows_only.asset = Some("saba-core-windows-x64.zip".to_string());
+        assert_eq!(
+            resolve_in_release(&windows_only, &release).is_some(),
+            cfg!(target_os = "windows") && cfg!(target_arch = "x86_64")
+        );
+    }
+
+    #[test]
+    fn patch_url_resolution() {
+        let release = release_with_assets(&["core.zip", "core-0.3.0.patch"]);
+        let mut info = info_with_signature(None);
+        info.patch_from.insert("v0.3.0".to_string(), "core-0.3.0.patch".to_string());
+        info.patch_from.insert("0.2.0".to_string(), "https://cdn.example.com/core-0.2.0.patch".to_string());
+        info.patch_from.insert("0.1.0".to_string(), "missing.patch".to_string());
+
+        let urls = patch_urls(&info, &release);
+        assert_eq!(urls.len(), 2, "릴리즈에 없는 패치 에셋은 제외");
+        assert_eq!(urls["0.3.0"], "https://dl.example.com/core-0.3.0.patch");
+        assert_eq!(urls["0.2.0"], "https://cdn.example.com/core-0.2.0.patch");
+    }
+
+    #[test]
+    fn signature_url_resolution() {
+        let release = release_with_assets(&["core.zip", "core.zip.sig", "custom.sig"]);
+
+        // 미지정 → {asset}.sig 에셋
+        assert_eq!(
+            signature_url(&info_with_signature(None), &release, "core.zip").as_deref(),
+            Some("https://dl.example.com/core.zip.sig")
+        );
+        // 에셋 이름 지정
+        assert_eq!(
+            signature_url(&info_with_signature(Some("custom.sig")), &release, "core.zip").as_deref(),
+            Some("https://dl.example.com/custom.sig")
+        );
+        // 외부 URL 지정
+        assert_eq!(
+            signature_url(&info_with_signature(Some("https://sig.example.com/core.sig")), &release, "core.zip").as_deref(),
+            Some("https://sig.example.com/core.sig")
+        );
+        // 서명 에셋 없음
+        let unsigned = release_with_assets(&["core.zip"]);
+        assert!(signature_url(&info_with_signature(None), &unsigned, "core.zip").is_none());
+    }
+
+    #[test]
+    fn parse_manifest_mirrors() {
+        let json = r#"{
+            "release_version": "0.2.0",
+            "components": {
+                "saba-core": {
+                    "version": "0.2.0",
+                    "asset": "saba-core-windows-x64.zip",
+                    "sha256": null,
+                    "install_dir": ".",
+                    "mirrors": ["https://mirror.example.com/saba-core-windows-x64.zip"]
+                },
+                "cli": {
+                    "version": "0.2.0",
+                    "asset": "saba-cli-windows-x64.zip",
+                    "sha256": null,
+                    "install_dir": null
+                }
+            }
+        }"#;
+
+        let manifest: ReleaseManifest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            manifest.components["saba-core"].mirrors,
+            vec!["https://mirror.example.com/saba-core-windows-x64.zip".to_string()]
+        );
+        // mirrors 필드가 없으면 빈 목록
+        assert!(manifest.components["cli"].mirrors.is_empty());
+    }
+
+    #[test]
+    fn parse_manifest_checks_schema_version() {
+        // 스키마 버전이 없으면 1로 취급
+        let manifest = ReleaseManifest::parse(r#"{"release_version": "1.0.0", "components": {}}"#).unwrap();
+        assert_eq!(manifest.manifest_schema_version, 1);
+
+        let newer = format!(
+            r#"{{"manifest_schema_version": {}, "release_version": "9.0.0", "components": {{}}}}"#,
+            SUPPORTED_MANIFEST_SCHEMA_VERSION + 1
+        );
+        let err = ReleaseManifest::parse(&newer).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<crate::UpdaterError>(),
+            Some(crate::UpdaterError::UnsupportedManifest { .. })
+        ));
+        assert!(err.to_string().contains("Updater too old"), "{}", err);
+    }
+
+    #[test]
+    fn file_url_roundtrip() {
+        assert_eq!(file_url(Path::new("/srv/releases/a.zip")), "file:///srv/releases/a.zip");
+        assert_eq!(file_url_path("file:///srv/releases/a.zip"), Some(PathBuf::from("/srv/releases/a.zip")));
+        assert_eq!(file_url(Path::new("C:\\releases\\a.zip")), "file:///C:/releases/a.zip");
+        assert_eq!(file_url_path("file:///C:/releases/a.zip"), Some(PathBuf::from("C:/releases/a.zip")));
+        assert_eq!(file_url_path("https://example.com/a.zip"), None);
+    }
+
+    #[tokio::test]
+    async fn local_release_dir_resolves_components() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("manifest.json"), r#"{
+            "release_version": "0.4.0",
+            "components": {
+                "saba-core": { "version": "0.4.0", "asset": "saba-core.zip", "install_dir": "." },
+                "gui": { "version": "0.4.0" }
+            }
+        }"#).unwrap();
+        std::fs::write(dir.path().join("saba-core.zip"), b"zip").unwrap();
+
+        let client = GitHubClient::local("owner", "saba-chan", dir.path());
+        let (manifest, resolved, releases) = client
+            .resolve_components_with_depth(ReleaseChannel::Stable, 30)
+            .await
+            .unwrap();
+
+        assert_eq!(manifest.release_version, "0.4.0");
+        assert_eq!(releases.len(), 1);
+        assert_eq!(releases[0].tag_name, "v0.4.0");
+        let core = &resolved["saba-core"];
+        assert_eq!(core.download_url, file_url(&dir.path().join("saba-core.zip")));
+        // 에셋이 없는 컴포넌트는 로컬 소스에서 해결되지 않음
+        assert!(!resolved.contains_key("gui"));
+
+        // 두 번째 페이지는 없음
+        assert!(client.fetch_releases_page(30, 2).await.unwrap().is_empty());
+
+        // manifest.json이 없는 디렉터리는 릴리즈 없음
+        let empty = GitHubClient::local("owner", "saba-chan-modules", &dir.path().join("missing"));
+        assert!(empty.fetch_releases(5).await.unwrap().is_empty());
+        assert!(empty.fetch_latest_release().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn missing_manifest_walks_back_or_fails_distinctly() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifest_path = dir.path().join("manifest.json");
+        std::fs::write(&manifest_path, r#"{
+            "release_version": "0.3.0",
+            "components": { "saba-core": { "version": "0.3.0", "asset": "saba-core.zip" } }
+        }"#).unwrap();
+
+        let tagged = |tag: &str, names: &[&str]| GitHubRelease { tag_name: tag.to_string(), ..release_with_assets(names) };
+        let client = GitHubClient::new("owner", "saba-chan");
+
+        // 최신 릴리즈에 manifest.json이 없으면 이전 릴리즈의 manifest 기준
+        let mut older = tagged("v0.3.0", &["manifest.json", "saba-core.zip"]);
+        older.assets[0].browser_download_url = file_url(&manifest_path);
+        let releases = vec![tagged("v0.4.0", &["saba-core.zip"]), older];
+        let (manifest, resolved) = client
+            .resolve_components_across_releases(&releases, ReleaseChannel::Stable)
+            .await
+            .unwrap();
+        assert_eq!(manifest.release_version, "0.3.0");
+        assert_eq!(resolved["saba-core"].source_release_tag, "v0.3.0");
+
+        // 어느 릴리즈에도 없으면 "업데이트 없음"이 아니라 별도 에러
+        let releases = vec![tagged("v0.4.0", &["saba-core.zip"]), tagged("v0.3.0", &["saba-core.zip"])];
+        let err = client
+            .resolve_components_across_releases(&releases, ReleaseChannel::Stable)
+            .await
+            .unwrap_err();
+        match crate::UpdaterError::from(err) {
+            crate::UpdaterError::ManifestNotFound { scanned_tags } => {
+                assert_eq!(scanned_tags, vec!["v0.4.0".to_string(), "v0.3.0".to_string()]);
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn enterprise_host_builds_api_and_asset_urls() {
+        assert_eq!(enterprise_host(""), None);
+        assert_eq!(enterprise_host("github.com"), None);
+        assert_eq!(enterprise_host("https://GitHub.com/"), None);
+        assert_eq!(enterprise_host("ghe.example.com/").as_deref(), Some("ghe.example.com"));
+
+        assert_eq!(host_api_base("github.com"), DEFAULT_API_BASE);
+        assert_eq!(host_api_base("ghe.example.com"), "https://ghe.example.com/api/v3");
+        assert_eq!(host_api_base("http://ghe.local:8080/"), "http://ghe.local:8080/api/v3");
+
+        let client = GitHubClient::with_host("corp", "saba-chan", "ghe.example.com");
+        assert_eq!(client.base_url, "https://ghe.example.com/api/v3");
+        let public = GitHubClient::with_host("corp", "saba-chan", "github.com");
+        assert_eq!(public.base_url, DEFAULT_API_BASE);
+        assert!(public.enterprise_web_base.is_none());
+
+        let asset = GitHubAsset {
+            name: "saba-core.zip".into(),
+            size: 1,
+            browser_download_url: "https://github.com/corp/saba-chan/releases/download/v1.0.0/saba-core.zip".into(),
+            content_type: None,
+        };
+        assert_eq!(
+            client.asset_url(&asset),
+            "https://ghe.example.com/corp/saba-chan/releases/download/v1.0.0/saba-core.zip"
+        );
+        assert_eq!(public.asset_url(&asset), asset.browser_download_url);
+        // 다른 호스트(CDN 미러 등)는 그대로
+        assert_eq!(
+            rewrite_asset_url("https://dl.example.com/saba-core.zip", "https://ghe.example.com"),
+            "https://dl.example.com/saba-core.zip"
+        );
+    }
+
+    #[test]
+    fn rate_limit_wait_reads_retry_after_and_reset() {
+        use reqwest::header::{HeaderMap, HeaderValue};
+        use reqwest::StatusCode;
+
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", HeaderValue::from_static("120"));
+        assert_eq!(rate_limit_wait(StatusCode::FORBIDDEN, &headers, 1_000), Some(120));
+        assert_eq!(rate_limit_wait(StatusCode::NOT_FOUND, &headers, 1_000), None);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("1300"));
+        assert_eq!(rate_limit_wait(StatusCode::FORBIDDEN, &headers, 1_000), Some(300));
+        // 이미 지난 reset 시각이어도 최소 1초는 대기
+        assert_eq!(rate_limit_wait(StatusCode::FORBIDDEN, &headers, 2_000), Some(1));
+
+        // 한도가 남아 있는 403은 권한 문제 — rate limit 아님
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("42"));
+        assert_eq!(rate_limit_wait(StatusCode::FORBIDDEN, &headers, 1_000), None);
+        assert_eq!(rate_limit_wait(StatusCode::TOO_MANY_REQUESTS, &HeaderMap::new(), 1_000), Some(60));
     }
 }
@@ -127,7 +127,7 @@ impl IntegrityChecker {
         }
 
         // 정렬: 코어 컴포넌트 → 모듈 → 익스텐션 순
-        components.sort_by(|a, b| component_sort_key(&a.component).cmp(&component_sort_key(&b.component)));
+        components.sort_by_key(|c| component_sort_key(&c.component));
 
         let total = components.len();
         let verified = components.iter().filter(|c| c.status == IntegrityStatus::Verified).count();
@@ -294,6 +294,13 @@ impl IntegrityChecker {
 // SHA256 계산
 // ══════════════════════════════════════════════════════
 
+/// 메모리 상 데이터의 SHA256 해시를 계산합니다.
+pub fn compute_sha256_bytes(data: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hex_encode(&hasher.finalize())
+}
+
 /// 파일의 SHA256 해시를 계산합니다.
 pub fn compute_sha256(path: &Path) -> Result<String, String> {
     use std::io::BufReader;
@@ -456,6 +463,45 @@ fn hex_encode(bytes: &[u8]) -> String {
     bytes.iter().map(|b| format!("{:02x}", b)).collect()
 }
 
+fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    let s = s.trim();
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| s.get(i..i + 2).and_then(|b| u8::from_str_radix(b, 16).ok()))
+        .collect()
+}
+
+// ══════════════════════════════════════════════════════
+// 서명 검증 (ed25519)
+// ══════════════════════════════════════════════════════
+
+/// ed25519 서명 검증
+///
+/// - `pubkey_hex`: 32바이트 공개키의 hex 문자열
+/// - `signature`: 64바이트 원시 서명, 또는 그 hex 텍스트 (`.sig` 파일 내용)
+pub fn verify_ed25519_signature(pubkey_hex: &str, message: &[u8], signature: &[u8]) -> Result<(), String> {
+    let pubkey = hex_decode(pubkey_hex)
+        .filter(|k| k.len() == 32)
+        .ok_or("공개키 형식 오류 (32바이트 hex 필요)")?;
+
+    let sig = if signature.len() == 64 {
+        signature.to_vec()
+    } else {
+        std::str::from_utf8(signature)
+            .ok()
+            .and_then(hex_decode)
+            .filter(|s| s.len() == 64)
+            .ok_or("서명 형식 오류 (64바이트 원시 또는 hex 필요)")?
+    };
+
+    ring::signature::UnparsedPublicKey::new(&ring::signature::ED25519, &pubkey)
+        .verify(message, &sig)
+        .map_err(|_| "서명이 일치하지 않음".to_string())
+}
+
 // ══════════════════════════════════════════════════════
 // 해시 정보 수집용 구조체
 // ══════════════════════════════════════════════════════
@@ -793,6 +839,38 @@ mod tests {
     use super::*;
     use std::io::Write;
 
+    fn test_keypair() -> ring::signature::Ed25519KeyPair {
+        let rng = ring::rand::SystemRandom::new();
+        let pkcs8 = ring::signature::Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+        ring::signature::Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap()
+    }
+
+    #[test]
+    fn test_verify_ed25519_signature() {
+        use ring::signature::KeyPair;
+        let key = test_keypair();
+        let pubkey = hex_encode(key.public_key().as_ref());
+        let sig = key.sign(b"release asset");
+
+        // 원시 64바이트 서명과 hex 텍스트 서명 모두 허용
+        assert!(verify_ed25519_signature(&pubkey, b"release asset", sig.as_ref()).is_ok());
+        let hex_sig = format!("{}\n", hex_encode(sig.as_ref()));
+        assert!(verify_ed25519_signature(&pubkey, b"release asset", hex_sig.as_bytes()).is_ok());
+
+        // 변조된 데이터는 거부
+        assert!(verify_ed25519_signature(&pubkey, b"tampered asset", sig.as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_verify_ed25519_rejects_malformed_input() {
+        use ring::signature::KeyPair;
+        let key = test_keypair();
+        let pubkey = hex_encode(key.public_key().as_ref());
+
+        assert!(verify_ed25519_signature("zz", b"data", &[0u8; 64]).is_err());
+        assert!(verify_ed25519_signature(&pubkey, b"data", b"not-a-signature").is_err());
+    }
+
     #[test]
     fn test_sha256_empty() {
         // SHA-256 of empty input = e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855
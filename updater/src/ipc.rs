@@ -256,6 +256,9 @@ pub struct UpdateCompletionMarker {
     pub updated_components: Vec<String>,
     pub success: bool,
     pub message: Option<String>,
+    /// 적용 종료 코드 (`ApplyExitCode::code`, 0/10/20/30)
+    #[serde(default)]
+    pub exit_code: i32,
 }
 
 impl UpdateCompletionMarker {
@@ -265,6 +268,7 @@ impl UpdateCompletionMarker {
             updated_components: components,
             success: true,
             message: None,
+            exit_code: crate::ApplyExitCode::Success.code(),
         }
     }
 
@@ -274,6 +278,7 @@ impl UpdateCompletionMarker {
             updated_components: Vec::new(),
             success: false,
             message: Some(message),
+            exit_code: crate::ApplyExitCode::PartialFailure.code(),
         }
     }
 
@@ -15,6 +15,8 @@
 //! - **큐(queue.rs)**: 백그라운드 다운로드 요청 순차 처리, 우선도 조절
 //! - **에러(error.rs)**: 네트워크 끊김, 타임아웃 등 장애 처리
 //! - **IPC(ipc.rs)**: GUI/CLI ↔ 데몬 메시지 통신
+//! - **델타(delta.rs)**: 큰 바이너리용 bsdiff 패치 적용
+//! - **소스(source.rs)**: 릴리즈 목록·manifest·에셋 전송 계층 (GitHub / 로컬 디렉터리)
 //!
 //! ## 디렉터리 구조
 //! 컴포넌트를 로컬에서 검색하고 버전을 판정하며,
@@ -40,13 +42,23 @@
 // ══════════════════════════════════════════════════════╁E
 
 pub mod constants;
+pub mod delta;
 pub mod error;
 pub mod foreground;
 pub mod github;
 pub mod integrity;
 pub mod ipc;
+pub mod lock;
+#[cfg(any(target_os = "linux", test))]
+mod linux_appimage;
+#[cfg(any(target_os = "macos", test))]
+mod macos_bundle;
+pub mod notify;
+pub mod platform;
 pub mod queue;
 pub mod scheduler;
+pub mod shadow;
+pub mod source;
 pub mod version;
 pub mod worker;
 
@@ -54,23 +66,43 @@ pub mod worker;
 mod tests;
 
 // Re-exports for convenience
-pub use error::{UpdaterError, RecoveryStrategy, NetworkChecker, ErrorContext};
-pub use foreground::{ForegroundApplier, SelfUpdater, ProcessChecker, ApplyPhase, ApplyProgress, ApplyPreparation};
-pub use github::{ResolvedComponent, ReleaseManifest, ComponentInfo, GitHubRelease};
+pub use error::{UpdaterError, UpdaterResult, ErrorPayload, RecoveryStrategy, NetworkChecker, ErrorContext};
+pub use foreground::{ForegroundApplier, SelfUpdater, ProcessChecker, ApplyPhase, ApplyProgress, ApplyPreparation, ApplyModeConfig, RelaunchOptions, parse_apply_args, relaunch_args, relaunch_command};
+pub use github::{ResolvedComponent, ReleaseManifest, ComponentInfo, GitHubRelease, ReleaseChannel};
 pub use integrity::{IntegrityChecker, IntegrityReport, IntegrityStatus, OverallIntegrity, ComponentIntegrity, ComponentHashInfo};
+pub use notify::{UpdateNotice, UpdateAvailableHook};
+pub use platform::{AssetMatch, Platform};
 pub use ipc::{DaemonIpcClient, StateFile, UpdateCompletionMarker, UpdateSummary, UpdaterCommand, UpdaterResponse};
-pub use queue::{DownloadQueue, DownloadRequest, DownloadResult, QueueStatus};
-pub use worker::{BackgroundWorker, BackgroundTask, WorkerEvent, WorkerStatus, AutoCheckScheduler};
+pub use queue::{DownloadQueue, DownloadRequest, DownloadResult, QueueHandle, QueueStatus};
+pub use shadow::ShadowInstall;
+pub use source::{ReleaseSource, GitHubSource, LocalDirSource};
+pub use worker::{
+    BackgroundWorker, BackgroundTask, WorkerEvent, WorkerStatus, AutoCheckScheduler, Quarantine,
+    QUARANTINE_BACKOFF, QUARANTINE_THRESHOLD,
+};
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex as StdMutex};
 
 use github::{GitHubClient};
 use version::SemVer;
 
+/// 다운로드 재시도 횟수 (첫 시도 포함 최대 3회)
+const DOWNLOAD_MAX_RETRIES: u32 = 2;
+
+/// 초기 설치 시 동시에 진행할 최대 다운로드 수
+const INSTALL_DOWNLOAD_CONCURRENCY: usize = 3;
+
+/// 바이너리 교체 전 대상 프로세스 종료를 기다리는 최대 시간 (초)
+const PROCESS_EXIT_TIMEOUT_SECS: u64 = 15;
+
+/// 업데이트 체크 전 릴리즈 서버 연결 확인 제한 시간
+const REACHABILITY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+
 // ══════════════════════════════════════════════════════
 // 다운로드 진행률
 // ══════════════════════════════════════════════════════
@@ -88,6 +120,43 @@ pub struct DownloadProgress {
     pub active: bool,
 }
 
+/// 토큰 버킷 방식의 다운로드 대역폭 제한기
+///
+/// 초당 `max_kbps` KB만큼 토큰이 채워지며 (버킷 크기는 1초 분량),
+/// 토큰이 모자라면 부족분이 채워질 때까지 대기한다.
+pub(crate) struct DownloadThrottle {
+    bytes_per_sec: f64,
+    tokens: f64,
+    last_refill: tokio::time::Instant,
+}
+
+impl DownloadThrottle {
+    /// 제한값이 없거나 0이면 None (제한 없음)
+    pub(crate) fn new(max_kbps: Option<u32>) -> Option<Self> {
+        let kbps = max_kbps.filter(|k| *k > 0)?;
+        let bytes_per_sec = kbps as f64 * 1024.0;
+        Some(Self {
+            bytes_per_sec,
+            tokens: bytes_per_sec,
+            last_refill: tokio::time::Instant::now(),
+        })
+    }
+
+    /// `bytes`만큼 토큰을 소비하고, 제한을 넘으면 필요한 만큼 대기
+    pub(crate) async fn consume(&mut self, bytes: usize) {
+        let now = tokio::time::Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.bytes_per_sec).min(self.bytes_per_sec);
+        self.last_refill = now;
+
+        self.tokens -= bytes as f64;
+        if self.tokens < 0.0 {
+            let wait = std::time::Duration::from_secs_f64(-self.tokens / self.bytes_per_sec);
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
 // ══════════════════════════════════════════════════════
 // 컴포넌트 정의
 // ══════════════════════════════════════════════════════
@@ -121,6 +190,11 @@ impl Component {
         }
     }
 
+    /// 적용 시 사바쨩 프로세스(GUI/데몬/업데이터) 재시작이 필요한 컴포넌트인지
+    pub fn requires_restart(&self) -> bool {
+        matches!(self, Component::Gui | Component::Updater | Component::CoreDaemon)
+    }
+
     /// manifest 키로부터 Component 생성 (알 수 없는 키는 None)
     pub fn try_from_manifest_key(key: &str) -> Option<Self> {
         match key {
@@ -177,6 +251,50 @@ pub struct ComponentVersion {
     pub downloaded_path: Option<String>,
     /// 해당 컴포넌트가 설치되어 있는지 여부 (false면 미설치 상태)
     pub installed: bool,
+    /// 확인 중 발생한 경고 (예: `version_unreadable` — 설치되어 있으나 로컬 버전을 읽을 수 없음)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+    /// 최신 버전이 요구하는 다른 컴포넌트의 최소 버전 (manifest `requires`)
+    /// pending 매니페스트에 함께 저장되어 별도 적용 프로세스에서도 의존성을 확인할 수 있다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requires: Option<HashMap<String, String>>,
+}
+
+/// 설치되어 있으나 로컬 버전을 읽거나 해석할 수 없는 컴포넌트의 경고 코드
+pub const VERSION_UNREADABLE: &str = "version_unreadable";
+
+/// `UpdateConfig.pinned`로 고정되어 최신 버전이 억제된 컴포넌트의 경고 코드
+pub const PINNED: &str = "pinned";
+
+/// 모듈/익스텐션 리포를 찾을 수 없거나 비공개라 확인하지 못한 컴포넌트의 경고 코드
+/// (토큰 없이 404면 `github_token` 설정, 토큰이 있으면 권한 확인이 필요)
+pub const REPO_UNAVAILABLE: &str = "repo_unavailable";
+
+/// 새 버전은 있지만 현재 OS/아키텍처용 에셋이 없는 컴포넌트의 경고 코드
+/// (다른 플랫폼용 에셋을 잘못 내려받지 않도록 다운로드 소스를 비워 둔다)
+pub const NO_ASSET_FOR_PLATFORM: &str = "no_asset_for_platform";
+
+/// 로컬 버전 판정 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum LocalVersion {
+    /// 디스크에 설치 파일이 없음
+    Missing,
+    /// 설치되어 있으나 버전을 읽을 수 없거나 SemVer로 해석할 수 없음
+    Unreadable,
+    /// 해석 가능한 버전
+    Known(String),
+}
+
+impl LocalVersion {
+    pub(crate) fn classify(installed: bool, current: &str) -> Self {
+        if SemVer::parse(current).is_some() {
+            LocalVersion::Known(current.to_string())
+        } else if installed {
+            LocalVersion::Unreadable
+        } else {
+            LocalVersion::Missing
+        }
+    }
 }
 
 /// 전체 업데이트/설치 상태 정보
@@ -190,7 +308,7 @@ pub struct UpdateStatus {
 }
 
 /// 설치 진행 상태 추적
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InstallProgress {
     /// 전체 설치가 완료되었는지 여부
     pub complete: bool,
@@ -202,10 +320,51 @@ pub struct InstallProgress {
     pub done: usize,
     /// 설치 완료된 컴포넌트 목록
     pub installed_components: Vec<String>,
+    /// 기존 설치본을 최신 버전으로 갱신한 컴포넌트 목록
+    #[serde(default)]
+    pub updated_components: Vec<String>,
+    /// 이미 설치되어 있어 건너뛴 컴포넌트 목록
+    #[serde(default)]
+    pub skipped_components: Vec<String>,
+    /// 요청하지 않았지만 다른 컴포넌트의 `requires`를 충족하기 위해 자동으로 추가된 컴포넌트 목록
+    #[serde(default)]
+    pub dependency_components: Vec<String>,
+    /// 컴포넌트별 다운로드 진행률 (0~100, 표시 이름 기준)
+    #[serde(default)]
+    pub download_percent: HashMap<String, u8>,
+    /// 사용자가 설치를 취소했는지 여부
+    #[serde(default)]
+    pub cancelled: bool,
     /// 에러 발생 내용
     pub errors: Vec<String>,
 }
 
+/// 설치 계획 — `fresh_install` / `install_or_update`가 처리할 대상
+#[derive(Debug, Clone, Default)]
+pub(crate) struct InstallPlan {
+    /// (manifest 키, 컴포넌트 정보, 기존 설치본 갱신 여부)
+    pub targets: Vec<(String, github::ComponentInfo, bool)>,
+    /// 이미 설치되어 건너뛰는 컴포넌트 (표시 이름)
+    pub skipped: Vec<String>,
+    /// `requires`를 충족하기 위해 자동 추가된 컴포넌트 (manifest 키)
+    pub dependencies: Vec<String>,
+    /// 이 설치로 충족할 수 없는 의존성 (사람이 읽을 수 있는 메시지)
+    pub unmet: Vec<String>,
+}
+
+/// `modules/*/module.toml`에서 읽은 설치된 모듈 정보 (`scan_modules` 결과)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ModuleEntry {
+    /// 모듈 이름 (`[module].name`, 없으면 최상위 `name`)
+    pub name: String,
+    /// 설치된 버전 (`[module].version`, 없으면 최상위 `version`)
+    pub version: Option<String>,
+    /// 업데이트 리포 (`[update].github_repo`)
+    pub repo: Option<String>,
+    /// 릴리즈 에셋 이름 패턴 (`[update].asset_pattern`, `{name}`/`{version}`/`{os}`/`{arch}`)
+    pub asset_pattern: Option<String>,
+}
+
 /// 버전 의존성 확인 결과
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DependencyCheck {
@@ -230,6 +389,11 @@ pub struct DependencyIssue {
     pub message: String,
 }
 
+/// 버전이 `>=x.y.z` 형식(접두사 생략 가능)의 최소 버전 요구를 충족하는지 여부
+fn version_satisfies(version: Option<&str>, requirement: &str) -> bool {
+    version.and_then(SemVer::parse).is_some_and(|v| v.satisfies(requirement))
+}
+
 
 /// 전체 의존성 확인 결과 (초기 설치 시 활용)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -267,7 +431,10 @@ pub struct ApplyComponentResult {
     pub success: bool,
     /// 결과 메시지
     pub message: String,
-    /// 업데이트 적용을 위해 중단한 프로세스 목록 (데몬 IPC 경유 적용 시)
+    /// 적용 전에 종료를 기다린 프로세스 목록 (예: "saba-core.exe")
+    ///
+    /// 시간 내에 종료되지 않은 프로세스가 있으면 적용 자체가 실패하므로,
+    /// 여기에 담긴 프로세스는 모두 제한 시간 안에 종료된 것이다.
     pub stopped_processes: Vec<String>,
     /// 재시작 필요 여부
     pub restart_needed: bool,
@@ -278,10 +445,236 @@ pub struct ApplyComponentResult {
 pub struct ApplyResult {
     /// 개별 컴포넌트 적용 결과
     pub results: Vec<ApplyComponentResult>,
-    /// 적용 후 재시작이 필요한 컴포넌트 목록 (CoreDaemon 업데이트 시 필수)
+    /// CoreDaemon이 교체된 경우 재시작할 데몬 실행파일 경로 (None이면 데몬 재시작 불필요)
     pub daemon_restart_script: Option<String>,
     /// GUI/CLI 자신의 업데이트가 포함 — 별도 self-update flow가 필요 (self-update flow)
     pub self_update_components: Vec<String>,
+    /// 의존성이 충족되지 않아 적용하지 않은 컴포넌트 (`force` 미지정 시)
+    #[serde(default)]
+    pub blocked: Vec<DependencyCheck>,
+}
+
+impl ApplyResult {
+    /// 재시작이 필요한 컴포넌트가 하나라도 있는지
+    pub fn restart_needed(&self) -> bool {
+        self.results.iter().any(|r| r.restart_needed)
+    }
+
+    /// 적용 결과에 대응하는 종료 코드
+    pub fn exit_code(&self) -> ApplyExitCode {
+        let failed = self.results.iter().filter(|r| !r.success).count() + self.blocked.len();
+        ApplyExitCode::from_outcome(failed, self.restart_needed())
+    }
+
+    /// 적용 성공한 컴포넌트의 표시 이름 목록 (기존 `Vec<String>` 반환 호환용)
+    pub fn applied_names(&self) -> Vec<String> {
+        self.results.iter()
+            .filter(|r| r.success)
+            .map(|r| Component::from_manifest_key(&r.component).display_name())
+            .collect()
+    }
+}
+
+/// 업데이트 적용 종료 코드 — 업데이터 `--apply` 프로세스 종료 코드와 JSON `exit_code` 필드에 사용
+///
+/// | 코드 | 의미 |
+/// |------|------|
+/// | `0`  | 적용 완료 또는 적용할 항목 없음 |
+/// | `10` | 적용 완료, 재시작 필요 |
+/// | `20` | 일부(또는 전체) 컴포넌트 적용 실패 |
+/// | `30` | 네트워크 오류 |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApplyExitCode {
+    Success,
+    RestartRequired,
+    PartialFailure,
+    NetworkError,
+}
+
+impl ApplyExitCode {
+    /// 프로세스 종료 코드 값
+    pub fn code(self) -> i32 {
+        match self {
+            ApplyExitCode::Success => 0,
+            ApplyExitCode::RestartRequired => 10,
+            ApplyExitCode::PartialFailure => 20,
+            ApplyExitCode::NetworkError => 30,
+        }
+    }
+
+    /// 실패 개수와 재시작 필요 여부로 종료 코드 결정 (실패가 재시작보다 우선)
+    pub fn from_outcome(failed: usize, restart_needed: bool) -> Self {
+        if failed > 0 {
+            ApplyExitCode::PartialFailure
+        } else if restart_needed {
+            ApplyExitCode::RestartRequired
+        } else {
+            ApplyExitCode::Success
+        }
+    }
+
+    /// 적용 중단 에러의 종료 코드 — 원인이 네트워크 오류면 `NetworkError`
+    pub fn from_error(err: &anyhow::Error) -> Self {
+        let is_network = err.chain().any(|cause| {
+            cause.downcast_ref::<reqwest::Error>().is_some()
+                || cause.downcast_ref::<UpdaterError>()
+                    .is_some_and(|e| Self::from_updater_error(e) == ApplyExitCode::NetworkError)
+        });
+        if is_network {
+            ApplyExitCode::NetworkError
+        } else {
+            ApplyExitCode::PartialFailure
+        }
+    }
+
+    /// `UpdateManager` 공개 API 에러의 종료 코드
+    pub fn from_updater_error(err: &UpdaterError) -> Self {
+        match err {
+            UpdaterError::NetworkError { .. }
+            | UpdaterError::Timeout { .. }
+            | UpdaterError::Offline { .. }
+            | UpdaterError::RateLimited { .. }
+            | UpdaterError::CorruptDownload { .. }
+            | UpdaterError::DownloadInterrupted { .. } => ApplyExitCode::NetworkError,
+            _ => ApplyExitCode::PartialFailure,
+        }
+    }
+}
+
+/// installed-manifest와 실제 설치 상태 사이의 불일치 (`verify_installed` 결과)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum VerifyIssue {
+    /// 매니페스트에 기록되어 있지만 디스크에서 파일/디렉터리를 찾을 수 없음
+    Missing,
+    /// 디스크에서 감지한 버전이 매니페스트 기록과 다름
+    VersionMismatch { recorded: String, detected: String },
+    /// 업데이터가 알지 못하는 매니페스트 키
+    UnknownComponent,
+}
+
+/// 다운로드 완료 후 적용 대기 중인 컴포넌트 (CLI `update pending` 출력용)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingUpdate {
+    /// 컴포넌트 manifest 키
+    pub component: String,
+    pub display_name: String,
+    /// 적용될 버전
+    pub version: Option<String>,
+    /// 스테이징된 파일 경로
+    pub staged_path: String,
+    /// 적용 시 프로세스 재시작 필요 여부
+    pub restart_required: bool,
+}
+
+/// `pending.json` 항목 — 스테이징 파일의 SHA256을 함께 기록해 `--apply` 시점에 다시 검증
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PendingEntry {
+    #[serde(flatten)]
+    component: ComponentVersion,
+    /// 다운로드 시점의 스테이징 파일 SHA256 (None이면 이전 버전이 저장한 매니페스트)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    staged_sha256: Option<String>,
+}
+
+/// 단일 컴포넌트 상태 요약 — 릴리즈 노트/다운로드 URL을 뺀 `ComponentVersion` (GUI 개별 갱신용)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentSummary {
+    /// 컴포넌트 manifest 키
+    pub component: String,
+    pub display_name: String,
+    pub current_version: String,
+    pub latest_version: Option<String>,
+    pub update_available: bool,
+    pub downloaded: bool,
+    pub installed: bool,
+    /// 경고 코드 (`pinned`, `repo_unavailable`, `no_asset_for_platform` 등)
+    pub warning: Option<String>,
+}
+
+impl From<&ComponentVersion> for ComponentSummary {
+    fn from(c: &ComponentVersion) -> Self {
+        Self {
+            component: c.component.manifest_key(),
+            display_name: c.component.display_name(),
+            current_version: c.current_version.clone(),
+            latest_version: c.latest_version.clone(),
+            update_available: c.update_available,
+            downloaded: c.downloaded,
+            installed: c.installed,
+            warning: c.warning.clone(),
+        }
+    }
+}
+
+/// 업데이트 사전 점검 보고서 — `plan_update` 결과 (다운로드 전 확인 화면용)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdatePlan {
+    /// 새로 다운로드할 컴포넌트
+    pub downloads: Vec<PlannedDownload>,
+    /// 다운로드하지 않는 컴포넌트와 이유
+    pub skipped: Vec<PlannedSkip>,
+    /// 설치된 버전으로 충족되지 않는 `requires` — 이번 업데이트로 충족되는지 함께 표시
+    pub dependencies: Vec<PlannedDependency>,
+    /// 적용 후 재시작이 필요한 컴포넌트 (manifest 키, 이미 다운로드된 대기분 포함)
+    pub restart_required: Vec<String>,
+    /// 크기를 확인한 다운로드의 합계 (바이트)
+    pub total_download_bytes: u64,
+    /// 크기를 확인하지 못한 다운로드가 있는지 (`total_download_bytes`가 하한값)
+    pub size_incomplete: bool,
+}
+
+/// 계획된 개별 다운로드
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlannedDownload {
+    /// 컴포넌트 manifest 키
+    pub component: String,
+    pub display_name: String,
+    pub current_version: String,
+    pub latest_version: String,
+    pub asset_name: String,
+    /// HEAD 요청의 `Content-Length` (None이면 알 수 없음)
+    pub size_bytes: Option<u64>,
+}
+
+/// 다운로드하지 않는 컴포넌트
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlannedSkip {
+    /// 컴포넌트 manifest 키
+    pub component: String,
+    pub display_name: String,
+    /// `up_to_date`, `downloaded`(적용 대기 중), `no_source` 또는 경고 코드 (`pinned` 등)
+    pub reason: String,
+}
+
+/// 계획에 포함된 컴포넌트의 의존성 조치
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlannedDependency {
+    /// 의존하는 컴포넌트 키
+    pub component: String,
+    /// 필요한 컴포넌트 키
+    pub required_component: String,
+    /// 필요한 최소 버전 (예: ">=0.3.0")
+    pub required_version: String,
+    /// 현재 설치된 버전 (None이면 미설치)
+    pub installed_version: Option<String>,
+    /// 이번 업데이트에 필요한 버전이 함께 포함되는지 (false면 적용이 차단됨)
+    pub satisfied_by_plan: bool,
+}
+
+/// 업데이트 대기 중인 컴포넌트의 릴리즈 노트 ("새로운 기능" 패널용)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangelogEntry {
+    /// 컴포넌트 manifest 키
+    pub component: String,
+    pub display_name: String,
+    /// 업데이트될 버전
+    pub version: Option<String>,
+    pub notes: String,
+    /// 같은 릴리즈 노트를 공유하는 다른 컴포넌트 (코어 릴리즈의 cli/gui 등, manifest 키)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shared_with: Vec<String>,
 }
 
 /// GUI/CLI 자신의 업데이트 정보 (업데이터 실행파일이 컴포넌트를 교체한 후 재시작하는 프로세스)
@@ -312,13 +705,110 @@ pub struct UpdateConfig {
     /// GitHub 레포지토리 이름
     pub github_repo: String,
     /// 프리릴리즈 버전을 포함할지 여부
+    ///
+    /// Deprecated: `channel`을 사용할 것. `true`이고 `channel`이 기본값(stable)이면 `edge`로 취급한다.
     pub include_prerelease: bool,
+    /// 업데이트 릴리즈 채널 (stable / beta / edge)
+    #[serde(default)]
+    pub channel: ReleaseChannel,
     /// 스테이징 디렉터리 (다운로드와 임시 파일 저장, 기본값: 실행파일 경로 기준)
     pub install_root: Option<String>,
     /// API 리다이렉트 URL 오버라이드 (테스트용 로컬 서버 지원,
     /// 예: "http://127.0.0.1:9876" 처럼 GitHub API 대신 사용할 URL 설정)
     #[serde(default)]
     pub api_base_url: Option<String>,
+    /// 리포별 API URL 오버라이드 (키: 리포 이름, 예: "saba-chan-modules")
+    /// 지정된 리포만 mock 서버로 보내고 나머지는 `api_base_url`/GitHub을 사용
+    #[serde(default)]
+    pub api_base_overrides: HashMap<String, String>,
+    /// GitHub Enterprise 호스트 (예: "ghe.example.com", None이면 공개 github.com)
+    /// 설정되면 API는 `https://<host>/api/v3`, 에셋 다운로드도 같은 호스트를 사용한다.
+    /// `api_base_url`/`api_base_overrides`가 지정된 리포는 그쪽이 우선.
+    #[serde(default)]
+    pub github_host: Option<String>,
+    /// GitHub API 토큰 (비공개 코어/모듈/익스텐션 리포 조회용)
+    /// None이면 `SABA_GITHUB_TOKEN` 환경변수를 사용한다. 설정 조회 응답에 노출되지 않도록 직렬화하지 않음.
+    #[serde(default, skip_serializing)]
+    pub github_token: Option<String>,
+    /// 릴리즈 서명 검증용 ed25519 공개키 (hex) — 빌드에 포함된 키보다 우선
+    /// 키가 있으면 서명이 없는 에셋은 거부된다 (fail closed)
+    #[serde(default)]
+    pub trusted_pubkey: Option<String>,
+    /// 코어 리포 walk-back 탐색 시 확인할 최대 릴리즈 수 (기본 30)
+    /// 미해결 컴포넌트가 있을 때만 GitHub 페이지를 넘겨가며 이 수까지 탐색한다
+    #[serde(default = "default_release_scan_depth")]
+    pub release_scan_depth: u32,
+    /// 백그라운드 다운로드 대역폭 제한 (KB/s, None이면 무제한)
+    /// `BackgroundWorker`가 수행하는 다운로드에만 적용되며, 사용자가 직접 실행한
+    /// 다운로드와 설치(fresh install)에는 적용되지 않는다.
+    #[serde(default)]
+    pub max_download_kbps: Option<u32>,
+    /// 로컬 릴리즈 디렉터리 (오프라인/에어갭 설치용)
+    /// 설정되면 GitHub 대신 `manifest.json`과 에셋 zip이 들어있는 이 디렉터리를 사용한다.
+    /// 모듈/익스텐션 리포는 `{dir}/{repo}/` 하위 디렉터리에서 찾는다.
+    /// `api_base_url`에 `file://` URL을 지정해도 같은 효과.
+    #[serde(default)]
+    pub local_release_dir: Option<String>,
+    /// 새 업데이트 알림 웹훅 URL (Discord/Slack 등)
+    /// 새로 발견된 컴포넌트 버전마다 한 번 `UpdateNotice` JSON을 POST한다.
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+    /// 버전 고정 (manifest 키 → 정확한 버전)
+    /// 고정된 컴포넌트는 최신 버전이 고정 버전과 같을 때만 업데이트로 표시되고 적용된다.
+    #[serde(default)]
+    pub pinned: HashMap<String, String>,
+    /// 스테이징 정리 기준 (일 단위, 기본 7, 0이면 정리하지 않음)
+    /// 이보다 오래되고 `pending.json`이 참조하지 않는 스테이징 파일은 시작 시 삭제된다.
+    #[serde(default = "default_staging_max_age_days")]
+    pub staging_max_age_days: u32,
+    /// 모듈 디렉터리 오버라이드 (상대경로는 install_root 기준)
+    /// 설정되면 호출자가 넘긴 modules_dir 대신 사용한다 — 포터블 설치용.
+    #[serde(default)]
+    pub modules_dir: Option<String>,
+    /// 익스텐션 디렉터리 오버라이드 (상대경로는 install_root 기준)
+    /// 설정되면 `SABA_EXTENSIONS_DIR`/%APPDATA% 대신 사용한다.
+    #[serde(default)]
+    pub extensions_dir: Option<String>,
+    /// 업데이터 실행파일 경로 오버라이드 (상대경로는 install_root 기준)
+    /// 설정되면 기본 후보 경로를 탐색하지 않으며, 파일이 없으면 `UpdaterNotFound`.
+    #[serde(default)]
+    pub updater_path: Option<String>,
+}
+
+fn default_release_scan_depth() -> u32 {
+    30
+}
+
+fn default_staging_max_age_days() -> u32 {
+    7
+}
+
+impl UpdateConfig {
+    /// 실제로 적용할 릴리즈 채널 (`include_prerelease` 하위 호환 반영)
+    pub fn effective_channel(&self) -> ReleaseChannel {
+        if self.include_prerelease && self.channel == ReleaseChannel::Stable {
+            ReleaseChannel::Edge
+        } else {
+            self.channel
+        }
+    }
+
+    /// 실제로 사용할 GitHub 토큰 (`github_token` → `SABA_GITHUB_TOKEN`, 빈 값은 무시)
+    pub fn effective_github_token(&self) -> Option<String> {
+        self.github_token.clone()
+            .or_else(|| std::env::var("SABA_GITHUB_TOKEN").ok())
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+    }
+
+    /// 고정 설정이 컴포넌트를 `version`으로 옮기는 것을 허용하는지 여부
+    /// (고정되지 않았거나 고정 버전과 같으면 true, `v` 접두사는 무시)
+    pub fn pin_allows(&self, component: &Component, version: &str) -> bool {
+        match self.pinned.get(&component.manifest_key()) {
+            Some(pin) => pin.trim_start_matches('v') == version.trim_start_matches('v'),
+            None => true,
+        }
+    }
 }
 
 impl Default for UpdateConfig {
@@ -331,8 +821,22 @@ impl Default for UpdateConfig {
             github_owner: crate::constants::GITHUB_OWNER.to_string(),
             github_repo: crate::constants::GITHUB_REPO.to_string(),
             include_prerelease: false,
+            channel: ReleaseChannel::Stable,
             install_root: None,
             api_base_url: None,
+            api_base_overrides: HashMap::new(),
+            github_host: None,
+            github_token: None,
+            trusted_pubkey: None,
+            release_scan_depth: default_release_scan_depth(),
+            max_download_kbps: None,
+            local_release_dir: None,
+            webhook_url: None,
+            pinned: HashMap::new(),
+            staging_max_age_days: default_staging_max_age_days(),
+            modules_dir: None,
+            extensions_dir: None,
+            updater_path: None,
         }
     }
 }
@@ -341,6 +845,18 @@ impl Default for UpdateConfig {
 // UpdateManager
 // ══════════════════════════════════════════════════════
 
+/// 마지막으로 성공한 업데이트 체크 기록 (`{staging}/check-state.json`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CheckSchedule {
+    last_check: String,
+    next_check: String,
+    /// 다음 체크 예정 시각 (UNIX 초) — 재시작 후 남은 대기 시간 계산용
+    next_check_unix: u64,
+    /// 이미 알린 업데이트 (컴포넌트 키 → 버전)
+    #[serde(default)]
+    announced: HashMap<String, String>,
+}
+
 /// 업데이트 확인/다운로드 및 적용을 관리하는 업데이트/초기 설치 매니저
 pub struct UpdateManager {
     pub config: UpdateConfig,
@@ -363,10 +879,239 @@ pub struct UpdateManager {
     /// 릴리즈 횡단 탐색 결과: 각 컴포넌트별 최적 다운로드 소스
     /// key = manifest key ("saba-core", "cli", "gui", ...)
     resolved_components: HashMap<String, ResolvedComponent>,
-    /// 설치 진행 상태
-    install_progress: Option<InstallProgress>,
+    /// 릴리즈 목록·manifest·에셋을 가져오는 전송 계층 (설정에서 생성, 테스트에서 교체 가능)
+    source: Arc<dyn ReleaseSource>,
+    /// 설치 진행 상태 (Arc로 공유 — 설치 중에도 Manager 잠금 없이 폴링 가능)
+    pub install_progress: Arc<StdMutex<Option<InstallProgress>>>,
+    /// 설치 취소 요청 플래그 (Arc로 공유 — Manager 잠금 없이 취소 가능)
+    pub install_cancel: Arc<AtomicBool>,
     /// 다운로드 진행 상태 (Arc로 공유 — Manager 잠금 없이 폴링 가능)
     pub download_progress: Arc<StdMutex<DownloadProgress>>,
+    /// 적용 작업 진행 플래그 (`ApplyLease`가 살아 있는 동안 true)
+    apply_in_progress: Arc<AtomicBool>,
+    /// 다음 자동 체크까지의 지연 (지터 적용 — `status.next_check`와 같은 시각)
+    next_check_delay: Option<std::time::Duration>,
+    /// 이미 알린 업데이트 버전 (check-state.json에 함께 저장)
+    announced_versions: HashMap<String, String>,
+    /// 스테이징 파일 경로 → 다운로드 검증 때 계산한 SHA256 (pending.json 기록용)
+    staged_sha256: HashMap<String, String>,
+    /// 새 업데이트 발견 시 호출되는 콜백
+    on_update_available: Option<notify::UpdateAvailableHook>,
+}
+
+/// 적용 작업 독점권 — drop 시 (성공/실패 경로 모두) 진행 플래그와 프로세스 잠금을 해제한다
+#[derive(Debug)]
+pub struct ApplyLease {
+    flag: Arc<AtomicBool>,
+    _process_lock: lock::ProcessLock,
+}
+
+impl Drop for ApplyLease {
+    fn drop(&mut self) {
+        self.flag.store(false, Ordering::SeqCst);
+    }
+}
+
+/// 버전 지정 설치 작업 — `prepare_component_version`에서 해석한 릴리즈와 적용 독점권을 들고 있어
+/// 다운로드는 Manager 잠금 없이 `download`로 진행하고, 결과 반영만 `finish_component_version`에서 한다
+pub struct ComponentVersionJob {
+    component: Component,
+    version: String,
+    info: ComponentInfo,
+    rc: ResolvedComponent,
+    staged_path: PathBuf,
+    source: Arc<dyn ReleaseSource>,
+    trusted_pubkey: Option<String>,
+    _lease: ApplyLease,
+}
+
+impl ComponentVersionJob {
+    /// 해석된 에셋을 스테이징 경로로 다운로드 (Manager 없이 실행)
+    pub async fn download(&self) -> UpdaterResult<()> {
+        tracing::info!(
+            "[Installer] Downloading {} v{} from release {}",
+            self.component.manifest_key(), self.version, self.rc.source_release_tag
+        );
+        UpdateManager::download_resolved(
+            self.source.as_ref(), &self.rc, &self.staged_path, None, self.trusted_pubkey.as_deref(), None,
+        )
+        .await
+        .map(|_| ())
+        .map_err(UpdaterError::from)
+    }
+}
+
+/// 일괄 설치 작업 — `prepare_install`이 계획과 공유 핸들(진행률·취소 플래그)을 담아 만들고,
+/// `run`이 Manager 없이 다운로드·압축 해제를 진행한다
+pub struct InstallJob {
+    targets: Vec<InstallTarget>,
+    total: usize,
+    skipped: Vec<String>,
+    dependency_components: Vec<String>,
+    errors: Vec<String>,
+    source: Arc<dyn ReleaseSource>,
+    trusted_pubkey: Option<String>,
+    progress: Arc<StdMutex<Option<InstallProgress>>>,
+    cancel: Arc<AtomicBool>,
+    /// 설치 대상이 있을 때만 적용 독점권을 쥔다
+    _lease: Option<ApplyLease>,
+}
+
+/// 다운로드 소스와 설치 위치가 정해진 설치 대상
+struct InstallTarget {
+    key: String,
+    is_update: bool,
+    rc: ResolvedComponent,
+    staged_path: PathBuf,
+    install_dir: PathBuf,
+}
+
+impl InstallJob {
+    /// 계획된 대상을 내려받아 설치 — 개별 컴포넌트 실패는 결과의 `errors`에 기록
+    pub async fn run(self) -> InstallProgress {
+        let InstallJob {
+            targets, total, skipped, dependency_components, mut errors,
+            source, trusted_pubkey, progress: slot, cancel, _lease,
+        } = self;
+
+        let mut installed = Vec::new();
+        let mut updated = Vec::new();
+        let mut not_installed = 0usize;
+
+        // 컴포넌트별 다운로드 진행률 — 주기적으로 InstallProgress.download_percent에 반영
+        let job_progress: Vec<(String, Arc<StdMutex<DownloadProgress>>)> = targets.iter()
+            .map(|target| {
+                let label = Component::from_manifest_key(&target.key).display_name();
+                (label, Arc::new(StdMutex::new(DownloadProgress::default())))
+            })
+            .collect();
+
+        // 다운로드는 최대 INSTALL_DOWNLOAD_CONCURRENCY개 병렬로,
+        // 압축 해제는 디스크 경합을 피하기 위해 다운로드가 끝난 순서대로 하나씩 진행.
+        // 취소 플래그는 각 다운로드 시작 전과 각 압축 해제 전에 확인
+        use futures_util::StreamExt;
+        let progress_handles: Vec<_> = job_progress.iter().map(|(_, p)| p.clone()).collect();
+        let mut downloads = futures_util::stream::iter(
+            targets.into_iter().zip(progress_handles).map(|(target, progress)| {
+                let trusted_pubkey = trusted_pubkey.clone();
+                let cancel = cancel.clone();
+                let source = source.clone();
+                async move {
+                    if cancel.load(Ordering::SeqCst) {
+                        return (target, None);
+                    }
+                    tracing::info!(
+                        "[Installer] Downloading {} v{} ← release {}",
+                        target.key, target.rc.latest_version, target.rc.source_release_tag
+                    );
+                    let result = UpdateManager::download_resolved(
+                        source.as_ref(), &target.rc, &target.staged_path, Some(&progress),
+                        trusted_pubkey.as_deref(), None,
+                    ).await;
+                    (target, Some(result))
+                }
+            }),
+        )
+        .buffer_unordered(INSTALL_DOWNLOAD_CONCURRENCY);
+
+        let mut ticker = tokio::time::interval(std::time::Duration::from_millis(250));
+        loop {
+            let next = tokio::select! {
+                next = downloads.next() => next,
+                _ = ticker.tick() => {
+                    UpdateManager::sync_download_percent(&slot, &job_progress);
+                    continue;
+                }
+            };
+            let Some((InstallTarget { key, is_update, rc, staged_path, install_dir }, result)) = next else {
+                break;
+            };
+            UpdateManager::sync_download_percent(&slot, &job_progress);
+
+            let comp_label = Component::from_manifest_key(&key).display_name();
+
+            let result = match result {
+                Some(result) if !cancel.load(Ordering::SeqCst) => result,
+                _ => {
+                    // 취소됨 — 받아둔 파일만 정리하고 설치하지 않음
+                    std::fs::remove_file(&staged_path).ok();
+                    not_installed += 1;
+                    continue;
+                }
+            };
+
+            if let Err(e) = result {
+                let err = format!("Download failed for {}: {}", comp_label, e);
+                tracing::error!("[Installer] {}", err);
+                UpdateManager::update_install_progress(&slot, |prog| prog.errors.push(err.clone()));
+                errors.push(err);
+                continue;
+            }
+
+            UpdateManager::update_install_progress(&slot, |prog| {
+                prog.current_component = Some(comp_label.clone());
+            });
+
+            // 압축 해제 (갱신이면 기존 설치 위에 덮어씀)
+            if let Err(e) = UpdateManager::extract_to_directory(&staged_path, &install_dir).await {
+                let err = format!("Extraction failed for {}: {}", comp_label, e);
+                tracing::error!("[Installer] {}", err);
+                std::fs::remove_file(&staged_path).ok();
+                UpdateManager::update_install_progress(&slot, |prog| prog.errors.push(err.clone()));
+                errors.push(err);
+                continue;
+            }
+
+            std::fs::remove_file(&staged_path).ok();
+
+            let dir_path = install_dir.to_string_lossy();
+            if is_update {
+                if let Err(e) = UpdateManager::update_installed_version(&key, &rc.latest_version) {
+                    tracing::warn!("[Installer] Failed to record version for {}: {}", key, e);
+                }
+                updated.push(comp_label.clone());
+                tracing::info!("[Installer] {} updated to v{} in {}", comp_label, rc.latest_version, dir_path);
+            } else {
+                installed.push(comp_label.clone());
+                tracing::info!("[Installer] {} installed to {}", comp_label, dir_path);
+            }
+
+            UpdateManager::update_install_progress(&slot, |prog| {
+                prog.done = installed.len() + updated.len();
+                prog.installed_components = installed.clone();
+                prog.updated_components = updated.clone();
+            });
+        }
+
+        let cancelled = cancel.swap(false, Ordering::SeqCst);
+        if cancelled {
+            let note = format!(
+                "Installation cancelled by user — {} component(s) were not installed",
+                not_installed
+            );
+            tracing::warn!("[Installer] {}", note);
+            errors.push(note);
+        }
+
+        let download_percent = slot.lock().ok()
+            .and_then(|p| p.as_ref().map(|p| p.download_percent.clone()))
+            .unwrap_or_default();
+        let progress = InstallProgress {
+            complete: true,
+            current_component: None,
+            total,
+            done: installed.len() + updated.len(),
+            installed_components: installed,
+            updated_components: updated,
+            skipped_components: skipped,
+            dependency_components,
+            download_percent,
+            cancelled,
+            errors,
+        };
+        UpdateManager::set_install_progress(&slot, progress.clone());
+        progress
+    }
 }
 
 impl UpdateManager {
@@ -384,10 +1129,14 @@ impl UpdateManager {
                     .unwrap_or_else(|| PathBuf::from("."))
             });
 
-        // extensions_dir: %APPDATA%/saba-chan/extensions 고정 경로
-        let extensions_dir = Self::resolve_extensions_dir();
+        // modules_dir/extensions_dir: config 오버라이드 우선, 없으면 호출자 경로 / %APPDATA%/saba-chan/extensions
+        let modules_dir_path = config.modules_dir.as_deref()
+            .map(|dir| install_root.join(dir))
+            .unwrap_or_else(|| PathBuf::from(modules_dir));
+        let extensions_dir = config.extensions_dir.as_deref()
+            .map(|dir| install_root.join(dir))
+            .unwrap_or_else(Self::resolve_extensions_dir);
 
-        let modules_dir_path = PathBuf::from(modules_dir);
         if !modules_dir_path.exists() {
             let _ = std::fs::create_dir_all(&modules_dir_path);
         }
@@ -395,7 +1144,9 @@ impl UpdateManager {
             let _ = std::fs::create_dir_all(&extensions_dir);
         }
 
-        Self {
+        let source = source::from_config(&config);
+
+        let manager = Self {
             config,
             status: UpdateStatus {
                 last_check: None,
@@ -404,7 +1155,7 @@ impl UpdateManager {
                 checking: false,
                 error: None,
             },
-            modules_dir: PathBuf::from(modules_dir),
+            modules_dir: modules_dir_path,
             extensions_dir,
             staging_dir,
             install_root,
@@ -412,9 +1163,23 @@ impl UpdateManager {
             cached_manifest: None,
             cached_releases: Vec::new(),
             resolved_components: HashMap::new(),
-            install_progress: None,
+            source,
+            install_progress: Arc::new(StdMutex::new(None)),
+            install_cancel: Arc::new(AtomicBool::new(false)),
             download_progress: Arc::new(StdMutex::new(DownloadProgress::default())),
+            apply_in_progress: Arc::new(AtomicBool::new(false)),
+            next_check_delay: None,
+            announced_versions: HashMap::new(),
+            staged_sha256: HashMap::new(),
+            on_update_available: None,
+        };
+
+        // 중단된 다운로드 등 버려진 스테이징 파일 정리
+        if let Err(e) = manager.prune_staging() {
+            tracing::warn!("[UpdateManager] Failed to prune staging dir: {}", e);
         }
+
+        manager
     }
 
     fn resolve_staging_dir() -> PathBuf {
@@ -451,13 +1216,32 @@ impl UpdateManager {
         self.status.clone()
     }
 
+    /// 현재 업데이트 상태를 복제하지 않고 참조로 반환
+    ///
+    /// 매니저 잠금을 잡은 채 일부 필드만 읽는 경로에서 사용 — 전체 컴포넌트 목록(릴리즈 노트 포함)을
+    /// 복제하지 않아 잠금 보유 시간이 짧아진다.
+    pub fn status(&self) -> &UpdateStatus {
+        &self.status
+    }
+
     /// GitHub API 클라이언트를 생성 (api_base_url 오버라이드 지원)
     fn create_client(&self) -> GitHubClient {
-        GitHubClient::with_base_url(
-            &self.config.github_owner,
-            &self.config.github_repo,
-            self.config.api_base_url.as_deref(),
-        )
+        self.client_for_repo(&self.config.github_repo)
+    }
+
+    /// 리포별 GitHub API 클라이언트 생성
+    ///
+    /// 릴리즈 목록·manifest 조회는 매니저의 `ReleaseSource`에 위임된다
+    /// (로컬 릴리즈 디렉터리 또는 `api_base_overrides[repo]` → `api_base_url` → GitHub).
+    fn client_for_repo(&self, repo: &str) -> GitHubClient {
+        GitHubClient::with_source(&self.config.github_owner, repo, self.source.clone())
+    }
+
+    /// 릴리즈 소스 교체 (테스트용 메모리 소스 주입 등)
+    ///
+    /// 이후 `update_config`를 호출하면 설정 기반 소스로 다시 생성된다.
+    pub fn set_release_source(&mut self, source: Arc<dyn ReleaseSource>) {
+        self.source = source;
     }
 
     /// 현재 설정 반환
@@ -475,6 +1259,13 @@ impl UpdateManager {
                 self.install_root = new_root;
             }
         }
+        if let Some(ref dir) = new_config.modules_dir {
+            self.modules_dir = self.install_root.join(dir);
+        }
+        if let Some(ref dir) = new_config.extensions_dir {
+            self.extensions_dir = self.install_root.join(dir);
+        }
+        self.source = source::from_config(&new_config);
         self.config = new_config;
     }
 
@@ -500,7 +1291,7 @@ impl UpdateManager {
             let releases = client.fetch_releases(5).await?;
             let latest = releases.iter()
                 .filter(|r| !r.draft)
-                .find(|r| self.config.include_prerelease || !r.prerelease)
+                .find(|r| self.config.effective_channel().accepts(r))
                 .ok_or_else(|| anyhow::anyhow!("코어 릴리즈를 찾을 수 없습니다"))?
                 .clone();
             let m = client.fetch_manifest(&latest).await?;
@@ -543,16 +1334,12 @@ impl UpdateManager {
 
     /// 지정된 리포의 최신 릴리즈에서 manifest.json 에셋을 raw JSON으로 다운로드합니다.
     async fn fetch_repo_manifest(&self, repo_name: &str) -> Result<String> {
-        let client = GitHubClient::with_base_url(
-            &self.config.github_owner,
-            repo_name,
-            self.config.api_base_url.as_deref(),
-        );
+        let client = self.client_for_repo(repo_name);
 
         let releases = client.fetch_releases(3).await?;
         let latest = releases.iter()
             .filter(|r| !r.draft)
-            .find(|r| self.config.include_prerelease || !r.prerelease)
+            .find(|r| self.config.effective_channel().accepts(r))
             .ok_or_else(|| anyhow::anyhow!("{} 릴리즈를 찾을 수 없습니다", repo_name))?;
 
         client.fetch_manifest_raw(latest).await
@@ -570,9 +1357,49 @@ impl UpdateManager {
     /// 1. 최신 릴리즈의 manifest.json → 최신 버전 확인
     /// 2. 에셋이 없는 컴포넌트 → 이전 릴리즈 순회하며 탐색
     /// 3. 각 컴포넌트별로 실제 에셋이 존재하는 릴리즈 기록 (`resolved_components`)
-    pub async fn check_for_updates(&mut self) -> Result<UpdateStatus> {
+    pub async fn check_for_updates(&mut self) -> UpdaterResult<UpdateStatus> {
+        self.check_for_updates_impl().await.map_err(UpdaterError::from)
+    }
+
+    /// 코어 리포 릴리즈 소스에 연결 가능한지 빠르게 확인 (최대 `REACHABILITY_TIMEOUT`)
+    ///
+    /// 로컬 디렉터리처럼 네트워크가 필요 없는 소스는 항상 true.
+    async fn is_source_reachable(&self) -> bool {
+        match self.source.probe_endpoint(&self.config.github_repo) {
+            Some(endpoint) => NetworkChecker::with_endpoints(vec![endpoint], REACHABILITY_TIMEOUT)
+                .is_reachable()
+                .await,
+            None => true,
+        }
+    }
+
+    /// GitHub 리포 설정 확인 — 비어 있으면 `UpdaterError::ConfigError`
+    fn ensure_repo_configured(&self) -> Result<()> {
         if self.config.github_owner.is_empty() || self.config.github_repo.is_empty() {
-            anyhow::bail!("GitHub owner/repo not configured");
+            return Err(anyhow::anyhow!(UpdaterError::ConfigError {
+                message: "GitHub owner/repo not configured".to_string(),
+            }));
+        }
+        Ok(())
+    }
+
+    async fn check_for_updates_impl(&mut self) -> Result<UpdateStatus> {
+        self.ensure_repo_configured()?;
+
+        // 오프라인이면 GitHub 호출(DNS 타임아웃)까지 가지 않고 바로 실패 — 스테이징된 업데이트는 복원
+        if !self.is_source_reachable().await {
+            let staged_updates = if self.staging_dir.join("pending.json").exists() {
+                self.load_pending_manifest().unwrap_or_else(|e| {
+                    tracing::warn!("[Updater] Failed to load pending manifest while offline: {}", e);
+                    0
+                })
+            } else {
+                0
+            };
+            tracing::warn!("[Updater] Update server unreachable — skipping check ({} staged updates)", staged_updates);
+            self.status.checking = false;
+            self.status.error = Some("Offline: update server unreachable".to_string());
+            return Err(anyhow::anyhow!(UpdaterError::Offline { staged_updates }));
         }
 
         self.status.checking = true;
@@ -591,23 +1418,31 @@ impl UpdateManager {
                 tracing::error!("[Updater] Core repo check failed: {}", e);
                 self.status.checking = false;
                 self.status.error = Some(format!("Core repo check failed: {}", e));
+                // rate limit이면 한도가 풀리는 시각으로 다음 체크를 미룸
+                if let Some(UpdaterError::RateLimited { retry_after_secs }) =
+                    e.chain().find_map(|c| c.downcast_ref::<UpdaterError>())
+                {
+                    self.schedule_next_check(std::time::Duration::from_secs(*retry_after_secs));
+                }
                 return Err(e);
             }
         }
 
         // ══ 2. 모듈 리포 개별 체크 ══
         let module_repos = self.discover_module_repos();
-        for (module_name, module_repo) in &module_repos {
-            let module_client = GitHubClient::with_base_url(
-                &self.config.github_owner,
-                module_repo,
-                self.config.api_base_url.as_deref(),
-            );
-            match self.check_module_repo(&module_client, module_name, &local_versions).await {
+        for module in &module_repos {
+            let Some(module_repo) = module.repo.as_deref() else { continue };
+            let module_name = &module.name;
+            let module_client = self.client_for_repo(module_repo);
+            match self.check_module_repo(&module_client, module, &local_versions).await {
                 Ok(Some(cv)) => components.push(cv),
                 Ok(None) => {}
                 Err(e) => {
                     tracing::warn!("[Updater] Module '{}' check failed: {}", module_name, e);
+                    if Self::is_repo_unavailable(&e) {
+                        let component = Component::Module(module_name.clone());
+                        components.push(self.repo_unavailable_status(component, &local_versions));
+                    }
                 }
             }
         }
@@ -615,64 +1450,86 @@ impl UpdateManager {
         // ══ 3. 익스텐션 리포 개별 체크 ══
         let ext_repos = self.discover_extension_repos();
         for (ext_name, ext_repo) in &ext_repos {
-            let ext_client = GitHubClient::with_base_url(
-                &self.config.github_owner,
-                ext_repo,
-                self.config.api_base_url.as_deref(),
-            );
+            let ext_client = self.client_for_repo(ext_repo);
             match self.check_extension_repo(&ext_client, ext_name, &local_versions).await {
                 Ok(Some(cv)) => components.push(cv),
                 Ok(None) => {}
                 Err(e) => {
                     tracing::warn!("[Updater] Extension '{}' check failed: {}", ext_name, e);
+                    if Self::is_repo_unavailable(&e) {
+                        let component = Component::Extension(ext_name.clone());
+                        components.push(self.repo_unavailable_status(component, &local_versions));
+                    }
                 }
             }
         }
 
-        // 타임스탬프 갱신
+        // 타임스탬프 갱신 — 다음 체크는 간격에 지터를 적용한 시각
         let now = chrono_now_iso();
-        let next = chrono_add_hours_iso(&now, self.config.check_interval_hours);
+        let delay = scheduler::jittered_interval(
+            std::time::Duration::from_secs(self.config.check_interval_hours as u64 * 3600),
+        );
 
         self.status = UpdateStatus {
             last_check: Some(now),
-            next_check: Some(next),
+            next_check: None,
             components,
             checking: false,
             error: None,
         };
+        self.announce_new_updates().await;
+        self.schedule_next_check(delay);
+        if let Err(e) = self.save_check_schedule() {
+            tracing::warn!("[UpdateManager] Failed to persist check schedule: {}", e);
+        }
+
+        // 교체가 검증된 바이너리의 .old 백업 정리 (이전 업데이트의 잔여물)
+        self.cleanup_stale_backups();
 
         Ok(self.status.clone())
     }
 
-    /// 코어 리포에서 릴리즈를 횡단 탐색하여 컴포넌트별 업데이트 정보를 반환한다.
-    ///
-    /// ## Walk-back 알고리즘
-    /// 1. 릴리즈 목록 fetch (30개)
-    /// 2. `resolve_components_across_releases`로 각 컴포넌트의 최적 다운로드 소스 결정
-    /// 3. 로컬 버전과 비교하여 `ComponentVersion` 목록 생성
-    async fn check_core_repo(
+    /// 코어 리포 릴리즈를 `release_scan_depth`까지 횡단 탐색하고 캐시를 갱신한다.
+    async fn refresh_core_releases(
         &mut self,
         client: &GitHubClient,
-        local_versions: &HashMap<String, String>,
-    ) -> Result<Vec<ComponentVersion>> {
-        let releases = client.fetch_releases(30).await?;
-
-        // 핵심: 여러 릴리즈를 횡단하여 각 컴포넌트의 에셋 소스를 결정
-        let (manifest, resolved) = client.resolve_components_across_releases(
-            &releases,
-            self.config.include_prerelease,
+    ) -> Result<(ReleaseManifest, HashMap<String, ResolvedComponent>)> {
+        let (manifest, mut resolved, releases) = client.resolve_components_with_depth(
+            self.config.effective_channel(),
+            self.config.release_scan_depth,
         ).await?;
 
-        // 캐시 갱신
+        // 에셋 목록에 크기가 없던 에셋(로컬/주입 소스 등)은 HEAD 요청으로 확인해 캐시
+        for rc in resolved.values_mut().filter(|rc| rc.size.is_none()) {
+            rc.size = self.source.asset_size(&rc.download_url).await;
+        }
+
         let latest_release = releases.iter()
             .filter(|r| !r.draft)
-            .find(|r| self.config.include_prerelease || !r.prerelease)
+            .find(|r| self.config.effective_channel().accepts(r))
             .cloned();
         self.cached_release = latest_release;
         self.cached_manifest = Some(manifest.clone());
         self.cached_releases = releases;
         self.resolved_components = resolved.clone();
 
+        Ok((manifest, resolved))
+    }
+
+    /// 코어 리포에서 릴리즈를 횡단 탐색하여 컴포넌트별 업데이트 정보를 반환한다.
+    ///
+    /// ## Walk-back 알고리즘
+    /// 1. 릴리즈 목록 fetch (`release_scan_depth`까지, 필요할 때만 다음 페이지)
+    /// 2. `resolve_components_with_depth`로 각 컴포넌트의 최적 다운로드 소스 결정
+    /// 3. 로컬 버전과 비교하여 `ComponentVersion` 목록 생성
+    async fn check_core_repo(
+        &mut self,
+        client: &GitHubClient,
+        local_versions: &HashMap<String, String>,
+    ) -> Result<Vec<ComponentVersion>> {
+        // 핵심: 여러 릴리즈를 횡단하여 각 컴포넌트의 에셋 소스를 결정
+        let (manifest, resolved) = self.refresh_core_releases(client).await?;
+
         // ComponentVersion 빌드
         let mut components = Vec::new();
         for (key, info) in &manifest.components {
@@ -691,24 +1548,14 @@ impl UpdateManager {
             };
             let current = local_versions.get(key).cloned().unwrap_or_default();
             let installed = self.is_component_installed(&component);
-
-            // 설치된 컴포넌트인데 로컬 버전을 감지할 수 없으면 거짓 양성 방지
-            // (프로덕션 환경에서 Cargo.toml/package.json이 없고 installed-manifest도 없는 경우)
-            let update_available = if installed && current.is_empty() {
-                tracing::warn!(
-                    "[Updater] Cannot detect local version for installed component '{}' — \
-                     assuming up-to-date to avoid false notification. \
-                     Run updater apply to populate installed-manifest.",
-                    key
-                );
-                // 감지 불가 → 최신 버전을 로컬 매니페스트에 자동 기록 (다음 체크에서 정상 비교)
-                if let Some(ver) = Some(&info.version) {
-                    let _ = Self::update_installed_version(key, ver);
-                }
-                false
-            } else {
-                self.compare_versions(&info.version, &current)
-            };
+            let (update_available, mut warning) =
+                self.evaluate_update(&component, &info.version, &current, installed);
+            if update_available && warning.is_none() && !resolved.contains_key(key)
+                && info.select_asset(&Platform::current(), |_| true) == AssetMatch::WrongPlatform
+            {
+                tracing::warn!("[Updater] {} v{} has no asset for {}", key, info.version, Platform::current());
+                warning = Some(NO_ASSET_FOR_PLATFORM.to_string());
+            }
 
             // resolved_components에서 다운로드 URL 조회
             // (최신 릴리즈에 에셋이 없으면 이전 릴리즈에서 찾은 URL이 들어있음)
@@ -733,23 +1580,50 @@ impl UpdateManager {
                 downloaded: false,
                 downloaded_path: None,
                 installed,
+                warning,
+                requires: info.requires.clone(),
             });
         }
 
         Ok(components)
     }
 
+    fn is_repo_unavailable(err: &anyhow::Error) -> bool {
+        err.chain().any(|c| matches!(c.downcast_ref::<UpdaterError>(), Some(UpdaterError::RepoUnavailable { .. })))
+    }
+
+    /// 리포에 접근할 수 없어 확인하지 못한 모듈/익스텐션 — 목록에서 빠지지 않도록
+    /// 현재 버전과 `repo_unavailable` 경고만 담아 상태에 남긴다
+    fn repo_unavailable_status(&self, component: Component, local_versions: &HashMap<String, String>) -> ComponentVersion {
+        ComponentVersion {
+            current_version: local_versions.get(&component.manifest_key()).cloned().unwrap_or_default(),
+            installed: self.is_component_installed(&component),
+            component,
+            latest_version: None,
+            update_available: false,
+            download_url: None,
+            asset_name: None,
+            release_notes: None,
+            published_at: None,
+            downloaded: false,
+            downloaded_path: None,
+            warning: Some(REPO_UNAVAILABLE.to_string()),
+            requires: None,
+        }
+    }
+
     async fn check_module_repo(
         &self,
         client: &GitHubClient,
-        module_name: &str,
+        module: &ModuleEntry,
         local_versions: &HashMap<String, String>,
     ) -> Result<Option<ComponentVersion>> {
+        let module_name = module.name.as_str();
         let releases = client.fetch_releases(5).await?;
 
         let release = match releases.iter()
             .filter(|r| !r.draft)
-            .find(|r| self.config.include_prerelease || !r.prerelease)
+            .find(|r| self.config.effective_channel().accepts(r))
         {
             Some(r) => r,
             None => return Ok(None),
@@ -762,27 +1636,33 @@ impl UpdateManager {
         // 태그에서 버전 추출: "v1.2.0" → "1.2.0"
         let latest_version = release.tag_name.trim_start_matches('v').to_string();
         let installed = self.is_component_installed(&component);
-
-        // 설치된 모듈인데 버전 감지 실패 시 거짓 양성 방지
-        let update_available = if installed && current.is_empty() {
-            tracing::warn!(
-                "[Updater] Cannot detect local version for installed module '{}' — assuming up-to-date",
-                module_name
-            );
-            if let Ok(latest) = SemVer::parse(&latest_version).ok_or(()) {
-                let _ = latest; // SemVer 파싱 가능한 경우에만 매니페스트 기록
-                let _ = Self::update_installed_version(&module_key, &latest_version);
+        let (update_available, mut warning) =
+            self.evaluate_update(&component, &latest_version, &current, installed);
+
+        let asset = match Self::select_module_asset(
+            &release.assets, module_name, &latest_version, module.asset_pattern.as_deref(), &Platform::current(),
+        ) {
+            AssetMatch::Found(asset) => Some(asset),
+            AssetMatch::WrongPlatform => {
+                tracing::warn!(
+                    "[Updater] Module '{}' release {} has no asset for {}",
+                    module_name, release.tag_name, Platform::current()
+                );
+                if update_available && warning.is_none() {
+                    warning = Some(NO_ASSET_FOR_PLATFORM.to_string());
+                }
+                None
+            }
+            AssetMatch::Missing => {
+                tracing::warn!(
+                    "[Updater] Module '{}' release {} has no asset matching {}",
+                    module_name, release.tag_name,
+                    module.asset_pattern.as_deref().unwrap_or("module-{name}.zip / {name}.zip")
+                );
+                None
             }
-            false
-        } else {
-            self.compare_versions(&latest_version, &current)
         };
 
-        // 에셋 파일 탐색 (module-{name}.zip 또는 {name}.zip)
-        let asset = release.assets.iter()
-            .find(|a| a.name == format!("module-{}.zip", module_name)
-                    || a.name == format!("{}.zip", module_name));
-
         let download_url = asset.map(|a| a.browser_download_url.clone());
         let asset_name = asset.map(|a| a.name.clone());
 
@@ -799,36 +1679,112 @@ impl UpdateManager {
             downloaded: false,
             downloaded_path: None,
             installed,
+            warning,
+            requires: None,
         }))
     }
 
-    /// module.toml의 [update] 섹션에서 리포 정보 추출
-    fn discover_module_repos(&self) -> Vec<(String, String)> {
-        let mut repos = Vec::new();
-        if let Ok(entries) = std::fs::read_dir(&self.modules_dir) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if path.is_dir() {
-                    let module_toml = path.join("module.toml");
-                    if module_toml.exists() {
-                        if let Ok(content) = std::fs::read_to_string(&module_toml) {
-                            if let Ok(parsed) = content.parse::<toml::Value>() {
-                                let name = parsed.get("module")
-                                    .and_then(|m| m.get("name"))
-                                    .and_then(|v| v.as_str());
-                                let repo = parsed.get("update")
-                                    .and_then(|u| u.get("github_repo"))
-                                    .and_then(|v| v.as_str());
-                                if let (Some(name), Some(repo)) = (name, repo) {
-                                    repos.push((name.to_string(), repo.to_string()));
-                                }
-                            }
-                        }
-                    }
+    /// 모듈 릴리즈 에셋 선택
+    ///
+    /// `asset_pattern`이 있으면 플레이스홀더를 치환한 이름과 정확히 일치하는 에셋만,
+    /// 없으면 `module-{name}` / `{name}` 이름의 zip 중 `platform`에 맞는 것을 찾는다.
+    /// `{os}`/`{arch}`는 `platform`의 값(`std::env::consts::OS`/`ARCH`, 예: `linux`, `x86_64`)으로 치환된다.
+    pub(crate) fn select_module_asset<'a>(
+        assets: &'a [github::GitHubAsset],
+        module_name: &str,
+        version: &str,
+        asset_pattern: Option<&str>,
+        platform: &Platform,
+    ) -> AssetMatch<&'a github::GitHubAsset> {
+        match asset_pattern {
+            Some(pattern) => {
+                let expected = pattern
+                    .replace("{name}", module_name)
+                    .replace("{version}", version)
+                    .replace("{os}", platform.os)
+                    .replace("{arch}", platform.arch);
+                match assets.iter().find(|a| a.name == expected) {
+                    Some(asset) => AssetMatch::Found(asset),
+                    None => AssetMatch::Missing,
                 }
             }
+            None => Self::select_named_asset(assets, &[format!("module-{}", module_name), module_name.to_string()], platform),
         }
-        repos
+    }
+
+    /// `{stem}.zip` 또는 `{stem}-<플랫폼 표기>.zip` 형태의 에셋 중 `platform`에 가장 잘 맞는 것 선택
+    fn select_named_asset<'a>(
+        assets: &'a [github::GitHubAsset],
+        stems: &[String],
+        platform: &Platform,
+    ) -> AssetMatch<&'a github::GitHubAsset> {
+        let candidates = assets.iter().filter(|a| {
+            let Some(base) = a.name.strip_suffix(".zip") else { return false };
+            stems.iter().any(|stem| {
+                base == stem
+                    || base.strip_prefix(stem.as_str()).is_some_and(|rest| rest.starts_with(['-', '_']))
+            })
+        });
+        platform.select(candidates, |a| a.name.as_str())
+    }
+
+    /// module.toml의 [update] 섹션에 리포가 지정된 모듈 목록
+    fn discover_module_repos(&self) -> Vec<ModuleEntry> {
+        self.scan_modules()
+            .into_iter()
+            .filter(|m| m.repo.is_some())
+            .collect()
+    }
+
+    /// `modules/*/module.toml`을 한 번씩 읽어 설치된 모듈 목록을 반환 (이름순)
+    ///
+    /// 리포 탐색(`discover_module_repos`)과 로컬 버전 수집이 같은 결과를 보도록 모듈 스캔은 여기서만 한다.
+    /// `.`으로 시작하는 디렉터리(모듈 교체용 임시/이전 버전)는 모듈로 취급하지 않는다.
+    pub(crate) fn scan_modules(&self) -> Vec<ModuleEntry> {
+        self.recover_interrupted_module_swaps();
+        let Ok(entries) = std::fs::read_dir(&self.modules_dir) else {
+            return Vec::new();
+        };
+        let mut modules: Vec<ModuleEntry> = entries
+            .flatten()
+            .filter(|entry| !entry.file_name().to_string_lossy().starts_with('.'))
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .filter_map(|path| Self::read_module_entry(&path.join("module.toml")))
+            .collect();
+        modules.sort_by(|a, b| a.name.cmp(&b.name));
+        modules
+    }
+
+    /// module.toml 하나를 TOML로 파싱 — `[module]` 테이블을 우선하고 최상위 키로 폴백
+    fn read_module_entry(module_toml: &Path) -> Option<ModuleEntry> {
+        let content = std::fs::read_to_string(module_toml).ok()?;
+        let parsed = match content.parse::<toml::Value>() {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                tracing::warn!("[UpdateManager] Invalid module.toml {:?}: {}", module_toml, e);
+                return None;
+            }
+        };
+        let field = |key: &str| {
+            parsed.get("module")
+                .and_then(|m| m.get(key))
+                .or_else(|| parsed.get(key))
+                .and_then(|v| v.as_str())
+                .map(String::from)
+        };
+        let update_field = |key: &str| {
+            parsed.get("update")
+                .and_then(|u| u.get(key))
+                .and_then(|v| v.as_str())
+                .map(String::from)
+        };
+        Some(ModuleEntry {
+            name: field("name")?,
+            version: field("version"),
+            repo: update_field("github_repo"),
+            asset_pattern: update_field("asset_pattern"),
+        })
     }
 
     /// extensions/*/extension.toml의 [update] 섹션에서 리포 정보 수집
@@ -872,7 +1828,7 @@ impl UpdateManager {
 
         let release = match releases.iter()
             .filter(|r| !r.draft)
-            .find(|r| self.config.include_prerelease || !r.prerelease)
+            .find(|r| self.config.effective_channel().accepts(r))
         {
             Some(r) => r,
             None => return Ok(None),
@@ -884,22 +1840,25 @@ impl UpdateManager {
 
         let latest_version = release.tag_name.trim_start_matches('v').to_string();
         let installed = self.is_component_installed(&component);
+        let (update_available, mut warning) =
+            self.evaluate_update(&component, &latest_version, &current, installed);
 
-        // 설치된 익스텐션인데 버전 감지 실패 시 거짓 양성 방지
-        let update_available = if installed && current.is_empty() {
-            tracing::warn!(
-                "[Updater] Cannot detect local version for installed extension '{}' — assuming up-to-date",
-                ext_name
-            );
-            false
-        } else {
-            self.compare_versions(&latest_version, &current)
+        let stems = [format!("ext-{}", ext_name), ext_name.to_string()];
+        let asset = match Self::select_named_asset(&release.assets, &stems, &Platform::current()) {
+            AssetMatch::Found(asset) => Some(asset),
+            AssetMatch::WrongPlatform => {
+                tracing::warn!(
+                    "[Updater] Extension '{}' release {} has no asset for {}",
+                    ext_name, release.tag_name, Platform::current()
+                );
+                if update_available && warning.is_none() {
+                    warning = Some(NO_ASSET_FOR_PLATFORM.to_string());
+                }
+                None
+            }
+            AssetMatch::Missing => None,
         };
 
-        let asset = release.assets.iter()
-            .find(|a| a.name == format!("ext-{}.zip", ext_name)
-                    || a.name == format!("{}.zip", ext_name));
-
         let download_url = asset.map(|a| a.browser_download_url.clone());
         let asset_name = asset.map(|a| a.name.clone());
 
@@ -915,6 +1874,8 @@ impl UpdateManager {
             downloaded: false,
             downloaded_path: None,
             installed,
+            warning,
+            requires: None,
         }))
     }
 
@@ -928,12 +1889,51 @@ impl UpdateManager {
         }
     }
 
+    /// 로컬 버전 상태에 따라 업데이트 여부와 경고를 결정
+    ///
+    /// 설치되어 있으나 버전을 읽을 수 없는 컴포넌트는 거짓 업데이트 알림을 막기 위해
+    /// 업데이트 대상에서 제외하고 `version_unreadable` 경고를 남긴다.
+    /// 미설치 컴포넌트는 기존처럼 설치 가능(업데이트 있음)으로 표시한다.
+    /// 고정 버전과 다른 최신 버전은 `pinned` 경고와 함께 억제한다.
+    pub(crate) fn evaluate_update(
+        &self,
+        component: &Component,
+        latest: &str,
+        current: &str,
+        installed: bool,
+    ) -> (bool, Option<String>) {
+        if !self.config.pin_allows(component, latest) {
+            tracing::info!(
+                "[Updater] {} is pinned to {} — ignoring {}",
+                component.display_name(), self.config.pinned[&component.manifest_key()], latest
+            );
+            return (false, Some(PINNED.to_string()));
+        }
+        match LocalVersion::classify(installed, current) {
+            LocalVersion::Unreadable => {
+                tracing::warn!(
+                    "[Updater] Cannot read local version of installed {} ({:?}) — not flagging an update",
+                    component.display_name(), current
+                );
+                (false, Some(VERSION_UNREADABLE.to_string()))
+            }
+            LocalVersion::Missing | LocalVersion::Known(_) => (self.compare_versions(latest, current), None),
+        }
+    }
+
+    /// 컴포넌트 에셋의 스테이징 경로 (`{staging}/{manifest_key}/{asset_name}`)
+    ///
+    /// 서로 다른 컴포넌트가 같은 에셋 이름을 써도 덮어쓰지 않도록 컴포넌트 키로 구분한다.
+    pub(crate) fn staged_asset_path(staging_dir: &Path, key: &str, asset_name: &str) -> PathBuf {
+        staging_dir.join(key).join(asset_name)
+    }
+
     /// staging 디렉터리에서 다운로드 상태 확인
     #[allow(dead_code)]
-    fn check_staged_status(&self, asset_name: Option<&str>) -> (bool, Option<String>) {
+    fn check_staged_status(&self, key: &str, asset_name: Option<&str>) -> (bool, Option<String>) {
         match asset_name {
             Some(name) => {
-                let staged_path = self.staging_dir.join(name);
+                let staged_path = Self::staged_asset_path(&self.staging_dir, key, name);
                 let exists = staged_path.exists();
                 (exists, if exists { Some(staged_path.to_string_lossy().to_string()) } else { None })
             }
@@ -983,16 +1983,9 @@ impl UpdateManager {
         }
 
         // 모듈: modules/*/module.toml에서 감지
-        if let Ok(entries) = std::fs::read_dir(&self.modules_dir) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if path.is_dir() {
-                    let module_toml = path.join("module.toml");
-                    if let Some((name, version)) = self.read_module_version(&module_toml) {
-                        let key = format!("module-{}", name);
-                        versions.entry(key).or_insert(version);
-                    }
-                }
+        for module in self.scan_modules() {
+            if let Some(version) = module.version {
+                versions.entry(format!("module-{}", module.name)).or_insert(version);
             }
         }
 
@@ -1070,32 +2063,6 @@ impl UpdateManager {
         None
     }
 
-    fn read_module_version(&self, module_toml: &Path) -> Option<(String, String)> {
-        let content = std::fs::read_to_string(module_toml).ok()?;
-        let mut name = None;
-        let mut version = None;
-
-        for line in content.lines() {
-            let trimmed = line.trim();
-            if trimmed.starts_with("name") && trimmed.contains('=') {
-                let val = trimmed.split('=').nth(1)?.trim().trim_matches('"');
-                name = Some(val.to_string());
-            }
-            if trimmed.starts_with("version") && trimmed.contains('=') {
-                let val = trimmed.split('=').nth(1)?.trim().trim_matches('"');
-                version = Some(val.to_string());
-            }
-            if name.is_some() && version.is_some() {
-                break;
-            }
-        }
-
-        match (name, version) {
-            (Some(n), Some(v)) => Some((n, v)),
-            _ => None,
-        }
-    }
-
     /// extension.toml에서 이름과 버전 읽기
     fn read_extension_version(&self, ext_toml: &Path) -> Option<(String, String)> {
         let content = std::fs::read_to_string(ext_toml).ok()?;
@@ -1130,52 +2097,54 @@ impl UpdateManager {
     ///
     /// resolved_components를 활용하여 각 컴포넌트의 에셋이 실제로 존재하는
     /// 릴리즈에서 다운로드한다 (최신 릴리즈에 없을 수 있음).
-    pub async fn download_available_updates(&mut self) -> Result<Vec<String>> {
+    pub async fn download_available_updates(&mut self) -> UpdaterResult<Vec<String>> {
+        self.download_available_updates_limited(None, &[]).await.map_err(UpdaterError::from)
+    }
+
+    /// `exclude`에 있는 컴포넌트 키를 제외하고 전체 다운로드 (예: 용량이 큰 GUI만 건너뛰기)
+    ///
+    /// 반환 목록과 `downloaded` 플래그에는 실제로 받은 컴포넌트만 반영됩니다.
+    pub async fn download_available_updates_except(&mut self, exclude: &[String]) -> UpdaterResult<Vec<String>> {
+        self.download_available_updates_limited(None, exclude).await.map_err(UpdaterError::from)
+    }
+
+    /// 백그라운드 워커용 — `max_download_kbps` 대역폭 제한을 적용하여 전체 다운로드
+    pub async fn download_available_updates_background(&mut self) -> UpdaterResult<Vec<String>> {
+        self.download_available_updates_limited(self.config.max_download_kbps, &[]).await.map_err(UpdaterError::from)
+    }
+
+    async fn download_available_updates_limited(&mut self, max_kbps: Option<u32>, exclude: &[String]) -> Result<Vec<String>> {
         std::fs::create_dir_all(&self.staging_dir)?;
 
         let mut downloaded = Vec::new();
 
-        // 업데이트 가능하고 아직 다운로드하지 않은 컴포넌트 목록
-        let to_download: Vec<(String, String, String)> = self.status.components.iter()
+        // 업데이트 가능하고 아직 다운로드하지 않은 컴포넌트 목록 (제외 목록 반영)
+        let to_download: Vec<(String, ResolvedComponent)> = self.status.components.iter()
             .filter(|c| c.update_available && !c.downloaded)
+            .filter(|c| !exclude.contains(&c.component.manifest_key()))
             .filter_map(|c| {
                 let key = c.component.manifest_key();
                 // resolved_components에서 실제 다운로드 소스 조회
-                self.resolved_components.get(&key).map(|rc| {
-                    (key, rc.download_url.clone(), rc.asset_name.clone())
-                })
+                self.resolved_components.get(&key).map(|rc| (key, rc.clone()))
             })
             .collect();
 
-        for (key, url, asset_name) in &to_download {
-            // URL에서 직접 다운로드 (특정 릴리즈의 에셋 URL)
-            let dest = self.staging_dir.join(asset_name);
+        for (key, rc) in &to_download {
+            // 특정 릴리즈의 에셋 URL에서 다운로드 (델타 패치 우선, 실패 시 미러)
+            let dest = Self::staged_asset_path(&self.staging_dir, key, &rc.asset_name);
             tracing::info!("[Updater] Downloading {} from resolved source", key);
 
-            // resolved_components에 저장된 URL로 직접 다운로드
-            let response = reqwest::get(url).await?;
-            if !response.status().is_success() {
-                anyhow::bail!("Failed to download {}: {}", asset_name, response.status());
-            }
-            let bytes = response.bytes().await?;
-            if let Some(parent) = dest.parent() {
-                std::fs::create_dir_all(parent)?;
-            }
-            std::fs::write(&dest, &bytes)?;
-            tracing::info!("[Updater] Downloaded {} ({} bytes)", asset_name, bytes.len());
-
-            downloaded.push(asset_name.clone());
-        }
-
-        // 상태 업데이트: downloaded 플래그 설정
-        for comp in &mut self.status.components {
-            if let Some(ref name) = comp.asset_name {
-                if downloaded.contains(name) {
-                    comp.downloaded = true;
-                    comp.downloaded_path = Some(
-                        self.staging_dir.join(name).to_string_lossy().to_string()
-                    );
-                }
+            let (bytes, sha256) = self.download_or_patch(key, rc, &dest, None, max_kbps).await?;
+            tracing::info!("[Updater] Downloaded {} ({} bytes)", rc.asset_name, bytes);
+
+            downloaded.push(rc.asset_name.clone());
+            // 받을 때마다 바로 표시 — 뒤 컴포넌트가 실패해도 이미 받은 것은 downloaded로 남음
+            // (에셋 이름이 겹칠 수 있으므로 컴포넌트 키로 매칭)
+            let path = dest.to_string_lossy().to_string();
+            self.staged_sha256.insert(path.clone(), sha256);
+            if let Some(comp) = self.status.components.iter_mut().find(|c| &c.component.manifest_key() == key) {
+                comp.downloaded = true;
+                comp.downloaded_path = Some(path);
             }
         }
 
@@ -1186,7 +2155,16 @@ impl UpdateManager {
     ///
     /// resolved_components를 조회하여 에셋이 포함된 릴리즈에서 다운로드.
     /// 최신 릴리즈에 에셋이 없어도 이전 릴리즈에서 자동으로 찾아온다.
-    pub async fn download_component(&mut self, component: &Component) -> Result<String> {
+    pub async fn download_component(&mut self, component: &Component) -> UpdaterResult<String> {
+        self.download_component_limited(component, None).await.map_err(UpdaterError::from)
+    }
+
+    /// 백그라운드 워커용 — `max_download_kbps` 대역폭 제한을 적용하여 단일 컴포넌트 다운로드
+    pub async fn download_component_background(&mut self, component: &Component) -> UpdaterResult<String> {
+        self.download_component_limited(component, self.config.max_download_kbps).await.map_err(UpdaterError::from)
+    }
+
+    async fn download_component_limited(&mut self, component: &Component, max_kbps: Option<u32>) -> Result<String> {
         std::fs::create_dir_all(&self.staging_dir)?;
 
         let comp_status = self.status.components.iter()
@@ -1197,14 +2175,22 @@ impl UpdateManager {
             anyhow::bail!("No update available for {}", component.display_name());
         }
 
+        if comp_status.warning.as_deref() == Some(NO_ASSET_FOR_PLATFORM) {
+            return Err(anyhow::anyhow!(UpdaterError::NoAssetForPlatform {
+                component: component.manifest_key(),
+                platform: Platform::current().to_string(),
+            }));
+        }
+
         let key = component.manifest_key();
         let rc = self.resolved_components.get(&key)
+            .cloned()
             .ok_or_else(|| anyhow::anyhow!(
                 "No resolved download source for {} — 에셋을 포함한 릴리즈를 찾지 못함",
                 component.display_name()
             ))?;
 
-        let dest = self.staging_dir.join(&rc.asset_name);
+        let dest = Self::staged_asset_path(&self.staging_dir, &key, &rc.asset_name);
 
         tracing::info!(
             "[Updater] Downloading {} v{} from release {}",
@@ -1220,52 +2206,24 @@ impl UpdateManager {
             prog.active = true;
         }
 
-        // 스트리밍 다운로드 (진행률 추적)
-        let response = reqwest::get(&rc.download_url).await?;
-        if !response.status().is_success() {
-            let mut prog = self.download_progress.lock().unwrap();
-            prog.active = false;
-            anyhow::bail!("Failed to download {}: {}", rc.asset_name, response.status());
-        }
-        let total = response.content_length().unwrap_or(0);
-        {
-            let mut prog = self.download_progress.lock().unwrap();
-            prog.total_bytes = total;
-        }
-
-        if let Some(parent) = dest.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-
-        {
-            use futures_util::StreamExt;
-            use std::io::Write;
-            let mut file = std::fs::File::create(&dest)?;
-            let mut received: u64 = 0;
-            let mut stream = response.bytes_stream();
-            while let Some(chunk) = stream.next().await {
-                let chunk = chunk?;
-                file.write_all(&chunk)?;
-                received += chunk.len() as u64;
-                if let Ok(mut prog) = self.download_progress.lock() {
-                    prog.bytes_received = received;
-                }
-            }
-            file.flush()?;
-        }
+        // 스트리밍 다운로드 (진행률 추적, 델타 패치 우선, 실패 시 미러)
+        let result = self.download_or_patch(&key, &rc, &dest, Some(&self.download_progress), max_kbps).await;
 
         // 진행률 완료
         {
             let mut prog = self.download_progress.lock().unwrap();
             prog.active = false;
         }
+        let (_, sha256) = result?;
 
         let asset_name = rc.asset_name.clone();
 
         // 상태 업데이트
+        let path = dest.to_string_lossy().to_string();
+        self.staged_sha256.insert(path.clone(), sha256);
         if let Some(comp) = self.status.components.iter_mut().find(|c| &c.component == component) {
             comp.downloaded = true;
-            comp.downloaded_path = Some(dest.to_string_lossy().to_string());
+            comp.downloaded_path = Some(path);
         }
 
         Ok(asset_name)
@@ -1278,12 +2236,17 @@ impl UpdateManager {
     /// - **모듈**: 기존 파일을 백업하고 다운로드된 zip 압축 해제
     /// - **GUI/CLI**: 직접 교체 (별도 프로세스 실행으로 처리)
     /// - **코어 데몬**: 실행 중이면 교체가 불가하므로 재시작 후 업데이트를 예약
-    pub async fn apply_updates(&mut self) -> Result<Vec<String>> {
+    pub async fn apply_updates(&mut self) -> UpdaterResult<ApplyResult> {
         let all_keys: Vec<String> = self.status.components.iter()
             .filter(|c| c.downloaded && c.update_available)
             .map(|c| c.component.manifest_key())
             .collect();
-        self.apply_components(&all_keys).await
+        self.apply_components(&all_keys, false, None).await
+    }
+
+    /// `apply_updates`의 표시 이름 목록 버전 (기존 Tauri 커맨드 호환용)
+    pub async fn apply_updates_names(&mut self) -> UpdaterResult<Vec<String>> {
+        Ok(self.apply_updates().await?.applied_names())
     }
 
     /// 지정한 컴포넌트만 적용 (빈 목록이면 전체 적용)
@@ -1295,77 +2258,140 @@ impl UpdateManager {
     /// 3. **현재 인터페이스**: 마지막에 GUI 또는 CLI를 적용 (재시작 필요)
     ///
     /// 이 순서를 지키면 업데이트 도중 프로세스 충돌이 방지됩니다.
-    pub async fn apply_components(&mut self, keys: &[String]) -> Result<Vec<String>> {
-        let mut applied = Vec::new();
+    /// 결과에는 데몬 재시작/self-update flow 필요 여부가 함께 담깁니다.
+    ///
+    /// 적용 전에 `check_apply_dependencies`로 `requires`를 확인합니다. 충족되지 않은
+    /// 컴포넌트는 적용하지 않고 `ApplyResult::blocked`로 돌려주며, `force`면 경고만 남기고 적용합니다.
+    ///
+    /// 스테이징 파일이 사라진 컴포넌트는 중단하지 않고 `success: false` 결과로 기록한 뒤
+    /// 미다운로드 상태로 되돌리고, 나머지 컴포넌트 적용을 계속합니다.
+    ///
+    /// `on_progress`가 있으면 단계 전환마다 `ApplyProgress`를 전달합니다:
+    /// Preparing → (WaitingForProcesses) → Extracting{component} … → WritingManifest → Completed
+    /// (실패 시 Failed)
+    pub async fn apply_components(
+        &mut self,
+        keys: &[String],
+        force: bool,
+        on_progress: Option<&(dyn Fn(ApplyProgress) + Send + Sync)>,
+    ) -> UpdaterResult<ApplyResult> {
+        let _lease = self.begin_apply()?;
+        self.apply_components_leased(keys, force, on_progress).await
+    }
+
+    /// `apply_components` 본체 — 호출자가 `begin_apply`로 얻은 lease를 보유해야 한다
+    ///
+    /// 매니페스트 로드부터 적용까지 한 세션으로 묶어 그 사이에 다른 작업이 끼어들지 않게 할 때 사용.
+    pub async fn apply_components_leased(
+        &mut self,
+        keys: &[String],
+        force: bool,
+        on_progress: Option<&(dyn Fn(ApplyProgress) + Send + Sync)>,
+    ) -> UpdaterResult<ApplyResult> {
+        self.apply_components_impl(keys, force, on_progress).await.map_err(UpdaterError::from)
+    }
+
+    async fn apply_components_impl(
+        &mut self,
+        keys: &[String],
+        force: bool,
+        on_progress: Option<&(dyn Fn(ApplyProgress) + Send + Sync)>,
+    ) -> Result<ApplyResult> {
+        let mut results = Vec::new();
+
+        let mut blocked = self.check_apply_dependencies(keys);
+        for check in &blocked {
+            for issue in &check.issues {
+                if force {
+                    tracing::warn!("[UpdateManager] ⚠ FORCED: applying despite unmet dependency — {}", issue.message);
+                } else {
+                    tracing::warn!("[UpdateManager] Blocking {}: {}", check.component, issue.message);
+                }
+            }
+        }
+        if force {
+            blocked.clear();
+        }
 
-        let mut components: Vec<ComponentVersion> = self.status.components.iter()
+        // 적용에 필요한 컴포넌트와 스테이징 경로만 복사 (릴리즈 노트 등 전체 ComponentVersion은 복제하지 않음)
+        let mut components: Vec<(Component, Option<String>)> = self.status.components.iter()
             .filter(|c| c.downloaded && c.update_available)
             .filter(|c| keys.is_empty() || keys.contains(&c.component.manifest_key()))
-            .cloned()
+            .filter(|c| {
+                let allowed = self.pin_allows_staged(c);
+                if !allowed {
+                    tracing::warn!(
+                        "[UpdateManager] Skipping {}: pinned version differs from staged {:?}",
+                        c.component.manifest_key(), c.latest_version
+                    );
+                }
+                allowed
+            })
+            .filter(|c| !blocked.iter().any(|b| b.component == c.component.manifest_key()))
+            .map(|c| (c.component.clone(), c.downloaded_path.clone()))
             .collect();
 
         // 적용 우선순위에 따라 정렬
-        components.sort_by_key(|c| Self::component_apply_priority(&c.component));
-
-        for comp in &components {
-            let staged_path = comp.downloaded_path.as_ref()
-                .ok_or_else(|| anyhow::anyhow!("No staged file for {:?}", comp.component))?;
+        components.sort_by_key(|(component, _)| Self::component_apply_priority(component));
+
+        let total = components.len();
+        let emit = |phase: ApplyPhase, current: Option<&Component>, done: usize, message: String| {
+            if let Some(on_progress) = on_progress {
+                on_progress(ApplyProgress {
+                    phase,
+                    current_component: current.map(Component::manifest_key),
+                    total,
+                    done,
+                    message,
+                });
+            }
+        };
+        emit(ApplyPhase::Preparing, None, 0, format!("Applying {} component(s)", total));
 
-            match &comp.component {
-                Component::Module(name) => {
-                    self.apply_module_update(name, staged_path).await?;
-                    applied.push(comp.component.display_name());
-                }
-                Component::Cli => {
-                    self.apply_binary_update("saba-cli", staged_path).await?;
-                    applied.push(comp.component.display_name());
-                }
-                Component::Gui => {
-                    self.apply_gui_update(staged_path).await?;
-                    applied.push(comp.component.display_name());
-                }
-                Component::Updater => {
-                    // Updater 자체 바이너리 교체 (apply 모드에서만 가능)
-                    self.apply_binary_update("saba-chan-updater", staged_path).await?;
-                    applied.push(comp.component.display_name());
-                }
-                Component::CoreDaemon => {
-                    // Updater exe can directly replace daemon binary
-                    self.apply_binary_update("saba-core", staged_path).await?;
-                    applied.push(comp.component.display_name());
-                }
-                Component::DiscordBot => {
-                    self.apply_discord_bot_update(staged_path).await?;
-                    applied.push(comp.component.display_name());
-                }
-                Component::Extension(name) => {
-                    self.apply_extension_update(name, staged_path).await?;
-                    applied.push(comp.component.display_name());
-                }
-                Component::Locales => {
-                    // locales.zip → locales/ 디렉터리에 압축 해제
-                    let target = self.install_root.join("locales");
-                    self.extract_to_directory(Path::new(staged_path), &target).await?;
-                    applied.push(comp.component.display_name());
-                }
+        for (done, (component, staged_path)) in components.iter().enumerate() {
+            let key = component.manifest_key();
+            if matches!(component, Component::CoreDaemon | Component::Cli | Component::Gui) {
+                emit(ApplyPhase::WaitingForProcesses, Some(component), done,
+                    format!("Waiting for {} to exit", component.display_name()));
             }
-        }
+            // 다운로드 후 스테이징 파일이 정리된 경우 — 이 컴포넌트만 실패로 기록하고 나머지는 계속 적용
+            let Some(staged_path) = staged_path.as_deref().filter(|p| Path::new(p).exists()) else {
+                let missing = staged_path.as_deref().unwrap_or("none");
+                tracing::warn!("[UpdateManager] Staged file for {} is missing ({}), skipping", key, missing);
+                self.mark_staged_file_missing(component);
+                results.push(ApplyComponentResult {
+                    component: key,
+                    success: false,
+                    message: format!("Staged file missing: {}", missing),
+                    stopped_processes: Vec::new(),
+                    restart_needed: false,
+                });
+                continue;
+            };
+            emit(ApplyPhase::Extracting { component: key.clone() }, Some(component), done,
+                format!("Applying {} ({}/{})", key, done + 1, total));
 
-        // 적용 완료된 컴포넌트의 상태 업데이트
-        for comp in &mut self.status.components {
-            if applied.iter().any(|a| a.starts_with(&comp.component.display_name())) {
-                comp.update_available = false;
-                comp.downloaded = false;
-                comp.downloaded_path = None;
-                if let Some(ref latest) = comp.latest_version {
-                    comp.current_version = latest.clone();
+            let result = match self.apply_staged_component(component, staged_path).await {
+                Ok(result) => result,
+                Err(e) => {
+                    emit(ApplyPhase::Failed, Some(component), done, format!("{}: {}", key, e));
+                    return Err(e);
                 }
-            }
+            };
+            // 적용 완료된 컴포넌트의 상태 업데이트
+            self.mark_component_applied(component);
+            results.push(result);
         }
 
+        let applied_keys: Vec<String> = results.iter()
+            .filter(|r| r.success)
+            .map(|r| r.component.clone())
+            .collect();
+
         // 적용 성공한 컴포넌트들의 버전을 로컬 매니페스트에 기록
-        if !applied.is_empty() {
-            if let Err(e) = self.update_installed_versions_batch(&applied) {
+        if !applied_keys.is_empty() {
+            emit(ApplyPhase::WritingManifest, None, total, "Writing installed manifest".to_string());
+            if let Err(e) = self.update_installed_versions_batch(&applied_keys) {
                 tracing::warn!("[UpdateManager] Failed to update installed manifest: {}", e);
             }
         }
@@ -1381,34 +2407,219 @@ impl UpdateManager {
         }
 
         // 적용 완료 후 .old 백업 파일 정리
-        if !applied.is_empty() {
+        if !applied_keys.is_empty() {
             self.cleanup_old_files();
         }
 
-        Ok(applied)
+        emit(ApplyPhase::Completed, None, total, format!("{} component(s) applied", applied_keys.len()));
+        let mut result = self.build_apply_result(results);
+        result.blocked = blocked;
+        Ok(result)
     }
 
-    /// 컴포넌트의 적용 우선순위를 반환합니다.
+    /// 대기 중인 업데이트를 라이브 설치와 분리된 섀도 디렉터리에 적용하고 검증 (blue-green 적용)
     ///
-    /// 낮은 값일수록 먼저 적용됩니다:
-    /// - 0: Updater (업데이트 방식 변경 대응 — 디스크의 바이너리를 먼저 교체)
-    /// - 1: 모듈, 익스텐션, Locales (프로세스 중단 불필요)
-    /// - 2: DiscordBot (프로세스 중단 불필요)
-    /// - 3: CoreDaemon (데몬 프로세스 종료 후 교체)
-    /// - 4: 사용하지 않는 인터페이스 (GUI 사용 중이면 CLI, 반대도 마찬가지)
-    /// - 5: 현재 사용 중인 인터페이스 (재시작 필요)
-    fn component_apply_priority(component: &Component) -> u8 {
-        match component {
-            Component::Updater => 0,
-            Component::Module(_) | Component::Extension(_) | Component::Locales => 1,
-            Component::DiscordBot => 2,
-            Component::CoreDaemon => 3,
-            // GUI/CLI는 현재 실행 중인 인터페이스를 마지막에 적용
-            Component::Gui => {
-                if ProcessChecker::is_gui_running() { 5 } else { 4 }
-            }
-            Component::Cli => {
-                if ProcessChecker::is_cli_running() { 5 } else { 4 }
+    /// `install_root`를 형제 디렉터리로 복사한 뒤 CoreDaemon/CLI/Updater/Locales만 섀도에 풀고,
+    /// 의존성과 주 실행파일을 검증합니다. 실패하면 섀도를 삭제하고 라이브 설치는 그대로 둡니다.
+    /// 실행 중인 프로세스를 기다리지 않으므로 데몬이 동작하는 동안에도 준비할 수 있습니다.
+    ///
+    /// install_root 밖에 설치되는 모듈/익스텐션/GUI/Discord Bot은 `ShadowInstall::deferred`로
+    /// 돌려주며, 승격 후 `apply_components`로 적용합니다. 반환된 섀도가 살아 있는 동안
+    /// 적용 잠금이 유지됩니다.
+    pub async fn apply_to_shadow(&mut self) -> UpdaterResult<ShadowInstall> {
+        self.apply_to_shadow_impl().await.map_err(UpdaterError::from)
+    }
+
+    async fn apply_to_shadow_impl(&mut self) -> Result<ShadowInstall> {
+        let lease = self.begin_apply()?;
+        let live_root = std::fs::canonicalize(&self.install_root)?;
+        let (shadow_dir, previous_dir) = shadow::swap_dirs(&live_root)?;
+        shadow::recover_swap(&live_root, &previous_dir)?;
+
+        let mut targets = Vec::new();
+        let mut deferred = Vec::new();
+        for comp in self.status.components.iter().filter(|c| c.downloaded && c.update_available) {
+            match comp.downloaded_path.as_ref() {
+                Some(staged) if shadow::is_shadowable(&comp.component) && self.pin_allows_staged(comp) => {
+                    targets.push((comp.component.clone(), PathBuf::from(staged)));
+                }
+                _ => deferred.push(comp.component.manifest_key()),
+            }
+        }
+        if targets.is_empty() {
+            anyhow::bail!("No pending update can be applied to a shadow install");
+        }
+        targets.sort_by_key(|(component, _)| Self::component_apply_priority(component));
+
+        if shadow_dir.exists() {
+            std::fs::remove_dir_all(&shadow_dir)?;
+        }
+        let mut skip = vec![shadow_dir.clone(), previous_dir.clone()];
+        if let Ok(staging) = std::fs::canonicalize(&self.staging_dir) {
+            skip.push(staging);
+        }
+        tracing::info!("[Updater] Building shadow install {} from {}", shadow_dir.display(), live_root.display());
+
+        // 여기부터 실패하면 ShadowInstall drop이 섀도 디렉터리를 정리
+        let mut shadow = ShadowInstall {
+            live_root,
+            root: shadow_dir,
+            previous: previous_dir,
+            applied: Vec::new(),
+            deferred,
+            promoted: false,
+            _lease: lease,
+        };
+        shadow::copy_tree(&shadow.live_root, &shadow.root, &skip)?;
+
+        for (component, staged) in targets {
+            let target = match component {
+                Component::Locales => shadow.root.join("locales"),
+                _ => shadow.root.clone(),
+            };
+            if let Some(primary) = Self::shadow_binary_name(&component) {
+                if !Self::archive_contains_root_file(&staged, &primary)? {
+                    anyhow::bail!("Update archive for {} does not contain {}", component.manifest_key(), primary);
+                }
+            }
+            Self::extract_to_directory(&staged, &target).await?;
+            shadow.applied.push((component, staged));
+        }
+
+        self.verify_shadow(&shadow)?;
+        tracing::info!(
+            "[Updater] Shadow install verified: {:?} (deferred: {:?})",
+            shadow.applied_keys(), shadow.deferred
+        );
+        Ok(shadow)
+    }
+
+    /// 섀도 적용 대상 컴포넌트의 주 실행파일 이름 (Locales는 None)
+    fn shadow_binary_name(component: &Component) -> Option<String> {
+        match component {
+            Component::CoreDaemon => Self::primary_binary_file_name("saba-core"),
+            Component::Cli => Self::primary_binary_file_name("saba-cli"),
+            Component::Updater => Self::primary_binary_file_name("saba-chan-updater"),
+            _ => None,
+        }
+    }
+
+    /// 섀도 검증 — 의존성 충족, 주 실행파일 유효성, locales/en 존재
+    fn verify_shadow(&self, shadow: &ShadowInstall) -> Result<()> {
+        if let Some(check) = self.check_apply_dependencies(&shadow.applied_keys()).first() {
+            let issues: Vec<&str> = check.issues.iter().map(|i| i.message.as_str()).collect();
+            anyhow::bail!("Shadow install of {} has unmet dependencies: {}", check.component, issues.join("; "));
+        }
+        for (component, _) in &shadow.applied {
+            if let Some(primary) = Self::shadow_binary_name(component) {
+                let path = shadow.root.join(&primary);
+                // zip 추출은 권한을 보존하지 않으므로 apply_binary_update와 같이 실행 권한 부여
+                #[cfg(unix)]
+                if path.is_file() {
+                    use std::os::unix::fs::PermissionsExt;
+                    let mut perms = std::fs::metadata(&path)?.permissions();
+                    perms.set_mode(perms.mode() | 0o755);
+                    std::fs::set_permissions(&path, perms)?;
+                }
+                Self::verify_executable(&path).map_err(|e| {
+                    anyhow::anyhow!("Shadow {} is not a valid executable: {}", path.display(), e)
+                })?;
+            } else if matches!(component, Component::Locales) && !shadow.root.join("locales").join("en").is_dir() {
+                anyhow::bail!("Shadow install has no locales/en directory");
+            }
+        }
+        Ok(())
+    }
+
+    /// 검증된 섀도를 라이브 설치와 교체하고 적용 결과를 반환
+    ///
+    /// rename 기반 교체이므로 Windows에서 install_root의 실행파일이 사용 중이면 실패하며,
+    /// 이 경우 라이브 설치는 변경되지 않고 섀도는 삭제됩니다.
+    pub fn promote_shadow(&mut self, shadow: ShadowInstall) -> UpdaterResult<ApplyResult> {
+        self.promote_shadow_impl(shadow).map_err(UpdaterError::from)
+    }
+
+    fn promote_shadow_impl(&mut self, mut shadow: ShadowInstall) -> Result<ApplyResult> {
+        shadow::swap_in(&shadow.live_root, &shadow.root, &shadow.previous)?;
+        shadow.promoted = true;
+        tracing::info!("[Updater] Shadow install promoted to {}", shadow.live_root.display());
+
+        let mut results = Vec::new();
+        for (component, staged) in &shadow.applied {
+            std::fs::remove_file(staged).ok();
+            self.mark_component_applied(component);
+            results.push(ApplyComponentResult {
+                component: component.manifest_key(),
+                success: true,
+                message: format!("{} updated via shadow install", component.display_name()),
+                stopped_processes: Vec::new(),
+                restart_needed: component.requires_restart(),
+            });
+        }
+
+        let applied_keys = shadow.applied_keys();
+        if let Err(e) = self.update_installed_versions_batch(&applied_keys) {
+            tracing::warn!("[UpdateManager] Failed to update installed manifest: {}", e);
+        }
+        if applied_keys.contains(&Component::CoreDaemon.manifest_key()) {
+            if let Some(core_comp) = self.status.components.iter()
+                .find(|c| matches!(c.component, Component::CoreDaemon))
+            {
+                if let Err(e) = Self::update_registry_display_version(&core_comp.current_version) {
+                    tracing::warn!("[UpdateManager] Failed to update registry DisplayVersion: {}", e);
+                }
+            }
+        }
+        self.cleanup_old_files();
+
+        Ok(self.build_apply_result(results))
+    }
+
+    /// 개별 적용 결과로부터 재시작/self-update 정보를 담은 `ApplyResult` 구성
+    pub(crate) fn build_apply_result(&self, results: Vec<ApplyComponentResult>) -> ApplyResult {
+        let core_key = Component::CoreDaemon.manifest_key();
+        let daemon_restart_script = results.iter()
+            .any(|r| r.success && r.component == core_key)
+            .then(|| {
+                let daemon_exe_name = if cfg!(target_os = "windows") { "saba-core.exe" } else { "saba-core" };
+                self.install_root.join(daemon_exe_name).to_string_lossy().to_string()
+            });
+
+        let self_update_components = results.iter()
+            .filter(|r| r.success)
+            .filter(|r| matches!(Component::from_manifest_key(&r.component), Component::Gui | Component::Cli))
+            .map(|r| r.component.clone())
+            .collect();
+
+        ApplyResult {
+            results,
+            daemon_restart_script,
+            self_update_components,
+            blocked: Vec::new(),
+        }
+    }
+
+    /// 컴포넌트의 적용 우선순위를 반환합니다.
+    ///
+    /// 낮은 값일수록 먼저 적용됩니다:
+    /// - 0: Updater (업데이트 방식 변경 대응 — 디스크의 바이너리를 먼저 교체)
+    /// - 1: 모듈, 익스텐션, Locales (프로세스 중단 불필요)
+    /// - 2: DiscordBot (프로세스 중단 불필요)
+    /// - 3: CoreDaemon (데몬 프로세스 종료 후 교체)
+    /// - 4: 사용하지 않는 인터페이스 (GUI 사용 중이면 CLI, 반대도 마찬가지)
+    /// - 5: 현재 사용 중인 인터페이스 (재시작 필요)
+    fn component_apply_priority(component: &Component) -> u8 {
+        match component {
+            Component::Updater => 0,
+            Component::Module(_) | Component::Extension(_) | Component::Locales => 1,
+            Component::DiscordBot => 2,
+            Component::CoreDaemon => 3,
+            // GUI/CLI는 현재 실행 중인 인터페이스를 마지막에 적용
+            Component::Gui => {
+                if ProcessChecker::is_gui_running() { 5 } else { 4 }
+            }
+            Component::Cli => {
+                if ProcessChecker::is_cli_running() { 5 } else { 4 }
             }
         }
     }
@@ -1465,6 +2676,94 @@ impl UpdateManager {
         }
     }
 
+    /// 이전 업데이트가 남긴 `*.exe.old` / `*.old` 백업 중 교체가 검증된 것만 삭제
+    ///
+    /// 업데이트 확인 성공 직후 호출됩니다. 삭제된 백업 파일 경로를 반환합니다.
+    pub fn cleanup_stale_backups(&self) -> Vec<PathBuf> {
+        let installed = Self::load_installed_manifest();
+        let mut removed = Vec::new();
+
+        for path in self.collect_stale_backups(&installed) {
+            match std::fs::remove_file(&path) {
+                Ok(()) => {
+                    tracing::info!("[Updater] Removed stale backup: {}", path.display());
+                    removed.push(path);
+                }
+                Err(e) => {
+                    tracing::debug!("[Updater] Cannot remove stale backup {} (still in use?): {}", path.display(), e);
+                }
+            }
+        }
+        removed
+    }
+
+    /// install_root의 백업 파일 중 삭제해도 안전한 것을 골라낸다
+    ///
+    /// 다음을 모두 만족해야 안전한 것으로 판단합니다:
+    /// - 같은 이름의 실행 중인(live) 바이너리가 존재
+    /// - 해당 컴포넌트에 적용 대기 중인 업데이트가 없음
+    /// - installed-manifest에 기록된 버전이 최신 버전과 일치 (교체가 검증됨)
+    pub(crate) fn collect_stale_backups(&self, installed: &HashMap<String, String>) -> Vec<PathBuf> {
+        let Ok(entries) = std::fs::read_dir(&self.install_root) else {
+            return Vec::new();
+        };
+
+        let mut stale = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else { continue };
+            let Some(live_name) = name.strip_suffix(".old") else { continue };
+
+            let live_path = self.install_root.join(live_name);
+            if !live_path.is_file() {
+                continue;
+            }
+            let Some(component) = Self::component_for_binary(live_name) else { continue };
+            let key = component.manifest_key();
+
+            let Some(status) = self.status.components.iter().find(|c| c.component == component) else {
+                continue;
+            };
+            if status.update_available || status.downloaded {
+                tracing::debug!("[Updater] Keeping backup {} — {} has a pending update", name, key);
+                continue;
+            }
+            let Some(latest) = status.latest_version.as_deref() else { continue };
+            let verified = installed.get(&key)
+                .map(|v| Self::same_version(v, latest))
+                .unwrap_or(false);
+            if verified {
+                stale.push(path);
+            } else {
+                tracing::debug!("[Updater] Keeping backup {} — replacement of {} not verified yet", name, key);
+            }
+        }
+        stale
+    }
+
+    /// 실행파일 이름(확장자 포함)에 대응하는 코어 컴포넌트
+    fn component_for_binary(file_name: &str) -> Option<Component> {
+        let stem = file_name
+            .strip_suffix(std::env::consts::EXE_SUFFIX)
+            .filter(|_| !std::env::consts::EXE_SUFFIX.is_empty())
+            .unwrap_or(file_name);
+        match stem {
+            "saba-core" => Some(Component::CoreDaemon),
+            "saba-chan-cli" => Some(Component::Cli),
+            "saba-chan-gui" => Some(Component::Gui),
+            "saba-chan-updater" => Some(Component::Updater),
+            _ => None,
+        }
+    }
+
+    /// 두 버전 문자열이 같은 버전인지 (SemVer로 해석되면 의미 비교, 아니면 문자열 비교)
+    fn same_version(a: &str, b: &str) -> bool {
+        match (SemVer::parse(a), SemVer::parse(b)) {
+            (Some(a), Some(b)) => a == b,
+            _ => a.trim_start_matches('v') == b.trim_start_matches('v'),
+        }
+    }
+
     /// 적용 대기 중인 개별 컴포넌트를 반환
     pub fn get_pending_components(&self) -> Vec<&ComponentVersion> {
         self.status.components.iter()
@@ -1472,11 +2771,158 @@ impl UpdateManager {
             .collect()
     }
 
+    /// 적용 대기 중인 컴포넌트를 버전·스테이징 경로·재시작 필요 여부와 함께 반환
+    ///
+    /// `load_pending_manifest` 후 호출하면 적용하지 않고 대기 목록만 확인할 수 있습니다.
+    pub fn pending_updates(&self) -> Vec<PendingUpdate> {
+        self.get_pending_components().into_iter()
+            .map(|c| PendingUpdate {
+                component: c.component.manifest_key(),
+                display_name: c.component.display_name(),
+                version: c.latest_version.clone(),
+                staged_path: c.downloaded_path.clone().unwrap_or_default(),
+                restart_required: c.component.requires_restart(),
+            })
+            .collect()
+    }
+
+    /// 단일 컴포넌트의 상태 — 마지막 체크(또는 `load_pending_manifest`) 결과에서 조회하며 새로 체크하지 않음
+    pub fn component_status(&self, component: &Component) -> Option<ComponentVersion> {
+        self.status.components.iter()
+            .find(|c| &c.component == component)
+            .cloned()
+    }
+
+    /// 마지막 체크 결과로 업데이트 계획을 세운다 — 다운로드/적용 없이 무엇이 바뀔지만 보고
+    ///
+    /// 다운로드 크기는 체크 때 캐시한 `ResolvedComponent::size`를 쓰고,
+    /// 없으면 에셋 URL(실패 시 미러)에 HEAD 요청을 보내 확인한다.
+    /// 아직 체크하지 않았으면 빈 계획을 반환한다.
+    pub async fn plan_update(&self) -> UpdatePlan {
+        let mut plan = UpdatePlan::default();
+        let mut components: Vec<&ComponentVersion> = self.status.components.iter().collect();
+        components.sort_by_key(|c| c.component.manifest_key());
+
+        let mut planned: Vec<&ComponentVersion> = Vec::new();
+        for comp in components {
+            let key = comp.component.manifest_key();
+            let skip = |reason: &str| PlannedSkip {
+                component: key.clone(),
+                display_name: comp.component.display_name(),
+                reason: reason.to_string(),
+            };
+            match (comp.update_available, comp.downloaded, self.resolved_components.get(&key)) {
+                (true, false, Some(rc)) => {
+                    // 체크 때 캐시한 크기 우선, 없으면 미러까지 HEAD 요청
+                    let mut size_bytes = rc.size;
+                    for url in std::iter::once(&rc.download_url).chain(&rc.mirrors) {
+                        if size_bytes.is_some() {
+                            break;
+                        }
+                        size_bytes = self.source.asset_size(url).await;
+                    }
+                    match size_bytes {
+                        Some(size) => plan.total_download_bytes += size,
+                        None => plan.size_incomplete = true,
+                    }
+                    plan.downloads.push(PlannedDownload {
+                        component: key.clone(),
+                        display_name: comp.component.display_name(),
+                        current_version: comp.current_version.clone(),
+                        latest_version: rc.latest_version.clone(),
+                        asset_name: rc.asset_name.clone(),
+                        size_bytes,
+                    });
+                    planned.push(comp);
+                }
+                (true, true, _) => {
+                    plan.skipped.push(skip("downloaded"));
+                    planned.push(comp);
+                }
+                (true, false, None) => plan.skipped.push(skip(comp.warning.as_deref().unwrap_or("no_source"))),
+                (false, ..) => plan.skipped.push(skip(comp.warning.as_deref().unwrap_or("up_to_date"))),
+            }
+        }
+
+        // 설치된 버전 → 계획대로 적용한 뒤의 버전
+        let installed: HashMap<String, String> = self.status.components.iter()
+            .filter(|c| c.installed)
+            .map(|c| (c.component.manifest_key(), c.current_version.clone()))
+            .collect();
+        let mut after = installed.clone();
+        for comp in &planned {
+            if let Some(ref latest) = comp.latest_version {
+                after.insert(comp.component.manifest_key(), latest.clone());
+            }
+        }
+
+        for comp in planned {
+            let key = comp.component.manifest_key();
+            if comp.component.requires_restart() {
+                plan.restart_required.push(key.clone());
+            }
+            let mut requires: Vec<(String, String)> = self.component_requires(&key).unwrap_or_default().into_iter().collect();
+            requires.sort();
+            for (dep_key, min_version) in requires {
+                let installed_version = installed.get(&dep_key).cloned();
+                if version_satisfies(installed_version.as_deref(), &min_version) {
+                    continue;
+                }
+                plan.dependencies.push(PlannedDependency {
+                    component: key.clone(),
+                    satisfied_by_plan: version_satisfies(after.get(&dep_key).map(String::as_str), &min_version),
+                    required_component: dep_key,
+                    required_version: min_version,
+                    installed_version,
+                });
+            }
+        }
+
+        plan
+    }
+
+    /// 업데이트 가능한 컴포넌트의 릴리즈 노트를 하나의 목록으로 모아 반환
+    ///
+    /// 코어 릴리즈처럼 여러 컴포넌트가 같은 노트를 공유하면 처음 나온 컴포넌트 항목에 한 번만 싣고
+    /// 나머지는 `shared_with`에 기록한다. 노트가 없는 컴포넌트와 Locales는 제외.
+    pub fn get_pending_changelog(&self) -> Vec<ChangelogEntry> {
+        let mut entries: Vec<ChangelogEntry> = Vec::new();
+        for comp in self.status.components.iter()
+            .filter(|c| c.update_available && !matches!(c.component, Component::Locales))
+        {
+            let Some(notes) = comp.release_notes.as_deref().map(str::trim).filter(|n| !n.is_empty()) else {
+                continue;
+            };
+            let key = comp.component.manifest_key();
+            match entries.iter_mut().find(|e| e.notes == notes) {
+                Some(entry) => entry.shared_with.push(key),
+                None => entries.push(ChangelogEntry {
+                    component: key,
+                    display_name: comp.component.display_name(),
+                    version: comp.latest_version.clone(),
+                    notes: notes.to_string(),
+                    shared_with: Vec::new(),
+                }),
+            }
+        }
+        entries
+    }
+
     /// 다운로드 완료된 컴포넌트 정보를 staging 디렉터리에 매니페스트로 저장합니다.
     /// 업데이터 --apply 모드에서 이 매니페스트를 읽어 네트워크 없이 적용할 수 있습니다.
+    /// 각 스테이징 파일의 SHA256은 다운로드 검증 때 계산한 값을 기록하며 (저장 시점에 다시 계산하지 않음),
+    /// 기록된 해시가 없는 항목은 적용 시 검증할 수 없으므로 매니페스트에서 제외합니다.
     pub fn save_pending_manifest(&self) -> Result<()> {
-        let pending: Vec<&ComponentVersion> = self.status.components.iter()
-            .filter(|c| c.downloaded && c.downloaded_path.is_some())
+        let pending: Vec<PendingEntry> = self.status.components.iter()
+            .filter(|c| c.downloaded)
+            .filter_map(|c| {
+                let path = c.downloaded_path.as_deref()?;
+                let Some(sha256) = self.staged_sha256.get(path) else {
+                    tracing::warn!("[UpdateManager] No download checksum recorded for {} — not adding it to pending manifest", path);
+                    return None;
+                };
+                Some(PendingEntry { component: c.clone(), staged_sha256: Some(sha256.clone()) })
+            })
             .collect();
 
         if pending.is_empty() {
@@ -1493,27 +2939,57 @@ impl UpdateManager {
 
     /// staging 디렉터리의 매니페스트를 읽어 컴포넌트 상태를 복원합니다.
     /// 네트워크 없이 apply_updates()를 실행할 수 있게 해줍니다.
+    /// 기록된 SHA256과 디스크의 파일이 다르면(다운로드 후 변조) 경고와 함께 제외합니다.
     pub fn load_pending_manifest(&mut self) -> Result<usize> {
         let manifest_path = self.staging_dir.join("pending.json");
+        self.load_pending_manifest_from(&manifest_path)
+    }
+
+    /// 지정한 pending 매니페스트로 상태 복원 — 다른 곳(빌드 서버 등)에서 준비해 옮겨 온 매니페스트 적용용
+    ///
+    /// 상대 경로로 기록된 스테이징 파일은 매니페스트가 있는 디렉터리 기준으로 해석하고,
+    /// 절대 경로가 없으면 같은 디렉터리의 `{컴포넌트 키}/{파일명}`(스테이징 디렉터리 구조)을 찾습니다.
+    pub fn load_pending_manifest_from(&mut self, manifest_path: &Path) -> Result<usize> {
         if !manifest_path.exists() {
             anyhow::bail!("No pending manifest found at {:?}", manifest_path);
         }
+        let manifest_dir = manifest_path.parent().unwrap_or(Path::new("."));
 
-        let content = std::fs::read_to_string(&manifest_path)?;
-        let components: Vec<ComponentVersion> = serde_json::from_str(&content)?;
+        let content = std::fs::read_to_string(manifest_path)?;
+        let entries: Vec<PendingEntry> = serde_json::from_str(&content)?;
 
-        // 실제 파일 존재 여부 재확인
+        // 실제 파일 존재 여부와 다운로드 이후 변조 여부 재확인
         let mut valid = Vec::new();
-        for mut comp in components {
-            if let Some(ref path) = comp.downloaded_path {
-                if std::path::Path::new(path).exists() {
-                    comp.downloaded = true;
-                    comp.update_available = true;
-                    valid.push(comp);
-                } else {
-                    tracing::warn!("[UpdateManager] Staged file missing: {}", path);
-                }
+        for PendingEntry { component: mut comp, staged_sha256 } in entries {
+            let Some(ref recorded) = comp.downloaded_path else { continue };
+            let Some(staged) = Self::locate_staged_file(manifest_dir, &comp.component.manifest_key(), recorded) else {
+                tracing::warn!("[UpdateManager] Staged file missing: {}", recorded);
+                continue;
+            };
+            let path = staged.to_string_lossy().to_string();
+            match staged_sha256 {
+                Some(expected) => match integrity::compute_sha256(&staged) {
+                    Ok(actual) if actual.eq_ignore_ascii_case(&expected) => {
+                        self.staged_sha256.insert(path.clone(), expected);
+                    }
+                    Ok(actual) => {
+                        tracing::warn!(
+                            "[UpdateManager] Staged file {} changed since download (expected {}, got {}) — dropping",
+                            path, expected, actual
+                        );
+                        continue;
+                    }
+                    Err(e) => {
+                        tracing::warn!("[UpdateManager] Failed to verify staged file {}: {} — dropping", path, e);
+                        continue;
+                    }
+                },
+                None => tracing::warn!("[UpdateManager] No checksum recorded for staged file {}, skipping re-verification", path),
             }
+            comp.downloaded = true;
+            comp.update_available = true;
+            comp.downloaded_path = Some(path);
+            valid.push(comp);
         }
 
         let count = valid.len();
@@ -1522,6 +2998,21 @@ impl UpdateManager {
         Ok(count)
     }
 
+    /// pending 매니페스트에 기록된 스테이징 파일의 실제 위치
+    fn locate_staged_file(manifest_dir: &Path, key: &str, recorded: &str) -> Option<PathBuf> {
+        let recorded = Path::new(recorded);
+        if recorded.is_relative() {
+            let path = manifest_dir.join(recorded);
+            return path.exists().then_some(path);
+        }
+        if recorded.exists() {
+            return Some(recorded.to_path_buf());
+        }
+        // 다른 머신에서 스테이징 디렉터리째 복사해 온 경우
+        let copied = Self::staged_asset_path(manifest_dir, key, &recorded.file_name()?.to_string_lossy());
+        copied.exists().then_some(copied)
+    }
+
     /// pending 매니페스트 파일 삭제 (적용 완료 후)
     pub fn clear_pending_manifest(&self) {
         let manifest_path = self.staging_dir.join("pending.json");
@@ -1534,6 +3025,74 @@ impl UpdateManager {
         }
     }
 
+    /// 오래된 스테이징 파일 정리
+    ///
+    /// `staging_max_age_days`보다 오래된 항목 중 `pending.json`이 참조하지 않는 것을 삭제하고
+    /// 회수한 바이트 수를 반환합니다. 상태 파일(`pending.json`, `apply-targets.json`,
+    /// `check-state.json`)은 삭제하지 않으며, `pending.json`을 해석할 수 없으면 아무것도 지우지 않습니다.
+    pub fn prune_staging(&self) -> Result<u64> {
+        const STATE_FILES: [&str; 3] = ["pending.json", "apply-targets.json", "check-state.json"];
+
+        if self.config.staging_max_age_days == 0 || !self.staging_dir.is_dir() {
+            return Ok(0);
+        }
+        let max_age = std::time::Duration::from_secs(u64::from(self.config.staging_max_age_days) * 24 * 60 * 60);
+
+        let pending_path = self.staging_dir.join("pending.json");
+        let mut referenced = std::collections::HashSet::new();
+        if pending_path.exists() {
+            let content = std::fs::read_to_string(&pending_path)?;
+            let pending: Vec<ComponentVersion> = serde_json::from_str(&content)?;
+            for path in pending.iter().filter_map(|c| c.downloaded_path.as_deref()) {
+                let path = Path::new(path);
+                referenced.insert(path.canonicalize().unwrap_or_else(|_| path.to_path_buf()));
+            }
+        }
+
+        let now = std::time::SystemTime::now();
+        let mut removed = 0usize;
+        let mut reclaimed: u64 = 0;
+        for entry in std::fs::read_dir(&self.staging_dir)?.flatten() {
+            let path = entry.path();
+            let name = entry.file_name();
+            if STATE_FILES.iter().any(|f| name == *f) {
+                continue;
+            }
+            // 참조된 파일 자체이거나 이를 담은 컴포넌트별 디렉터리는 유지
+            let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+            if referenced.iter().any(|r| r.starts_with(&canonical)) {
+                continue;
+            }
+            let Ok(meta) = entry.metadata() else { continue };
+            let age = meta.modified().ok().and_then(|m| now.duration_since(m).ok());
+            if age.is_none_or(|a| a < max_age) {
+                continue;
+            }
+
+            let size = if meta.is_dir() { dir_size(&path) } else { meta.len() };
+            let result = if meta.is_dir() {
+                std::fs::remove_dir_all(&path)
+            } else {
+                std::fs::remove_file(&path)
+            };
+            match result {
+                Ok(()) => {
+                    removed += 1;
+                    reclaimed += size;
+                }
+                Err(e) => tracing::warn!("[UpdateManager] Failed to prune {:?}: {}", path, e),
+            }
+        }
+
+        if removed > 0 {
+            tracing::info!(
+                "[UpdateManager] Pruned {} stale staging entries ({} bytes reclaimed)",
+                removed, reclaimed
+            );
+        }
+        Ok(reclaimed)
+    }
+
     /// 업데이터 exe가 적용해야 할 컴포넌트 키 목록을 저장합니다.
     /// 데몬이 apply_updates 응답의 needs_updater 목록을 기록하여,
     /// GUI가 CLI 인자로 일부만 전달해도 업데이터가 정확한 대상을 알 수 있도록 합니다.
@@ -1675,38 +3234,27 @@ impl UpdateManager {
     /// 예: GUI 0.3.0 → requires: { "saba-core": ">=0.3.0" }
     ///     → saba-core이 0.3.0 미만이면 DependencyIssue 반환
     pub fn check_dependencies(&self, component_key: &str) -> DependencyCheck {
-        let installed = Self::load_installed_manifest();
+        self.check_dependencies_against(component_key, &Self::load_installed_manifest())
+    }
+
+    /// `versions`(컴포넌트 키 → 버전)를 기준으로 의존성 확인
+    fn check_dependencies_against(&self, component_key: &str, versions: &HashMap<String, String>) -> DependencyCheck {
         let mut issues = Vec::new();
 
-        // 캐시된 서버 매니페스트에서 requires 정보 조회
-        if let Some(ref manifest) = self.cached_manifest {
-            if let Some(info) = manifest.components.get(component_key) {
-                if let Some(ref requires) = info.requires {
-                    for (dep_key, min_version_str) in requires {
-                        let dep_version = installed.get(dep_key);
-                        let satisfied = dep_version.is_some_and(|v| {
-                            // ">=" 접두사 제거 후 SemVer 비교
-                            let min_clean = min_version_str.trim_start_matches(">=").trim();
-                            match (SemVer::parse(v), SemVer::parse(min_clean)) {
-                                (Some(installed_v), Some(required_v)) => installed_v >= required_v,
-                                _ => false,
-                            }
-                        });
-
-                        if !satisfied {
-                            issues.push(DependencyIssue {
-                                required_component: dep_key.clone(),
-                                required_version: min_version_str.clone(),
-                                installed_version: dep_version.cloned(),
-                                message: format!(
-                                    "{} requires {} {} but {} is installed",
-                                    component_key, dep_key, min_version_str,
-                                    dep_version.map_or("not installed".to_string(), |v| v.clone())
-                                ),
-                            });
-                        }
-                    }
-                }
+        // 상태(pending 매니페스트 포함) 또는 캐시된 서버 매니페스트에서 requires 정보 조회
+        for (dep_key, min_version_str) in self.component_requires(component_key).unwrap_or_default() {
+            let dep_version = versions.get(&dep_key);
+            if !version_satisfies(dep_version.map(String::as_str), &min_version_str) {
+                issues.push(DependencyIssue {
+                    message: format!(
+                        "{} requires {} {} but {} is installed",
+                        component_key, dep_key, min_version_str,
+                        dep_version.map_or("not installed".to_string(), |v| v.clone())
+                    ),
+                    installed_version: dep_version.cloned(),
+                    required_component: dep_key,
+                    required_version: min_version_str,
+                });
             }
         }
 
@@ -1717,6 +3265,18 @@ impl UpdateManager {
         }
     }
 
+    /// 컴포넌트의 `requires` — 상태에 기록된 값 우선, 없으면 캐시된 서버 매니페스트
+    fn component_requires(&self, component_key: &str) -> Option<HashMap<String, String>> {
+        self.status.components.iter()
+            .find(|c| c.component.manifest_key() == component_key)
+            .and_then(|c| c.requires.clone())
+            .or_else(|| {
+                self.cached_manifest.as_ref()?
+                    .components.get(component_key)?
+                    .requires.clone()
+            })
+    }
+
     /// 모든 업데이트 가능한 컴포넌트의 의존성을 일괄 확인합니다.
     pub fn check_all_dependencies(&self) -> Vec<DependencyCheck> {
         self.status.components.iter()
@@ -1725,20 +3285,241 @@ impl UpdateManager {
             .collect()
     }
 
+    /// `force`로 확인한 의존성 경고 해제 — 저장된 pending 매니페스트를 읽는
+    /// 업데이터 프로세스가 같은 컴포넌트를 다시 차단하지 않도록 `requires`를 비운다.
+    pub fn acknowledge_dependencies(&mut self, keys: &[String]) {
+        for comp in &mut self.status.components {
+            if keys.contains(&comp.component.manifest_key()) {
+                comp.requires = None;
+            }
+        }
+    }
+
+    /// 적용 전 의존성 사전 확인 — 충족되지 않아 적용하면 안 되는 컴포넌트 목록을 반환
+    ///
+    /// `keys`가 비어 있으면 다운로드된 전체 업데이트가 대상이다.
+    /// 같은 배치에서 함께 적용되는 컴포넌트는 적용 후 버전을 기준으로 판단하고,
+    /// 차단된 컴포넌트에 기대던 컴포넌트도 연쇄적으로 차단한다.
+    pub fn check_apply_dependencies(&self, keys: &[String]) -> Vec<DependencyCheck> {
+        let installed = Self::load_installed_manifest();
+        let mut batch: Vec<&ComponentVersion> = self.status.components.iter()
+            .filter(|c| c.downloaded && c.update_available)
+            .filter(|c| keys.is_empty() || keys.contains(&c.component.manifest_key()))
+            .collect();
+        let mut blocked = Vec::new();
+
+        loop {
+            let mut versions = installed.clone();
+            for comp in &batch {
+                if let Some(ref latest) = comp.latest_version {
+                    versions.insert(comp.component.manifest_key(), latest.clone());
+                }
+            }
+            let unsatisfied: Vec<DependencyCheck> = batch.iter()
+                .map(|c| self.check_dependencies_against(&c.component.manifest_key(), &versions))
+                .filter(|check| !check.satisfied)
+                .collect();
+            if unsatisfied.is_empty() {
+                return blocked;
+            }
+            batch.retain(|c| !unsatisfied.iter().any(|check| check.component == c.component.manifest_key()));
+            blocked.extend(unsatisfied);
+        }
+    }
+
 
     /// 단일 컴포넌트만 개별적으로 적용하는 메서드 (데몬 IPC 경유 시 개별 컴포넌트를 순차적으로 처리)
     ///
     /// Flow 1 (백그라운드 워커): IPC 커맨드를 통해 데몬이 직접 적용한 후 재시작
     /// Flow 2 (GUI/CLI): 직접 적용, self-update flow로 전환
-    pub async fn apply_single_component(&mut self, component: &Component) -> Result<ApplyComponentResult> {
+    pub async fn apply_single_component(&mut self, component: &Component) -> UpdaterResult<ApplyComponentResult> {
+        let _lease = self.begin_apply()?;
+        self.apply_single_component_leased(component).await
+    }
+
+    /// 적용 작업 독점권 획득 — 다른 적용이 진행 중이면 즉시 `UpdaterError::ApplyInProgress`
+    ///
+    /// 같은 스테이징 디렉터리를 쓰는 다른 프로세스(데몬/GUI 업데이터)가 적용 중이면
+    /// `UpdaterError::AlreadyRunning`. 여러 컴포넌트를 순차 적용하는 호출자는 lease를 유지한 채
+    /// `apply_single_component_leased`를 사용해 중간에 다른 적용이 끼어들지 않게 한다.
+    pub fn begin_apply(&self) -> Result<ApplyLease> {
+        if self.apply_in_progress
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            tracing::warn!("[Updater] Apply requested while another apply is in progress");
+            return Err(anyhow::anyhow!(UpdaterError::ApplyInProgress));
+        }
+        match lock::ProcessLock::acquire(&self.staging_dir) {
+            Ok(process_lock) => Ok(ApplyLease {
+                flag: self.apply_in_progress.clone(),
+                _process_lock: process_lock,
+            }),
+            Err(e) => {
+                self.apply_in_progress.store(false, Ordering::SeqCst);
+                Err(anyhow::anyhow!(e))
+            }
+        }
+    }
+
+    /// 다음 자동 체크 시각을 `delay` 후로 기록 (`status.next_check`에 반영)
+    pub fn schedule_next_check(&mut self, delay: std::time::Duration) {
+        self.next_check_delay = Some(delay);
+        self.status.next_check = Some(chrono_after_iso(delay));
+    }
+
+    /// 마지막으로 예약된 다음 자동 체크까지의 지연
+    pub fn next_check_delay(&self) -> Option<std::time::Duration> {
+        self.next_check_delay
+    }
+
+    /// 체크 기록을 staging 디렉터리에 저장 (재시작 후 중복 체크 방지)
+    fn save_check_schedule(&self) -> Result<()> {
+        let (Some(last_check), Some(next_check)) =
+            (self.status.last_check.clone(), self.status.next_check.clone())
+        else {
+            return Ok(());
+        };
+        let delay = self.next_check_delay.unwrap_or_default();
+        let schedule = CheckSchedule {
+            last_check,
+            next_check,
+            next_check_unix: unix_now_secs() + delay.as_secs(),
+            announced: self.announced_versions.clone(),
+        };
+        std::fs::create_dir_all(&self.staging_dir)?;
+        let json = serde_json::to_string_pretty(&schedule)?;
+        std::fs::write(self.staging_dir.join("check-state.json"), json)?;
+        Ok(())
+    }
+
+    /// 저장된 체크 기록을 복원하고 다음 체크까지 남은 시간을 반환
+    ///
+    /// 기록이 없거나 읽을 수 없으면 `None`, 예약 시각이 이미 지났으면 `Duration::ZERO`.
+    pub fn restore_check_schedule(&mut self) -> Option<std::time::Duration> {
+        let schedule = self.load_check_schedule()?;
+        let remaining = std::time::Duration::from_secs(
+            schedule.next_check_unix.saturating_sub(unix_now_secs()),
+        );
+        if self.status.last_check.is_none() {
+            self.status.last_check = Some(schedule.last_check);
+        }
+        self.announced_versions = schedule.announced;
+        self.schedule_next_check(remaining);
+        Some(remaining)
+    }
+
+    fn load_check_schedule(&self) -> Option<CheckSchedule> {
+        let path = self.staging_dir.join("check-state.json");
+        let content = std::fs::read_to_string(&path).ok()?;
+        match serde_json::from_str(&content) {
+            Ok(s) => Some(s),
+            Err(e) => {
+                tracing::warn!("[UpdateManager] Ignoring corrupt check schedule {:?}: {}", path, e);
+                None
+            }
+        }
+    }
+
+    /// 새 업데이트 발견 시 호출할 콜백 등록
+    pub fn set_on_update_available(&mut self, hook: impl Fn(&UpdateNotice) + Send + Sync + 'static) {
+        self.on_update_available = Some(Arc::new(hook));
+    }
+
+    /// 이번 체크에서 새로 발견된 버전을 콜백/웹훅으로 알림 (버전당 한 번)
+    async fn announce_new_updates(&mut self) {
+        // 재시작 후에도 중복 알림하지 않도록 저장된 기록과 병합
+        if let Some(schedule) = self.load_check_schedule() {
+            for (key, version) in schedule.announced {
+                self.announced_versions.entry(key).or_insert(version);
+            }
+        }
+        let notices = notify::collect_new_notices(&self.status.components, &mut self.announced_versions);
+
+        for notice in &notices {
+            tracing::info!(
+                "[UpdateManager] New update available: {} {} → {}",
+                notice.component, notice.current, notice.latest
+            );
+            if let Some(hook) = &self.on_update_available {
+                hook(notice);
+            }
+            if let Some(url) = self.config.webhook_url.as_deref().filter(|u| !u.is_empty()) {
+                if let Err(e) = notify::post_webhook(url, notice).await {
+                    tracing::warn!("[UpdateManager] Update webhook failed for {}: {}", notice.component, e);
+                }
+            }
+        }
+    }
+
+    /// 적용 작업이 진행 중인지 여부
+    pub fn is_applying(&self) -> bool {
+        self.apply_in_progress.load(Ordering::SeqCst)
+    }
+
+    /// `apply_single_component` 본체 — 호출자가 `begin_apply`로 얻은 lease를 보유해야 한다
+    pub async fn apply_single_component_leased(&mut self, component: &Component) -> UpdaterResult<ApplyComponentResult> {
+        self.apply_single_component_impl(component).await.map_err(UpdaterError::from)
+    }
+
+    async fn apply_single_component_impl(&mut self, component: &Component) -> Result<ApplyComponentResult> {
         let comp = self.status.components.iter()
             .find(|c| &c.component == component && c.downloaded && c.update_available)
             .cloned()
             .ok_or_else(|| anyhow::anyhow!("Component {:?} is not ready for apply", component))?;
+        if !self.pin_allows_staged(&comp) {
+            anyhow::bail!(
+                "Component {} is pinned to {}, refusing to apply {:?}",
+                component.manifest_key(), self.config.pinned[&component.manifest_key()], comp.latest_version
+            );
+        }
 
         let staged_path = comp.downloaded_path.as_ref()
             .ok_or_else(|| anyhow::anyhow!("No staged file for {:?}", component))?;
 
+        let result = self.apply_staged_component(component, staged_path).await?;
+
+        // 적용 성공 시 상태 업데이트
+        self.mark_component_applied(component);
+
+        // 로컬 매니페스트에 버전 기록
+        if result.success {
+            if let Some(comp_state) = self.status.components.iter()
+                .find(|c| &c.component == component)
+            {
+                if let Err(e) = Self::update_installed_version(
+                    &component.manifest_key(),
+                    &comp_state.current_version,
+                ) {
+                    tracing::warn!("[UpdateManager] Failed to update installed manifest for {}: {}", component.manifest_key(), e);
+                }
+            }
+
+            // 코어 컴포넌트가 업데이트된 경우, 레지스트리의 DisplayVersion도 갱신
+            if matches!(component, Component::CoreDaemon) {
+                if let Some(core_comp) = self.status.components.iter()
+                    .find(|c| matches!(c.component, Component::CoreDaemon))
+                {
+                    if let Err(e) = Self::update_registry_display_version(&core_comp.current_version) {
+                        tracing::warn!("[UpdateManager] Failed to update registry DisplayVersion: {}", e);
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// 스테이징된 컴포넌트가 고정 설정을 벗어나지 않는지 확인 (버전을 모르면 고정된 경우 거부)
+    fn pin_allows_staged(&self, comp: &ComponentVersion) -> bool {
+        match comp.latest_version.as_deref() {
+            Some(version) => self.config.pin_allows(&comp.component, version),
+            None => !self.config.pinned.contains_key(&comp.component.manifest_key()),
+        }
+    }
+
+    /// 스테이징된 파일로 단일 컴포넌트를 교체하고 결과를 반환 (상태 갱신은 호출자가 담당)
+    async fn apply_staged_component(&mut self, component: &Component, staged_path: &str) -> Result<ApplyComponentResult> {
         let result = match component {
             Component::Module(name) => {
                 self.apply_module_update(name, staged_path).await?;
@@ -1746,49 +3527,52 @@ impl UpdateManager {
                     component: component.manifest_key(),
                     success: true,
                     message: format!("Module '{}' updated", name),
-                    stopped_processes: Vec::new(), // IPC 커맨드 경유 시 해당 없음
+                    stopped_processes: Vec::new(), // 모듈은 프로세스 종료 불필요
                     restart_needed: false,
                 }
             }
             Component::CoreDaemon => {
                 // Windows: 실행 중인 exe를 .exe.old로 rename 후 새 바이너리 추출
-                self.apply_binary_update("saba-core", staged_path).await?;
+                let stopped_processes = self.apply_binary_update("saba-core", staged_path).await?;
                 ApplyComponentResult {
                     component: component.manifest_key(),
                     success: true,
                     message: "Saba-Core updated (restart required)".to_string(),
-                    stopped_processes: Vec::new(),
+                    stopped_processes,
                     restart_needed: true,
                 }
             }
             Component::Cli => {
-                self.apply_binary_update("saba-cli", staged_path).await?;
+                let stopped_processes = self.apply_binary_update("saba-cli", staged_path).await?;
                 ApplyComponentResult {
                     component: component.manifest_key(),
                     success: true,
                     message: "CLI updated".to_string(),
-                    stopped_processes: Vec::new(),
+                    stopped_processes,
                     restart_needed: false,
                 }
             }
             Component::Gui => {
                 // 업데이터 exe가 호출한 경우: GUI 파일 직접 교체 (GUI 프로세스는 이미 종료됨)
-                self.apply_gui_update(staged_path).await?;
+                let stopped_processes = self.apply_gui_update(staged_path).await?;
                 ApplyComponentResult {
                     component: component.manifest_key(),
                     success: true,
-                    message: "GUI updated".to_string(),
-                    stopped_processes: Vec::new(),
+                    message: "GUI updated (restart required)".to_string(),
+                    stopped_processes,
                     restart_needed: true,
                 }
             }
             Component::Updater => {
-                // 업데이터 exe가 자기 자신을 교체 (Windows: 실행 중 rename 허용)
-                self.apply_binary_update("saba-chan-updater", staged_path).await?;
+                let deferred = self.apply_updater_update(staged_path).await?;
                 ApplyComponentResult {
                     component: component.manifest_key(),
                     success: true,
-                    message: "Updater updated".to_string(),
+                    message: if deferred {
+                        "Updater staged (replaced after exit)".to_string()
+                    } else {
+                        "Updater updated".to_string()
+                    },
                     stopped_processes: Vec::new(),
                     restart_needed: false,
                 }
@@ -1815,7 +3599,7 @@ impl UpdateManager {
             }
             Component::Locales => {
                 let target = self.install_root.join("locales");
-                self.extract_to_directory(Path::new(staged_path), &target).await?;
+                Self::extract_to_directory(Path::new(staged_path), &target).await?;
                 ApplyComponentResult {
                     component: component.manifest_key(),
                     success: true,
@@ -1826,34 +3610,6 @@ impl UpdateManager {
             }
         };
 
-        // 적용 성공 시 상태 업데이트
-        self.mark_component_applied(component);
-
-        // 로컬 매니페스트에 버전 기록
-        if result.success {
-            if let Some(comp_state) = self.status.components.iter()
-                .find(|c| &c.component == component)
-            {
-                if let Err(e) = Self::update_installed_version(
-                    &component.manifest_key(),
-                    &comp_state.current_version,
-                ) {
-                    tracing::warn!("[UpdateManager] Failed to update installed manifest for {}: {}", component.manifest_key(), e);
-                }
-            }
-
-            // 코어 컴포넌트가 업데이트된 경우, 레지스트리의 DisplayVersion도 갱신
-            if matches!(component, Component::CoreDaemon) {
-                if let Some(core_comp) = self.status.components.iter()
-                    .find(|c| matches!(c.component, Component::CoreDaemon))
-                {
-                    if let Err(e) = Self::update_registry_display_version(&core_comp.current_version) {
-                        tracing::warn!("[UpdateManager] Failed to update registry DisplayVersion: {}", e);
-                    }
-                }
-            }
-        }
-
         Ok(result)
     }
 
@@ -1871,8 +3627,24 @@ impl UpdateManager {
         }
     }
 
+    /// 스테이징 파일이 사라진 컴포넌트를 미다운로드 상태로 되돌림 (다음 다운로드에서 다시 받도록)
+    fn mark_staged_file_missing(&mut self, component: &Component) {
+        for comp in &mut self.status.components {
+            if &comp.component == component {
+                comp.downloaded = false;
+                comp.downloaded_path = None;
+            }
+        }
+    }
+
     /// GUI/CLI 자신의 업데이트 정보를 반환 (업데이터 실행파일을 통해 컴포넌트를 교체한 후 재시작하는 self-update 지원)
-    pub fn get_self_update_info(&self, component: &Component) -> Result<SelfUpdateInfo> {
+    ///
+    /// 업데이터 실행파일을 찾지 못하면 `UpdaterError::UpdaterNotFound` — GUI가 경로 지정을 안내할 수 있음.
+    pub fn get_self_update_info(&self, component: &Component) -> UpdaterResult<SelfUpdateInfo> {
+        self.get_self_update_info_impl(component).map_err(UpdaterError::from)
+    }
+
+    fn get_self_update_info_impl(&self, component: &Component) -> Result<SelfUpdateInfo> {
         let comp = self.status.components.iter()
             .find(|c| &c.component == component && c.downloaded)
             .ok_or_else(|| anyhow::anyhow!("Component {:?} not downloaded", component))?;
@@ -1898,7 +3670,21 @@ impl UpdateManager {
     }
 
     /// 업데이터 CLI 실행파일의 경로를 탐색
-    fn find_updater_executable(&self) -> Result<String> {
+    ///
+    /// `config.updater_path`가 있으면 그 경로만 확인하고, 없으면 배포/개발 후보 경로를 순서대로 탐색합니다.
+    /// 어디에도 없으면 존재하지 않는 경로를 지어내지 않고 `UpdaterError::UpdaterNotFound`를 반환합니다.
+    pub(crate) fn find_updater_executable(&self) -> Result<String> {
+        if let Some(ref configured) = self.config.updater_path {
+            let path = self.install_root.join(configured);
+            if path.is_file() {
+                return Ok(path.display().to_string());
+            }
+            tracing::warn!("[Updater] Configured updater_path does not exist: {}", path.display());
+            return Err(anyhow::anyhow!(UpdaterError::UpdaterNotFound {
+                searched: vec![path.display().to_string()],
+            }));
+        }
+
         // 배포 환경: install_root/saba-chan-updater(.exe)
         let candidates = if cfg!(target_os = "windows") {
             vec![
@@ -1939,55 +3725,65 @@ impl UpdateManager {
             }
         }
 
-        // 찾지 못하면 기본값을 반환 (배포 환경에서는 첫 후보 사용)
-        Ok(candidates[0].display().to_string())
+        let searched: Vec<String> = candidates.iter().chain(&dev_candidates)
+            .map(|p| p.display().to_string())
+            .collect();
+        tracing::warn!("[Updater] Updater executable not found (searched: {:?})", searched);
+        Err(anyhow::anyhow!(UpdaterError::UpdaterNotFound { searched }))
     }
 
-    /// 모듈 업데이트 적용 — 기존 zip 파일을 압축 해제하여 디렉터리에 배치
+    /// 모듈 업데이트 적용 — zip을 형제 임시 디렉터리에 풀고, 검증까지 끝나면 교체
+    ///
+    /// 새 버전을 `modules/.<name>.updating`에 완성한 뒤 기존 디렉터리를
+    /// `.<name>.previous`로, 임시 디렉터리를 실제 경로로 rename한다. 압축 해제 중
+    /// 중단되어도 기존 모듈은 그대로 남고, 모듈 디렉터리가 비어 있는 구간이 없다.
     async fn apply_module_update(&self, module_name: &str, staged_path: &str) -> Result<()> {
         let target_dir = self.modules_dir.join(module_name);
         let staged = Path::new(staged_path);
 
         tracing::info!("[Updater] Applying module update: {} → {}", module_name, target_dir.display());
 
-        // 기존 백업 생성
-        let backup_dir = self.staging_dir.join(format!("{}_backup", module_name));
-        if target_dir.exists() {
-            if backup_dir.exists() {
-                std::fs::remove_dir_all(&backup_dir)?;
-            }
-            self.copy_dir_recursive(&target_dir, &backup_dir)?;
+        if !staged.extension().map(|e| e == "zip").unwrap_or(false) {
+            anyhow::bail!("Module update for '{}' is not a zip archive: {}", module_name, staged.display());
         }
 
-        // zip 압축 해제
-        if staged.extension().map(|e| e == "zip").unwrap_or(false) {
-            let file = std::fs::File::open(staged)?;
-            let mut archive = zip::ZipArchive::new(file)?;
-
-            // 기존 파일을 삭제하고 새 파일로 교체
-            if target_dir.exists() {
-                // __pycache__와 같은 캐시 파일은 제외하고 삭제
-                self.clean_module_dir(&target_dir)?;
-            }
+        self.recover_module_swap(module_name)?;
+        let (temp_dir, previous_dir) = self.module_swap_dirs(module_name);
+        if temp_dir.exists() {
+            std::fs::remove_dir_all(&temp_dir)?;
+        }
+        std::fs::create_dir_all(&temp_dir)?;
 
-            for i in 0..archive.len() {
-                let mut entry = archive.by_index(i)?;
-                let name = entry.name().to_string();
-                let out_path = target_dir.join(&name);
+        // module.toml이 없거나 이름이 다르면 모듈을 감지할 수 없게 되므로 교체하지 않음
+        if let Err(e) = self.build_module_update(&target_dir, &temp_dir, staged, module_name) {
+            tracing::error!("[Updater] Module '{}' update is invalid, keeping current version: {}", module_name, e);
+            std::fs::remove_dir_all(&temp_dir).ok();
+            return Err(e);
+        }
 
-                if entry.is_dir() {
-                    std::fs::create_dir_all(&out_path)?;
-                } else {
-                    if let Some(parent) = out_path.parent() {
-                        std::fs::create_dir_all(parent)?;
-                    }
-                    let mut outfile = std::fs::File::create(&out_path)?;
-                    std::io::copy(&mut entry, &mut outfile)?;
-                }
+        // 교체: 기존 → .previous, 임시 → 실제 경로
+        if previous_dir.exists() {
+            std::fs::remove_dir_all(&previous_dir)?;
+        }
+        let had_live = target_dir.exists();
+        if had_live {
+            if let Err(e) = std::fs::rename(&target_dir, &previous_dir) {
+                std::fs::remove_dir_all(&temp_dir).ok();
+                return Err(anyhow::anyhow!("Failed to move module '{}' aside: {}", module_name, e));
+            }
+        }
+        if let Err(e) = std::fs::rename(&temp_dir, &target_dir) {
+            tracing::error!("[Updater] Module '{}' swap failed, restoring previous version: {}", module_name, e);
+            if had_live {
+                std::fs::rename(&previous_dir, &target_dir)?;
+            }
+            std::fs::remove_dir_all(&temp_dir).ok();
+            return Err(anyhow::anyhow!("Failed to swap in module '{}': {}", module_name, e));
+        }
+        if had_live {
+            if let Err(e) = std::fs::remove_dir_all(&previous_dir) {
+                tracing::warn!("[Updater] Failed to remove {}: {}", previous_dir.display(), e);
             }
-        } else {
-            // zip이 아닌 단일 파일인 경우 직접 복사
-            std::fs::copy(staged, &target_dir)?;
         }
 
         // 스테이징 파일 삭제
@@ -1997,6 +3793,77 @@ impl UpdateManager {
         Ok(())
     }
 
+    /// 새 모듈 버전을 `temp_dir`에 구성 — 기존 디렉터리에서 유지할 항목을 먼저 옮겨 놓고
+    /// zip을 풀어 덮은 뒤 module.toml을 검증한다
+    fn build_module_update(&self, live_dir: &Path, temp_dir: &Path, staged: &Path, module_name: &str) -> Result<()> {
+        let preserve = Self::module_preserve_list(live_dir);
+        if live_dir.exists() {
+            // __pycache__, dotfile, [update].preserve 항목은 새 버전에도 그대로 유지
+            self.copy_kept_entries(live_dir, temp_dir, Path::new(""), &preserve)?;
+        }
+
+        let file = std::fs::File::open(staged)?;
+        let mut archive = zip::ZipArchive::new(file)?;
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i)?;
+            let name = entry.name().to_string();
+            let out_path = temp_dir.join(&name);
+
+            // 보존 대상이 이미 있으면 릴리즈에 포함된 기본값으로 덮어쓰지 않음
+            if Self::is_preserved(Path::new(&name), &preserve) && out_path.exists() {
+                continue;
+            }
+
+            if entry.is_dir() {
+                std::fs::create_dir_all(&out_path)?;
+            } else {
+                if let Some(parent) = out_path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                let mut outfile = std::fs::File::create(&out_path)?;
+                std::io::copy(&mut entry, &mut outfile)?;
+            }
+        }
+
+        Self::verify_module_manifest(temp_dir, module_name)
+    }
+
+    /// 모듈 교체에 쓰는 형제 디렉터리 (`.<name>.updating`, `.<name>.previous`)
+    fn module_swap_dirs(&self, module_name: &str) -> (PathBuf, PathBuf) {
+        (
+            self.modules_dir.join(format!(".{}.updating", module_name)),
+            self.modules_dir.join(format!(".{}.previous", module_name)),
+        )
+    }
+
+    /// 교체 도중 중단되어 실제 디렉터리 없이 `.previous`만 남았으면 되돌림
+    fn recover_module_swap(&self, module_name: &str) -> Result<()> {
+        let target_dir = self.modules_dir.join(module_name);
+        let (_, previous_dir) = self.module_swap_dirs(module_name);
+        if !target_dir.exists() && previous_dir.is_dir() {
+            tracing::warn!("[Updater] Restoring module '{}' left behind by an interrupted update", module_name);
+            std::fs::rename(&previous_dir, &target_dir)?;
+        }
+        Ok(())
+    }
+
+    /// 모든 `modules/.<name>.previous`에 대해 `recover_module_swap` 수행
+    fn recover_interrupted_module_swaps(&self) {
+        let Ok(entries) = std::fs::read_dir(&self.modules_dir) else { return };
+        for entry in entries.flatten() {
+            let file_name = entry.file_name();
+            let Some(module_name) = file_name.to_str()
+                .and_then(|n| n.strip_prefix('.'))
+                .and_then(|n| n.strip_suffix(".previous"))
+            else {
+                continue;
+            };
+            if let Err(e) = self.recover_module_swap(module_name) {
+                tracing::warn!("[Updater] Failed to restore module '{}': {}", module_name, e);
+            }
+        }
+    }
+
     /// 익스텐션 업데이트 적용 — zip 압축 해제하여 extensions/ 디렉터리에 배치
     async fn apply_extension_update(&self, ext_name: &str, staged_path: &str) -> Result<()> {
         let target_dir = self.resolve_ext_dir(ext_name);
@@ -2006,7 +3873,8 @@ impl UpdateManager {
 
         // 기존 백업
         let backup_dir = self.staging_dir.join(format!("{}_ext_backup", ext_name));
-        if target_dir.exists() {
+        let has_backup = target_dir.exists();
+        if has_backup {
             if backup_dir.exists() {
                 std::fs::remove_dir_all(&backup_dir)?;
             }
@@ -2041,22 +3909,83 @@ impl UpdateManager {
             std::fs::copy(staged, &target_dir)?;
         }
 
+        if let Err(e) = Self::verify_extension_manifest(&target_dir, ext_name) {
+            tracing::error!("[Updater] Extension '{}' update is invalid, rolling back: {}", ext_name, e);
+            self.restore_dir_backup(&target_dir, has_backup.then_some(backup_dir.as_path()))?;
+            return Err(e);
+        }
+
         std::fs::remove_file(staged).ok();
 
         tracing::info!("[Updater] Extension '{}' updated successfully", ext_name);
         Ok(())
     }
 
+    /// 적용된 모듈 디렉터리의 module.toml 검증 — 존재하고, TOML로 파싱되고, 이름이 일치해야 함
+    fn verify_module_manifest(dir: &Path, module_name: &str) -> Result<()> {
+        let module_toml = dir.join("module.toml");
+        if !module_toml.is_file() {
+            anyhow::bail!("module.toml missing after updating module '{}'", module_name);
+        }
+        let entry = Self::read_module_entry(&module_toml)
+            .ok_or_else(|| anyhow::anyhow!("module.toml of '{}' has no valid name", module_name))?;
+        if entry.name != module_name {
+            anyhow::bail!("module.toml name '{}' does not match module '{}'", entry.name, module_name);
+        }
+        Ok(())
+    }
+
+    /// 적용된 익스텐션 디렉터리 검증 — extension.toml(`[extension].name`) 또는
+    /// manifest.json(`id`)이 있어야 하고 이름이 일치해야 함 (`-`/`_`는 같은 것으로 취급)
+    fn verify_extension_manifest(dir: &Path, ext_name: &str) -> Result<()> {
+        let declared = if dir.join("extension.toml").is_file() {
+            let content = std::fs::read_to_string(dir.join("extension.toml"))?;
+            let parsed: toml::Value = content.parse()
+                .map_err(|e| anyhow::anyhow!("extension.toml of '{}' is invalid: {}", ext_name, e))?;
+            parsed.get("extension")
+                .and_then(|e| e.get("name"))
+                .or_else(|| parsed.get("name"))
+                .and_then(|v| v.as_str())
+                .map(String::from)
+        } else if dir.join("manifest.json").is_file() {
+            let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(dir.join("manifest.json"))?)
+                .map_err(|e| anyhow::anyhow!("manifest.json of '{}' is invalid: {}", ext_name, e))?;
+            json.get("id").and_then(|v| v.as_str()).map(String::from)
+        } else {
+            anyhow::bail!("extension.toml missing after updating extension '{}'", ext_name);
+        };
+
+        let declared = declared
+            .ok_or_else(|| anyhow::anyhow!("extension manifest of '{}' has no name", ext_name))?;
+        if declared.replace('-', "_") != ext_name.replace('-', "_") {
+            anyhow::bail!("extension name '{}' does not match extension '{}'", declared, ext_name);
+        }
+        Ok(())
+    }
+
+    /// 적용 실패 시 대상 디렉터리를 백업으로 되돌림 (백업이 없으면 대상 디렉터리만 제거)
+    fn restore_dir_backup(&self, target_dir: &Path, backup_dir: Option<&Path>) -> Result<()> {
+        if target_dir.is_dir() {
+            std::fs::remove_dir_all(target_dir)?;
+        } else if target_dir.exists() {
+            std::fs::remove_file(target_dir)?;
+        }
+        if let Some(backup_dir) = backup_dir {
+            self.copy_dir_recursive(backup_dir, target_dir)?;
+            tracing::info!("[Updater] Restored {} from {}", target_dir.display(), backup_dir.display());
+        }
+        Ok(())
+    }
+
     /// Windows에서 실행 중인 .exe를 rename하기 위한 재시도 로직
     /// 프로세스가 파일을 해제할 때까지 지수 백오프로 최대 max_retries번 재시도
     /// core zip에 포함된 공유 Python 모듈(i18n.py, daemon_rcon.py)을
-    /// %APPDATA%/saba-chan/modules/ 로 복사한다.
+    /// 모듈 디렉터리(기본 %APPDATA%/saba-chan/modules/)로 복사한다.
     fn install_shared_modules(&self) {
         const SHARED_MODULES: &[&str] = &["i18n.py", "daemon_rcon.py"];
 
-        let data_dir = Self::resolve_data_dir();
-        let modules_dir = data_dir.join("modules");
-        let _ = std::fs::create_dir_all(&modules_dir);
+        let modules_dir = &self.modules_dir;
+        let _ = std::fs::create_dir_all(modules_dir);
 
         for name in SHARED_MODULES {
             let src = self.install_root.join(name);
@@ -2075,12 +4004,376 @@ impl UpdateManager {
         }
     }
 
-    /// 데이터 디렉토리 경로 (설정·모듈·확장 등) — constants 모듈에 위임
-    fn resolve_data_dir() -> PathBuf {
-        crate::constants::resolve_data_dir()
+    /// 다운로드 요청 — 일시적 실패(5xx, 연결 끊김, 타임아웃)만 지수 백오프로 재시도
+    ///
+    /// 404 같은 4xx 응답은 재시도해도 결과가 같으므로 즉시 실패한다.
+    async fn get_with_retry(url: &str, label: &str) -> Result<reqwest::Response> {
+        Self::get_with_strategy(url, label, RecoveryStrategy::new(DOWNLOAD_MAX_RETRIES)).await
+    }
+
+    /// 에셋을 `dest`에 내려받는다 — 기본 URL이 실패하면 manifest의 `mirrors`를 순서대로 시도
+    ///
+    /// manifest에 sha256이 있으면 어느 소스에서 받았든 동일하게 검증하며,
+    /// 불일치한 파일은 지우고 다음 미러로 넘어간다. 신뢰 공개키가 있으면 서명도 검증한다.
+    /// `max_kbps`가 있으면 해당 대역폭 이하로 수신한다.
+    pub(crate) async fn download_resolved(
+        source: &dyn ReleaseSource,
+        rc: &ResolvedComponent,
+        dest: &Path,
+        progress: Option<&Arc<StdMutex<DownloadProgress>>>,
+        trusted_pubkey: Option<&str>,
+        max_kbps: Option<u32>,
+    ) -> Result<u64> {
+        Self::download_resolved_hashed(source, rc, dest, progress, trusted_pubkey, max_kbps)
+            .await
+            .map(|(received, _)| received)
+    }
+
+    /// `download_resolved`와 같지만, 검증 때 계산한 파일의 SHA256도 함께 반환
+    async fn download_resolved_hashed(
+        source: &dyn ReleaseSource,
+        rc: &ResolvedComponent,
+        dest: &Path,
+        progress: Option<&Arc<StdMutex<DownloadProgress>>>,
+        trusted_pubkey: Option<&str>,
+        max_kbps: Option<u32>,
+    ) -> Result<(u64, String)> {
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let sources: Vec<&str> = std::iter::once(rc.download_url.as_str())
+            .chain(rc.mirrors.iter().map(String::as_str))
+            .collect();
+        let mut last_err = None;
+
+        for (idx, url) in sources.iter().enumerate() {
+            if idx > 0 {
+                tracing::info!(
+                    "[Updater] Trying mirror {}/{} for {}: {}",
+                    idx, sources.len() - 1, rc.asset_name, url
+                );
+            }
+            let result: Result<(u64, String)> = async {
+                let received = source.download(url, dest, progress, max_kbps).await?;
+                let sha256 = Self::verify_download_sha256(&rc.asset_name, dest, rc.sha256.as_deref())?;
+                Self::verify_download_signature(source, rc, dest, &sha256, trusted_pubkey).await?;
+                Self::verify_download_archive(&rc.asset_name, dest)?;
+                Ok((received, sha256))
+            }.await;
+
+            match result {
+                Ok(staged) => return Ok(staged),
+                Err(e) => {
+                    tracing::warn!("[Updater] Download {} from {} failed: {}", rc.asset_name, url, e);
+                    std::fs::remove_file(dest).ok();
+                    last_err = Some(e);
+                }
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| anyhow::anyhow!("No download source for {}", rc.asset_name)))
+    }
+
+    /// 업데이트 에셋 스테이징 — 적용 가능한 델타 패치가 있으면 먼저 시도하고, 아니면 전체 다운로드
+    ///
+    /// 반환: (받은 바이트 수, 스테이징된 파일의 SHA256)
+    async fn download_or_patch(
+        &self,
+        key: &str,
+        rc: &ResolvedComponent,
+        dest: &Path,
+        progress: Option<&Arc<StdMutex<DownloadProgress>>>,
+        max_kbps: Option<u32>,
+    ) -> Result<(u64, String)> {
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let trusted_pubkey = self.trusted_pubkey();
+        if trusted_pubkey.is_none() {
+            if let Some(staged) = self.try_download_patch(key, rc, dest, progress, max_kbps).await {
+                return Ok(staged);
+            }
+        } else if !rc.patch_from.is_empty() {
+            // 패치 파일은 서명되지 않으므로 서명 검증이 켜져 있으면 전체 에셋만 사용
+            tracing::debug!("[Updater] Signature verification enabled — skipping delta patch for {}", key);
+        }
+        Self::download_resolved_hashed(self.source.as_ref(), rc, dest, progress, trusted_pubkey.as_deref(), max_kbps).await
+    }
+
+    /// 델타 패치로 스테이징 시도 (성공 시 받은 바이트 수와 스테이징 zip의 SHA256, 해당 없음/실패 시 None)
+    ///
+    /// 조건: 바이너리 컴포넌트이고, manifest의 `patch_from`에 현재 로컬 버전용 패치와
+    /// `binary_sha256`이 있으며, 설치된 주 바이너리가 존재해야 한다.
+    /// 패치 결과는 전체 에셋과 같은 형태(루트에 주 바이너리가 있는 zip)로 `dest`에 만든다.
+    async fn try_download_patch(
+        &self,
+        key: &str,
+        rc: &ResolvedComponent,
+        dest: &Path,
+        progress: Option<&Arc<StdMutex<DownloadProgress>>>,
+        max_kbps: Option<u32>,
+    ) -> Option<(u64, String)> {
+        if rc.patch_from.is_empty() {
+            return None;
+        }
+        let component = Component::try_from_manifest_key(key)?;
+        if !matches!(component, Component::CoreDaemon | Component::Cli | Component::Gui | Component::Updater) {
+            return None;
+        }
+        let expected = rc.binary_sha256.as_deref().filter(|h| !h.is_empty())?;
+        let current = self.status.components.iter()
+            .find(|c| c.component == component)
+            .map(|c| c.current_version.trim_start_matches('v').to_string())?;
+        let patch_url = rc.patch_from.get(&current)?;
+        let file_name = Self::primary_binary_file_name(key)?;
+        let live = self.install_root.join(&file_name);
+        if !live.is_file() {
+            return None;
+        }
+
+        tracing::info!(
+            "[Updater] Applying delta patch for {} {} → {}",
+            key, current, rc.latest_version
+        );
+        let patch_path = dest.with_file_name(format!("{}.patch", rc.asset_name));
+        let result = self
+            .stage_patched_binary(&live, patch_url, &patch_path, expected, &file_name, dest, progress, max_kbps)
+            .await;
+        std::fs::remove_file(&patch_path).ok();
+        match result {
+            Ok((received, sha256)) => {
+                tracing::info!("[Updater] Delta patch applied for {} ({} bytes downloaded)", key, received);
+                Some((received, sha256))
+            }
+            Err(e) => {
+                tracing::warn!("[Updater] Delta patch for {} failed, falling back to full download: {}", key, e);
+                std::fs::remove_file(dest).ok();
+                None
+            }
+        }
+    }
+
+    /// 패치를 내려받아 설치된 바이너리에 적용하고, SHA256 검증 후 zip으로 스테이징
+    ///
+    /// 반환: (받은 패치 바이트 수, 기록한 zip의 SHA256)
+    #[allow(clippy::too_many_arguments)]
+    async fn stage_patched_binary(
+        &self,
+        live: &Path,
+        patch_url: &str,
+        patch_path: &Path,
+        expected_sha256: &str,
+        file_name: &str,
+        dest: &Path,
+        progress: Option<&Arc<StdMutex<DownloadProgress>>>,
+        max_kbps: Option<u32>,
+    ) -> Result<(u64, String)> {
+        use std::io::Write;
+
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let received = self.source.download(patch_url, patch_path, progress, max_kbps).await?;
+        let patched = delta::apply_bsdiff_patch(&std::fs::read(live)?, &std::fs::read(patch_path)?)?;
+
+        let actual = integrity::compute_sha256_bytes(&patched);
+        if !actual.eq_ignore_ascii_case(expected_sha256) {
+            return Err(anyhow::anyhow!(UpdaterError::ValidationError {
+                component: file_name.to_string(),
+                expected: expected_sha256.to_string(),
+                actual,
+            }));
+        }
+
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        let options = zip::write::FileOptions::default().unix_permissions(0o755);
+        zip.start_file(file_name, options)?;
+        zip.write_all(&patched)?;
+        let archive = zip.finish()?.into_inner();
+        std::fs::write(dest, &archive)?;
+        Ok((received, integrity::compute_sha256_bytes(&archive)))
+    }
+
+    /// 응답 본문을 파일로 스트리밍 (progress가 있으면 수신 바이트 갱신, max_kbps가 있으면 대역폭 제한)
+    async fn stream_to_file(
+        response: reqwest::Response,
+        dest: &Path,
+        progress: Option<&Arc<StdMutex<DownloadProgress>>>,
+        max_kbps: Option<u32>,
+    ) -> Result<u64> {
+        use futures_util::StreamExt;
+        use std::io::Write;
+
+        if let Some(progress) = progress {
+            if let Ok(mut prog) = progress.lock() {
+                prog.bytes_received = 0;
+                prog.total_bytes = response.content_length().unwrap_or(0);
+            }
+        }
+
+        let mut file = std::fs::File::create(dest)?;
+        let mut received: u64 = 0;
+        let mut throttle = DownloadThrottle::new(max_kbps);
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            file.write_all(&chunk)?;
+            if let Some(throttle) = throttle.as_mut() {
+                throttle.consume(chunk.len()).await;
+            }
+            received += chunk.len() as u64;
+            if let Some(progress) = progress {
+                if let Ok(mut prog) = progress.lock() {
+                    prog.bytes_received = received;
+                }
+            }
+        }
+        file.flush()?;
+        Ok(received)
+    }
+
+    /// 릴리즈 서명 검증 — 신뢰 공개키가 없으면 건너뛰고, 있는데 서명이 없으면 거부
+    ///
+    /// 서명 대상은 에셋 본문이 아니라 그 SHA256 다이제스트(소문자 hex 텍스트)이므로
+    /// 이미 계산한 해시만으로 검증하고 에셋 전체를 메모리에 올리지 않는다.
+    async fn verify_download_signature(
+        source: &dyn ReleaseSource,
+        rc: &ResolvedComponent,
+        path: &Path,
+        sha256: &str,
+        trusted_pubkey: Option<&str>,
+    ) -> Result<()> {
+        let pubkey = match trusted_pubkey {
+            Some(k) => k,
+            None => {
+                if rc.signature_url.is_some() {
+                    tracing::debug!(
+                        "[Updater] {} is signed but no trusted key is configured — skipping signature check",
+                        rc.asset_name
+                    );
+                }
+                return Ok(());
+            }
+        };
+
+        let sig_url = rc.signature_url.as_deref().ok_or_else(|| {
+            anyhow::anyhow!(UpdaterError::ValidationError {
+                component: rc.asset_name.clone(),
+                expected: "ed25519 signature".to_string(),
+                actual: "signature missing".to_string(),
+            })
+        })?;
+
+        let sig_path = path.with_file_name(format!("{}.sig", rc.asset_name));
+        let fetched = source.download(sig_url, &sig_path, None, None).await;
+        let signature = fetched.and_then(|_| Ok(std::fs::read(&sig_path)?));
+        std::fs::remove_file(&sig_path).ok();
+        let signature = signature?;
+        let digest = sha256.to_ascii_lowercase();
+        integrity::verify_ed25519_signature(pubkey, digest.as_bytes(), &signature).map_err(|e| {
+            anyhow::anyhow!(UpdaterError::ValidationError {
+                component: rc.asset_name.clone(),
+                expected: "valid ed25519 signature".to_string(),
+                actual: e,
+            })
+        })?;
+
+        tracing::info!("[Updater] Signature verified for {}", rc.asset_name);
+        Ok(())
+    }
+
+    /// 서명 검증에 사용할 신뢰 공개키 (설정값 → 빌드 내장 키 순)
+    fn trusted_pubkey(&self) -> Option<String> {
+        self.config.trusted_pubkey.as_deref()
+            .or(crate::constants::TRUSTED_SIGNING_PUBKEY)
+            .map(str::trim)
+            .filter(|k| !k.is_empty())
+            .map(String::from)
+    }
+
+    /// zip/tar.gz 에셋이 실제로 열리는지 확인 — 200 응답으로 저장된 HTML 오류 페이지 등을
+    /// 적용 단계가 아니라 다운로드 직후에 걸러낸다 (그 밖의 형식은 통과)
+    fn verify_download_archive(label: &str, path: &Path) -> Result<()> {
+        let corrupt = |message: String| anyhow::anyhow!(UpdaterError::CorruptDownload {
+            asset: label.to_string(),
+            message,
+        });
+        if path.extension().is_some_and(|e| e.eq_ignore_ascii_case("zip")) {
+            zip::ZipArchive::new(std::fs::File::open(path)?).map_err(|e| corrupt(e.to_string()))?;
+        } else if Self::is_tar_gz(path) {
+            let decoder = flate2::read::GzDecoder::new(std::fs::File::open(path)?);
+            let mut archive = tar::Archive::new(decoder);
+            for entry in archive.entries().map_err(|e| corrupt(e.to_string()))? {
+                entry.map_err(|e| corrupt(e.to_string()))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// manifest에 기록된 SHA256과 다운로드 파일 비교 (해시가 없으면 통과) — 계산한 SHA256 반환
+    fn verify_download_sha256(label: &str, path: &Path, expected: Option<&str>) -> Result<String> {
+        let actual = integrity::compute_sha256(path).map_err(|e| anyhow::anyhow!(e))?;
+        match expected {
+            Some(expected) if !expected.is_empty() && !actual.eq_ignore_ascii_case(expected) => {
+                Err(anyhow::anyhow!(UpdaterError::ValidationError {
+                    component: label.to_string(),
+                    expected: expected.to_string(),
+                    actual,
+                }))
+            }
+            _ => Ok(actual),
+        }
+    }
+
+    pub(crate) async fn get_with_strategy(
+        url: &str,
+        label: &str,
+        mut strategy: RecoveryStrategy,
+    ) -> Result<reqwest::Response> {
+        let max_attempts = strategy.max_retries + 1;
+        loop {
+            let attempt = strategy.current_attempt + 1;
+            let error = match reqwest::get(url).await {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) => UpdaterError::ApiError {
+                    status_code: response.status().as_u16(),
+                    message: format!("Failed to download {}: {}", label, response.status()),
+                },
+                Err(e) => UpdaterError::from_reqwest(&e, "download"),
+            };
+
+            if !error.is_recoverable() || !strategy.can_retry() {
+                if attempt > 1 {
+                    tracing::error!(
+                        "[Updater] Download {} failed after {} attempt(s): {}",
+                        label, attempt, error
+                    );
+                }
+                return Err(anyhow::anyhow!(error));
+            }
+
+            let delay = strategy.next_delay();
+            tracing::warn!(
+                "[Updater] Download {} failed (attempt {}/{}), retrying in {:?}: {}",
+                label, attempt, max_attempts, delay, error
+            );
+            tokio::time::sleep(delay).await;
+            strategy.increment();
+        }
     }
 
     fn rename_with_retry(from: &Path, to: &Path, max_retries: u32) -> Result<()> {
+        Self::rename_with_retry_using(from, to, max_retries, |a, b| std::fs::rename(a, b))
+    }
+
+    /// `rename_with_retry` 본체 — 테스트에서 rename 실패를 주입할 수 있도록 분리
+    fn rename_with_retry_using(
+        from: &Path,
+        to: &Path,
+        max_retries: u32,
+        rename: impl Fn(&Path, &Path) -> std::io::Result<()>,
+    ) -> Result<()> {
         // 기존 백업 파일이 있으면 먼저 삭제 시도
         if to.exists() {
             std::fs::remove_file(to).ok();
@@ -2088,7 +4381,7 @@ impl UpdateManager {
 
         let mut last_err = None;
         for attempt in 0..=max_retries {
-            match std::fs::rename(from, to) {
+            match Self::move_file_using(from, to, &rename) {
                 Ok(()) => return Ok(()),
                 Err(e) => {
                     last_err = Some(e);
@@ -2110,52 +4403,72 @@ impl UpdateManager {
         ))
     }
 
-    async fn apply_binary_update(&self, binary_name: &str, staged_path: &str) -> Result<()> {
-        let staged = Path::new(staged_path);
-
-        let exe_dir = self.install_root.clone();
+    /// 파일 이동 — 다른 파일시스템 간 이동이면 복사 후 원본 삭제로 대체
+    ///
+    /// 스테이징 디렉터리(%APPDATA% 등)와 install_root가 다른 드라이브에 있으면
+    /// `std::fs::rename`이 EXDEV(`CrossesDevices`)로 실패한다.
+    fn move_file(from: &Path, to: &Path) -> std::io::Result<()> {
+        Self::move_file_using(from, to, |a, b| std::fs::rename(a, b))
+    }
 
-        // Windows: 대상 프로세스가 실행 중이라면 종료를 대기
-        #[cfg(target_os = "windows")]
-        {
-            let process_names: Vec<&str> = match binary_name {
-                n if n.contains("daemon") || n.contains("core") => vec!["saba-core.exe"],
-                n if n.contains("cli") => vec!["saba-chan-cli.exe"],
-                n if n.contains("gui") => vec!["saba-chan-gui.exe"],
-                _ => vec![],
-            };
-            for proc in &process_names {
-                if ProcessChecker::is_running(proc) {
-                    tracing::info!("[Updater] Waiting for {} to exit before applying update...", proc);
-                    let exited = ProcessChecker::wait_for_exit(proc, 15).await;
-                    if !exited {
-                        tracing::warn!("[Updater] {} did not exit within timeout, attempting update anyway", proc);
-                    }
+    fn move_file_using(
+        from: &Path,
+        to: &Path,
+        rename: impl Fn(&Path, &Path) -> std::io::Result<()>,
+    ) -> std::io::Result<()> {
+        match rename(from, to) {
+            Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {
+                tracing::info!(
+                    "[Updater] {} -> {} crosses filesystems, falling back to copy",
+                    from.display(), to.display()
+                );
+                std::fs::copy(from, to)?;
+                if let Err(e) = std::fs::remove_file(from) {
+                    // 원본을 지울 수 없으면 이동 실패로 간주하고 복사본 정리
+                    std::fs::remove_file(to).ok();
+                    return Err(e);
                 }
+                Ok(())
             }
+            other => other,
         }
+    }
 
-        #[cfg(not(target_os = "windows"))]
-        {
-            let process_names: Vec<&str> = match binary_name {
-                n if n.contains("daemon") || n.contains("core") => vec!["saba-core"],
-                n if n.contains("cli") => vec!["saba-chan-cli"],
-                n if n.contains("gui") => vec!["saba-chan-gui"],
-                _ => vec![],
-            };
-            for proc in &process_names {
-                if ProcessChecker::is_running(proc) {
-                    tracing::info!("[Updater] Waiting for {} to exit before applying update...", proc);
-                    let exited = ProcessChecker::wait_for_exit(proc, 15).await;
-                    if !exited {
-                        tracing::warn!("[Updater] {} did not exit within timeout, attempting update anyway", proc);
-                    }
-                }
+    /// 실행파일 업데이트 적용 — 종료를 기다린 프로세스 이름 목록을 반환
+    async fn apply_binary_update(&self, binary_name: &str, staged_path: &str) -> Result<Vec<String>> {
+        let staged = Path::new(staged_path);
+
+        let exe_dir = self.install_root.clone();
+
+        // 대상 프로세스가 실행 중이라면 종료를 대기 (모든 플랫폼)
+        // 시간 내에 종료되지 않으면 실행 중인 바이너리를 덮어쓰지 않고 실패
+        let process_name = match binary_name {
+            n if n.contains("daemon") || n.contains("core") => Some("saba-core"),
+            n if n.contains("cli") => Some("saba-chan-cli"),
+            n if n.contains("gui") => Some("saba-chan-gui"),
+            _ => None,
+        };
+        let mut stopped = Vec::new();
+        if let Some(name) = process_name {
+            let proc = format!("{}{}", name, std::env::consts::EXE_SUFFIX);
+            if ProcessChecker::ensure_exited(&proc, PROCESS_EXIT_TIMEOUT_SECS).await? {
+                stopped.push(proc);
             }
         }
 
         tracing::info!("[Updater] Applying binary update: {} in {}", binary_name, exe_dir.display());
 
+        // 아카이브에 주 실행파일이 없으면 아무것도 건드리지 않고 실패
+        let primary = Self::primary_binary_file_name(binary_name);
+        if let Some(ref primary) = primary {
+            if !Self::archive_contains_root_file(staged, primary)? {
+                anyhow::bail!(
+                    "Update archive for {} does not contain {}",
+                    binary_name, primary
+                );
+            }
+        }
+
         if staged.extension().map(|e| e == "zip").unwrap_or(false) {
             let file = std::fs::File::open(staged)?;
             let mut archive = zip::ZipArchive::new(file)?;
@@ -2181,16 +4494,35 @@ impl UpdateManager {
                         }
                     } else if cfg!(unix) && out_path.exists() && Self::is_known_binary(&out_path) {
                         let backup = out_path.with_extension("old");
-                        let _ = std::fs::rename(&out_path, &backup);
+                        let _ = Self::move_file(&out_path, &backup);
                     }
                     let mut outfile = std::fs::File::create(&out_path)?;
                     std::io::copy(&mut entry, &mut outfile)?;
+
+                    // Linux: 실행 파일에 실행 권한 부여
+                    #[cfg(unix)]
+                    if Self::is_known_binary(&out_path) {
+                        use std::os::unix::fs::PermissionsExt;
+                        let mut perms = std::fs::metadata(&out_path)?.permissions();
+                        perms.set_mode(perms.mode() | 0o755);
+                        std::fs::set_permissions(&out_path, perms)?;
+                    }
                 }
             }
         } else if Self::is_tar_gz(staged) {
             Self::extract_tar_gz(staged, &exe_dir)?;
         }
 
+        // 추출된 주 실행파일 검증 — 실행 불가능하면 .old 백업으로 복원
+        if let Some(ref primary) = primary {
+            let live = exe_dir.join(primary);
+            if let Err(e) = Self::verify_executable(&live) {
+                tracing::error!("[Updater] Extracted {} is not a valid executable: {}", live.display(), e);
+                Self::restore_binary_backup(&live);
+                anyhow::bail!("Update for {} produced an invalid executable ({}); previous binary restored", binary_name, e);
+            }
+        }
+
         std::fs::remove_file(staged).ok();
         tracing::info!("[Updater] Binary '{}' updated", binary_name);
 
@@ -2199,11 +4531,11 @@ impl UpdateManager {
             self.install_shared_modules();
         }
 
-        Ok(())
+        Ok(stopped)
     }
 
-    /// GUI 업데이트 적용
-    async fn apply_gui_update(&self, staged_path: &str) -> Result<()> {
+    /// GUI 업데이트 적용 — 종료를 기다린 프로세스 이름 목록을 반환
+    async fn apply_gui_update(&self, staged_path: &str) -> Result<Vec<String>> {
         let staged = Path::new(staged_path);
 
         // Portable exe mode: install_root/saba-chan-gui(.exe)
@@ -2212,6 +4544,32 @@ impl UpdateManager {
         } else {
             "saba-chan-gui"
         };
+
+        // 실행 중인 GUI를 덮어쓰지 않도록 종료를 대기 (모든 플랫폼)
+        // Linux AppImage: 실행 중이어도 파일을 교체할 수 있으므로 종료 대기 없이 교체
+        // (실행 중인 GUI는 이전 inode를 유지하며, 재시작 시 새 버전이 실행됨)
+        #[cfg(target_os = "linux")]
+        if let Some(appimage) = linux_appimage::find_appimage(&self.install_root) {
+            tracing::info!("[Updater] GUI AppImage detected at {}", appimage.display());
+            linux_appimage::replace_appimage(staged, &appimage)?;
+            std::fs::remove_file(staged).ok();
+            return Ok(Vec::new());
+        }
+
+        let mut stopped = Vec::new();
+        if ProcessChecker::ensure_exited(gui_exe_name, PROCESS_EXIT_TIMEOUT_SECS).await? {
+            stopped.push(gui_exe_name.to_string());
+        }
+
+        // macOS: Saba-chan.app 번들 전체를 교체
+        #[cfg(target_os = "macos")]
+        if let Some(bundle) = macos_bundle::find_bundle(&self.install_root) {
+            tracing::info!("[Updater] GUI app bundle detected at {}", bundle.display());
+            macos_bundle::replace_bundle(staged, &bundle)?;
+            macos_bundle::restamp_bundle(&bundle);
+            std::fs::remove_file(staged).ok();
+            return Ok(stopped);
+        }
         let portable_exe = self.install_root.join(gui_exe_name);
         if portable_exe.exists() {
             tracing::info!("[Updater] GUI portable exe detected at {}", portable_exe.display());
@@ -2238,7 +4596,7 @@ impl UpdateManager {
                         } else if cfg!(unix) && out_path.exists() && Self::is_known_binary(&out_path) {
                             // Linux: 알려진 바이너리를 .old로 백업 후 교체
                             let backup = out_path.with_extension("old");
-                            let _ = std::fs::rename(&out_path, &backup);
+                            let _ = Self::move_file(&out_path, &backup);
                         }
                         let mut outfile = std::fs::File::create(&out_path)?;
                         std::io::copy(&mut entry, &mut outfile)?;
@@ -2249,7 +4607,7 @@ impl UpdateManager {
             }
             std::fs::remove_file(staged).ok();
             tracing::info!("[Updater] GUI (portable exe) updated");
-            return Ok(());
+            return Ok(stopped);
         }
 
         // Directory mode fallback (unpacked Electron / dev)
@@ -2263,10 +4621,14 @@ impl UpdateManager {
                 if res_build.exists() {
                     res_build
                 } else {
-                    if gui_dir.join("src").exists() && gui_dir.join("package.json").exists() {
+                    if let Some(reason) = Self::gui_source_tree_reason(&gui_dir) {
+                        tracing::warn!(
+                            "[Updater] Refusing to apply GUI update to {}: {}",
+                            gui_dir.display(), reason
+                        );
                         anyhow::bail!(
-                            "GUI directory appears to be a source tree ({}). Refusing to overwrite.",
-                            gui_dir.display()
+                            "GUI directory appears to be a source tree ({}): {}. Refusing to overwrite.",
+                            gui_dir.display(), reason
                         );
                     }
                     gui_dir.clone()
@@ -2295,7 +4657,7 @@ impl UpdateManager {
         }
         std::fs::remove_file(staged).ok();
         tracing::info!("[Updater] GUI updated successfully");
-        Ok(())
+        Ok(stopped)
     }
 
     /// 코어 데몬의 업데이트를 준비 (재시작 후 적용)
@@ -2403,6 +4765,136 @@ rm -f "$0"
         Ok(Some(result_script_path))
     }
 
+    /// 업데이터 자신의 바이너리 교체
+    ///
+    /// 실행 중인 업데이터는 Windows에서 자기 exe를 덮어쓸 수 없으므로
+    /// 새 바이너리를 `saba-chan-updater(.exe).new`로 스테이징하고, 현재 프로세스가
+    /// 종료된 뒤 교체하는 분리 스크립트를 실행한다 (`prepare_daemon_update`와 같은 방식).
+    /// 데몬 등 다른 프로세스가 적용하는 경우에는 일반 바이너리 교체로 처리한다.
+    ///
+    /// 교체가 프로세스 종료 후로 미뤄졌으면 `true`를 반환한다.
+    async fn apply_updater_update(&self, staged_path: &str) -> Result<bool> {
+        if !Self::is_running_as_updater() {
+            self.apply_binary_update("saba-chan-updater", staged_path).await?;
+            return Ok(false);
+        }
+
+        let exe_name = if cfg!(target_os = "windows") { "saba-chan-updater.exe" } else { "saba-chan-updater" };
+        let exe_path = self.install_root.join(exe_name);
+        let new_path = self.install_root.join(format!("{}.new", exe_name));
+
+        // 임시 디렉터리에 풀어서 업데이터 바이너리만 .new로 빼고 나머지 파일은 바로 설치
+        let unpack_dir = self.staging_dir.join("updater-new");
+        if unpack_dir.exists() {
+            std::fs::remove_dir_all(&unpack_dir)?;
+        }
+        Self::extract_to_directory(Path::new(staged_path), &unpack_dir).await?;
+
+        let unpacked_exe = unpack_dir.join(exe_name);
+        if !unpacked_exe.exists() {
+            std::fs::remove_dir_all(&unpack_dir).ok();
+            anyhow::bail!("Updater package does not contain {}", exe_name);
+        }
+        std::fs::copy(&unpacked_exe, &new_path)?;
+        std::fs::remove_file(&unpacked_exe)?;
+        self.copy_dir_recursive(&unpack_dir, &self.install_root)?;
+        std::fs::remove_dir_all(&unpack_dir).ok();
+
+        let script_name = if cfg!(target_os = "windows") { "_update_updater.ps1" } else { "_update_updater.sh" };
+        let script_path = self.install_root.join(script_name);
+        let script = Self::updater_replacer_script(std::process::id(), &exe_path, &new_path);
+        std::fs::write(&script_path, script)?;
+
+        #[cfg(target_os = "windows")]
+        let mut cmd = {
+            use std::os::windows::process::CommandExt;
+            let mut cmd = std::process::Command::new("powershell");
+            cmd.args(["-NoProfile", "-ExecutionPolicy", "Bypass", "-WindowStyle", "Hidden", "-File"])
+                .arg(&script_path);
+            // DETACHED_PROCESS | CREATE_BREAKAWAY_FROM_JOB | CREATE_NEW_PROCESS_GROUP
+            cmd.creation_flags(0x00000008 | 0x01000000 | 0x00000200);
+            cmd
+        };
+
+        #[cfg(not(target_os = "windows"))]
+        let mut cmd = {
+            let mut cmd = std::process::Command::new("sh");
+            cmd.arg(&script_path);
+            cmd
+        };
+
+        cmd.stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()?;
+
+        std::fs::remove_file(staged_path).ok();
+        tracing::info!(
+            "[Updater] Updater binary staged as {} — will be swapped after this process exits",
+            new_path.display()
+        );
+        Ok(true)
+    }
+
+    /// 현재 프로세스가 업데이터 실행 파일인지
+    fn is_running_as_updater() -> bool {
+        std::env::current_exe()
+            .ok()
+            .and_then(|p| p.file_stem().map(|s| s.to_string_lossy().into_owned()))
+            .map(|stem| stem == "saba-chan-updater")
+            .unwrap_or(false)
+    }
+
+    /// 업데이터 프로세스(`pid`) 종료를 기다렸다가 `new_exe`를 `exe` 자리로 옮기는 스크립트
+    pub(crate) fn updater_replacer_script(pid: u32, exe: &Path, new_exe: &Path) -> String {
+        if cfg!(target_os = "windows") {
+            format!(
+                r#"# saba-chan updater self-update script
+$exePath = "{exe}"
+$newPath = "{new}"
+
+# Wait for the updater process to exit
+Wait-Process -Id {pid} -Timeout 60 -ErrorAction SilentlyContinue
+
+for ($i = 0; $i -lt 10; $i++) {{
+    try {{
+        Move-Item -Force $newPath $exePath -ErrorAction Stop
+        break
+    }} catch {{
+        Start-Sleep -Milliseconds 500
+    }}
+}}
+Remove-Item -Force $MyInvocation.MyCommand.Source -ErrorAction SilentlyContinue
+"#,
+                exe = exe.display(),
+                new = new_exe.display(),
+                pid = pid,
+            )
+        } else {
+            format!(
+                r#"#!/bin/sh
+# saba-chan updater self-update script
+EXE="{exe}"
+NEW="{new}"
+
+# Wait for the updater process to exit (max 60s)
+i=0
+while kill -0 {pid} 2>/dev/null && [ $i -lt 120 ]; do
+    sleep 0.5
+    i=$((i + 1))
+done
+
+mv -f "$NEW" "$EXE"
+chmod +x "$EXE"
+rm -f "$0"
+"#,
+                exe = exe.display(),
+                new = new_exe.display(),
+                pid = pid,
+            )
+        }
+    }
+
     // ─────── 유틸리티 ────────────────────────────────────────────────────────────────────────
 
     fn find_gui_directory(&self) -> Result<PathBuf> {
@@ -2578,7 +5070,42 @@ rm -f "$0"
         Ok(())
     }
 
+    /// 모듈 디렉터리를 비움 (`__pycache__`와 dotfile은 유지)
+    ///
+    /// `module.toml`의 `[update] preserve = ["data/", "config.yml"]`에 나열된 경로
+    /// (모듈 디렉터리 기준 상대 경로)는 삭제하지 않는다. 목록이 없으면 기본 동작만 적용.
     fn clean_module_dir(&self, dir: &Path) -> Result<()> {
+        let preserve = Self::module_preserve_list(dir);
+        self.clean_dir_preserving(dir, Path::new(""), &preserve)
+    }
+
+    /// `clean_module_dir`가 남기는 항목(`__pycache__`, dotfile, 보존 목록)만 `dst`로 복사
+    fn copy_kept_entries(&self, src: &Path, dst: &Path, rel: &Path, preserve: &[PathBuf]) -> Result<()> {
+        std::fs::create_dir_all(dst)?;
+        for entry in std::fs::read_dir(src)?.flatten() {
+            let path = entry.path();
+            let name = entry.file_name();
+            let name_str = name.to_string_lossy();
+            let entry_rel = rel.join(&name);
+            let dest = dst.join(&name);
+
+            let kept = name_str == "__pycache__"
+                || name_str.starts_with('.')
+                || preserve.contains(&entry_rel);
+            if kept {
+                if path.is_dir() {
+                    self.copy_dir_recursive(&path, &dest)?;
+                } else {
+                    std::fs::copy(&path, &dest)?;
+                }
+            } else if path.is_dir() && preserve.iter().any(|p| p.starts_with(&entry_rel)) {
+                self.copy_kept_entries(&path, &dest, &entry_rel, preserve)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn clean_dir_preserving(&self, dir: &Path, rel: &Path, preserve: &[PathBuf]) -> Result<()> {
         for entry in std::fs::read_dir(dir)?.flatten() {
             let path = entry.path();
             let name = entry.file_name();
@@ -2591,8 +5118,18 @@ rm -f "$0"
                 continue;
             }
 
+            let entry_rel = rel.join(&name);
+            if preserve.contains(&entry_rel) {
+                continue;
+            }
+
             if path.is_dir() {
-                std::fs::remove_dir_all(&path)?;
+                // 하위에 보존 경로가 있으면 디렉터리째 지우지 않고 안쪽만 정리
+                if preserve.iter().any(|p| p.starts_with(&entry_rel)) {
+                    self.clean_dir_preserving(&path, &entry_rel, preserve)?;
+                } else {
+                    std::fs::remove_dir_all(&path)?;
+                }
             } else {
                 std::fs::remove_file(&path)?;
             }
@@ -2600,6 +5137,44 @@ rm -f "$0"
         Ok(())
     }
 
+    /// 모듈 디렉터리의 `module.toml` `[update].preserve` 목록을 상대 경로로 읽음
+    ///
+    /// 절대 경로나 `..`를 포함한 항목은 모듈 밖을 가리킬 수 있으므로 무시한다.
+    fn module_preserve_list(dir: &Path) -> Vec<PathBuf> {
+        let Ok(content) = std::fs::read_to_string(dir.join("module.toml")) else {
+            return Vec::new();
+        };
+        let Ok(parsed) = content.parse::<toml::Value>() else {
+            return Vec::new();
+        };
+        let Some(items) = parsed.get("update")
+            .and_then(|u| u.get("preserve"))
+            .and_then(|v| v.as_array())
+        else {
+            return Vec::new();
+        };
+
+        items.iter()
+            .filter_map(|v| v.as_str())
+            .filter_map(|raw| {
+                let trimmed = raw.trim().trim_end_matches(['/', '\\']);
+                let path = PathBuf::from(trimmed);
+                let safe = !trimmed.is_empty()
+                    && path.components().all(|c| matches!(c, std::path::Component::Normal(_)));
+                if !safe {
+                    tracing::warn!("[Updater] Ignoring unsafe preserve entry {:?} in {}", raw, dir.display());
+                    return None;
+                }
+                Some(path)
+            })
+            .collect()
+    }
+
+    /// 상대 경로가 보존 목록의 항목이거나 그 하위인지 확인
+    fn is_preserved(rel: &Path, preserve: &[PathBuf]) -> bool {
+        preserve.iter().any(|p| rel.starts_with(p))
+    }
+
     fn copy_dir_recursive(&self, src: &Path, dst: &Path) -> Result<()> {
         std::fs::create_dir_all(dst)?;
         for entry in std::fs::read_dir(src)?.flatten() {
@@ -2664,6 +5239,95 @@ rm -f "$0"
         }
     }
 
+    /// 컴포넌트가 설치되어 있고 로컬 버전이 `min_version` 이상인지 확인
+    ///
+    /// 버전은 디스크 메타데이터에서 감지한 값을 우선하고, 감지할 수 없으면 installed-manifest
+    /// 기록을 사용합니다. 버전을 알 수 없으면 "설치됐지만 너무 오래됨"과 같이 취급합니다.
+    pub fn is_component_installed_at_least(&self, component: &Component, min_version: &str) -> bool {
+        self.installed_at_least(component, None, min_version)
+    }
+
+    /// `is_component_installed_at_least` 구현 — `known_version`이 있으면 감지 대신 사용
+    fn installed_at_least(&self, component: &Component, known_version: Option<&str>, min_version: &str) -> bool {
+        if !self.is_component_installed(component) {
+            return false;
+        }
+        let local = known_version.map(String::from)
+            .or_else(|| self.detect_disk_version(component))
+            .or_else(|| match component {
+                Component::CoreDaemon => Some(env!("CARGO_PKG_VERSION").to_string()),
+                _ => Self::load_installed_manifest().remove(&component.manifest_key()),
+            });
+        version_satisfies(local.as_deref(), min_version)
+    }
+
+    /// installed-manifest.json을 실제 설치 상태와 대조하여 불일치 목록을 반환
+    ///
+    /// 각 컴포넌트에 대해 `is_component_installed`와 같은 기준으로 존재 여부를 확인하고,
+    /// 디스크에서 버전을 감지할 수 있으면 기록된 버전과 비교합니다.
+    pub fn verify_installed(&self) -> Vec<(String, VerifyIssue)> {
+        self.verify_installed_against(&Self::load_installed_manifest())
+    }
+
+    pub(crate) fn verify_installed_against(&self, installed: &HashMap<String, String>) -> Vec<(String, VerifyIssue)> {
+        let mut keys: Vec<&String> = installed.keys().collect();
+        keys.sort();
+
+        let mut issues = Vec::new();
+        for key in keys {
+            let Some(component) = Component::try_from_manifest_key(key) else {
+                issues.push((key.clone(), VerifyIssue::UnknownComponent));
+                continue;
+            };
+            if !self.is_component_installed(&component) {
+                issues.push((key.clone(), VerifyIssue::Missing));
+                continue;
+            }
+            let recorded = &installed[key];
+            if let Some(detected) = self.detect_disk_version(&component) {
+                if !Self::same_version(recorded, &detected) {
+                    issues.push((key.clone(), VerifyIssue::VersionMismatch {
+                        recorded: recorded.clone(),
+                        detected,
+                    }));
+                }
+            }
+        }
+
+        if issues.is_empty() {
+            tracing::info!("[Updater] Installed manifest verified: {} component(s) in sync", installed.len());
+        } else {
+            tracing::warn!("[Updater] Installed manifest drift: {:?}", issues);
+        }
+        issues
+    }
+
+    /// 디스크의 메타데이터 파일에서 컴포넌트 버전 감지 (감지할 수 없으면 None)
+    ///
+    /// 코어 데몬처럼 바이너리만 있는 컴포넌트는 실행하지 않고는 버전을 알 수 없으므로 None.
+    fn detect_disk_version(&self, component: &Component) -> Option<String> {
+        match component {
+            Component::Module(name) => {
+                Self::read_module_entry(&self.modules_dir.join(name).join("module.toml"))?.version
+            }
+            Component::Extension(name) => {
+                let ext_dir = self.resolve_ext_dir(name);
+                self.read_extension_version(&ext_dir.join("extension.toml"))
+                    .map(|(_, v)| v)
+                    .or_else(|| {
+                        let content = std::fs::read_to_string(ext_dir.join("manifest.json")).ok()?;
+                        let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+                        json.get("version").and_then(|v| v.as_str()).map(String::from)
+                    })
+            }
+            Component::Gui => self.read_package_json_version("saba-chan-gui"),
+            Component::DiscordBot => self.read_package_json_version("discord_bot"),
+            Component::Cli => self.read_cargo_version("saba-chan-cli"),
+            Component::Updater => self.read_cargo_version("updater"),
+            Component::CoreDaemon | Component::Locales => None,
+        }
+    }
+
     /// 전체 컴포넌트의 설치 현황 반환
     pub fn get_install_status(&self) -> InstallStatus {
         let components: Vec<(Component, bool)> = vec![
@@ -2736,193 +5400,306 @@ rm -f "$0"
                 display_name: c.display_name(),
                 installed: i,
             }).collect(),
-            progress: self.install_progress.clone(),
+            progress: self.get_install_progress(),
         }
     }
 
     /// 미설치된 컴포넌트를 일괄 설치하는 초기 설치 (릴리즈 횡단 탐색)
     ///
-    /// 각 릴리즈의 manifest 에셋 정보를 기반으로 컴포넌트별 최신 에셋을 찾아 개별 다운로드합니다.
-    /// 미설치된 필수 컴포넌트를 설치하는 초기 설치 (릴리즈 횡단 탐색 지원)
-    ///
     /// resolved_components를 활용하여 에셋이 포함된 릴리즈에서 개별 다운로드.
-    pub async fn fresh_install(&mut self, components_filter: Option<Vec<String>>) -> Result<InstallProgress> {
-        if self.config.github_owner.is_empty() || self.config.github_repo.is_empty() {
-            anyhow::bail!("GitHub owner/repo not configured — cannot install");
-        }
+    /// 이미 설치된 컴포넌트는 버전과 무관하게 건너뛰고 `skipped_components`에 기록합니다.
+    pub async fn fresh_install(&mut self, components_filter: Option<Vec<String>>) -> UpdaterResult<InstallProgress> {
+        self.run_install(components_filter, false).await.map_err(UpdaterError::from)
+    }
 
-        let client = self.create_client();
+    /// 설치 또는 갱신 (복구/재설치 플로우)
+    ///
+    /// 미설치 컴포넌트는 새로 설치하고, 설치되어 있지만 원격보다 오래된 컴포넌트는
+    /// 제자리에서 갱신합니다. 결과는 `installed_components` / `updated_components`로 구분됩니다.
+    pub async fn install_or_update(&mut self, components_filter: Option<Vec<String>>) -> UpdaterResult<InstallProgress> {
+        self.run_install(components_filter, true).await.map_err(UpdaterError::from)
+    }
 
-        // 릴리즈 목록 fetch & 횡단 탐색
-        let releases = client.fetch_releases(30).await?;
-        let (manifest, resolved) = client.resolve_components_across_releases(
-            &releases,
-            self.config.include_prerelease,
-        ).await?;
+    /// 설치 대상 계획 수립
+    ///
+    /// CoreDaemon은 항상 제외. 설치된 컴포넌트는 `update_outdated`이고
+    /// 로컬 버전이 resolved 버전보다 낮을 때만 갱신 대상으로 포함합니다.
+    /// 대상의 `requires` 중 충족되지 않은 컴포넌트는 전이적으로 추가하여 먼저 설치되도록
+    /// 앞쪽에 배치하고, 이 설치로 충족할 수 없는 의존성(CoreDaemon 등)은 `unmet`에 남깁니다.
+    pub(crate) fn plan_install(
+        &self,
+        manifest: &github::ReleaseManifest,
+        resolved: &HashMap<String, github::ResolvedComponent>,
+        components_filter: Option<&[String]>,
+        local_versions: &HashMap<String, String>,
+        update_outdated: bool,
+    ) -> InstallPlan {
+        let mut plan = InstallPlan::default();
 
-        let latest_release = releases.iter()
-            .filter(|r| !r.draft)
-            .find(|r| self.config.include_prerelease || !r.prerelease)
-            .cloned();
-        self.cached_release = latest_release;
-        self.cached_manifest = Some(manifest.clone());
-        self.cached_releases = releases;
-        self.resolved_components = resolved.clone();
+        let mut keys: Vec<&String> = manifest.components.keys().collect();
+        keys.sort();
 
-        // 설치 대상 필터
-        let targets: Vec<(String, github::ComponentInfo)> = manifest.components.iter()
-            .filter(|(key, _)| {
-                if let Some(ref filter) = components_filter {
-                    filter.iter().any(|f| f == *key)
-                } else {
-                    true
-                }
-            })
-            .filter(|(key, _)| {
-                let comp = Component::from_manifest_key(key);
-                if comp == Component::CoreDaemon {
-                    return false;
+        for key in keys {
+            if let Some(filter) = components_filter {
+                if !filter.iter().any(|f| f == key) {
+                    continue;
                 }
-                !self.is_component_installed(&comp)
-            })
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect();
+            }
+            let comp = Component::from_manifest_key(key);
+            if comp == Component::CoreDaemon {
+                continue;
+            }
+            let info = &manifest.components[key];
 
-        if targets.is_empty() {
-            let progress = InstallProgress {
-                complete: true,
-                current_component: None,
-                total: 0,
-                done: 0,
-                installed_components: vec![],
-                errors: vec![],
-            };
-            self.install_progress = Some(progress.clone());
-            return Ok(progress);
+            if !self.is_component_installed(&comp) {
+                plan.targets.push((key.clone(), info.clone(), false));
+                continue;
+            }
+
+            let outdated = update_outdated
+                && resolved.get(key).is_some_and(|rc| {
+                    let current = local_versions.get(key).map(String::as_str).unwrap_or("");
+                    self.compare_versions(&rc.latest_version, current)
+                        && self.config.pin_allows(&comp, &rc.latest_version)
+                });
+            if outdated {
+                plan.targets.push((key.clone(), info.clone(), true));
+            } else {
+                plan.skipped.push(comp.display_name());
+            }
         }
 
-        let total = targets.len();
-        let mut installed = Vec::new();
-        let mut errors = Vec::new();
+        self.add_install_dependencies(&mut plan, manifest, local_versions);
+        plan
+    }
 
-        self.install_progress = Some(InstallProgress {
-            complete: false,
-            current_component: None,
-            total,
-            done: 0,
-            installed_components: vec![],
-            errors: vec![],
-        });
+    /// 설치 대상의 충족되지 않은 `requires`를 계획에 전이적으로 추가
+    fn add_install_dependencies(
+        &self,
+        plan: &mut InstallPlan,
+        manifest: &github::ReleaseManifest,
+        local_versions: &HashMap<String, String>,
+    ) {
+        let mut queue: Vec<String> = plan.targets.iter().map(|(key, ..)| key.clone()).collect();
+        while let Some(key) = queue.pop() {
+            let Some(requires) = manifest.components.get(&key).and_then(|info| info.requires.as_ref()) else {
+                continue;
+            };
+            let mut requires: Vec<(&String, &String)> = requires.iter().collect();
+            requires.sort();
 
-        std::fs::create_dir_all(&self.staging_dir)?;
+            for (dep_key, min_version) in requires {
+                if plan.targets.iter().any(|(k, ..)| k == dep_key) {
+                    continue;
+                }
+                let dep = Component::from_manifest_key(dep_key);
+                let local = local_versions.get(dep_key).map(String::as_str);
+                // 설치돼 있어도 버전이 낮거나 알 수 없으면 갱신 대상
+                if self.installed_at_least(&dep, local, min_version) {
+                    continue;
+                }
+                let installed = self.is_component_installed(&dep);
+
+                let available = manifest.components.get(dep_key)
+                    .filter(|info| version_satisfies(Some(&info.version), min_version))
+                    .filter(|info| dep != Component::CoreDaemon && self.config.pin_allows(&dep, &info.version));
+                let Some(dep_info) = available else {
+                    plan.unmet.push(format!(
+                        "{} requires {} {} but {} is installed",
+                        key, dep_key, min_version, local.unwrap_or("not installed")
+                    ));
+                    continue;
+                };
+
+                tracing::info!("[Installer] Adding {} {} (required by {})", dep_key, dep_info.version, key);
+                plan.skipped.retain(|name| name != &dep.display_name());
+                // 의존성이 먼저 설치되도록 앞쪽에 배치 (더 깊은 의존성일수록 앞으로)
+                plan.targets.insert(0, (dep_key.clone(), dep_info.clone(), installed));
+                plan.dependencies.push(dep_key.clone());
+                queue.push(dep_key.clone());
+            }
+        }
+    }
 
-        for (idx, (key, info)) in targets.iter().enumerate() {
-            let component = Component::from_manifest_key(key);
-            let comp_label = component.display_name();
+    /// `fresh_install` / `install_or_update` 공통 구현
+    async fn run_install(
+        &mut self,
+        components_filter: Option<Vec<String>>,
+        update_outdated: bool,
+    ) -> Result<InstallProgress> {
+        let job = self.prepare_install_impl(components_filter, update_outdated).await?;
+        Ok(job.run().await)
+    }
 
-            if let Some(ref mut prog) = self.install_progress {
-                prog.current_component = Some(comp_label.clone());
-                prog.done = idx;
-            }
+    /// 일괄 설치 준비 — 릴리즈를 갱신하고 설치 계획을 세워 `InstallJob`으로 돌려줌
+    ///
+    /// `update_outdated`가 false면 `fresh_install`, true면 `install_or_update`와 같은 대상을 고른다.
+    /// 다운로드와 압축 해제는 `InstallJob::run`이 Manager 없이 진행하므로, Manager를 잠금으로
+    /// 공유하는 호출자(데몬)는 이 준비 단계에서만 잠금을 쥐면 된다.
+    pub async fn prepare_install(
+        &mut self,
+        components_filter: Option<Vec<String>>,
+        update_outdated: bool,
+    ) -> UpdaterResult<InstallJob> {
+        self.prepare_install_impl(components_filter, update_outdated).await.map_err(UpdaterError::from)
+    }
 
-            tracing::info!("[Installer] ({}/{}) Installing {}...", idx + 1, total, comp_label);
+    async fn prepare_install_impl(
+        &mut self,
+        components_filter: Option<Vec<String>>,
+        update_outdated: bool,
+    ) -> Result<InstallJob> {
+        self.ensure_repo_configured()?;
 
-            // resolved_components에서 다운로드 소스 조회
-            let rc = match resolved.get(key) {
-                Some(rc) => rc,
-                None => {
-                    let err = format!("{}: 에셋을 포함한 릴리즈를 찾지 못함", comp_label);
-                    tracing::warn!("[Installer] {}", err);
-                    errors.push(err);
-                    continue;
-                }
-            };
+        let client = self.create_client();
 
-            let staged_path = self.staging_dir.join(&rc.asset_name);
+        // 릴리즈 목록 fetch & 횡단 탐색
+        let (manifest, resolved) = self.refresh_core_releases(&client).await?;
 
-            // resolved URL에서 다운로드
-            tracing::info!(
-                "[Installer] {} v{} ← release {}",
-                key, rc.latest_version, rc.source_release_tag
-            );
-            let download_result: Result<()> = async {
-                let response = reqwest::get(&rc.download_url).await?;
-                if !response.status().is_success() {
-                    anyhow::bail!("HTTP {}", response.status());
-                }
-                let bytes = response.bytes().await?;
-                std::fs::write(&staged_path, &bytes)?;
-                Ok(())
-            }.await;
+        // 설치 대상 필터 (로컬 버전은 갱신 판단과 의존성 충족 확인에 사용)
+        let local_versions = self.collect_local_versions();
+        let InstallPlan { targets, skipped, dependencies, unmet } = self.plan_install(
+            &manifest,
+            &resolved,
+            components_filter.as_deref(),
+            &local_versions,
+            update_outdated,
+        );
+        for name in &skipped {
+            tracing::info!("[Installer] {} already installed — skipping", name);
+        }
+        tracing::info!("[Installer] {} component(s) to install/update", targets.len());
+        let dependency_components: Vec<String> = dependencies.iter()
+            .map(|key| Component::from_manifest_key(key).display_name())
+            .collect();
+        let mut errors = Vec::new();
+        for issue in unmet {
+            tracing::warn!("[Installer] Unmet dependency: {}", issue);
+            errors.push(issue);
+        }
 
-            if let Err(e) = download_result {
-                let err = format!("Download failed for {}: {}", comp_label, e);
-                tracing::error!("[Installer] {}", err);
-                errors.push(err);
-                continue;
-            }
+        let mut job = InstallJob {
+            targets: Vec::new(),
+            total: targets.len(),
+            skipped,
+            dependency_components,
+            errors,
+            source: self.source.clone(),
+            trusted_pubkey: self.trusted_pubkey(),
+            progress: self.install_progress.clone(),
+            cancel: self.install_cancel.clone(),
+            _lease: None,
+        };
+        if targets.is_empty() {
+            return Ok(job);
+        }
 
-            // 설치 디렉터리 결정 & 압축 해제
-            let install_dir = self.resolve_install_dir(&component, info.install_dir.as_deref());
+        // 설치 중 다른 적용(업데이트 적용·버전 지정 설치)이 같은 파일을 건드리지 않도록 독점권 유지
+        job._lease = Some(self.begin_apply()?);
+        self.install_cancel.store(false, Ordering::SeqCst);
+        Self::set_install_progress(&self.install_progress, InstallProgress {
+            total: job.total,
+            skipped_components: job.skipped.clone(),
+            dependency_components: job.dependency_components.clone(),
+            errors: job.errors.clone(),
+            ..InstallProgress::default()
+        });
 
-            if let Err(e) = self.extract_to_directory(&staged_path, &install_dir).await {
-                let err = format!("Extraction failed for {}: {}", comp_label, e);
-                tracing::error!("[Installer] {}", err);
-                errors.push(err);
-                continue;
+        std::fs::create_dir_all(&self.staging_dir)?;
+        // 기본 디렉터리 생성 (필요하면) — 설치 대상 디렉터리 해석 전에 준비
+        self.ensure_default_config().ok();
+
+        // 다운로드 소스가 없는 대상은 바로 에러로 기록, 나머지는 설치 위치까지 미리 결정
+        for (key, info, is_update) in targets {
+            let component = Component::from_manifest_key(&key);
+            match resolved.get(&key) {
+                Some(rc) => job.targets.push(InstallTarget {
+                    staged_path: Self::staged_asset_path(&self.staging_dir, &key, &rc.asset_name),
+                    install_dir: self.resolve_install_dir(&component, info.install_dir.as_deref()),
+                    key,
+                    is_update,
+                    rc: rc.clone(),
+                }),
+                None => {
+                    let err = format!("{}: 에셋을 포함한 릴리즈를 찾지 못함", component.display_name());
+                    tracing::warn!("[Installer] {}", err);
+                    job.errors.push(err);
+                }
             }
+        }
+        Ok(job)
+    }
 
-            std::fs::remove_file(&staged_path).ok();
+    /// 진행 중인 설치를 취소 요청합니다.
+    ///
+    /// 이미 시작된 다운로드는 끝까지 받지만 설치하지 않고, 이후 다운로드는 시작하지 않습니다.
+    /// 이미 설치가 끝난 컴포넌트는 그대로 유지됩니다.
+    /// `fresh_install` 등으로 설치하는 동안에는 Manager가 빌려져 있으므로 보통 `install_cancel` 핸들을 직접 사용합니다.
+    pub fn cancel_install(&self) {
+        tracing::info!("[Installer] Cancellation requested");
+        self.install_cancel.store(true, Ordering::SeqCst);
+    }
 
-            installed.push(comp_label.clone());
-            let dir_path = install_dir.to_string_lossy();
-            tracing::info!("[Installer] {} installed to {}", comp_label, dir_path);
+    fn set_install_progress(slot: &StdMutex<Option<InstallProgress>>, progress: InstallProgress) {
+        if let Ok(mut slot) = slot.lock() {
+            *slot = Some(progress);
         }
+    }
 
-        // 기본 config 파일 생성 (필요하면)
-        self.ensure_default_config().ok();
-
-        let progress = InstallProgress {
-            complete: true,
-            current_component: None,
-            total,
-            done: installed.len(),
-            installed_components: installed,
-            errors,
-        };
-        self.install_progress = Some(progress.clone());
+    fn update_install_progress(slot: &StdMutex<Option<InstallProgress>>, f: impl FnOnce(&mut InstallProgress)) {
+        if let Ok(mut slot) = slot.lock() {
+            if let Some(ref mut prog) = *slot {
+                f(prog);
+            }
+        }
+    }
 
-        Ok(progress)
+    /// 각 다운로드의 바이트 진행 상황을 퍼센트로 환산하여 InstallProgress에 반영
+    fn sync_download_percent(
+        slot: &StdMutex<Option<InstallProgress>>,
+        job_progress: &[(String, Arc<StdMutex<DownloadProgress>>)],
+    ) {
+        Self::update_install_progress(slot, |prog| {
+            for (label, progress) in job_progress {
+                let Ok(p) = progress.lock() else { continue };
+                if p.total_bytes == 0 {
+                    continue;
+                }
+                let percent = (p.bytes_received.saturating_mul(100) / p.total_bytes).min(100) as u8;
+                prog.download_percent.insert(label.clone(), percent);
+            }
+        });
     }
 
     /// 특정 컴포넌트를 단일 설치 (릴리즈 횡단 탐색 지원)
-    pub async fn install_component(&mut self, component: &Component) -> Result<String> {
-        if self.config.github_owner.is_empty() || self.config.github_repo.is_empty() {
-            anyhow::bail!("GitHub owner/repo not configured");
-        }
+    pub async fn install_component(&mut self, component: &Component) -> UpdaterResult<String> {
+        self.install_component_impl(component).await.map_err(UpdaterError::from)
+    }
+
+    async fn install_component_impl(&mut self, component: &Component) -> Result<String> {
+        self.ensure_repo_configured()?;
 
         if self.is_component_installed(component) {
             anyhow::bail!("{} is already installed", component.display_name());
         }
 
+        let (staged_path, _, manifest_dir) = self.stage_latest_asset(component).await?;
+        let install_dir = self.resolve_install_dir(component, manifest_dir.as_deref());
+        Self::extract_to_directory(&staged_path, &install_dir).await?;
+        std::fs::remove_file(&staged_path).ok();
+
+        tracing::info!("[Installer] {} installed to {}", component.display_name(), install_dir.display());
+        Ok(install_dir.to_string_lossy().to_string())
+    }
+
+    /// 컴포넌트의 최신 에셋을 스테이징에 내려받음 → (스테이징 경로, 버전, manifest의 install_dir)
+    async fn stage_latest_asset(&mut self, component: &Component) -> Result<(PathBuf, String, Option<String>)> {
         let client = self.create_client();
         let key = component.manifest_key();
 
         // resolved_components가 있으면 그것을 사용, 없으면 릴리즈를 새로 fetch
         if self.resolved_components.is_empty() {
-            let releases = client.fetch_releases(30).await?;
-            let (manifest, resolved) = client.resolve_components_across_releases(
-                &releases,
-                self.config.include_prerelease,
-            ).await?;
-            let latest_release = releases.iter()
-                .filter(|r| !r.draft)
-                .find(|r| self.config.include_prerelease || !r.prerelease)
-                .cloned();
-            self.cached_release = latest_release;
-            self.cached_manifest = Some(manifest);
-            self.cached_releases = releases;
-            self.resolved_components = resolved;
+            self.refresh_core_releases(&client).await?;
         }
 
         let rc = self.resolved_components.get(&key)
@@ -2937,38 +5714,229 @@ rm -f "$0"
 
         // resolved URL에서 직접 다운로드
         std::fs::create_dir_all(&self.staging_dir)?;
-        let staged_path = self.staging_dir.join(&rc.asset_name);
+        let staged_path = Self::staged_asset_path(&self.staging_dir, &key, &rc.asset_name);
 
         tracing::info!(
             "[Installer] Downloading {} v{} from release {}",
             key, rc.latest_version, rc.source_release_tag
         );
 
-        let response = reqwest::get(&rc.download_url).await?;
-        if !response.status().is_success() {
-            anyhow::bail!("Failed to download {}: {}", rc.asset_name, response.status());
-        }
-        let bytes = response.bytes().await?;
-        std::fs::write(&staged_path, &bytes)?;
+        let trusted_pubkey = self.trusted_pubkey();
+        Self::download_resolved(
+            self.source.as_ref(), rc, &staged_path, None, trusted_pubkey.as_deref(), None,
+        ).await?;
+
+        Ok((staged_path, rc.latest_version.clone(), info.install_dir.clone()))
+    }
+
+    /// 설치 여부와 관계없이 컴포넌트를 최신 버전으로 다시 설치 (손상된 설치 복구용)
+    ///
+    /// 전용 디렉터리를 쓰는 컴포넌트(모듈/익스텐션/봇 등)는 기존 디렉터리를 백업한 뒤 비우고
+    /// 새로 압축을 풀며, 실패하면 백업을 되돌린다. 설치 루트를 공유하는 바이너리 컴포넌트는
+    /// 일반 적용 경로(`.old` 백업/복원)로 교체한다.
+    pub async fn reinstall_component(&mut self, component: &Component) -> UpdaterResult<String> {
+        self.reinstall_component_impl(component).await.map_err(UpdaterError::from)
+    }
+
+    async fn reinstall_component_impl(&mut self, component: &Component) -> Result<String> {
+        self.ensure_repo_configured()?;
+        let key = component.manifest_key();
+
+        let _lease = self.begin_apply()?;
+        let (staged_path, version, manifest_dir) = self.stage_latest_asset(component).await?;
+        let install_dir = self.resolve_install_dir(component, manifest_dir.as_deref());
 
-        let install_dir = self.resolve_install_dir(component, info.install_dir.as_deref());
-        self.extract_to_directory(&staged_path, &install_dir).await?;
+        let result = if install_dir == self.install_root {
+            match self.apply_staged_component(component, &staged_path.to_string_lossy()).await {
+                Ok(result) if result.success => Ok(result.message),
+                Ok(result) => Err(anyhow::anyhow!(result.message)),
+                Err(e) => Err(e),
+            }
+        } else {
+            self.reinstall_into_dir(&key, &staged_path, &install_dir).await
+                .map(|()| format!("{} reinstalled to {}", component.display_name(), install_dir.display()))
+        };
         std::fs::remove_file(&staged_path).ok();
+        let message = result?;
 
-        tracing::info!("[Installer] {} installed to {}", component.display_name(), install_dir.display());
-        Ok(install_dir.to_string_lossy().to_string())
+        if let Err(e) = Self::update_installed_version(&key, &version) {
+            tracing::warn!("[UpdateManager] Failed to update installed manifest for {}: {}", key, e);
+        }
+        for comp in self.status.components.iter_mut().filter(|c| &c.component == component) {
+            comp.current_version = version.clone();
+            comp.installed = true;
+            comp.downloaded = false;
+            comp.downloaded_path = None;
+            comp.update_available = false;
+        }
+
+        tracing::info!("[Installer] {} v{} reinstalled", key, version);
+        Ok(message)
+    }
+
+    /// 전용 설치 디렉터리를 백업 → 비움 → 압축 해제 (실패 시 백업 복원)
+    async fn reinstall_into_dir(&self, key: &str, staged: &Path, install_dir: &Path) -> Result<()> {
+        let backup_dir = self.staging_dir.join(format!("{}_reinstall_backup", key));
+        if backup_dir.exists() {
+            std::fs::remove_dir_all(&backup_dir)?;
+        }
+        let had_existing = install_dir.exists();
+        if had_existing {
+            self.copy_dir_recursive(install_dir, &backup_dir)?;
+            self.clean_module_dir(install_dir)?;
+        }
+
+        if let Err(e) = Self::extract_to_directory(staged, install_dir).await {
+            tracing::error!("[Installer] Reinstall of {} failed, restoring backup: {}", key, e);
+            std::fs::remove_dir_all(install_dir).ok();
+            if had_existing {
+                match self.copy_dir_recursive(&backup_dir, install_dir) {
+                    Ok(()) => {
+                        std::fs::remove_dir_all(&backup_dir).ok();
+                    }
+                    Err(restore_err) => tracing::error!(
+                        "[Installer] Failed to restore {} (backup kept at {}): {}",
+                        install_dir.display(), backup_dir.display(), restore_err
+                    ),
+                }
+            }
+            return Err(e);
+        }
+
+        std::fs::remove_dir_all(&backup_dir).ok();
+        Ok(())
+    }
+
+    /// 컴포넌트를 지정한 버전으로 설치 (잘못된 릴리즈 이후 롤백/롤포워드용)
+    ///
+    /// 캐시된 릴리즈 목록(`release_scan_depth`까지)에서 manifest 버전과 에셋이 일치하는
+    /// 릴리즈를 찾아 그 에셋을 내려받는다. 설치되어 있으면 일반 적용 경로로 교체하고,
+    /// 미설치면 설치 디렉터리에 압축을 푼 뒤 설치 매니페스트에 버전을 기록한다.
+    ///
+    /// 잠금을 나눠 잡아야 하는 호출자(데몬)는 `prepare_component_version` → `ComponentVersionJob::download`
+    /// → `finish_component_version`을 직접 사용한다.
+    pub async fn install_component_version(&mut self, component: &Component, version: &str) -> UpdaterResult<String> {
+        let job = self.prepare_component_version(component, version).await?;
+        job.download().await?;
+        self.finish_component_version(job).await
+    }
+
+    /// 버전 지정 설치 준비 — 핀/재시작 필요 여부 확인, 릴리즈 해석, 적용 독점권 획득
+    ///
+    /// 설치된 데몬/GUI/업데이터는 실행 중인 바이너리를 덮어써야 하므로 제자리 설치하지 않고
+    /// `UpdaterError::RequiresRestart`로 거절한다 (다운로드 후 업데이터 적용 경로 사용).
+    pub async fn prepare_component_version(
+        &mut self,
+        component: &Component,
+        version: &str,
+    ) -> UpdaterResult<ComponentVersionJob> {
+        self.prepare_component_version_impl(component, version).await.map_err(UpdaterError::from)
+    }
+
+    async fn prepare_component_version_impl(
+        &mut self,
+        component: &Component,
+        version: &str,
+    ) -> Result<ComponentVersionJob> {
+        let key = component.manifest_key();
+        let version = version.trim_start_matches('v').to_string();
+        if !self.config.pin_allows(component, &version) {
+            anyhow::bail!(
+                "Component {} is pinned to {}, refusing to install {}",
+                key, self.config.pinned[&key], version
+            );
+        }
+        if component.requires_restart() && self.is_component_installed(component) {
+            tracing::warn!("[Installer] Refusing in-place install of running component {}", key);
+            return Err(anyhow::anyhow!(UpdaterError::RequiresRestart {
+                component: component.display_name(),
+            }));
+        }
+
+        let client = self.create_client();
+        if self.cached_releases.is_empty() {
+            self.refresh_core_releases(&client).await?;
+        }
+        let (info, rc) = client
+            .resolve_component_version(&self.cached_releases, &key, &version)
+            .await
+            .ok_or_else(|| anyhow::anyhow!(
+                "No release with {} v{} found in the last {} releases",
+                key, version, self.cached_releases.len()
+            ))?;
+
+        let lease = self.begin_apply()?;
+        std::fs::create_dir_all(&self.staging_dir)?;
+        let staged_path = Self::staged_asset_path(&self.staging_dir, &key, &rc.asset_name);
+        Ok(ComponentVersionJob {
+            component: component.clone(),
+            version,
+            info,
+            rc,
+            staged_path,
+            source: self.source.clone(),
+            trusted_pubkey: self.trusted_pubkey(),
+            _lease: lease,
+        })
+    }
+
+    /// 다운로드가 끝난 버전 지정 설치를 적용하고 설치 버전·상태를 갱신
+    pub async fn finish_component_version(&mut self, job: ComponentVersionJob) -> UpdaterResult<String> {
+        self.finish_component_version_impl(job).await.map_err(UpdaterError::from)
+    }
+
+    async fn finish_component_version_impl(&mut self, job: ComponentVersionJob) -> Result<String> {
+        let ComponentVersionJob { component, version, info, rc, staged_path, .. } = &job;
+        let key = component.manifest_key();
+
+        let message = if self.is_component_installed(component) {
+            let result = self
+                .apply_staged_component(component, &staged_path.to_string_lossy())
+                .await?;
+            result.message
+        } else {
+            let install_dir = self.resolve_install_dir(component, info.install_dir.as_deref());
+            Self::extract_to_directory(staged_path, &install_dir).await?;
+            format!("{} installed to {}", component.display_name(), install_dir.display())
+        };
+        std::fs::remove_file(staged_path).ok();
+
+        if let Err(e) = Self::update_installed_version(&key, version) {
+            tracing::warn!("[UpdateManager] Failed to update installed manifest for {}: {}", key, e);
+        }
+        for comp in self.status.components.iter_mut().filter(|c| &c.component == component) {
+            comp.current_version = version.clone();
+            comp.installed = true;
+            comp.downloaded = false;
+            comp.downloaded_path = None;
+            comp.update_available = comp.latest_version.as_deref()
+                .is_some_and(|latest| self.config.pin_allows(component, latest)
+                    && SemVer::parse(latest).zip(SemVer::parse(version))
+                        .is_some_and(|(l, c)| l.is_newer_than(&c)));
+        }
+
+        tracing::info!("[Installer] {} v{} installed from {}", key, version, rc.source_release_tag);
+        Ok(message)
     }
+
     /// 설치 진행 상태 반환
     pub fn get_install_progress(&self) -> Option<InstallProgress> {
-        self.install_progress.clone()
+        self.install_progress.lock().ok().and_then(|p| p.clone())
     }
 
     // ─────── 초기 설치 유틸리티 ────────────────────────────────────────────────────────────────────────
 
     /// 컴포넌트의 설치 디렉터리를 결정
     fn resolve_install_dir(&self, component: &Component, manifest_dir: Option<&str>) -> PathBuf {
+        // 모듈/익스텐션 디렉터리 오버라이드가 있으면 manifest의 install_dir보다 우선
+        let overridden = match component {
+            Component::Module(_) => self.config.modules_dir.is_some(),
+            Component::Extension(_) => self.config.extensions_dir.is_some(),
+            _ => false,
+        };
+
         // manifest의 install_dir가 지정되면 install_root 하위로 결합
-        if let Some(dir) = manifest_dir {
+        if let Some(dir) = manifest_dir.filter(|_| !overridden) {
             // Extension인 경우 디렉토리명의 하이픈을 언더스코어로 변환 (Python import 호환)
             if matches!(component, Component::Extension(_)) {
                 let safe = dir.replace('-', "_");
@@ -2991,7 +5959,7 @@ rm -f "$0"
     }
 
     /// zip/tar.gz(또는 단일 파일)을 대상 디렉터리에 압축 해제
-    async fn extract_to_directory(&self, staged: &Path, target_dir: &Path) -> Result<()> {
+    async fn extract_to_directory(staged: &Path, target_dir: &Path) -> Result<()> {
         std::fs::create_dir_all(target_dir)?;
 
         if staged.extension().map(|e| e == "zip").unwrap_or(false) {
@@ -3066,7 +6034,7 @@ rm -f "$0"
                         let _ = Self::rename_with_retry(&out_path, &backup, 5);
                     } else if cfg!(unix) && Self::is_known_binary(&out_path) {
                         let backup = out_path.with_extension("old");
-                        let _ = std::fs::rename(&out_path, &backup);
+                        let _ = Self::move_file(&out_path, &backup);
                     }
                 }
 
@@ -3086,6 +6054,136 @@ rm -f "$0"
         Ok(())
     }
 
+    /// `apply_binary_update` 대상의 주 실행파일 이름 (플랫폼 확장자 포함)
+    fn primary_binary_file_name(binary_name: &str) -> Option<String> {
+        let stem = match binary_name {
+            n if n.contains("updater") => "saba-chan-updater",
+            n if n.contains("daemon") || n.contains("core") => "saba-core",
+            n if n.contains("cli") => "saba-chan-cli",
+            n if n.contains("gui") => "saba-chan-gui",
+            _ => return None,
+        };
+        Some(format!("{}{}", stem, std::env::consts::EXE_SUFFIX))
+    }
+
+    /// 스테이징된 아카이브(zip / tar.gz)의 루트에 `file_name` 파일이 있는지 확인
+    ///
+    /// 아카이브가 아닌 파일은 검사 대상이 아니므로 true를 반환합니다.
+    fn archive_contains_root_file(staged: &Path, file_name: &str) -> Result<bool> {
+        let is_root_match = |name: &str| name.trim_start_matches("./") == file_name;
+
+        if staged.extension().map(|e| e == "zip").unwrap_or(false) {
+            let archive = zip::ZipArchive::new(std::fs::File::open(staged)?)?;
+            let found = archive.file_names().any(is_root_match);
+            Ok(found)
+        } else if Self::is_tar_gz(staged) {
+            let decoder = flate2::read::GzDecoder::new(std::fs::File::open(staged)?);
+            let mut archive = tar::Archive::new(decoder);
+            for entry in archive.entries()? {
+                let entry = entry?;
+                if entry.path()?.to_str().map(is_root_match).unwrap_or(false) {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        } else {
+            Ok(true)
+        }
+    }
+
+    /// 파일이 현재 플랫폼에서 실행 가능한 바이너리인지 검사
+    ///
+    /// - Windows: PE 매직 (`MZ`)
+    /// - Unix: ELF / Mach-O 매직 + 실행 권한 비트
+    pub(crate) fn verify_executable(path: &Path) -> Result<()> {
+        use std::io::Read;
+
+        let mut magic = [0u8; 4];
+        let mut file = std::fs::File::open(path)
+            .map_err(|e| anyhow::anyhow!("cannot open {}: {}", path.display(), e))?;
+        let read = file.read(&mut magic)?;
+        let magic = &magic[..read];
+
+        #[cfg(target_os = "windows")]
+        {
+            if !magic.starts_with(b"MZ") {
+                anyhow::bail!("missing PE header (MZ)");
+            }
+        }
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            const MAGICS: &[[u8; 4]] = &[
+                [0x7f, b'E', b'L', b'F'], // ELF
+                [0xfe, 0xed, 0xfa, 0xce], // Mach-O 32
+                [0xfe, 0xed, 0xfa, 0xcf], // Mach-O 64
+                [0xce, 0xfa, 0xed, 0xfe], // Mach-O 32 (LE)
+                [0xcf, 0xfa, 0xed, 0xfe], // Mach-O 64 (LE)
+                [0xca, 0xfe, 0xba, 0xbe], // Mach-O universal
+            ];
+            if !MAGICS.iter().any(|m| magic == m) {
+                anyhow::bail!("missing ELF/Mach-O header");
+            }
+            let mode = std::fs::metadata(path)?.permissions().mode();
+            if mode & 0o111 == 0 {
+                anyhow::bail!("executable bit not set (mode {:o})", mode & 0o777);
+            }
+        }
+
+        #[cfg(not(any(unix, target_os = "windows")))]
+        let _ = magic;
+
+        Ok(())
+    }
+
+    /// 잘못 교체된 바이너리를 지우고 `.old` 백업(`*.exe.old` / `*.old`)을 되돌린다
+    fn restore_binary_backup(live: &Path) {
+        let backup = if live.extension().map(|e| e == "exe").unwrap_or(false) {
+            live.with_extension("exe.old")
+        } else {
+            live.with_extension("old")
+        };
+        if !backup.exists() {
+            tracing::warn!("[Updater] No backup to restore for {}", live.display());
+            return;
+        }
+        std::fs::remove_file(live).ok();
+        match Self::move_file(&backup, live) {
+            Ok(()) => tracing::info!("[Updater] Restored {} from {}", live.display(), backup.display()),
+            Err(e) => tracing::error!("[Updater] Failed to restore {} from backup: {}", live.display(), e),
+        }
+    }
+
+    /// GUI 디렉터리가 개발용 소스 체크아웃인지 판별하고, 그렇다면 근거를 반환
+    ///
+    /// 패키징된 GUI도 루트에 `package.json`/`src`를 포함할 수 있으므로,
+    /// 여기에 더해 빌드 도구 흔적(vite 설정, node_modules, Cargo.toml, src-tauri)이
+    /// 하나 이상 있어야 소스 트리로 간주한다.
+    fn gui_source_tree_reason(dir: &Path) -> Option<String> {
+        if !dir.join("package.json").is_file() || !dir.join("src").is_dir() {
+            return None;
+        }
+        const DEV_MARKERS: &[&str] = &[
+            "vite.config.js",
+            "vite.config.ts",
+            "vite.config.mjs",
+            "vite.config.cjs",
+            "node_modules",
+            "Cargo.toml",
+            "src-tauri",
+        ];
+        let found: Vec<&str> = DEV_MARKERS.iter()
+            .copied()
+            .filter(|m| dir.join(m).exists())
+            .collect();
+        if found.is_empty() {
+            return None;
+        }
+        Some(format!("found package.json, src/ and {}", found.join(", ")))
+    }
+
     /// 경로가 saba-chan 바이너리인지 판별 (확장자 없는 비-바이너리 파일 오인 방지)
     fn is_known_binary(path: &Path) -> bool {
         const KNOWN_BINARIES: &[&str] = &[
@@ -3121,7 +6219,7 @@ rm -f "$0"
     /// 테스트 전용: extract_to_directory를 외부에서 호출
     #[doc(hidden)]
     pub async fn extract_to_directory_for_test(&self, staged: &Path, target: &Path) {
-        self.extract_to_directory(staged, target).await.unwrap();
+        Self::extract_to_directory(staged, target).await.unwrap();
     }
 
     /// 테스트 전용: resolve_install_dir를 외부에서 호출
@@ -3131,8 +6229,34 @@ rm -f "$0"
     }
 }
 
+// ─────── 파일 유틸리티 ────────────────────────────────────────────────────────────────────────
+
+/// 디렉터리 전체 크기 (바이트, 읽을 수 없는 항목은 무시)
+fn dir_size(path: &Path) -> u64 {
+    std::fs::read_dir(path)
+        .map(|entries| {
+            entries.flatten()
+                .map(|e| match e.metadata() {
+                    Ok(m) if m.is_dir() => dir_size(&e.path()),
+                    Ok(m) => m.len(),
+                    Err(_) => 0,
+                })
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
 // ─────── 시간 유틸리티 (chrono 미사용) ────────────────────────────────────────────────────────────────────────
 
+/// 현재 UNIX 시각 (초)
+fn unix_now_secs() -> u64 {
+    use std::time::SystemTime;
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
 /// 현재 시간을 ISO 8601 문자열로 반환
 fn chrono_now_iso() -> String {
     use std::time::SystemTime;
@@ -3142,15 +6266,13 @@ fn chrono_now_iso() -> String {
     format_unix_timestamp(now.as_secs())
 }
 
-/// hours 시간 후의 ISO 8601 문자열 반환
-fn chrono_add_hours_iso(_iso: &str, hours: u32) -> String {
-    // 단순하게 현재 UNIX timestamp + hours * 3600
+/// 지금으로부터 `delay` 후의 ISO 8601 문자열 반환
+fn chrono_after_iso(delay: std::time::Duration) -> String {
     use std::time::SystemTime;
     let now = SystemTime::now()
         .duration_since(SystemTime::UNIX_EPOCH)
         .unwrap_or_default();
-    let future = now.as_secs() + (hours as u64 * 3600);
-    format_unix_timestamp(future)
+    format_unix_timestamp((now + delay).as_secs())
 }
 
 fn format_unix_timestamp(secs: u64) -> String {
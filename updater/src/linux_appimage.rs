@@ -0,0 +1,161 @@
+//! Linux AppImage GUI 교체
+//!
+//! GUI를 단일 `.AppImage` 파일로 실행하는 경우, 새 AppImage를 같은 디렉터리에
+//! 임시 파일로 쓰고 실행 권한을 준 뒤 rename으로 덮어씁니다.
+//! 실행 중인 프로세스는 이전 inode를 계속 사용하므로 GUI 종료를 기다릴 필요가 없고,
+//! 새 버전은 다음 실행(재시작) 시 적용됩니다.
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+fn is_appimage(path: &Path) -> bool {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_ascii_lowercase().ends_with(".appimage"))
+        .unwrap_or(false)
+}
+
+/// 교체할 GUI AppImage 경로 탐색
+///
+/// 1. `APPIMAGE` 환경 변수 (AppImage로 실행된 GUI가 업데이터를 띄우면 상속됨)
+/// 2. install_root의 `saba-chan*.AppImage` 파일
+pub(crate) fn find_appimage(install_root: &Path) -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os("APPIMAGE").map(PathBuf::from) {
+        if is_appimage(&path) && path.is_file() {
+            return Some(path);
+        }
+    }
+    find_in_dir(install_root)
+}
+
+/// 디렉터리에서 `saba-chan*.AppImage` 파일 탐색 (여러 개면 이름순 첫 번째)
+fn find_in_dir(dir: &Path) -> Option<PathBuf> {
+    let mut candidates: Vec<PathBuf> = std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && is_appimage(p))
+        .filter(|p| {
+            p.file_name()
+                .map(|n| n.to_string_lossy().to_ascii_lowercase().starts_with("saba-chan"))
+                .unwrap_or(false)
+        })
+        .collect();
+    candidates.sort();
+    candidates.into_iter().next()
+}
+
+/// 스테이징된 에셋(`.AppImage` 또는 AppImage를 담은 zip)으로 `target`을 교체
+pub(crate) fn replace_appimage(staged: &Path, target: &Path) -> Result<()> {
+    let dir = target
+        .parent()
+        .ok_or_else(|| anyhow::anyhow!("AppImage has no parent directory: {}", target.display()))?;
+    let file_name = target.file_name().unwrap_or_default().to_string_lossy().to_string();
+    // 같은 디렉터리에 써야 rename이 원자적으로 동작
+    let temp = dir.join(format!(".{}.new", file_name));
+
+    let result = write_new_image(staged, &temp).and_then(|_| {
+        std::fs::rename(&temp, target)
+            .map_err(|e| anyhow::anyhow!("Cannot replace {}: {}", target.display(), e))
+    });
+    if result.is_err() {
+        std::fs::remove_file(&temp).ok();
+    }
+    result?;
+
+    tracing::info!("[Updater] GUI AppImage replaced: {} (relaunch to use the new version)", target.display());
+    Ok(())
+}
+
+/// 새 AppImage를 `dest`에 쓰고 실행 권한 부여
+fn write_new_image(staged: &Path, dest: &Path) -> Result<()> {
+    if is_appimage(staged) {
+        std::fs::copy(staged, dest)?;
+    } else if staged.extension().map(|e| e == "zip").unwrap_or(false) {
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(staged)?)?;
+        let index = (0..archive.len())
+            .find(|&i| {
+                archive.by_index(i)
+                    .map(|e| !e.is_dir() && e.name().to_ascii_lowercase().ends_with(".appimage"))
+                    .unwrap_or(false)
+            })
+            .ok_or_else(|| anyhow::anyhow!("Update archive does not contain an AppImage"))?;
+        let mut entry = archive.by_index(index)?;
+        let mut out = std::fs::File::create(dest)?;
+        std::io::copy(&mut entry, &mut out)?;
+    } else {
+        anyhow::bail!("Unsupported AppImage update asset: {}", staged.display());
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(dest, std::fs::Permissions::from_mode(0o755))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_appimage_in_install_root() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert!(find_in_dir(tmp.path()).is_none());
+
+        std::fs::write(tmp.path().join("other.AppImage"), b"x").unwrap();
+        assert!(find_in_dir(tmp.path()).is_none());
+
+        std::fs::write(tmp.path().join("Saba-chan-0.1.0-x86_64.AppImage"), b"x").unwrap();
+        assert_eq!(
+            find_in_dir(tmp.path()).unwrap(),
+            tmp.path().join("Saba-chan-0.1.0-x86_64.AppImage")
+        );
+    }
+
+    #[test]
+    fn replaces_appimage_in_place() {
+        let tmp = tempfile::tempdir().unwrap();
+        let target = tmp.path().join("Saba-chan.AppImage");
+        std::fs::write(&target, b"old").unwrap();
+        let staged = tmp.path().join("staged.AppImage");
+        std::fs::write(&staged, b"new").unwrap();
+
+        // 실행 중인 프로세스처럼 이전 파일을 열어둔 상태에서도 교체 가능
+        let held = std::fs::File::open(&target).unwrap();
+        replace_appimage(&staged, &target).unwrap();
+        drop(held);
+
+        assert_eq!(std::fs::read(&target).unwrap(), b"new");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(&target).unwrap().permissions().mode() & 0o777, 0o755);
+        }
+        assert!(!tmp.path().join(".Saba-chan.AppImage.new").exists());
+    }
+
+    #[test]
+    fn extracts_appimage_from_zip() {
+        use std::io::Write;
+        let tmp = tempfile::tempdir().unwrap();
+        let target = tmp.path().join("Saba-chan.AppImage");
+        std::fs::write(&target, b"old").unwrap();
+        let staged = tmp.path().join("gui.zip");
+        let mut writer = zip::ZipWriter::new(std::fs::File::create(&staged).unwrap());
+        writer.start_file("README.md", zip::write::FileOptions::default()).unwrap();
+        writer.write_all(b"readme").unwrap();
+        writer.start_file("Saba-chan-0.2.0.AppImage", zip::write::FileOptions::default()).unwrap();
+        writer.write_all(b"new").unwrap();
+        writer.finish().unwrap();
+
+        replace_appimage(&staged, &target).unwrap();
+        assert_eq!(std::fs::read(&target).unwrap(), b"new");
+
+        // AppImage가 없는 zip은 거부하고 기존 파일 유지
+        let empty = tmp.path().join("empty.zip");
+        zip::ZipWriter::new(std::fs::File::create(&empty).unwrap()).finish().unwrap();
+        assert!(replace_appimage(&empty, &target).is_err());
+        assert_eq!(std::fs::read(&target).unwrap(), b"new");
+    }
+}
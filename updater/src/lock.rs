@@ -0,0 +1,150 @@
+//! 프로세스 간 업데이터 잠금
+//!
+//! 데몬의 백그라운드 적용과 GUI/CLI 업데이터가 같은 스테이징 디렉터리와
+//! installed-manifest를 동시에 건드리지 않도록, 스테이징 디렉터리에 PID를 기록한
+//! `updater.lock` 파일을 둔다. 잠금 파일은 PID를 다 쓴 임시 파일을 hard link로
+//! 붙여서 만들기 때문에, 다른 프로세스가 내용이 비어 있는 잠금을 보는 일이 없다.
+//! 기록된 PID의 프로세스가 이미 종료되었으면 stale 잠금으로 보고 회수한다.
+//! 회수는 잠금 파일을 이 프로세스 전용 경로로 rename해서 가져온 뒤 내용을 다시 확인하는
+//! 방식이라, 읽은 뒤 다른 프로세스가 새로 만든 잠금을 지우는 일은 없다.
+
+use std::path::{Path, PathBuf};
+
+use crate::UpdaterError;
+
+/// 잠금 파일 이름 (스테이징 디렉터리 기준)
+pub const LOCK_FILE_NAME: &str = "updater.lock";
+
+/// 획득한 프로세스 잠금 — drop 시 잠금 파일을 삭제
+#[derive(Debug)]
+pub struct ProcessLock {
+    path: PathBuf,
+    /// 이 인스턴스가 잠금 파일을 만들었는지
+    /// (같은 프로세스의 다른 `UpdateManager`가 만든 잠금은 지우지 않음)
+    owned: bool,
+}
+
+impl ProcessLock {
+    /// `dir/updater.lock` 획득 — 살아 있는 다른 프로세스가 잡고 있으면 `UpdaterError::AlreadyRunning`
+    pub fn acquire(dir: &Path) -> Result<Self, UpdaterError> {
+        Self::acquire_with(dir, is_process_alive)
+    }
+
+    pub(crate) fn acquire_with(dir: &Path, is_alive: impl Fn(u32) -> bool) -> Result<Self, UpdaterError> {
+        let path = dir.join(LOCK_FILE_NAME);
+        let own_pid = std::process::id();
+        let io_err = |e: std::io::Error, operation: &str, p: &Path| {
+            UpdaterError::from_io(&e, operation, &p.display().to_string())
+        };
+        std::fs::create_dir_all(dir).map_err(|e| io_err(e, "create staging dir", dir))?;
+
+        // PID를 다 쓴 임시 파일을 잠금 경로에 link — 대상이 있으면 AlreadyExists로 실패 (원자적)
+        let temp = dir.join(format!("{}.{}", LOCK_FILE_NAME, own_pid));
+        std::fs::write(&temp, own_pid.to_string()).map_err(|e| io_err(e, "write updater lock", &temp))?;
+
+        let mut holder = None;
+        // stale 잠금을 한 번 회수한 뒤 재시도
+        for _ in 0..2 {
+            match std::fs::hard_link(&temp, &path) {
+                Ok(()) => {
+                    std::fs::remove_file(&temp).ok();
+                    return Ok(Self { path, owned: true });
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+                Err(e) => {
+                    std::fs::remove_file(&temp).ok();
+                    return Err(io_err(e, "create updater lock", &path));
+                }
+            }
+
+            let observed = std::fs::read_to_string(&path).ok();
+            holder = observed.as_deref().and_then(parse_pid);
+            match holder {
+                // 같은 프로세스 안의 동시 적용은 UpdateManager의 적용 플래그가 막는다
+                Some(pid) if pid == own_pid => {
+                    std::fs::remove_file(&temp).ok();
+                    return Ok(Self { path, owned: false });
+                }
+                Some(pid) if is_alive(pid) => break,
+                stale => {
+                    tracing::warn!(
+                        "[Updater] Reclaiming stale updater lock {} (pid {:?} is not running)",
+                        path.display(), stale
+                    );
+                    let claimed = dir.join(format!("{}.stale.{}", LOCK_FILE_NAME, own_pid));
+                    match reclaim_stale(&path, &claimed, observed.as_deref()) {
+                        Ok(Reclaim::Removed) => {}
+                        // 읽은 뒤 다른 프로세스가 새 잠금을 잡았음 — 되돌려 두고 그 프로세스에 양보
+                        Ok(Reclaim::Replaced(pid)) => {
+                            holder = pid;
+                            break;
+                        }
+                        Err(e) => {
+                            std::fs::remove_file(&temp).ok();
+                            return Err(io_err(e, "remove stale updater lock", &path));
+                        }
+                    }
+                }
+            }
+        }
+
+        std::fs::remove_file(&temp).ok();
+        let pid = holder.unwrap_or_default();
+        tracing::warn!("[Updater] Another updater process (pid {}) holds {}", pid, path.display());
+        Err(UpdaterError::AlreadyRunning { pid })
+    }
+}
+
+impl Drop for ProcessLock {
+    fn drop(&mut self) {
+        if self.owned {
+            std::fs::remove_file(&self.path).ok();
+        }
+    }
+}
+
+fn parse_pid(contents: &str) -> Option<u32> {
+    contents.trim().parse().ok()
+}
+
+/// stale 잠금 회수 결과
+enum Reclaim {
+    /// 읽었던 stale 잠금을 지웠음 (또는 다른 프로세스가 이미 지웠음)
+    Removed,
+    /// 읽은 뒤 잠금 내용이 바뀌었음 — 다른 프로세스가 새로 잡은 잠금이므로 그대로 둠
+    Replaced(Option<u32>),
+}
+
+/// `observed` 내용을 읽었던 잠금 파일을 회수
+///
+/// 잠금 파일을 `claimed`로 rename(원자적)해서 가져온 다음 내용을 다시 비교한다. 그 사이
+/// 다른 프로세스가 stale 잠금을 회수하고 새 잠금을 만들었다면 내용이 다르므로,
+/// 지우지 않고 원래 경로로 되돌린다.
+fn reclaim_stale(path: &Path, claimed: &Path, observed: Option<&str>) -> std::io::Result<Reclaim> {
+    match std::fs::rename(path, claimed) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Reclaim::Removed),
+        Err(e) => return Err(e),
+    }
+
+    let current = std::fs::read_to_string(claimed).ok();
+    if current.as_deref() == observed {
+        std::fs::remove_file(claimed).ok();
+        return Ok(Reclaim::Removed);
+    }
+
+    let pid = current.as_deref().and_then(parse_pid);
+    if let Err(e) = std::fs::hard_link(claimed, path) {
+        tracing::warn!(
+            "[Updater] Could not restore updater lock {} held by pid {:?}: {}",
+            path.display(), pid, e
+        );
+    }
+    std::fs::remove_file(claimed).ok();
+    Ok(Reclaim::Replaced(pid))
+}
+
+fn is_process_alive(pid: u32) -> bool {
+    let mut sys = sysinfo::System::new();
+    sys.refresh_process_specifics(sysinfo::Pid::from_u32(pid), sysinfo::ProcessRefreshKind::new())
+}
@@ -0,0 +1,263 @@
+//! macOS `.app` 번들 교체
+//!
+//! macOS GUI는 `Saba-chan.app` 번들로 배포되므로 파일 단위 덮어쓰기 대신
+//! 번들 전체를 교체합니다.
+//! 1. 에셋(zip/tar.gz)을 번들 옆 임시 디렉터리에 추출 (권한/심볼릭 링크 보존)
+//! 2. `Contents/MacOS` 실행파일 존재 검증
+//! 3. 기존 번들을 `.app.old`로 rename 후 새 번들을 rename — 같은 디렉터리 안이므로 원자적
+//!
+//! 교체에 실패하면 이전 번들을 복원합니다.
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+/// 배포되는 GUI 번들 이름
+pub(crate) const GUI_BUNDLE_NAME: &str = "Saba-chan.app";
+
+/// install_root 기준 GUI 번들 경로 탐색
+///
+/// `install_root/Saba-chan.app`이 있으면 그것을, 업데이터가 번들 내부
+/// (`Saba-chan.app/Contents/MacOS`)에서 실행 중이면 상위 번들을 반환한다.
+pub(crate) fn find_bundle(install_root: &Path) -> Option<PathBuf> {
+    let direct = install_root.join(GUI_BUNDLE_NAME);
+    if direct.is_dir() {
+        return Some(direct);
+    }
+    install_root
+        .ancestors()
+        .find(|p| p.extension().map(|e| e == "app").unwrap_or(false) && p.is_dir())
+        .map(Path::to_path_buf)
+}
+
+/// 스테이징된 에셋으로 `bundle`을 교체
+pub(crate) fn replace_bundle(staged: &Path, bundle: &Path) -> Result<()> {
+    let parent = bundle
+        .parent()
+        .ok_or_else(|| anyhow::anyhow!("Bundle has no parent directory: {}", bundle.display()))?;
+    let bundle_name = bundle
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| GUI_BUNDLE_NAME.to_string());
+    // 같은 파일시스템에서 rename할 수 있도록 번들 옆에 추출
+    let work_dir = parent.join(format!(".{}.update", bundle_name));
+    if work_dir.exists() {
+        std::fs::remove_dir_all(&work_dir)?;
+    }
+    std::fs::create_dir_all(&work_dir)?;
+
+    let result = extract_archive(staged, &work_dir)
+        .and_then(|_| locate_bundle_root(&work_dir))
+        .and_then(|new_bundle| {
+            validate_bundle(&new_bundle)?;
+            swap_bundle(&new_bundle, bundle)
+        });
+    std::fs::remove_dir_all(&work_dir).ok();
+    result?;
+
+    tracing::info!("[Updater] GUI bundle replaced: {}", bundle.display());
+    Ok(())
+}
+
+/// 추출 결과에서 번들 루트 찾기 (`X.app/Contents` 또는 `Contents`가 최상위인 경우 모두 허용)
+fn locate_bundle_root(dir: &Path) -> Result<PathBuf> {
+    if dir.join("Contents").join("MacOS").is_dir() {
+        return Ok(dir.to_path_buf());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().map(|e| e == "app").unwrap_or(false)
+            && path.join("Contents").join("MacOS").is_dir()
+        {
+            return Ok(path);
+        }
+    }
+    anyhow::bail!("Update archive does not contain an .app bundle (Contents/MacOS missing)")
+}
+
+/// `Contents/MacOS`에 실행파일이 하나 이상 있는지 검증
+fn validate_bundle(bundle: &Path) -> Result<()> {
+    let macos_dir = bundle.join("Contents").join("MacOS");
+    let has_executable = std::fs::read_dir(&macos_dir)?
+        .filter_map(|e| e.ok())
+        .any(|e| e.path().is_file());
+    if !has_executable {
+        anyhow::bail!("Invalid app bundle: {} contains no executable", macos_dir.display());
+    }
+    Ok(())
+}
+
+/// 기존 번들을 `.app.old`로 옮기고 새 번들로 교체 (실패 시 복원)
+fn swap_bundle(new_bundle: &Path, bundle: &Path) -> Result<()> {
+    let backup = bundle.with_extension("app.old");
+    if backup.exists() {
+        std::fs::remove_dir_all(&backup)?;
+    }
+    let had_previous = bundle.exists();
+    if had_previous {
+        std::fs::rename(bundle, &backup)
+            .map_err(|e| anyhow::anyhow!("Cannot move {} aside: {}", bundle.display(), e))?;
+    }
+    if let Err(e) = std::fs::rename(new_bundle, bundle) {
+        if had_previous {
+            if let Err(restore_err) = std::fs::rename(&backup, bundle) {
+                tracing::error!(
+                    "[Updater] Failed to restore {} from backup: {}",
+                    bundle.display(), restore_err
+                );
+            }
+        }
+        anyhow::bail!("Cannot install new bundle at {}: {}", bundle.display(), e);
+    }
+    if had_previous {
+        std::fs::remove_dir_all(&backup).ok();
+    }
+    Ok(())
+}
+
+/// zip/tar.gz 에셋 추출 — 실행 권한과 심볼릭 링크(Frameworks)를 보존
+fn extract_archive(staged: &Path, dest: &Path) -> Result<()> {
+    let name = staged.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    if name.ends_with(".tar.gz") {
+        let decoder = flate2::read::GzDecoder::new(std::fs::File::open(staged)?);
+        tar::Archive::new(decoder).unpack(dest)?;
+        return Ok(());
+    }
+    if !name.ends_with(".zip") {
+        anyhow::bail!("Unsupported GUI bundle archive: {}", name);
+    }
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(staged)?)?;
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let Some(rel) = entry.enclosed_name().map(Path::to_path_buf) else {
+            continue;
+        };
+        let out_path = dest.join(rel);
+        if entry.is_dir() {
+            std::fs::create_dir_all(&out_path)?;
+            continue;
+        }
+        if let Some(parent) = out_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mode = entry.unix_mode();
+
+        #[cfg(unix)]
+        if mode.is_some_and(|m| m & 0o170000 == 0o120000) {
+            use std::io::Read;
+            let mut target = String::new();
+            entry.read_to_string(&mut target)?;
+            std::os::unix::fs::symlink(target, &out_path)?;
+            continue;
+        }
+
+        let mut outfile = std::fs::File::create(&out_path)?;
+        std::io::copy(&mut entry, &mut outfile)?;
+
+        #[cfg(unix)]
+        if let Some(mode) = mode {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&out_path, std::fs::Permissions::from_mode(mode & 0o777))?;
+        }
+    }
+    Ok(())
+}
+
+/// 교체된 번들의 격리 속성 제거 및 서명 확인
+///
+/// 다운로드된 번들에는 `com.apple.quarantine`이 붙어 Gatekeeper가 실행을 막을 수 있다.
+/// 서명 검증에 실패하면 ad-hoc 서명으로 재서명을 시도하며, 어느 단계가 실패해도
+/// 업데이트 자체는 실패시키지 않고 경고만 남긴다.
+#[cfg(target_os = "macos")]
+pub(crate) fn restamp_bundle(bundle: &Path) {
+    use std::process::Command;
+
+    match Command::new("xattr").arg("-dr").arg("com.apple.quarantine").arg(bundle).status() {
+        Ok(status) if status.success() => {}
+        Ok(status) => tracing::warn!("[Updater] xattr exited with {} for {}", status, bundle.display()),
+        Err(e) => tracing::warn!("[Updater] Failed to run xattr: {}", e),
+    }
+
+    let verified = Command::new("codesign")
+        .args(["--verify", "--deep", "--strict"])
+        .arg(bundle)
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false);
+    if verified {
+        return;
+    }
+    tracing::warn!("[Updater] Code signature invalid for {}, applying ad-hoc signature", bundle.display());
+    match Command::new("codesign").args(["--force", "--deep", "--sign", "-"]).arg(bundle).status() {
+        Ok(status) if status.success() => {}
+        Ok(status) => tracing::warn!("[Updater] codesign exited with {} for {}", status, bundle.display()),
+        Err(e) => tracing::warn!("[Updater] Failed to run codesign: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn bundle_zip(path: &Path, files: &[(&str, &[u8], u32)]) {
+        let mut writer = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+        for (name, data, mode) in files {
+            let options = zip::write::FileOptions::default().unix_permissions(*mode);
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(data).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    fn existing_bundle(root: &Path) -> PathBuf {
+        let bundle = root.join(GUI_BUNDLE_NAME);
+        std::fs::create_dir_all(bundle.join("Contents/MacOS")).unwrap();
+        std::fs::write(bundle.join("Contents/MacOS/saba-chan-gui"), b"old").unwrap();
+        bundle
+    }
+
+    #[test]
+    fn find_bundle_in_root_or_ancestor() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert!(find_bundle(tmp.path()).is_none());
+
+        let bundle = existing_bundle(tmp.path());
+        assert_eq!(find_bundle(tmp.path()).unwrap(), bundle);
+        assert_eq!(find_bundle(&bundle.join("Contents/MacOS")).unwrap(), bundle);
+    }
+
+    #[test]
+    fn replaces_bundle_and_keeps_permissions() {
+        let tmp = tempfile::tempdir().unwrap();
+        let bundle = existing_bundle(tmp.path());
+        let staged = tmp.path().join("gui.zip");
+        bundle_zip(&staged, &[
+            ("Saba-chan.app/Contents/Info.plist", b"<plist/>", 0o644),
+            ("Saba-chan.app/Contents/MacOS/saba-chan-gui", b"new", 0o755),
+        ]);
+
+        replace_bundle(&staged, &bundle).unwrap();
+        let exe = bundle.join("Contents/MacOS/saba-chan-gui");
+        assert_eq!(std::fs::read(&exe).unwrap(), b"new");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(&exe).unwrap().permissions().mode() & 0o777, 0o755);
+        }
+        assert!(!bundle.with_extension("app.old").exists());
+        assert!(!tmp.path().join(".Saba-chan.app.update").exists());
+    }
+
+    #[test]
+    fn rejects_archive_without_executable() {
+        let tmp = tempfile::tempdir().unwrap();
+        let bundle = existing_bundle(tmp.path());
+        let staged = tmp.path().join("gui.zip");
+        bundle_zip(&staged, &[("Saba-chan.app/Contents/Info.plist", b"<plist/>", 0o644)]);
+
+        assert!(replace_bundle(&staged, &bundle).is_err());
+        // 기존 번들은 그대로 유지
+        assert_eq!(std::fs::read(bundle.join("Contents/MacOS/saba-chan-gui")).unwrap(), b"old");
+    }
+}
@@ -0,0 +1,120 @@
+//! 새 업데이트 알림 (콜백 / 웹훅)
+//!
+//! 업데이트 체크에서 새로 발견된 컴포넌트 버전마다 한 번씩 `UpdateNotice`를 발송합니다.
+//! 이미 알린 버전은 `check-state.json`에 기록되어 폴링할 때마다 중복 알림하지 않습니다.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::ComponentVersion;
+
+/// 웹훅 요청 타임아웃
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 새 업데이트 알림 페이로드 (웹훅 JSON 본문과 동일)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateNotice {
+    /// manifest 키 ("saba-core", "module-minecraft", ...)
+    pub component: String,
+    pub current: String,
+    pub latest: String,
+    pub release_notes: Option<String>,
+}
+
+/// 새 업데이트 알림 콜백
+pub type UpdateAvailableHook = Arc<dyn Fn(&UpdateNotice) + Send + Sync>;
+
+/// 아직 알리지 않은 업데이트만 골라 반환하고 `announced`에 기록
+///
+/// `announced`는 컴포넌트 키 → 마지막으로 알린 버전 맵이다.
+pub(crate) fn collect_new_notices(
+    components: &[ComponentVersion],
+    announced: &mut HashMap<String, String>,
+) -> Vec<UpdateNotice> {
+    let mut notices = Vec::new();
+    for comp in components.iter().filter(|c| c.update_available) {
+        let Some(latest) = comp.latest_version.as_ref() else {
+            continue;
+        };
+        let key = comp.component.manifest_key();
+        if announced.get(&key) == Some(latest) {
+            continue;
+        }
+        announced.insert(key.clone(), latest.clone());
+        notices.push(UpdateNotice {
+            component: key,
+            current: comp.current_version.clone(),
+            latest: latest.clone(),
+            release_notes: comp.release_notes.clone(),
+        });
+    }
+    notices
+}
+
+/// 웹훅 URL로 알림 페이로드를 POST
+pub(crate) async fn post_webhook(url: &str, notice: &UpdateNotice) -> Result<()> {
+    let client = reqwest::Client::builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()?;
+    client
+        .post(url)
+        .json(notice)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Component;
+
+    fn available(component: Component, current: &str, latest: &str) -> ComponentVersion {
+        ComponentVersion {
+            component,
+            current_version: current.to_string(),
+            latest_version: Some(latest.to_string()),
+            update_available: true,
+            download_url: None,
+            asset_name: None,
+            release_notes: Some("notes".to_string()),
+            published_at: None,
+            downloaded: false,
+            downloaded_path: None,
+            installed: true,
+            warning: None,
+            requires: None,
+        }
+    }
+
+    #[test]
+    fn announces_each_version_once() {
+        let mut announced = HashMap::new();
+        let mut components = vec![available(Component::CoreDaemon, "1.0.0", "1.1.0")];
+        let mut up_to_date = available(Component::Cli, "1.0.0", "1.0.0");
+        up_to_date.update_available = false;
+        components.push(up_to_date);
+
+        let notices = collect_new_notices(&components, &mut announced);
+        assert_eq!(notices, vec![UpdateNotice {
+            component: "saba-core".to_string(),
+            current: "1.0.0".to_string(),
+            latest: "1.1.0".to_string(),
+            release_notes: Some("notes".to_string()),
+        }]);
+
+        // 같은 버전은 다시 알리지 않음
+        assert!(collect_new_notices(&components, &mut announced).is_empty());
+
+        // 새 버전이 나오면 다시 알림
+        components[0] = available(Component::CoreDaemon, "1.0.0", "1.2.0");
+        let notices = collect_new_notices(&components, &mut announced);
+        assert_eq!(notices.len(), 1);
+        assert_eq!(notices[0].latest, "1.2.0");
+        assert_eq!(announced.get("saba-core").map(String::as_str), Some("1.2.0"));
+    }
+}
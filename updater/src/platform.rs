@@ -0,0 +1,141 @@
+//! 실행 플랫폼(OS/아키텍처)에 맞는 릴리즈 에셋 선택
+//!
+//! 에셋 이름을 `-`, `_`, `.` 등으로 나눈 토큰에서 OS/아키텍처 표기를 찾는다.
+//! 다른 플랫폼 토큰만 가진 에셋(Linux에서 `saba-core-windows-x64.zip`)은 후보에서 제외하고,
+//! 남은 후보 중 현재 플랫폼 토큰을 더 많이 가진 에셋을 고른다 (OS 일치 > 아키텍처 일치).
+//! 플랫폼 토큰이 전혀 없는 에셋(`module-minecraft.zip`)은 모든 플랫폼용으로 본다.
+
+/// OS별 에셋 이름 토큰 (`std::env::consts::OS` 기준)
+const OS_TOKENS: &[(&str, &[&str])] = &[
+    ("windows", &["windows", "win", "win32", "win64", "exe", "msi"]),
+    ("linux", &["linux", "appimage"]),
+    ("macos", &["macos", "darwin", "mac", "osx", "dmg"]),
+];
+
+/// 아키텍처별 에셋 이름 토큰 (`std::env::consts::ARCH` 기준, `x86_64`/`x86-64`는 `x64`로 정규화)
+const ARCH_TOKENS: &[(&str, &[&str])] = &[
+    ("x86_64", &["x64", "amd64"]),
+    ("aarch64", &["aarch64", "arm64"]),
+    ("x86", &["x86", "i386", "i686"]),
+    ("arm", &["arm", "armv7", "armhf"]),
+];
+
+/// 에셋 선택 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetMatch<T> {
+    /// 현재 플랫폼에 맞는 에셋
+    Found(T),
+    /// 후보는 있지만 모두 다른 플랫폼용 (`no_asset_for_platform`)
+    WrongPlatform,
+    /// 후보 에셋 없음
+    Missing,
+}
+
+impl<T> AssetMatch<T> {
+    pub fn found(self) -> Option<T> {
+        match self {
+            AssetMatch::Found(t) => Some(t),
+            _ => None,
+        }
+    }
+}
+
+/// 에셋 선택 기준 플랫폼
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Platform {
+    pub os: &'static str,
+    pub arch: &'static str,
+}
+
+impl Platform {
+    /// 현재 실행 중인 플랫폼
+    pub fn current() -> Self {
+        Self { os: std::env::consts::OS, arch: std::env::consts::ARCH }
+    }
+
+    /// 에셋 이름의 플랫폼 적합도 — 다른 플랫폼 전용이면 None, 아니면 높을수록 구체적
+    pub fn score(&self, asset_name: &str) -> Option<u8> {
+        let normalized = asset_name.to_ascii_lowercase()
+            .replace("x86_64", "x64")
+            .replace("x86-64", "x64");
+        let tokens: Vec<&str> = normalized
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|t| !t.is_empty())
+            .collect();
+        let has_any = |list: &[&str]| tokens.iter().any(|t| list.contains(t));
+
+        let (own_os, foreign_os) = Self::classify(OS_TOKENS, self.os, &has_any);
+        let (mut own_arch, foreign_arch) = Self::classify(ARCH_TOKENS, self.arch, &has_any);
+        // macOS universal 바이너리는 모든 아키텍처에서 실행 가능
+        if self.os == "macos" && tokens.contains(&"universal") {
+            own_arch = true;
+        }
+
+        if (foreign_os && !own_os) || (foreign_arch && !own_arch) {
+            return None;
+        }
+        Some(u8::from(own_os) * 2 + u8::from(own_arch))
+    }
+
+    /// 후보 중 가장 구체적으로 현재 플랫폼을 가리키는 것 선택 (동점이면 앞선 후보)
+    pub fn select<T>(&self, candidates: impl IntoIterator<Item = T>, name: impl Fn(&T) -> &str) -> AssetMatch<T> {
+        let mut any = false;
+        let mut best: Option<(u8, T)> = None;
+        for candidate in candidates {
+            any = true;
+            let Some(score) = self.score(name(&candidate)) else { continue };
+            if best.as_ref().is_none_or(|(best_score, _)| score > *best_score) {
+                best = Some((score, candidate));
+            }
+        }
+        match best {
+            Some((_, candidate)) => AssetMatch::Found(candidate),
+            None if any => AssetMatch::WrongPlatform,
+            None => AssetMatch::Missing,
+        }
+    }
+
+    /// (현재 값의 토큰 포함 여부, 다른 값의 토큰 포함 여부)
+    fn classify(table: &[(&str, &[&str])], current: &str, has_any: &impl Fn(&[&str]) -> bool) -> (bool, bool) {
+        let own = table.iter().any(|(key, tokens)| *key == current && has_any(tokens));
+        let foreign = table.iter().any(|(key, tokens)| *key != current && has_any(tokens));
+        (own, foreign)
+    }
+}
+
+impl std::fmt::Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.os, self.arch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINUX_X64: Platform = Platform { os: "linux", arch: "x86_64" };
+    const WINDOWS_X64: Platform = Platform { os: "windows", arch: "x86_64" };
+    const MACOS_ARM: Platform = Platform { os: "macos", arch: "aarch64" };
+
+    #[test]
+    fn score_rejects_other_platforms() {
+        assert_eq!(LINUX_X64.score("saba-core-windows-x64.zip"), None);
+        assert_eq!(LINUX_X64.score("saba-chan-updater.exe"), None);
+        assert_eq!(LINUX_X64.score("saba-core-linux-arm64.tar.gz"), None);
+        assert_eq!(WINDOWS_X64.score("saba-core-linux-x86_64.tar.gz"), None);
+        assert_eq!(LINUX_X64.score("saba-core-linux-x86_64.tar.gz"), Some(3));
+        assert_eq!(LINUX_X64.score("saba-core-linux.tar.gz"), Some(2));
+        assert_eq!(LINUX_X64.score("module-minecraft.zip"), Some(0));
+        assert_eq!(MACOS_ARM.score("saba-chan-gui-darwin-universal.zip"), Some(3));
+    }
+
+    #[test]
+    fn select_prefers_most_specific() {
+        let names = ["saba-core.zip", "saba-core-windows-x64.zip", "saba-core-linux.zip", "saba-core-linux-amd64.zip"];
+        assert_eq!(LINUX_X64.select(names, |n| n), AssetMatch::Found("saba-core-linux-amd64.zip"));
+        assert_eq!(WINDOWS_X64.select(names, |n| n), AssetMatch::Found("saba-core-windows-x64.zip"));
+        assert_eq!(MACOS_ARM.select(names, |n| n), AssetMatch::Found("saba-core.zip"));
+        assert_eq!(LINUX_X64.select(["saba-core-windows-x64.zip"], |n| n), AssetMatch::WrongPlatform);
+        assert_eq!(LINUX_X64.select(Vec::<&str>::new(), |n| n), AssetMatch::Missing);
+    }
+}
@@ -1,12 +1,14 @@
 //! 다운로드 큐 — 다수의 다운로드 요청을 순차적으로 처리
 //!
 //! ## 특징
-//! - FIFO 큐로 다운로드 요청 관리
+//! - 우선순위 힙으로 다운로드 요청 관리 (같은 우선순위는 FIFO)
 //! - 재시도 로직 (네트워크 오류 시)
-//! - 우선순위 지원 (긴급 다운로드)
+//! - 우선순위 지원 (긴급 다운로드), 대기 중 요청의 우선순위 변경
 //! - 일시정지/재개 기능
 
-use std::collections::VecDeque;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{mpsc, RwLock, Mutex};
@@ -50,6 +52,57 @@ impl DownloadRequest {
     }
 }
 
+/// 힙 항목 — 우선순위가 높을수록, 같으면 먼저 들어온(seq가 작은) 요청이 먼저
+#[derive(Debug, Clone)]
+struct QueuedRequest {
+    request: DownloadRequest,
+    seq: u64,
+}
+
+impl Ord for QueuedRequest {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.request.priority
+            .cmp(&other.request.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl PartialOrd for QueuedRequest {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for QueuedRequest {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QueuedRequest {}
+
+/// 큐에 들어간 다운로드 요청의 핸들 — 결과는 `DownloadResult.callback_id`로 매칭
+///
+/// 같은 컴포넌트가 이미 대기/다운로드 중이면 새 요청 대신 기존 요청의 핸들을 돌려준다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueHandle {
+    pub component: Component,
+    /// 실제로 처리될 요청의 콜백 ID (중복이면 기존 요청의 것)
+    pub callback_id: Option<String>,
+    /// 새로 추가됐으면 true, 기존 요청에 합쳐졌으면 false
+    pub newly_queued: bool,
+}
+
+impl QueueHandle {
+    fn of(request: &DownloadRequest, newly_queued: bool) -> Self {
+        Self {
+            component: request.component.clone(),
+            callback_id: request.callback_id.clone(),
+            newly_queued,
+        }
+    }
+}
+
 /// 다운로드 결과
 #[derive(Debug, Clone)]
 pub struct DownloadResult {
@@ -76,8 +129,15 @@ pub struct QueueStatus {
 
 /// 다운로드 큐 매니저
 pub struct DownloadQueue {
-    /// 요청 큐 (우선순위 정렬)
-    queue: Arc<Mutex<VecDeque<DownloadRequest>>>,
+    /// 요청 큐 (우선순위 힙)
+    queue: Arc<Mutex<BinaryHeap<QueuedRequest>>>,
+    /// 삽입 순번 (같은 우선순위 내 FIFO 보장)
+    next_seq: AtomicU64,
+    /// 현재 다운로드 중인 요청 (중복 요청 판정용, 재시도 대기 중에도 유지)
+    ///
+    /// 잠금 순서는 항상 `queue` → `in_flight`. 큐에서 꺼내는 것과 `in_flight` 설정이
+    /// 같은 `queue` 잠금 안에서 일어나야 그 사이에 들어온 중복 요청을 놓치지 않는다.
+    in_flight: Arc<Mutex<Option<DownloadRequest>>>,
     /// 결과 수신 채널
     result_rx: Arc<Mutex<mpsc::Receiver<DownloadResult>>>,
     /// 결과 송신 채널
@@ -95,7 +155,9 @@ impl DownloadQueue {
         let (result_tx, result_rx) = mpsc::channel(64);
         
         Self {
-            queue: Arc::new(Mutex::new(VecDeque::new())),
+            queue: Arc::new(Mutex::new(BinaryHeap::new())),
+            next_seq: AtomicU64::new(0),
+            in_flight: Arc::new(Mutex::new(None)),
             result_rx: Arc::new(Mutex::new(result_rx)),
             result_tx,
             status: Arc::new(RwLock::new(QueueStatus {
@@ -111,21 +173,71 @@ impl DownloadQueue {
     }
 
     /// 다운로드 요청 추가
-    pub async fn enqueue(&self, request: DownloadRequest) {
+    ///
+    /// 같은 컴포넌트(= 같은 staging 에셋)가 이미 대기 중이거나 다운로드(재시도 대기 포함) 중이면
+    /// 새로 추가하지 않고 기존 요청의 핸들(`newly_queued: false`)을 반환한다.
+    /// 대기 중인 요청은 더 높은 우선순위로만 갱신된다.
+    pub async fn enqueue(&self, request: DownloadRequest) -> QueueHandle {
         let mut queue = self.queue.lock().await;
-        
-        // 우선순위에 따라 삽입 위치 결정
-        let pos = queue
-            .iter()
-            .position(|r| r.priority < request.priority)
-            .unwrap_or(queue.len());
-        
-        queue.insert(pos, request);
-        
+        if let Some(active) = self.in_flight.lock().await.as_ref()
+            .filter(|active| active.component == request.component)
+        {
+            tracing::debug!(
+                "[Queue] {} is already downloading, skipping duplicate",
+                request.component.display_name()
+            );
+            return QueueHandle::of(active, false);
+        }
+
+        if let Some(existing) = queue.iter().find(|e| e.request.component == request.component) {
+            let handle = QueueHandle::of(&existing.request, false);
+            if existing.request.priority < request.priority {
+                Self::reprioritize(&mut queue, &request.component, request.priority);
+            }
+            tracing::debug!(
+                "[Queue] {} already queued, skipping duplicate",
+                request.component.display_name()
+            );
+            return handle;
+        }
+
+        let handle = QueueHandle::of(&request, true);
+        let seq = self.next_seq.fetch_add(1, AtomicOrdering::Relaxed);
+        queue.push(QueuedRequest { request, seq });
+
         let mut status = self.status.write().await;
         status.pending = queue.len();
         
         tracing::debug!("[Queue] Request enqueued, pending: {}", queue.len());
+        handle
+    }
+
+    /// 대기 중인 요청의 우선순위 변경 (사용자가 직접 요청한 다운로드를 앞당길 때)
+    ///
+    /// 해당 컴포넌트가 큐에 없으면 `false`를 반환한다.
+    pub async fn requeue_with_priority(&self, component: &Component, priority: u8) -> bool {
+        let mut queue = self.queue.lock().await;
+        Self::reprioritize(&mut queue, component, priority)
+    }
+
+    /// 잠금을 쥔 상태의 힙에서 컴포넌트의 우선순위를 바꾸고 힙을 재구성
+    fn reprioritize(queue: &mut BinaryHeap<QueuedRequest>, component: &Component, priority: u8) -> bool {
+        let mut items = std::mem::take(queue).into_vec();
+
+        let mut found = false;
+        for item in items.iter_mut().filter(|i| &i.request.component == component) {
+            item.request.priority = priority;
+            found = true;
+        }
+        *queue = BinaryHeap::from(items);
+
+        if found {
+            tracing::debug!(
+                "[Queue] {} reprioritized to {}",
+                component.display_name(), priority
+            );
+        }
+        found
     }
 
     /// 여러 요청 일괄 추가
@@ -154,14 +266,18 @@ impl DownloadQueue {
                 continue;
             }
 
-            // 다음 요청 가져오기
-            let request = {
+            // 다음 요청 가져오기 — 꺼내는 것과 in_flight 설정을 같은 queue 잠금 안에서
+            let (entry, pending) = {
                 let mut queue = self.queue.lock().await;
-                queue.pop_front()
+                let entry = queue.pop();
+                if let Some(ref e) = entry {
+                    *self.in_flight.lock().await = Some(e.request.clone());
+                }
+                (entry, queue.len())
             };
 
-            let request = match request {
-                Some(r) => r,
+            let QueuedRequest { request, seq } = match entry {
+                Some(e) => e,
                 None => {
                     // 큐가 비었으면 종료
                     tracing::info!("[Queue] Queue empty, stopping");
@@ -173,7 +289,7 @@ impl DownloadQueue {
             {
                 let mut status = self.status.write().await;
                 status.current = Some(request.component.display_name());
-                status.pending = self.queue.lock().await.len();
+                status.pending = pending;
             }
 
             // 다운로드 실행
@@ -181,6 +297,7 @@ impl DownloadQueue {
 
             // 결과 처리
             if result.success {
+                *self.in_flight.lock().await = None;
                 let mut status = self.status.write().await;
                 status.completed += 1;
                 status.current = None;
@@ -196,13 +313,16 @@ impl DownloadQueue {
                         result.error.as_deref().unwrap_or("unknown")
                     );
                     
-                    // 재시도 전 대기
+                    // 재시도 전 대기 — 그동안 in_flight를 유지해 같은 컴포넌트의 중복 요청을 막음
                     tokio::time::sleep(Duration::from_secs(2u64.pow(retry_request.retries as u32))).await;
                     
-                    // 큐 앞에 다시 추가 (우선 처리)
+                    // 원래 순번으로 다시 추가 (같은 우선순위 중 가장 먼저 처리)
+                    // 큐에 넣는 것과 in_flight 해제를 같은 잠금 안에서 — 이후 중복 판정은 큐가 담당
                     let mut queue = self.queue.lock().await;
-                    queue.push_front(retry_request);
+                    queue.push(QueuedRequest { request: retry_request, seq });
+                    *self.in_flight.lock().await = None;
                 } else {
+                    *self.in_flight.lock().await = None;
                     let mut status = self.status.write().await;
                     status.failed += 1;
                     status.current = None;
@@ -320,11 +440,83 @@ mod tests {
         let q = queue.queue.lock().await;
         assert_eq!(q.len(), 2);
         // 첫 번째가 high여야 함
-        if let Component::Module(name) = &q[0].component {
+        if let Component::Module(name) = &q.peek().unwrap().request.component {
             assert_eq!(name, "high");
         }
     }
 
+    /// 큐에서 꺼낼 순서대로 컴포넌트 키 나열
+    async fn drain_order(queue: &DownloadQueue) -> Vec<String> {
+        let mut q = queue.queue.lock().await;
+        std::iter::from_fn(|| q.pop())
+            .map(|e| e.request.component.manifest_key())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_queue_same_priority_is_fifo() {
+        let queue = DownloadQueue::new();
+        queue.enqueue(DownloadRequest::new(Component::Module("a".into()))).await;
+        queue.enqueue(DownloadRequest::new(Component::Module("b".into()))).await;
+        queue.enqueue(DownloadRequest::new(Component::CoreDaemon).with_priority(5)).await;
+        queue.enqueue(DownloadRequest::new(Component::Module("c".into()))).await;
+
+        assert_eq!(
+            drain_order(&queue).await,
+            vec!["saba-core", "module-a", "module-b", "module-c"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_deduplicates_pending_component() {
+        let queue = DownloadQueue::new();
+        assert!(queue.enqueue(DownloadRequest::new(Component::Module("a".into()))).await.newly_queued);
+        assert!(queue.enqueue(DownloadRequest::new(Component::Gui).with_callback("first".into())).await.newly_queued);
+
+        // 중복 요청은 기존 요청의 핸들을 돌려받음
+        let handle = queue.enqueue(DownloadRequest::new(Component::Gui).with_callback("second".into())).await;
+        assert!(!handle.newly_queued, "중복 요청");
+        assert_eq!(handle.callback_id.as_deref(), Some("first"));
+
+        let status = queue.get_status().await;
+        assert_eq!(status.pending, 2, "중복은 pending에 포함되지 않음");
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_duplicate_raises_priority() {
+        let queue = DownloadQueue::new();
+        queue.enqueue(DownloadRequest::new(Component::Module("a".into()))).await;
+        queue.enqueue(DownloadRequest::new(Component::Gui)).await;
+        // 중복이지만 더 높은 우선순위 → 기존 요청이 앞당겨짐
+        assert!(!queue.enqueue(DownloadRequest::new(Component::Gui).with_priority(9)).await.newly_queued);
+
+        assert_eq!(drain_order(&queue).await, vec!["gui", "module-a"]);
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_skips_in_flight_component() {
+        let queue = DownloadQueue::new();
+        *queue.in_flight.lock().await = Some(DownloadRequest::new(Component::CoreDaemon).with_callback("worker".into()));
+
+        let handle = queue.enqueue(DownloadRequest::new(Component::CoreDaemon)).await;
+        assert!(!handle.newly_queued);
+        assert_eq!(handle.callback_id.as_deref(), Some("worker"));
+        assert_eq!(queue.get_status().await.pending, 0);
+    }
+
+    #[tokio::test]
+    async fn test_requeue_with_priority() {
+        let queue = DownloadQueue::new();
+        queue.enqueue(DownloadRequest::new(Component::Module("a".into()))).await;
+        queue.enqueue(DownloadRequest::new(Component::Module("b".into()))).await;
+        queue.enqueue(DownloadRequest::new(Component::Gui)).await;
+
+        assert!(queue.requeue_with_priority(&Component::Gui, 10).await);
+        assert!(!queue.requeue_with_priority(&Component::Cli, 10).await, "큐에 없는 컴포넌트");
+
+        assert_eq!(drain_order(&queue).await, vec!["gui", "module-a", "module-b"]);
+    }
+
     #[tokio::test]
     async fn test_queue_batch() {
         let queue = DownloadQueue::new();
@@ -10,6 +10,7 @@
 //! 이 모듈은 그때 사용되는 원샷 유틸리티를 제공합니다.
 
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 
 use super::UpdateManager;
@@ -53,6 +54,24 @@ impl SchedulerConfig {
     }
 }
 
+/// 자동 체크 간격에 적용할 무작위 지터 비율 (±10%)
+const CHECK_JITTER_RATIO: f64 = 0.1;
+/// 시작 직후 첫 자동 체크 전 최대 무작위 지연 (5분)
+const MAX_STARTUP_DELAY_SECS: u64 = 300;
+
+/// 체크 간격에 ±10% 무작위 지터 적용
+///
+/// 같은 시각에 릴리즈를 확인한 설치들이 매번 동시에 GitHub을 호출하지 않도록 분산한다.
+pub fn jittered_interval(base: Duration) -> Duration {
+    let factor = 1.0 + CHECK_JITTER_RATIO * (fastrand::f64() * 2.0 - 1.0);
+    base.mul_f64(factor)
+}
+
+/// 시작 직후 첫 자동 체크 전 무작위 지연 (0~5분)
+pub fn startup_delay() -> Duration {
+    Duration::from_secs(fastrand::u64(0..=MAX_STARTUP_DELAY_SECS))
+}
+
 /// 원샷 업데이트 체크 결과
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CheckResult {
@@ -0,0 +1,124 @@
+//! 섀도 설치 — 라이브 설치를 건드리지 않는 blue-green 적용
+//!
+//! `install_root`를 같은 부모 아래 `.<name>.shadow`로 복사하고, 스테이징된 업데이트를
+//! 섀도에만 풀어 검증한다. 검증을 통과한 섀도는 rename 두 번으로 라이브와 교체한다
+//! (`live → .<name>.previous`, `shadow → live`). 두 번째 rename이 실패하면 즉시 되돌리고,
+//! 그 사이에 프로세스가 죽어 라이브 디렉터리가 사라졌으면 다음 섀도 적용 시 복원한다.
+//! 같은 부모 디렉터리를 쓰므로 rename은 항상 같은 파일시스템 안에서 일어난다.
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+use crate::{ApplyLease, Component};
+
+/// 섀도 디렉터리에 구성된 설치본 — `UpdateManager::promote_shadow`로 라이브에 반영
+///
+/// 승격하지 않고 drop하면 섀도 디렉터리를 삭제하고 적용 잠금을 해제한다.
+#[derive(Debug)]
+pub struct ShadowInstall {
+    pub(crate) live_root: PathBuf,
+    pub(crate) root: PathBuf,
+    pub(crate) previous: PathBuf,
+    /// 섀도에 적용된 컴포넌트와 스테이징 파일 경로
+    pub(crate) applied: Vec<(Component, PathBuf)>,
+    /// install_root 밖에 설치되어 섀도로 적용할 수 없는 컴포넌트 (일반 적용 필요)
+    pub(crate) deferred: Vec<String>,
+    pub(crate) promoted: bool,
+    /// 승격 또는 폐기까지 다른 적용이 라이브 설치를 바꾸지 못하게 유지
+    pub(crate) _lease: ApplyLease,
+}
+
+impl ShadowInstall {
+    /// 섀도 디렉터리 경로
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// 교체 대상 라이브 설치 경로
+    pub fn live_root(&self) -> &Path {
+        &self.live_root
+    }
+
+    /// 섀도에 적용된 컴포넌트의 manifest key
+    pub fn applied_keys(&self) -> Vec<String> {
+        self.applied.iter().map(|(c, _)| c.manifest_key()).collect()
+    }
+
+    /// 섀도로 적용하지 않은 대기 중 컴포넌트 — 승격 후 `apply_components`로 적용
+    pub fn deferred(&self) -> &[String] {
+        &self.deferred
+    }
+}
+
+impl Drop for ShadowInstall {
+    fn drop(&mut self) {
+        if !self.promoted && self.root.exists() {
+            if let Err(e) = std::fs::remove_dir_all(&self.root) {
+                tracing::warn!("[Updater] Failed to discard shadow install {}: {}", self.root.display(), e);
+            }
+        }
+    }
+}
+
+/// 섀도로 적용할 수 있는 컴포넌트 — 파일이 모두 install_root 아래에 있는 것만
+pub(crate) fn is_shadowable(component: &Component) -> bool {
+    matches!(component, Component::CoreDaemon | Component::Cli | Component::Updater | Component::Locales)
+}
+
+/// 라이브 설치의 형제 디렉터리 (`.<name>.shadow`, `.<name>.previous`)
+pub(crate) fn swap_dirs(live_root: &Path) -> Result<(PathBuf, PathBuf)> {
+    let parent = live_root.parent()
+        .ok_or_else(|| anyhow::anyhow!("Install root {} has no parent directory", live_root.display()))?;
+    let name = live_root.file_name()
+        .ok_or_else(|| anyhow::anyhow!("Install root {} has no directory name", live_root.display()))?
+        .to_string_lossy();
+    Ok((
+        parent.join(format!(".{}.shadow", name)),
+        parent.join(format!(".{}.previous", name)),
+    ))
+}
+
+/// 교체 도중 중단되어 라이브 없이 `.previous`만 남았으면 되돌림
+pub(crate) fn recover_swap(live_root: &Path, previous: &Path) -> Result<()> {
+    if !live_root.exists() && previous.is_dir() {
+        tracing::warn!("[Updater] Restoring {} left behind by an interrupted shadow promotion", live_root.display());
+        std::fs::rename(previous, live_root)?;
+    }
+    Ok(())
+}
+
+/// `src`를 `dst`로 재귀 복사 — `skip`에 포함된 경로는 건너뜀 (스테이징/섀도 디렉터리 등)
+pub(crate) fn copy_tree(src: &Path, dst: &Path, skip: &[PathBuf]) -> Result<()> {
+    std::fs::create_dir_all(dst)?;
+    for entry in std::fs::read_dir(src)? {
+        let path = entry?.path();
+        if skip.contains(&path) {
+            continue;
+        }
+        let dest = dst.join(path.file_name().unwrap_or_default());
+        if path.is_dir() {
+            copy_tree(&path, &dest, skip)?;
+        } else {
+            std::fs::copy(&path, &dest)?;
+        }
+    }
+    Ok(())
+}
+
+/// 섀도를 라이브 위치로 교체 — 실패하면 라이브 설치는 원래 상태로 남음
+pub(crate) fn swap_in(live_root: &Path, shadow: &Path, previous: &Path) -> Result<()> {
+    if previous.exists() {
+        std::fs::remove_dir_all(previous)?;
+    }
+    std::fs::rename(live_root, previous)
+        .map_err(|e| anyhow::anyhow!("Failed to move {} aside: {}", live_root.display(), e))?;
+    if let Err(e) = std::fs::rename(shadow, live_root) {
+        tracing::error!("[Updater] Shadow promotion failed, restoring {}: {}", live_root.display(), e);
+        std::fs::rename(previous, live_root)?;
+        anyhow::bail!("Failed to swap in shadow install {}: {}", shadow.display(), e);
+    }
+    if let Err(e) = std::fs::remove_dir_all(previous) {
+        tracing::warn!("[Updater] Failed to remove {}: {}", previous.display(), e);
+    }
+    Ok(())
+}
@@ -0,0 +1,256 @@
+//! 릴리즈 소스 추상화
+//!
+//! 업데이트 매니저가 릴리즈 목록, manifest.json, 에셋을 가져오는 전송 계층입니다.
+//! - **GitHubSource**: GitHub REST API (리포별 `api_base_overrides`, GitHub Enterprise 호스트 지원)
+//! - **LocalDirSource**: 로컬 디렉터리 (오프라인/에어갭 설치용)
+//!
+//! 테스트에서는 `UpdateManager::set_release_source`로 메모리 기반 구현을 주입할 수 있습니다.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex as StdMutex};
+
+use crate::github::{self, GitHubClient, GitHubRelease};
+use crate::{DownloadProgress, UpdateConfig, UpdateManager};
+
+/// 릴리즈 정보와 에셋을 제공하는 전송 계층
+#[async_trait]
+pub trait ReleaseSource: Send + Sync {
+    /// 리포의 릴리즈 목록 한 페이지 (최신순, `page`는 1부터)
+    async fn fetch_releases(&self, repo: &str, per_page: u32, page: u32) -> Result<Vec<GitHubRelease>>;
+
+    /// 릴리즈에 포함된 manifest.json 원문
+    async fn fetch_manifest(&self, repo: &str, release: &GitHubRelease) -> Result<String>;
+
+    /// `url`의 에셋을 `dest`에 저장하고 받은 바이트 수를 반환
+    ///
+    /// `progress`가 있으면 수신 바이트를 갱신하고, `max_kbps`가 있으면 대역폭을 제한한다.
+    async fn download(
+        &self,
+        url: &str,
+        dest: &Path,
+        progress: Option<&Arc<StdMutex<DownloadProgress>>>,
+        max_kbps: Option<u32>,
+    ) -> Result<u64>;
+
+    /// 체크 전 연결 확인에 사용할 엔드포인트 (None이면 네트워크가 필요 없는 소스)
+    fn probe_endpoint(&self, _repo: &str) -> Option<String> {
+        None
+    }
+
+    /// `url` 에셋의 크기 (다운로드 전 총 크기 표시용) — 서버가 알려주지 않으면 None
+    async fn asset_size(&self, url: &str) -> Option<u64> {
+        github::file_url_path(url).and_then(|p| std::fs::metadata(p).ok()).map(|m| m.len())
+    }
+}
+
+/// 설정에 맞는 릴리즈 소스 생성 (로컬 릴리즈 디렉터리 → GitHub 순)
+pub fn from_config(config: &UpdateConfig) -> Arc<dyn ReleaseSource> {
+    match local_release_root(config) {
+        Some(root) => Arc::new(LocalDirSource::new(&config.github_owner, &config.github_repo, root)),
+        None => Arc::new(GitHubSource::from_config(config)),
+    }
+}
+
+/// 로컬 릴리즈 루트 디렉터리 (`local_release_dir` → `file://` api_base_url 순)
+pub(crate) fn local_release_root(config: &UpdateConfig) -> Option<PathBuf> {
+    if let Some(dir) = config.local_release_dir.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        return Some(PathBuf::from(dir));
+    }
+    config.api_base_url.as_deref().and_then(github::file_url_path)
+}
+
+/// `file://` URL이면 로컬 파일 복사 (progress가 있으면 전체 크기로 갱신)
+fn copy_local_asset(
+    src: &Path,
+    dest: &Path,
+    progress: Option<&Arc<StdMutex<DownloadProgress>>>,
+) -> Result<u64> {
+    let copied = std::fs::copy(src, dest)
+        .map_err(|e| anyhow::anyhow!("Failed to copy local asset {}: {}", src.display(), e))?;
+    if let Some(progress) = progress {
+        if let Ok(mut prog) = progress.lock() {
+            prog.total_bytes = copied;
+            prog.bytes_received = copied;
+        }
+    }
+    Ok(copied)
+}
+
+// ═══════════════════════════════════════════════════════
+// GitHub
+// ═══════════════════════════════════════════════════════
+
+/// GitHub REST API 릴리즈 소스
+pub struct GitHubSource {
+    owner: String,
+    api_base_url: Option<String>,
+    api_base_overrides: HashMap<String, String>,
+    /// GitHub Enterprise 호스트 (None이면 공개 github.com)
+    github_host: Option<String>,
+    /// GitHub API 토큰 (`UpdateConfig::effective_github_token`)
+    token: Option<String>,
+}
+
+impl GitHubSource {
+    pub fn new(owner: &str) -> Self {
+        Self {
+            owner: owner.to_string(),
+            api_base_url: None,
+            api_base_overrides: HashMap::new(),
+            github_host: None,
+            token: None,
+        }
+    }
+
+    /// `api_base_url` / `api_base_overrides` / `github_host` 설정을 반영한 소스
+    pub fn from_config(config: &UpdateConfig) -> Self {
+        Self {
+            owner: config.github_owner.clone(),
+            api_base_url: config.api_base_url.clone(),
+            api_base_overrides: config.api_base_overrides.clone(),
+            github_host: config.github_host.as_deref().and_then(github::enterprise_host),
+            token: config.effective_github_token(),
+        }
+    }
+
+    /// 리포에 적용될 API 베이스 URL (None이면 GitHub 기본값)
+    ///
+    /// `api_base_overrides[repo]` → `api_base_url` 순으로 적용하며, 빈 오버라이드는 무시한다.
+    pub(crate) fn api_base_for_repo(&self, repo: &str) -> Option<&str> {
+        self.api_base_overrides
+            .get(repo)
+            .map(String::as_str)
+            .filter(|s| !s.trim().is_empty())
+            .or(self.api_base_url.as_deref())
+    }
+
+    /// 리포에 적용될 API 베이스 URL — 명시적 오버라이드가 없으면 `github_host` 기준 (Enterprise면 `/api/v3`)
+    fn effective_api_base(&self, repo: &str) -> String {
+        match (self.api_base_for_repo(repo), &self.github_host) {
+            (Some(base), _) => base.to_string(),
+            (None, Some(host)) => github::host_api_base(host),
+            (None, None) => github::DEFAULT_API_BASE.to_string(),
+        }
+    }
+
+    /// 리포별 클라이언트 — 코어/모듈/익스텐션 리포 모두 같은 토큰을 사용
+    fn client(&self, repo: &str) -> GitHubClient {
+        let client = match (self.api_base_for_repo(repo), &self.github_host) {
+            (None, Some(host)) => GitHubClient::with_host(&self.owner, repo, host),
+            (base, _) => GitHubClient::with_base_url(&self.owner, repo, base),
+        };
+        client.with_token(self.token.clone())
+    }
+
+    /// Enterprise 호스트면 github.com 에셋 URL(미러 등)을 같은 경로의 Enterprise 주소로 재작성
+    fn asset_url(&self, url: &str) -> String {
+        match self.github_host {
+            Some(ref host) => github::rewrite_asset_url(url, &github::host_web_base(host)),
+            None => url.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl ReleaseSource for GitHubSource {
+    async fn fetch_releases(&self, repo: &str, per_page: u32, page: u32) -> Result<Vec<GitHubRelease>> {
+        self.client(repo).fetch_releases_page(per_page, page).await
+    }
+
+    async fn fetch_manifest(&self, repo: &str, release: &GitHubRelease) -> Result<String> {
+        self.client(repo).fetch_manifest_raw(release).await
+    }
+
+    async fn download(
+        &self,
+        url: &str,
+        dest: &Path,
+        progress: Option<&Arc<StdMutex<DownloadProgress>>>,
+        max_kbps: Option<u32>,
+    ) -> Result<u64> {
+        // manifest의 mirrors에 file:// URL이 섞여 있을 수 있음
+        if let Some(local) = github::file_url_path(url) {
+            return copy_local_asset(&local, dest, progress);
+        }
+        let url = self.asset_url(url);
+        let label = dest.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        let response = UpdateManager::get_with_retry(&url, &label).await?;
+        UpdateManager::stream_to_file(response, dest, progress, max_kbps).await
+    }
+
+    fn probe_endpoint(&self, repo: &str) -> Option<String> {
+        Some(self.effective_api_base(repo))
+    }
+
+    async fn asset_size(&self, url: &str) -> Option<u64> {
+        // 에셋 URL은 절대 경로라 리포와 무관 — Enterprise 호스트 재작성만 반영
+        self.client("").asset_size(url).await
+    }
+}
+
+// ═══════════════════════════════════════════════════════
+// 로컬 디렉터리
+// ═══════════════════════════════════════════════════════
+
+/// 로컬 디렉터리 릴리즈 소스 (오프라인/에어갭 설치용)
+///
+/// 각 리포 디렉터리의 `manifest.json`과 에셋 파일들을 하나의 릴리즈로 취급한다.
+pub struct LocalDirSource {
+    owner: String,
+    core_repo: String,
+    root: PathBuf,
+}
+
+impl LocalDirSource {
+    pub fn new(owner: &str, core_repo: &str, root: PathBuf) -> Self {
+        Self {
+            owner: owner.to_string(),
+            core_repo: core_repo.to_string(),
+            root,
+        }
+    }
+
+    /// 리포별 로컬 릴리즈 디렉터리
+    ///
+    /// 코어 리포는 `{root}/{repo}/manifest.json`이 있으면 그 하위 디렉터리, 없으면 루트 자체를 쓴다.
+    /// 모듈/익스텐션 리포는 항상 `{root}/{repo}/`.
+    pub fn repo_dir(&self, repo: &str) -> PathBuf {
+        let sub = self.root.join(repo);
+        if repo == self.core_repo && !sub.join("manifest.json").is_file() {
+            self.root.clone()
+        } else {
+            sub
+        }
+    }
+
+    fn client(&self, repo: &str) -> GitHubClient {
+        GitHubClient::local(&self.owner, repo, &self.repo_dir(repo))
+    }
+}
+
+#[async_trait]
+impl ReleaseSource for LocalDirSource {
+    async fn fetch_releases(&self, repo: &str, per_page: u32, page: u32) -> Result<Vec<GitHubRelease>> {
+        self.client(repo).fetch_releases_page(per_page, page).await
+    }
+
+    async fn fetch_manifest(&self, repo: &str, release: &GitHubRelease) -> Result<String> {
+        self.client(repo).fetch_manifest_raw(release).await
+    }
+
+    async fn download(
+        &self,
+        url: &str,
+        dest: &Path,
+        progress: Option<&Arc<StdMutex<DownloadProgress>>>,
+        _max_kbps: Option<u32>,
+    ) -> Result<u64> {
+        match github::file_url_path(url) {
+            Some(local) => copy_local_asset(&local, dest, progress),
+            None => anyhow::bail!("Local release source cannot fetch remote URL: {}", url),
+        }
+    }
+}
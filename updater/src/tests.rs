@@ -7,10 +7,10 @@
 //! 4. 포그라운드 적용: 파일 교체 플로우
 
 use crate::{
-    Component, UpdateConfig, UpdateManager,
+    Component, UpdateConfig, UpdateManager, ApplyComponentResult,
     DownloadQueue, DownloadRequest,
     UpdaterError, RecoveryStrategy, NetworkChecker,
-    BackgroundWorker, WorkerEvent,
+    BackgroundWorker, WorkerEvent, ProcessChecker,
 };
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -27,6 +27,7 @@ fn test_config(mock_url: &str) -> UpdateConfig {
         include_prerelease: true,
         install_root: Some("./test_install".to_string()),
         api_base_url: Some(mock_url.to_string()),
+        ..UpdateConfig::default()
     }
 }
 
@@ -328,6 +329,9 @@ fn test_display_all_variants() {
         UpdaterError::ValidationError { component: "gui".into(), expected: "abc".into(), actual: "def".into() },
         UpdaterError::ConfigError { message: "missing key".into() },
         UpdaterError::Unknown { message: "??".into() },
+        UpdaterError::ApplyInProgress,
+        UpdaterError::UnsupportedManifest { schema_version: 2, supported: 1 },
+        UpdaterError::RequiresRestart { component: "GUI".into() },
     ];
 
     for err in &variants {
@@ -336,6 +340,42 @@ fn test_display_all_variants() {
     }
 }
 
+/// anyhow → UpdaterError 분류 — 체인의 UpdaterError/IO 에러 우선, 나머지는 Unknown
+#[test]
+fn test_from_anyhow_classifies_cause() {
+    use anyhow::Context;
+
+    let wrapped = Err::<(), _>(anyhow::anyhow!(UpdaterError::ApplyInProgress))
+        .context("apply")
+        .unwrap_err();
+    assert!(matches!(UpdaterError::from(wrapped), UpdaterError::ApplyInProgress));
+
+    let io = Err::<(), _>(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied"))
+        .context("extract module")
+        .unwrap_err();
+    let err = UpdaterError::from(io);
+    assert_eq!(err.code(), "file_system_error");
+    assert_eq!(err.to_string(), "File system error during extract module: denied");
+
+    let err = UpdaterError::from(anyhow::anyhow!("Component x is not ready"));
+    assert_eq!(err.code(), "unknown");
+    let payload = crate::ErrorPayload::from(&err);
+    assert_eq!(payload.code, "unknown");
+    assert!(payload.message.contains("not ready"));
+}
+
+#[tokio::test]
+async fn test_public_api_reports_missing_repo_as_config_error() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.config.github_repo.clear();
+
+    let err = manager.check_for_updates().await.unwrap_err();
+    assert!(matches!(err, UpdaterError::ConfigError { .. }), "{:?}", err);
+    let err = manager.fresh_install(None).await.unwrap_err();
+    assert_eq!(err.code(), "config_error");
+}
+
 /// from_io — IO 에러 → FileSystemError 변환
 #[test]
 fn test_from_io() {
@@ -509,7 +549,7 @@ async fn test_background_worker() {
         Ok(Ok(WorkerEvent::CheckCompleted { updates_available, .. })) => {
             println!("✓ 체크 완료 이벤트 수신: {} 업데이트", updates_available);
         }
-        Ok(Ok(WorkerEvent::CheckFailed { error })) => {
+        Ok(Ok(WorkerEvent::CheckFailed { error, .. })) => {
             println!("✗ 체크 실패: {}", error);
         }
         _ => {
@@ -576,6 +616,55 @@ async fn test_network_checker_default_has_endpoints() {
     // Default trait과 ::new()의 동작이 동일한지 확인
 }
 
+#[tokio::test]
+async fn test_network_checker_is_reachable_uses_tcp_connect() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let timeout = std::time::Duration::from_secs(2);
+
+    let checker = NetworkChecker::with_endpoints(vec![format!("http://{}", addr)], timeout);
+    assert!(checker.is_reachable().await, "리스닝 중인 호스트는 도달 가능");
+
+    let checker = NetworkChecker::with_endpoints(
+        vec!["not a url".to_string(), "http://127.0.0.1:1".to_string()],
+        timeout,
+    );
+    assert!(!checker.is_reachable().await, "연결 거부/잘못된 URL은 도달 불가");
+}
+
+#[tokio::test]
+async fn test_check_offline_short_circuits_and_restores_staged_updates() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+
+    // 스테이징된 업데이트가 없으면 0개
+    let err = manager.check_for_updates().await.unwrap_err();
+    assert!(matches!(err, UpdaterError::Offline { staged_updates: 0 }), "{:?}", err);
+    assert_eq!(err.code(), "offline");
+
+    let staged = manager.staging_dir.join("saba-core").join("saba-core.zip");
+    std::fs::create_dir_all(staged.parent().unwrap()).unwrap();
+    std::fs::write(&staged, b"staged").unwrap();
+    let mut pending = backup_test_component(Component::CoreDaemon, "2.0.0", true);
+    pending.downloaded_path = Some(staged.to_string_lossy().to_string());
+    std::fs::write(
+        manager.staging_dir.join("pending.json"),
+        serde_json::to_string(&vec![pending]).unwrap(),
+    ).unwrap();
+
+    let started = std::time::Instant::now();
+    let err = manager.check_for_updates().await.unwrap_err();
+    assert!(started.elapsed() < std::time::Duration::from_secs(3), "연결 확인은 2초 안에 끝나야 함");
+    assert!(matches!(err, UpdaterError::Offline { staged_updates: 1 }), "{:?}", err);
+
+    let status = manager.get_status();
+    assert!(!status.checking);
+    assert!(status.error.is_some());
+    assert_eq!(status.components.len(), 1, "오프라인이어도 스테이징된 업데이트는 적용 가능해야 함");
+    assert!(status.components[0].downloaded);
+}
+
 // ═══════════════════════════════════════════════════════
 // 테스트 7: 큐 심층 테스트
 // ═══════════════════════════════════════════════════════
@@ -607,6 +696,42 @@ async fn test_queue_with_callback() {
     assert_eq!(status.pending, 1);
 }
 
+#[tokio::test]
+async fn test_queue_keeps_in_flight_during_retry_backoff() {
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = Arc::new(RwLock::new(plan_test_manager(tmp.path())));
+    let queue = Arc::new(DownloadQueue::new());
+
+    // 상태에 없는 컴포넌트 → 즉시 실패 → 재시도 대기(2초)
+    let mut req = DownloadRequest::new(Component::Cli).with_callback("original".to_string());
+    req.max_retries = 1;
+    assert!(queue.enqueue(req).await.newly_queued);
+
+    let worker = {
+        let queue = queue.clone();
+        let manager = manager.clone();
+        tokio::spawn(async move { queue.process(manager).await })
+    };
+
+    // 첫 시도가 실패하고 백오프에 들어갈 때까지 대기
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(1);
+    while queue.get_status().await.pending > 0 || queue.get_status().await.current.is_none() {
+        assert!(std::time::Instant::now() < deadline, "queue never started");
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+    let handle = queue.enqueue(DownloadRequest::new(Component::Cli).with_callback("dup".to_string())).await;
+    assert!(!handle.newly_queued, "백오프 중에도 중복 요청은 기존 요청에 합쳐져야 함");
+    assert_eq!(handle.callback_id.as_deref(), Some("original"));
+
+    worker.await.unwrap();
+    let result = queue.recv_result().await.unwrap();
+    assert!(!result.success);
+    assert_eq!(result.callback_id.as_deref(), Some("original"));
+    assert_eq!(queue.get_status().await.failed, 1, "중복 요청이 따로 처리되면 안 됨");
+}
+
 #[tokio::test]
 async fn test_queue_empty_status() {
     let queue = DownloadQueue::new();
@@ -703,7 +828,6 @@ fn test_dependency_check_serde_roundtrip() {
     assert!(deserialized.issues[1].installed_version.is_none());
 }
 
-#[test]
 #[test]
 fn test_component_info_requires_field() {
     use crate::github::ComponentInfo;
@@ -822,40 +946,3267 @@ fn test_component_apply_priority_categories() {
     println!("✓ 컴포넌트 적용 우선순위 카테고리 테스트 통과");
 }
 
-#[cfg(test)]
-mod run_all {
-    use super::*;
+// ═══════════════════════════════════════════════════════
+// 테스트: 다운로드 재시도
+// ═══════════════════════════════════════════════════════
 
-    /// 유닛 테스트만 실행 (mock 서버 불필요)
-    #[test]
-    fn run_unit_tests() {
-        test_error_recovery_strategy();
-        test_is_recoverable_exhaustive();
-        test_retry_delay_exponential_backoff_with_cap();
-        test_retry_delay_timeout_base();
-        test_retry_delay_rate_limit_429();
-        test_retry_delay_api_5xx();
-        test_user_message_content();
-        test_display_all_variants();
-        test_from_io();
-        test_error_context_builder();
-        test_error_context_without_component();
-        test_recovery_strategy_zero_retries();
-        test_recovery_strategy_no_backoff();
-        test_recovery_strategy_full_lifecycle();
-        test_error_serde_roundtrip();
-        test_download_interrupted_progress_data();
-        test_component_manifest_key();
-        test_dependency_check_satisfied();
-        test_dependency_check_unsatisfied();
-        test_dependency_issue_not_installed();
-        test_dependency_check_serde_roundtrip();
-        test_component_info_requires_field();
-        test_component_info_requires_empty_by_default();
-        test_module_extension_no_restart_in_apply_result();
-        test_component_apply_priority_categories();
-        println!("\n═══════════════════════════════════════");
-        println!("✓ 모든 유닛 테스트 통과!");
-        println!("═══════════════════════════════════════\n");
+/// 요청마다 `statuses`의 상태 코드를 순서대로 응답하는 로컬 HTTP 서버
+async fn spawn_status_server(statuses: Vec<u16>) -> (String, Arc<std::sync::atomic::AtomicUsize>) {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let hits = Arc::new(AtomicUsize::new(0));
+    let hits_clone = hits.clone();
+
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let n = hits_clone.fetch_add(1, Ordering::SeqCst);
+            let code = statuses.get(n).copied().unwrap_or(200);
+            let mut buf = [0u8; 1024];
+            let _ = socket.read(&mut buf).await;
+            let resp = format!(
+                "HTTP/1.1 {} X\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok",
+                code
+            );
+            let _ = socket.write_all(resp.as_bytes()).await;
+        }
+    });
+
+    (format!("http://{}/asset.zip", addr), hits)
+}
+
+#[tokio::test]
+async fn test_rate_limited_check_delays_next_attempt() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut buf = [0u8; 1024];
+            let _ = socket.read(&mut buf).await;
+            let resp = "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 120\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+            let _ = socket.write_all(resp.as_bytes()).await;
+        }
+    });
+
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+    manager.config.api_base_url = Some(format!("http://{}", addr));
+    manager.set_release_source(crate::source::from_config(&manager.config));
+
+    let err = manager.check_for_updates().await.unwrap_err();
+    assert!(matches!(err, UpdaterError::RateLimited { retry_after_secs: 120 }), "{:?}", err);
+    assert_eq!(err.code(), "rate_limited");
+    assert_eq!(err.retry_delay(3), std::time::Duration::from_secs(120), "백오프 없이 서버 지정 시간");
+    assert_eq!(manager.next_check_delay(), Some(std::time::Duration::from_secs(120)));
+    assert!(manager.get_status().next_check.is_some());
+}
+
+/// 코어 리포는 공개, `private-mod` 리포는 `Bearer secret` 토큰이 있어야 보이는 mock GitHub
+async fn spawn_private_repo_server() -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let core_releases = serde_json::json!([{
+        "tag_name": "v1.0.0",
+        "name": null,
+        "body": null,
+        "prerelease": false,
+        "draft": false,
+        "published_at": null,
+        "html_url": "",
+        "assets": [{
+            "name": "manifest.json",
+            "size": 0,
+            "browser_download_url": format!("{}/dl/manifest.json", base),
+            "content_type": null
+        }]
+    }]).to_string();
+
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap_or(0);
+            let req = String::from_utf8_lossy(&buf[..n]).to_string();
+            let path = req.split_whitespace().nth(1).unwrap_or("").to_string();
+            let authorized = req.to_ascii_lowercase().contains("authorization: bearer secret");
+            let (code, body) = if path.starts_with("/repos/test-owner/saba-chan/releases") {
+                (200, core_releases.clone())
+            } else if path == "/dl/manifest.json" {
+                (200, r#"{"release_version":"1.0.0","components":{}}"#.to_string())
+            } else if path.starts_with("/repos/test-owner/private-mod/releases") && authorized {
+                (200, "[]".to_string())
+            } else {
+                (404, r#"{"message":"Not Found"}"#.to_string())
+            };
+            let resp = format!(
+                "HTTP/1.1 {} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                code, body.len(), body
+            );
+            let _ = socket.write_all(resp.as_bytes()).await;
+        }
+    });
+    base
+}
+
+#[tokio::test]
+async fn test_private_repo_404_distinguished_by_token() {
+    let base = spawn_private_repo_server().await;
+    let client = |token: Option<&str>| {
+        crate::github::GitHubClient::with_base_url("test-owner", "private-mod", Some(&base))
+            .with_token(token.map(String::from))
+    };
+
+    let err = UpdaterError::from(client(None).fetch_releases(5).await.unwrap_err());
+    assert!(matches!(err, UpdaterError::RepoUnavailable { authenticated: false, .. }), "{:?}", err);
+    assert_eq!(err.code(), "repo_unavailable");
+    assert!(err.to_string().contains("github_token"));
+
+    let err = UpdaterError::from(client(Some("wrong")).fetch_releases(5).await.unwrap_err());
+    assert!(matches!(err, UpdaterError::RepoUnavailable { authenticated: true, .. }), "{:?}", err);
+
+    // 토큰이 맞으면 릴리즈가 없는 리포도 오류가 아닌 빈 목록
+    assert!(client(Some("secret")).fetch_releases(5).await.unwrap().is_empty());
+    assert!(client(Some("  ")).fetch_releases(5).await.is_err(), "빈 토큰은 보내지 않음");
+}
+
+#[tokio::test]
+async fn test_private_module_repo_surfaces_warning_and_uses_token() {
+    let base = spawn_private_repo_server().await;
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+    let module_dir = manager.modules_dir.join("secret-game");
+    std::fs::create_dir_all(&module_dir).unwrap();
+    std::fs::write(
+        module_dir.join("module.toml"),
+        "[module]\nname = \"secret-game\"\nversion = \"1.0.0\"\n\n[update]\ngithub_repo = \"private-mod\"\n",
+    ).unwrap();
+
+    let mut config = manager.get_config();
+    config.api_base_url = Some(base.clone());
+    config.github_token = None;
+    manager.update_config(config.clone());
+
+    // 토큰 없이 404 → 목록에서 빠지지 않고 repo_unavailable 경고로 남음
+    let status = manager.check_for_updates().await.unwrap();
+    let module = status.components.iter()
+        .find(|c| c.component == Component::Module("secret-game".to_string()))
+        .expect("private module must stay in the status");
+    assert_eq!(module.warning.as_deref(), Some(crate::REPO_UNAVAILABLE));
+    assert_eq!(module.current_version, "1.0.0");
+    assert!(!module.update_available);
+
+    // 토큰을 설정하면 모듈 리포 클라이언트에도 전달되어 정상 확인 (릴리즈 없음 → 항목 없음)
+    config.github_token = Some("secret".to_string());
+    manager.update_config(config);
+    let status = manager.check_for_updates().await.unwrap();
+    assert!(!status.components.iter().any(|c| c.warning.as_deref() == Some(crate::REPO_UNAVAILABLE)));
+    assert!(serde_json::to_value(manager.get_config()).unwrap().get("github_token").is_none());
+}
+
+fn instant_retry_strategy() -> RecoveryStrategy {
+    RecoveryStrategy {
+        base_delay: std::time::Duration::ZERO,
+        ..RecoveryStrategy::new(2)
+    }
+}
+
+#[tokio::test]
+async fn test_download_retries_on_5xx_then_succeeds() {
+    use std::sync::atomic::Ordering;
+    let (url, hits) = spawn_status_server(vec![503, 502, 200]).await;
+
+    let resp = UpdateManager::get_with_strategy(&url, "asset.zip", instant_retry_strategy()).await;
+    assert!(resp.is_ok(), "세 번째 시도에서 성공해야 함");
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_asset_size_from_head_content_length() {
+    let (url, _) = spawn_status_server(vec![200, 404]).await;
+    let client = crate::github::GitHubClient::new("test-owner", "saba-chan");
+
+    assert_eq!(client.asset_size(&url).await, Some(2));
+    // 크기를 알려주지 않는 응답은 None
+    assert_eq!(client.asset_size(&url).await, None);
+
+    let tmp = tempfile::tempdir().unwrap();
+    let local = tmp.path().join("asset.zip");
+    std::fs::write(&local, b"12345").unwrap();
+    let file_url = crate::github::file_url(&local);
+    assert_eq!(client.asset_size(&file_url).await, Some(5));
+    assert_eq!(crate::ReleaseSource::asset_size(&http_source(), &file_url).await, Some(5));
+}
+
+#[tokio::test]
+async fn test_download_does_not_retry_on_404() {
+    use std::sync::atomic::Ordering;
+    let (url, hits) = spawn_status_server(vec![404, 200]).await;
+
+    let err = UpdateManager::get_with_strategy(&url, "asset.zip", instant_retry_strategy())
+        .await
+        .unwrap_err();
+    assert_eq!(hits.load(Ordering::SeqCst), 1, "404는 재시도하지 않음");
+    match err.downcast_ref::<UpdaterError>() {
+        Some(UpdaterError::ApiError { status_code, .. }) => assert_eq!(*status_code, 404),
+        other => panic!("expected ApiError, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_download_returns_last_error_after_max_attempts() {
+    use std::sync::atomic::Ordering;
+    let (url, hits) = spawn_status_server(vec![500, 500, 500, 200]).await;
+
+    let err = UpdateManager::get_with_strategy(&url, "asset.zip", instant_retry_strategy())
+        .await
+        .unwrap_err();
+    assert_eq!(hits.load(Ordering::SeqCst), 3, "최대 3회까지만 시도");
+    assert!(err.to_string().contains("500"));
+}
+
+#[tokio::test]
+async fn test_download_asset_to_file_streams_into_nested_dest() {
+    let (url, _) = spawn_status_server(vec![200]).await;
+    let tmp = tempfile::tempdir().unwrap();
+    let dest = tmp.path().join("staging").join("asset.zip");
+
+    let asset = crate::github::GitHubAsset {
+        name: "asset.zip".to_string(),
+        size: 2,
+        browser_download_url: url,
+        content_type: None,
+    };
+    crate::github::GitHubClient::new("owner", "repo")
+        .download_asset_to_file(&asset, &dest)
+        .await
+        .unwrap();
+    assert_eq!(std::fs::read(&dest).unwrap(), b"ok");
+}
+
+fn resolved_for_test(url: &str, mirrors: Vec<String>, sha256: Option<&str>) -> crate::ResolvedComponent {
+    crate::ResolvedComponent {
+        latest_version: "1.0.0".to_string(),
+        source_release_tag: "v1.0.0".to_string(),
+        download_url: url.to_string(),
+        asset_name: "asset.zip".to_string(),
+        install_dir: None,
+        sha256: sha256.map(String::from),
+        mirrors,
+        signature_url: None,
+        requires: None,
+        patch_from: std::collections::HashMap::new(),
+        binary_sha256: None,
+        size: None,
+    }
+}
+
+/// 다운로드 테스트용 HTTP 소스
+fn http_source() -> crate::GitHubSource {
+    crate::GitHubSource::new("test-owner")
+}
+
+/// "ok" 본문의 SHA256
+const OK_BODY_SHA256: &str = "2689367b205c16ce32ed4200942b8b8b1e262dfc70d9bc9fbc77c49699a4f1df";
+
+#[tokio::test]
+async fn test_download_falls_back_to_mirror() {
+    use std::sync::atomic::Ordering;
+    let (primary, primary_hits) = spawn_status_server(vec![404]).await;
+    let (mirror, mirror_hits) = spawn_status_server(vec![200]).await;
+    let dir = tempfile::tempdir().unwrap();
+    let dest = dir.path().join("asset.bin");
+
+    let rc = resolved_for_test(&primary, vec![mirror], Some(OK_BODY_SHA256));
+    let received = UpdateManager::download_resolved(&http_source(), &rc, &dest, None, None, None).await.unwrap();
+
+    assert_eq!(received, 2);
+    assert_eq!(primary_hits.load(Ordering::SeqCst), 1);
+    assert_eq!(mirror_hits.load(Ordering::SeqCst), 1);
+    assert_eq!(std::fs::read(&dest).unwrap(), b"ok");
+}
+
+#[tokio::test]
+async fn test_download_checksum_mismatch_tries_next_mirror() {
+    let (primary, _) = spawn_status_server(vec![200]).await;
+    let dir = tempfile::tempdir().unwrap();
+    let dest = dir.path().join("asset.zip");
+
+    // 모든 소스의 해시가 맞지 않으면 검증 오류로 실패하고 파일은 남지 않음
+    let rc = resolved_for_test(&primary, Vec::new(), Some(&"0".repeat(64)));
+    let err = UpdateManager::download_resolved(&http_source(), &rc, &dest, None, None, None).await.unwrap_err();
+
+    assert!(matches!(
+        err.downcast_ref::<UpdaterError>(),
+        Some(UpdaterError::ValidationError { .. })
+    ));
+    assert!(!dest.exists(), "검증 실패 파일은 삭제되어야 함");
+}
+
+#[tokio::test]
+async fn test_download_fails_closed_without_signature() {
+    let (primary, _) = spawn_status_server(vec![200]).await;
+    let dir = tempfile::tempdir().unwrap();
+    let dest = dir.path().join("asset.zip");
+
+    // 신뢰 공개키가 있는데 서명이 제공되지 않으면 거부
+    let rc = resolved_for_test(&primary, Vec::new(), None);
+    let pubkey = "00".repeat(32);
+    let err = UpdateManager::download_resolved(&http_source(), &rc, &dest, None, Some(&pubkey), None).await.unwrap_err();
+
+    match err.downcast_ref::<UpdaterError>() {
+        Some(UpdaterError::ValidationError { actual, .. }) => assert_eq!(actual, "signature missing"),
+        other => panic!("expected ValidationError, got {:?}", other),
+    }
+    assert!(!dest.exists());
+}
+
+#[tokio::test]
+async fn test_download_verifies_signature_over_sha256_digest() {
+    use ring::signature::KeyPair;
+    let rng = ring::rand::SystemRandom::new();
+    let pkcs8 = ring::signature::Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+    let key = ring::signature::Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+    let pubkey: String = key.public_key().as_ref().iter().map(|b| format!("{:02x}", b)).collect();
+    let dir = tempfile::tempdir().unwrap();
+    let dest = dir.path().join("asset.bin");
+
+    let mut source = MemorySource::default();
+    source.files.insert("mem://cdn/asset.bin".to_string(), b"ok".to_vec());
+    source.files.insert("mem://cdn/digest.sig".to_string(), key.sign(OK_BODY_SHA256.as_bytes()).as_ref().to_vec());
+    source.files.insert("mem://cdn/body.sig".to_string(), key.sign(b"ok").as_ref().to_vec());
+    let mut rc = resolved_for_test("mem://cdn/asset.bin", Vec::new(), Some(OK_BODY_SHA256));
+    rc.asset_name = "asset.bin".to_string();
+
+    // 다이제스트에 대한 서명 → 통과
+    rc.signature_url = Some("mem://cdn/digest.sig".to_string());
+    UpdateManager::download_resolved(&source, &rc, &dest, None, Some(&pubkey), None).await.unwrap();
+    assert_eq!(std::fs::read(&dest).unwrap(), b"ok");
+
+    // 에셋 본문에 대한 서명은 다이제스트 서명이 아니므로 거부
+    rc.signature_url = Some("mem://cdn/body.sig".to_string());
+    let err = UpdateManager::download_resolved(&source, &rc, &dest, None, Some(&pubkey), None).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<UpdaterError>(),
+        Some(UpdaterError::ValidationError { .. })
+    ));
+    assert!(!dest.exists());
+}
+
+#[tokio::test]
+async fn test_corrupt_archive_download_is_not_marked_downloaded() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+
+    let good_path = tmp.path().join("good.zip");
+    binary_test_zip(&good_path, &[("module.toml", b"name = \"old\"")]);
+    let mut source = MemorySource::default();
+    source.files.insert("mem://cdn/old.zip".to_string(), b"<html>502 Bad Gateway</html>".to_vec());
+    source.files.insert("mem://cdn/old.tar.gz".to_string(), b"<html>502 Bad Gateway</html>".to_vec());
+    source.files.insert("mem://mirror/old.zip".to_string(), std::fs::read(&good_path).unwrap());
+    manager.set_release_source(Arc::new(source));
+
+    // tar.gz도 열어서 확인
+    let dest = tmp.path().join("old.tar.gz");
+    let mut rc = resolved_for_test("mem://cdn/old.tar.gz", Vec::new(), None);
+    rc.asset_name = "old.tar.gz".to_string();
+    let err = UpdateManager::download_resolved(manager.source.as_ref(), &rc, &dest, None, None, None)
+        .await.unwrap_err();
+    assert!(matches!(UpdaterError::from(err), UpdaterError::CorruptDownload { .. }));
+    assert!(!dest.exists());
+
+    let component = Component::Module("old".to_string());
+    let mut rc = resolved_for_test("mem://cdn/old.zip", Vec::new(), None);
+    rc.asset_name = "old.zip".to_string();
+    manager.resolved_components.insert(component.manifest_key(), rc.clone());
+    let mut comp = backup_test_component(component.clone(), "2.0.0", false);
+    comp.update_available = true;
+    manager.status.components.push(comp);
+
+    // 200으로 받은 HTML 오류 페이지 → corrupt_download, 파일 삭제, downloaded 유지 안 함
+    let err = manager.download_component(&component).await.unwrap_err();
+    assert!(matches!(err, UpdaterError::CorruptDownload { ref asset, .. } if asset == "old.zip"), "{:?}", err);
+    assert_eq!(err.code(), "corrupt_download");
+    let status = manager.status().components.iter().find(|c| c.component == component).unwrap();
+    assert!(!status.downloaded);
+    assert!(!UpdateManager::staged_asset_path(&manager.staging_dir, "module-old", "old.zip").exists());
+
+    // 미러에 정상 zip이 있으면 그쪽으로 스테이징
+    rc.mirrors = vec!["mem://mirror/old.zip".to_string()];
+    manager.resolved_components.insert(component.manifest_key(), rc);
+    manager.download_component(&component).await.unwrap();
+    assert!(manager.status().components.iter().any(|c| c.component == component && c.downloaded));
+}
+
+#[tokio::test]
+async fn test_download_from_local_release_dir() {
+    let src = tempfile::tempdir().unwrap();
+    let asset = src.path().join("asset.zip");
+    std::fs::write(&asset, b"ok").unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let dest = dir.path().join("asset.bin");
+
+    let url = crate::github::file_url(&asset);
+    let rc = resolved_for_test(&url, Vec::new(), Some(OK_BODY_SHA256));
+    let received = UpdateManager::download_resolved(&http_source(), &rc, &dest, None, None, None).await.unwrap();
+    assert_eq!(received, 2);
+    assert_eq!(std::fs::read(&dest).unwrap(), b"ok");
+
+    // 로컬 소스도 sha256 검증을 동일하게 거침
+    let rc = resolved_for_test(&url, Vec::new(), Some(&"0".repeat(64)));
+    let err = UpdateManager::download_resolved(&http_source(), &rc, &dest, None, None, None).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<UpdaterError>(),
+        Some(UpdaterError::ValidationError { .. })
+    ));
+}
+
+#[test]
+fn test_local_release_dir_config() {
+    use crate::source::local_release_root;
+
+    let root = tempfile::tempdir().unwrap();
+    let mut config = test_config("http://mock-global");
+    config.local_release_dir = Some(root.path().to_string_lossy().to_string());
+    assert_eq!(local_release_root(&config).as_deref(), Some(root.path()));
+
+    // 코어 리포는 루트, 모듈/익스텐션 리포는 리포 이름 하위 디렉터리
+    let source = crate::LocalDirSource::new("test-owner", "saba-chan", root.path().to_path_buf());
+    assert_eq!(source.repo_dir("saba-chan"), root.path());
+    assert_eq!(source.repo_dir("saba-chan-modules"), root.path().join("saba-chan-modules"));
+
+    // 코어 리포 하위 디렉터리에 manifest.json이 있으면 그쪽을 사용
+    std::fs::create_dir_all(root.path().join("saba-chan")).unwrap();
+    std::fs::write(root.path().join("saba-chan").join("manifest.json"), "{}").unwrap();
+    assert_eq!(source.repo_dir("saba-chan"), root.path().join("saba-chan"));
+
+    // file:// api_base_url도 로컬 소스로 취급
+    assert_eq!(
+        local_release_root(&test_config("file:///srv/saba-releases")),
+        Some(std::path::PathBuf::from("/srv/saba-releases"))
+    );
+    assert!(local_release_root(&test_config("http://mock-global")).is_none());
+}
+
+/// 서버 없이 릴리즈를 제공하는 메모리 소스
+#[derive(Default)]
+struct MemorySource {
+    releases: std::collections::HashMap<String, Vec<crate::GitHubRelease>>,
+    /// 릴리즈 태그 → manifest.json 원문
+    manifests: std::collections::HashMap<String, String>,
+    /// URL → 에셋 내용
+    files: std::collections::HashMap<String, Vec<u8>>,
+}
+
+impl MemorySource {
+    fn add_release(&mut self, repo: &str, tag: &str, manifest: serde_json::Value, files: &[(&str, &[u8])]) {
+        let url = |name: &str| format!("mem://{}/{}/{}", repo, tag, name);
+        let mut assets = vec![crate::github::GitHubAsset {
+            name: "manifest.json".to_string(),
+            size: 0,
+            browser_download_url: url("manifest.json"),
+            content_type: None,
+        }];
+        for (name, data) in files {
+            assets.push(crate::github::GitHubAsset {
+                name: name.to_string(),
+                size: data.len() as u64,
+                browser_download_url: url(name),
+                content_type: None,
+            });
+            self.files.insert(url(name), data.to_vec());
+        }
+        self.manifests.insert(tag.to_string(), manifest.to_string());
+        self.releases.entry(repo.to_string()).or_default().push(crate::GitHubRelease {
+            tag_name: tag.to_string(),
+            name: None,
+            body: None,
+            prerelease: false,
+            draft: false,
+            published_at: None,
+            html_url: String::new(),
+            assets,
+        });
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ReleaseSource for MemorySource {
+    async fn fetch_releases(&self, repo: &str, _per_page: u32, page: u32) -> anyhow::Result<Vec<crate::GitHubRelease>> {
+        match page {
+            1 => Ok(self.releases.get(repo).cloned().unwrap_or_default()),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    async fn fetch_manifest(&self, _repo: &str, release: &crate::GitHubRelease) -> anyhow::Result<String> {
+        self.manifests.get(&release.tag_name).cloned()
+            .ok_or_else(|| anyhow::anyhow!("no manifest for {}", release.tag_name))
+    }
+
+    async fn download(
+        &self,
+        url: &str,
+        dest: &std::path::Path,
+        _progress: Option<&Arc<std::sync::Mutex<crate::DownloadProgress>>>,
+        _max_kbps: Option<u32>,
+    ) -> anyhow::Result<u64> {
+        let data = self.files.get(url).ok_or_else(|| anyhow::anyhow!("not found: {}", url))?;
+        std::fs::write(dest, data)?;
+        Ok(data.len() as u64)
+    }
+
+    async fn asset_size(&self, url: &str) -> Option<u64> {
+        self.files.get(url).map(|data| data.len() as u64)
+    }
+}
+
+#[tokio::test]
+async fn test_injected_release_source_walk_back_and_download() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+
+    // 최신 릴리즈(v2.0.0)에는 GUI만 포함 → 데몬은 v1.0.0 릴리즈에서 찾아야 함
+    let mut source = MemorySource::default();
+    source.add_release("saba-chan", "v2.0.0", serde_json::json!({
+        "release_version": "2.0.0",
+        "components": {
+            "saba-core": { "version": "1.0.0" },
+            "gui": { "version": "2.0.0", "asset": "gui.zip" }
+        }
+    }), &[("gui.zip", b"gui")]);
+    source.add_release("saba-chan", "v1.0.0", serde_json::json!({
+        "release_version": "1.0.0",
+        "components": {
+            "saba-core": { "version": "1.0.0", "asset": "core.zip", "sha256": OK_BODY_SHA256 }
+        }
+    }), &[("core.zip", b"ok")]);
+    manager.set_release_source(Arc::new(source));
+
+    let client = manager.create_client();
+    let (manifest, resolved) = manager.refresh_core_releases(&client).await.unwrap();
+    assert_eq!(manifest.release_version, "2.0.0");
+    assert_eq!(resolved["gui"].source_release_tag, "v2.0.0");
+    assert_eq!(resolved["saba-core"].source_release_tag, "v1.0.0");
+
+    let dest = tmp.path().join("core.bin");
+    let received = UpdateManager::download_resolved(
+        manager.source.as_ref(), &resolved["saba-core"], &dest, None, None, None,
+    ).await.unwrap();
+    assert_eq!(received, 2);
+    assert_eq!(std::fs::read(&dest).unwrap(), b"ok");
+}
+
+#[tokio::test]
+async fn test_download_available_updates_separates_same_asset_names() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+
+    // 두 모듈이 서로 다른 리포에서 같은 이름의 에셋을 배포
+    let bundle = |name: &str| {
+        let path = tmp.path().join(format!("{}-bundle.zip", name));
+        binary_test_zip(&path, &[("module.toml", name.as_bytes())]);
+        std::fs::read(path).unwrap()
+    };
+    let (old_zip, current_zip) = (bundle("old"), bundle("current"));
+    let mut source = MemorySource::default();
+    source.files.insert("mem://a/bundle.zip".to_string(), old_zip.clone());
+    source.files.insert("mem://b/bundle.zip".to_string(), current_zip.clone());
+    manager.set_release_source(Arc::new(source));
+
+    for (name, url) in [("old", "mem://a/bundle.zip"), ("current", "mem://b/bundle.zip")] {
+        let component = Component::Module(name.to_string());
+        let key = component.manifest_key();
+        let mut rc = resolved_for_test(url, Vec::new(), None);
+        rc.asset_name = "bundle.zip".to_string();
+        manager.resolved_components.insert(key, rc);
+        let mut comp = backup_test_component(component, "2.0.0", false);
+        comp.update_available = true;
+        comp.asset_name = Some("bundle.zip".to_string());
+        manager.status.components.push(comp);
+    }
+
+    manager.download_available_updates().await.unwrap();
+    manager.save_pending_manifest().unwrap();
+
+    let mut restored = plan_test_manager(tmp.path());
+    restored.staging_dir = tmp.path().join("staging");
+    assert_eq!(restored.load_pending_manifest().unwrap(), 2);
+    for (key, body) in [("module-old", &old_zip), ("module-current", &current_zip)] {
+        let comp = restored.status.components.iter()
+            .find(|c| c.component.manifest_key() == key)
+            .unwrap();
+        let path = std::path::PathBuf::from(comp.downloaded_path.as_ref().unwrap());
+        assert_eq!(path, tmp.path().join("staging").join(key).join("bundle.zip"));
+        assert_eq!(&std::fs::read(&path).unwrap(), body);
     }
 }
+
+#[tokio::test]
+async fn test_download_available_updates_except_skips_excluded() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+
+    let mut source = MemorySource::default();
+    for name in ["keep", "skip"] {
+        let path = tmp.path().join(format!("{}.zip", name));
+        binary_test_zip(&path, &[("module.toml", name.as_bytes())]);
+        source.files.insert(format!("mem://{}.zip", name), std::fs::read(path).unwrap());
+
+        let component = Component::Module(name.to_string());
+        let mut rc = resolved_for_test(&format!("mem://{}.zip", name), Vec::new(), None);
+        rc.asset_name = format!("{}.zip", name);
+        manager.resolved_components.insert(component.manifest_key(), rc);
+        let mut comp = backup_test_component(component, "2.0.0", false);
+        comp.update_available = true;
+        comp.asset_name = Some(format!("{}.zip", name));
+        manager.status.components.push(comp);
+    }
+    manager.set_release_source(Arc::new(source));
+
+    let downloaded = manager.download_available_updates_except(&["module-skip".to_string()]).await.unwrap();
+    assert_eq!(downloaded, vec!["keep.zip".to_string()]);
+
+    let flag = |key: &str| manager.status.components.iter()
+        .find(|c| c.component.manifest_key() == key)
+        .map(|c| (c.downloaded, c.downloaded_path.is_some()))
+        .unwrap();
+    assert_eq!(flag("module-keep"), (true, true));
+    assert_eq!(flag("module-skip"), (false, false));
+    assert!(!tmp.path().join("staging").join("module-skip").exists());
+}
+
+#[tokio::test]
+async fn test_plan_update_reports_downloads_skips_and_dependencies() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+
+    let mut source = MemorySource::default();
+    source.files.insert("mem://mirror/gui.zip".to_string(), vec![0; 1500]);
+    manager.set_release_source(Arc::new(source));
+
+    let component = |c: Component, current: &str, latest: &str| crate::ComponentVersion {
+        current_version: current.to_string(),
+        update_available: current != latest,
+        downloaded: false,
+        ..backup_test_component(c, latest, false)
+    };
+
+    // GUI: 기본 URL 크기 확인 실패 → 미러에서 크기 확인, 새 데몬 필요
+    let mut gui = component(Component::Gui, "1.0.0", "2.0.0");
+    gui.requires = Some(std::collections::HashMap::from([("saba-core".to_string(), ">=2.0.0".to_string())]));
+    let mut rc = resolved_for_test("mem://missing/gui.zip", vec!["mem://mirror/gui.zip".to_string()], None);
+    rc.latest_version = "2.0.0".to_string();
+    rc.asset_name = "gui.zip".to_string();
+    manager.resolved_components.insert("gui".to_string(), rc);
+    // 데몬: 이미 다운로드되어 적용 대기 중
+    let mut core = component(Component::CoreDaemon, "1.0.0", "2.0.0");
+    core.downloaded = true;
+    // CLI: 크기를 알 수 없는 다운로드, 설치본보다 새 CLI가 필요한데 계획에 없음
+    let mut cli = component(Component::Cli, "1.0.0", "2.0.0");
+    cli.requires = Some(std::collections::HashMap::from([("updater".to_string(), ">=3.0.0".to_string())]));
+    manager.resolved_components.insert("cli".to_string(), resolved_for_test("mem://missing/cli.zip", Vec::new(), None));
+    let updater = component(Component::Updater, "1.0.0", "1.0.0");
+    let mut pinned = component(Component::Module("minecraft".to_string()), "1.0.0", "1.0.0");
+    pinned.warning = Some(crate::PINNED.to_string());
+    manager.status.components = vec![gui, core, cli, updater, pinned];
+
+    let plan = manager.plan_update().await;
+
+    let downloads: Vec<(&str, Option<u64>)> = plan.downloads.iter()
+        .map(|d| (d.component.as_str(), d.size_bytes))
+        .collect();
+    assert_eq!(downloads, vec![("cli", None), ("gui", Some(1500))]);
+    assert_eq!(plan.total_download_bytes, 1500);
+    assert!(plan.size_incomplete);
+
+    let skipped: Vec<(&str, &str)> = plan.skipped.iter()
+        .map(|s| (s.component.as_str(), s.reason.as_str()))
+        .collect();
+    assert_eq!(skipped, vec![("module-minecraft", "pinned"), ("saba-core", "downloaded"), ("updater", "up_to_date")]);
+
+    let deps: Vec<(&str, &str, bool)> = plan.dependencies.iter()
+        .map(|d| (d.component.as_str(), d.required_component.as_str(), d.satisfied_by_plan))
+        .collect();
+    assert_eq!(deps, vec![("cli", "updater", false), ("gui", "saba-core", true)]);
+    assert_eq!(plan.restart_required, vec!["gui".to_string(), "saba-core".to_string()]);
+
+    // 아직 체크하지 않았으면 빈 계획
+    let empty = plan_test_manager(tmp.path()).plan_update().await;
+    assert_eq!(empty, crate::UpdatePlan::default());
+}
+
+#[tokio::test]
+async fn test_install_component_version_picks_requested_release() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+
+    let old_zip = tmp.path().join("old.zip");
+    binary_test_zip(&old_zip, &[("ko.json", b"{\"v\":1}")]);
+    let new_zip = tmp.path().join("new.zip");
+    binary_test_zip(&new_zip, &[("ko.json", b"{\"v\":2}")]);
+
+    let mut source = MemorySource::default();
+    source.add_release("saba-chan", "v2.0.0", serde_json::json!({
+        "release_version": "2.0.0",
+        "components": { "locales": { "version": "2.0.0", "asset": "locales.zip" } }
+    }), &[("locales.zip", &std::fs::read(&new_zip).unwrap())]);
+    source.add_release("saba-chan", "v1.0.0", serde_json::json!({
+        "release_version": "1.0.0",
+        "components": { "locales": { "version": "1.0.0", "asset": "locales.zip" } }
+    }), &[("locales.zip", &std::fs::read(&old_zip).unwrap())]);
+    manager.set_release_source(Arc::new(source));
+
+    // 없는 버전은 거부
+    let err = manager.install_component_version(&Component::Locales, "1.5.0").await.unwrap_err();
+    assert!(err.to_string().contains("1.5.0"), "{}", err);
+
+    manager.install_component_version(&Component::Locales, "v1.0.0").await.unwrap();
+    let installed = tmp.path().join("locales").join("ko.json");
+    assert_eq!(std::fs::read(&installed).unwrap(), b"{\"v\":1}");
+
+    // 고정 버전과 다른 버전은 설치하지 않음
+    manager.config.pinned.insert("locales".to_string(), "1.0.0".to_string());
+    assert!(manager.install_component_version(&Component::Locales, "2.0.0").await.is_err());
+    assert_eq!(std::fs::read(&installed).unwrap(), b"{\"v\":1}");
+
+    // 준비 → 다운로드 → 반영을 나눠 실행해도 동일 (다운로드 중에는 Manager를 빌리지 않음)
+    manager.config.pinned.clear();
+    let job = manager.prepare_component_version(&Component::Locales, "2.0.0").await.unwrap();
+    // 준비된 작업이 적용 독점권을 쥐고 있으므로 다른 적용은 거절
+    assert!(matches!(manager.begin_apply().unwrap_err().downcast_ref::<UpdaterError>(), Some(UpdaterError::ApplyInProgress)));
+    job.download().await.unwrap();
+    manager.finish_component_version(job).await.unwrap();
+    assert_eq!(std::fs::read(&installed).unwrap(), b"{\"v\":2}");
+    assert!(manager.begin_apply().is_ok());
+}
+
+#[tokio::test]
+async fn test_install_component_version_rejects_running_binaries() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+
+    let mut source = MemorySource::default();
+    source.add_release("saba-chan", "v1.0.0", serde_json::json!({
+        "release_version": "1.0.0",
+        "components": { "saba-core": { "version": "1.0.0", "asset": "saba-core.zip" } }
+    }), &[("saba-core.zip", b"not reached")]);
+    manager.set_release_source(Arc::new(source));
+
+    // 실행 중인 데몬 바이너리는 제자리 설치하지 않고 업데이터 적용 경로로 돌려보냄
+    let err = manager.install_component_version(&Component::CoreDaemon, "1.0.0").await.unwrap_err();
+    assert!(matches!(err, UpdaterError::RequiresRestart { .. }), "{:?}", err);
+    assert_eq!(err.code(), "requires_restart");
+    assert!(!err.is_recoverable());
+    assert!(!manager.staging_dir.exists(), "nothing should be staged");
+}
+
+#[tokio::test]
+async fn test_reinstall_component_replaces_dir_and_restores_on_failure() {
+    let tmp = tempfile::tempdir().unwrap();
+    let locales = tmp.path().join("locales");
+    std::fs::create_dir_all(&locales).unwrap();
+    std::fs::write(locales.join("ko.json"), b"corrupted").unwrap();
+    std::fs::write(locales.join("stray.tmp"), b"leftover").unwrap();
+
+    let good_zip = tmp.path().join("good.zip");
+    binary_test_zip(&good_zip, &[("ko.json", b"{\"v\":2}")]);
+    let release = |asset: &[u8]| {
+        let mut source = MemorySource::default();
+        source.add_release("saba-chan", "v2.0.0", serde_json::json!({
+            "release_version": "2.0.0",
+            "components": { "locales": { "version": "2.0.0", "asset": "locales.zip" } }
+        }), &[("locales.zip", asset)]);
+        Arc::new(source)
+    };
+
+    // 압축 해제 실패 → 기존 디렉터리 그대로 복원
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+    manager.set_release_source(release(b"not a zip"));
+    assert!(manager.reinstall_component(&Component::Locales).await.is_err());
+    assert_eq!(std::fs::read(locales.join("ko.json")).unwrap(), b"corrupted");
+    assert!(locales.join("stray.tmp").exists());
+    assert!(!manager.staging_dir.join("locales_reinstall_backup").exists());
+
+    // 설치되어 있어도 새로 압축을 풀고 남은 파일은 제거
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+    manager.set_release_source(release(&std::fs::read(&good_zip).unwrap()));
+    let message = manager.reinstall_component(&Component::Locales).await.unwrap();
+    assert!(message.contains("reinstalled"), "{}", message);
+    assert_eq!(std::fs::read(locales.join("ko.json")).unwrap(), b"{\"v\":2}");
+    assert!(!locales.join("stray.tmp").exists());
+    assert!(!manager.staging_dir.join("locales_reinstall_backup").exists());
+}
+
+// ═══════════════════════════════════════════════════════
+// 테스트: 워커 상태 공유
+// ═══════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_worker_status_handle_reflects_updates() {
+    let manager = Arc::new(RwLock::new(UpdateManager::new(test_config("http://127.0.0.1:1"), "./modules")));
+    let worker = BackgroundWorker::spawn(manager);
+
+    let handle = worker.status_handle();
+    {
+        let mut s = handle.write().await;
+        s.busy = true;
+        s.current_task = Some("Downloading Saba-Core...".to_string());
+    }
+
+    let status = worker.get_status().await;
+    assert!(status.busy, "핸들 변경이 get_status에 반영되어야 함");
+    assert_eq!(status.current_task.as_deref(), Some("Downloading Saba-Core..."));
+
+    let json = serde_json::to_value(&status).unwrap();
+    assert_eq!(json["busy"], true);
+    assert_eq!(json["pending_tasks"], 0);
+
+    worker.shutdown().await.unwrap();
+}
+
+async fn next_worker_event(rx: &mut tokio::sync::broadcast::Receiver<WorkerEvent>) -> WorkerEvent {
+    tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv()).await.unwrap().unwrap()
+}
+
+#[tokio::test]
+async fn test_worker_refuses_tasks_while_apply_lease_held() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+    let manager = Arc::new(RwLock::new(manager));
+    let worker = BackgroundWorker::spawn(manager.clone());
+    let mut rx = worker.subscribe();
+
+    let lease = manager.read().await.begin_apply().unwrap();
+    worker.check_now().await.unwrap();
+    match next_worker_event(&mut rx).await {
+        WorkerEvent::CheckFailed { error, .. } => {
+            assert_eq!(error, UpdaterError::ApplyInProgress.to_string());
+        }
+        other => panic!("check must be refused, got {:?}", other),
+    }
+
+    worker.download_component(Component::Locales).await.unwrap();
+    assert!(matches!(next_worker_event(&mut rx).await, WorkerEvent::DownloadFailed { .. }));
+    worker.download_all().await.unwrap();
+    assert!(matches!(next_worker_event(&mut rx).await, WorkerEvent::AllDownloadsCompleted { count: 0 }));
+    // 거절은 격리용 실패 횟수에 포함하지 않음
+    assert!(worker.get_status().await.consecutive_failures.is_empty());
+
+    // lease가 풀리면 다시 작업을 시작 (연결할 수 없는 서버라 체크 자체는 실패)
+    drop(lease);
+    worker.check_now().await.unwrap();
+    assert!(matches!(next_worker_event(&mut rx).await, WorkerEvent::CheckStarted));
+
+    worker.shutdown().await.unwrap();
+}
+
+/// 진행률을 조금씩 보고하며 천천히 내려받는 소스 (워커 진행률 이벤트 확인용)
+struct SlowSource {
+    data: Vec<u8>,
+}
+
+#[async_trait::async_trait]
+impl crate::ReleaseSource for SlowSource {
+    async fn fetch_releases(&self, _repo: &str, _per_page: u32, _page: u32) -> anyhow::Result<Vec<crate::GitHubRelease>> {
+        Ok(Vec::new())
+    }
+
+    async fn fetch_manifest(&self, _repo: &str, release: &crate::GitHubRelease) -> anyhow::Result<String> {
+        anyhow::bail!("no manifest for {}", release.tag_name)
+    }
+
+    async fn download(
+        &self,
+        _url: &str,
+        dest: &std::path::Path,
+        progress: Option<&Arc<std::sync::Mutex<crate::DownloadProgress>>>,
+        _max_kbps: Option<u32>,
+    ) -> anyhow::Result<u64> {
+        let total = self.data.len() as u64;
+        for step in 1..=4u64 {
+            if let Some(progress) = progress {
+                let mut p = progress.lock().unwrap();
+                p.total_bytes = total;
+                p.bytes_received = total * step / 4;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        }
+        std::fs::write(dest, &self.data)?;
+        Ok(total)
+    }
+
+    async fn asset_size(&self, _url: &str) -> Option<u64> {
+        Some(self.data.len() as u64)
+    }
+}
+
+#[tokio::test]
+async fn test_worker_broadcasts_download_progress() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+
+    let zip = tmp.path().join("module.zip");
+    binary_test_zip(&zip, &[("module.toml", b"name = \"old\"")]);
+    manager.set_release_source(Arc::new(SlowSource { data: std::fs::read(&zip).unwrap() }));
+
+    let component = Component::Module("old".to_string());
+    manager.resolved_components.insert(
+        component.manifest_key(),
+        resolved_for_test("mem://cdn/asset.zip", Vec::new(), None),
+    );
+    let mut comp = backup_test_component(component.clone(), "2.0.0", false);
+    comp.update_available = true;
+    manager.status.components.push(comp);
+
+    let worker = BackgroundWorker::spawn(Arc::new(RwLock::new(manager)));
+    let mut rx = worker.subscribe();
+    worker.download_component(component).await.unwrap();
+
+    let mut progress_events = Vec::new();
+    loop {
+        let event = tokio::time::timeout(std::time::Duration::from_secs(10), rx.recv())
+            .await
+            .expect("download should finish")
+            .unwrap();
+        match event {
+            WorkerEvent::DownloadProgress { component, downloaded, total } => {
+                assert_eq!(component, "Module: old");
+                progress_events.push((downloaded, total));
+            }
+            WorkerEvent::DownloadCompleted { .. } => break,
+            WorkerEvent::DownloadFailed { error, .. } => panic!("download failed: {}", error),
+            _ => {}
+        }
+    }
+
+    assert!(!progress_events.is_empty(), "at least one progress event during download");
+    let size = std::fs::metadata(&zip).unwrap().len();
+    assert!(progress_events.iter().all(|&(downloaded, total)| total == size && downloaded <= size));
+    // 같은 바이트 수는 다시 보내지 않음
+    assert!(progress_events.windows(2).all(|w| w[0].0 != w[1].0), "{:?}", progress_events);
+
+    worker.shutdown().await.unwrap();
+}
+
+#[test]
+fn test_worker_event_serializes_with_event_tag() {
+    let progress = serde_json::to_value(WorkerEvent::DownloadProgress {
+        component: "Saba-Core".to_string(),
+        downloaded: 512,
+        total: 1024,
+    }).unwrap();
+    assert_eq!(progress["event"], "download_progress");
+    assert_eq!(progress["downloaded"], 512);
+    assert_eq!(progress["total"], 1024);
+
+    let started = serde_json::to_value(WorkerEvent::CheckStarted).unwrap();
+    assert_eq!(started, serde_json::json!({ "event": "check_started" }));
+
+    let failed = serde_json::to_value(WorkerEvent::CheckFailed { error: "timeout".into(), retry_after_secs: None }).unwrap();
+    assert_eq!(failed["event"], "check_failed");
+    assert_eq!(failed["error"], "timeout");
+    assert!(failed.get("retry_after_secs").is_none());
+}
+
+#[test]
+fn test_worker_status_quarantines_after_repeated_failures() {
+    use crate::{WorkerStatus, QUARANTINE_BACKOFF, QUARANTINE_THRESHOLD};
+
+    let mut status = WorkerStatus::default();
+    let now = 1_700_000_000;
+    for _ in 1..QUARANTINE_THRESHOLD {
+        assert!(status.record_failure("saba-core", "bad asset", now).is_none());
+    }
+    assert!(!status.is_quarantined("saba-core", now));
+
+    let q = status.record_failure("saba-core", "bad asset", now).expect("threshold reached");
+    assert_eq!(q.until_unix, now + QUARANTINE_BACKOFF.as_secs());
+    assert_eq!(q.last_error, "bad asset");
+    assert!(status.is_quarantined("saba-core", now + 1));
+    assert!(!status.is_quarantined("cli", now + 1));
+
+    // 격리 기간이 지나면 해제되지만 실패 횟수는 유지 — 한 번 더 실패하면 다시 격리
+    assert!(!status.is_quarantined("saba-core", q.until_unix));
+    assert!(status.record_failure("saba-core", "bad asset", q.until_unix).is_some());
+
+    // 수동 다운로드/성공 시 초기화
+    status.clear_failures("saba-core");
+    assert!(!status.is_quarantined("saba-core", q.until_unix + 1));
+    assert!(!status.consecutive_failures.contains_key("saba-core"));
+
+    let event = serde_json::to_value(WorkerEvent::Quarantined {
+        component: "saba-core".into(),
+        until: q.until.clone(),
+    }).unwrap();
+    assert_eq!(event["event"], "quarantined");
+    assert_eq!(event["component"], "saba-core");
+}
+
+// ═══════════════════════════════════════════════════════
+// 테스트: 업데이터 셀프 업데이트 스크립트
+// ═══════════════════════════════════════════════════════
+
+#[test]
+fn test_updater_replacer_script_waits_for_pid_and_swaps() {
+    let exe = std::path::Path::new("/opt/saba-chan/saba-chan-updater");
+    let new_exe = std::path::Path::new("/opt/saba-chan/saba-chan-updater.new");
+    let script = UpdateManager::updater_replacer_script(4242, exe, new_exe);
+
+    assert!(script.contains("4242"), "종료를 기다릴 PID 포함");
+    assert!(script.contains(&exe.display().to_string()));
+    assert!(script.contains(&new_exe.display().to_string()));
+    if cfg!(target_os = "windows") {
+        assert!(script.contains("Wait-Process"));
+        assert!(script.contains("Move-Item"));
+    } else {
+        assert!(script.contains("kill -0 4242"));
+        assert!(script.contains(r#"mv -f "$NEW" "$EXE""#));
+    }
+}
+
+// ═══════════════════════════════════════════════════════
+// 설치 계획 (fresh_install / install_or_update)
+// ═══════════════════════════════════════════════════════
+
+/// 설치 계획 테스트용 매니저 — modules/{old,current}만 설치된 상태
+fn plan_test_manager(tmp: &std::path::Path) -> UpdateManager {
+    for name in ["old", "current"] {
+        let dir = tmp.join("modules").join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("module.toml"), format!("name = \"{}\"\n", name)).unwrap();
+    }
+    let mut config = test_config("http://127.0.0.1:1");
+    config.install_root = Some(tmp.to_string_lossy().to_string());
+    UpdateManager::new(config, tmp.join("modules").to_str().unwrap())
+}
+
+fn plan_test_manifest() -> crate::github::ReleaseManifest {
+    serde_json::from_value(serde_json::json!({
+        "release_version": "2.0.0",
+        "components": {
+            "saba-core": { "version": "2.0.0" },
+            "module-new": { "version": "2.0.0" },
+            "module-old": { "version": "2.0.0" },
+            "module-current": { "version": "2.0.0" }
+        }
+    }))
+    .unwrap()
+}
+
+fn plan_test_resolved() -> std::collections::HashMap<String, crate::ResolvedComponent> {
+    ["module-new", "module-old", "module-current"]
+        .into_iter()
+        .map(|key| {
+            let mut rc = resolved_for_test("http://127.0.0.1:1/asset.zip", Vec::new(), None);
+            rc.latest_version = "2.0.0".to_string();
+            (key.to_string(), rc)
+        })
+        .collect()
+}
+
+fn plan_test_local_versions() -> std::collections::HashMap<String, String> {
+    [("module-old", "1.0.0"), ("module-current", "2.0.0")]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_plan_install_fresh_skips_installed_components() {
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+
+    let plan = manager.plan_install(
+        &plan_test_manifest(),
+        &plan_test_resolved(),
+        None,
+        &plan_test_local_versions(),
+        false,
+    );
+
+    let targets: Vec<(&str, bool)> = plan.targets.iter().map(|(k, _, u)| (k.as_str(), *u)).collect();
+    assert_eq!(targets, vec![("module-new", false)]);
+    assert_eq!(plan.skipped.len(), 2, "old and current are both skipped: {:?}", plan.skipped);
+}
+
+#[test]
+fn test_plan_install_or_update_includes_outdated_components() {
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+
+    let plan = manager.plan_install(
+        &plan_test_manifest(),
+        &plan_test_resolved(),
+        None,
+        &plan_test_local_versions(),
+        true,
+    );
+
+    let targets: Vec<(&str, bool)> = plan.targets.iter().map(|(k, _, u)| (k.as_str(), *u)).collect();
+    assert_eq!(targets, vec![("module-new", false), ("module-old", true)]);
+    assert_eq!(plan.skipped, vec![Component::Module("current".to_string()).display_name()]);
+}
+
+#[test]
+fn test_plan_install_respects_filter() {
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+    let filter = vec!["module-old".to_string()];
+
+    let plan = manager.plan_install(
+        &plan_test_manifest(),
+        &plan_test_resolved(),
+        Some(&filter),
+        &plan_test_local_versions(),
+        true,
+    );
+
+    assert_eq!(plan.targets.len(), 1);
+    assert_eq!(plan.targets[0].0, "module-old");
+    assert!(plan.targets[0].2);
+    assert!(plan.skipped.is_empty());
+}
+
+#[test]
+fn test_plan_install_pulls_in_unsatisfied_requires() {
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+    let manifest: crate::github::ReleaseManifest = serde_json::from_value(serde_json::json!({
+        "release_version": "2.0.0",
+        "components": {
+            "saba-core": { "version": "2.0.0" },
+            "module-new": { "version": "2.0.0", "requires": {
+                "module-dep": ">=2.0.0",
+                "module-old": ">=2.0.0",
+                "module-current": ">=2.0.0",
+                "saba-core": ">=3.0.0"
+            } },
+            "module-dep": { "version": "2.0.0", "requires": { "module-deeper": ">=1.0.0" } },
+            "module-deeper": { "version": "1.0.0" },
+            "module-old": { "version": "2.0.0" },
+            "module-current": { "version": "2.0.0" }
+        }
+    }))
+    .unwrap();
+    let filter = vec!["module-new".to_string()];
+
+    let plan = manager.plan_install(
+        &manifest,
+        &plan_test_resolved(),
+        Some(&filter),
+        &plan_test_local_versions(),
+        false,
+    );
+
+    // 의존성이 먼저 오고, 설치돼 있지만 오래된 module-old는 갱신으로 추가
+    let targets: Vec<(&str, bool)> = plan.targets.iter().map(|(k, _, u)| (k.as_str(), *u)).collect();
+    assert_eq!(targets, vec![
+        ("module-deeper", false),
+        ("module-old", true),
+        ("module-dep", false),
+        ("module-new", false),
+    ]);
+    assert_eq!(plan.dependencies, vec!["module-dep", "module-old", "module-deeper"]);
+    // 데몬은 이 설치로 교체할 수 없으므로 충족 불가로 보고
+    assert_eq!(plan.unmet.len(), 1);
+    assert!(plan.unmet[0].contains("saba-core"), "{:?}", plan.unmet);
+}
+
+#[test]
+fn test_install_download_percent_sync() {
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+    UpdateManager::set_install_progress(&manager.install_progress, crate::InstallProgress {
+        total: 2,
+        ..crate::InstallProgress::default()
+    });
+
+    let half = Arc::new(std::sync::Mutex::new(crate::DownloadProgress {
+        bytes_received: 50,
+        total_bytes: 200,
+        ..crate::DownloadProgress::default()
+    }));
+    let unknown = Arc::new(std::sync::Mutex::new(crate::DownloadProgress::default()));
+    UpdateManager::sync_download_percent(&manager.install_progress, &[
+        ("Module: old".to_string(), half.clone()),
+        ("Module: new".to_string(), unknown),
+    ]);
+
+    let progress = manager.get_install_progress().unwrap();
+    assert_eq!(progress.download_percent.get("Module: old"), Some(&25));
+    assert!(!progress.download_percent.contains_key("Module: new"), "unknown size is not reported");
+
+    half.lock().unwrap().bytes_received = 200;
+    UpdateManager::sync_download_percent(&manager.install_progress, &[("Module: old".to_string(), half)]);
+    assert_eq!(manager.get_install_progress().unwrap().download_percent["Module: old"], 100);
+}
+
+#[test]
+fn test_cancel_install_sets_shared_flag() {
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+    let handle = manager.install_cancel.clone();
+    assert!(!handle.load(std::sync::atomic::Ordering::SeqCst));
+    manager.cancel_install();
+    assert!(handle.load(std::sync::atomic::Ordering::SeqCst));
+}
+
+#[test]
+fn test_install_progress_deserializes_without_new_fields() {
+    let json = r#"{"complete":true,"current_component":null,"total":1,"done":1,
+        "installed_components":["CLI"],"errors":[]}"#;
+    let progress: crate::InstallProgress = serde_json::from_str(json).unwrap();
+    assert!(!progress.cancelled);
+    assert!(progress.download_percent.is_empty());
+    assert!(progress.updated_components.is_empty());
+}
+
+// ═══════════════════════════════════════════════════════
+// 릴리즈 walk-back 페이지 탐색
+// ═══════════════════════════════════════════════════════
+
+/// 경로(쿼리 포함) → JSON 본문으로 응답하는 로컬 HTTP 서버. 요청된 경로를 기록합니다.
+async fn spawn_route_server(
+    build_routes: impl FnOnce(&str) -> std::collections::HashMap<String, String>,
+) -> (String, Arc<std::sync::Mutex<Vec<String>>>) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let routes = Arc::new(build_routes(&base));
+    let requested = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let requested_srv = requested.clone();
+
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let routes = routes.clone();
+            let requested = requested_srv.clone();
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap_or(0);
+                let req = String::from_utf8_lossy(&buf[..n]).to_string();
+                let path = req.split_whitespace().nth(1).unwrap_or("").to_string();
+                requested.lock().unwrap().push(path.clone());
+                let (code, body) = match routes.get(&path) {
+                    Some(body) => (200, body.clone()),
+                    None => (404, String::new()),
+                };
+                let resp = format!(
+                    "HTTP/1.1 {} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    code, body.len(), body
+                );
+                let _ = socket.write_all(resp.as_bytes()).await;
+            });
+        }
+    });
+
+    (base, requested)
+}
+
+/// 릴리즈 v5..v1 (최신순) — v5에는 gui 에셋만, cli 1.0.0 에셋은 v1에만 존재
+fn walk_back_routes(base: &str) -> std::collections::HashMap<String, String> {
+    let asset = |tag: &str, name: &str| serde_json::json!({
+        "name": name,
+        "size": 1,
+        "browser_download_url": format!("{}/dl/{}/{}", base, tag, name),
+        "content_type": null,
+    });
+    let release = |n: u32| {
+        let tag = format!("v{}", n);
+        let mut assets = vec![asset(&tag, "manifest.json")];
+        if n == 5 {
+            assets.push(asset(&tag, "gui.zip"));
+        }
+        if n == 1 {
+            assets.push(asset(&tag, "cli.zip"));
+        }
+        serde_json::json!({
+            "tag_name": tag,
+            "name": null,
+            "body": null,
+            "prerelease": false,
+            "draft": false,
+            "published_at": null,
+            "html_url": "",
+            "assets": assets,
+        })
+    };
+    let manifest = |n: u32| serde_json::json!({
+        "release_version": format!("{}.0.0", n),
+        "components": {
+            "gui": { "version": "5.0.0", "asset": "gui.zip" },
+            "cli": { "version": "1.0.0", "asset": "cli.zip", "asset_linux": "cli.zip" }
+        }
+    }).to_string();
+
+    let mut routes = std::collections::HashMap::new();
+    let pages: [&[u32]; 3] = [&[5, 4], &[3, 2], &[1]];
+    for (i, page) in pages.iter().enumerate() {
+        let list: Vec<_> = page.iter().map(|n| release(*n)).collect();
+        routes.insert(
+            format!("/repos/test-owner/saba-chan/releases?per_page=2&page={}", i + 1),
+            serde_json::Value::Array(list).to_string(),
+        );
+    }
+    for n in 1..=5 {
+        routes.insert(format!("/dl/v{}/manifest.json", n), manifest(n));
+    }
+    routes
+}
+
+#[tokio::test]
+async fn test_walk_back_pages_until_component_resolved() {
+    let (base, requested) = spawn_route_server(walk_back_routes).await;
+    let client = crate::github::GitHubClient::with_base_url("test-owner", "saba-chan", Some(&base));
+
+    let (_, resolved, releases) = client.resolve_components_paged(crate::ReleaseChannel::Stable, 10, 2).await.unwrap();
+
+    assert_eq!(resolved["gui"].source_release_tag, "v5");
+    assert_eq!(resolved["cli"].source_release_tag, "v1", "cli는 세 번째 페이지에서 발견되어야 함");
+    assert_eq!(releases.len(), 5);
+    let pages = requested.lock().unwrap().iter().filter(|p| p.contains("/releases?")).count();
+    assert_eq!(pages, 3);
+}
+
+#[tokio::test]
+async fn test_walk_back_stops_at_scan_depth() {
+    let (base, requested) = spawn_route_server(walk_back_routes).await;
+    let client = crate::github::GitHubClient::with_base_url("test-owner", "saba-chan", Some(&base));
+
+    let (_, resolved, releases) = client.resolve_components_paged(crate::ReleaseChannel::Stable, 4, 2).await.unwrap();
+
+    assert!(resolved.contains_key("gui"));
+    assert!(!resolved.contains_key("cli"), "깊이 4에서는 v1까지 내려가지 않음");
+    assert_eq!(releases.len(), 4);
+    assert!(!requested.lock().unwrap().iter().any(|p| p.ends_with("page=3")));
+}
+
+#[tokio::test]
+async fn test_walk_back_skips_extra_pages_when_resolved() {
+    let (base, requested) = spawn_route_server(|base| {
+        let mut routes = walk_back_routes(base);
+        // 첫 페이지만으로 모두 해결되도록 cli를 gui와 같은 릴리즈에 배치
+        let manifest = serde_json::json!({
+            "release_version": "5.0.0",
+            "components": {
+                "gui": { "version": "5.0.0", "asset": "gui.zip" }
+            }
+        }).to_string();
+        routes.insert("/dl/v5/manifest.json".to_string(), manifest);
+        routes
+    })
+    .await;
+    let client = crate::github::GitHubClient::with_base_url("test-owner", "saba-chan", Some(&base));
+
+    let (_, resolved, releases) = client.resolve_components_paged(crate::ReleaseChannel::Stable, 10, 2).await.unwrap();
+
+    assert_eq!(resolved.len(), 1);
+    assert_eq!(releases.len(), 2);
+    let pages = requested.lock().unwrap().iter().filter(|p| p.contains("/releases?")).count();
+    assert_eq!(pages, 1);
+}
+
+#[test]
+fn test_update_config_release_scan_depth_defaults() {
+    assert_eq!(UpdateConfig::default().release_scan_depth, 30);
+    let cfg: UpdateConfig = serde_json::from_value(serde_json::json!({
+        "enabled": true,
+        "check_interval_hours": 3,
+        "auto_download": false,
+        "auto_apply": false,
+        "github_owner": "o",
+        "github_repo": "r",
+        "include_prerelease": false,
+        "install_root": null
+    }))
+    .unwrap();
+    assert_eq!(cfg.release_scan_depth, 30, "기존 설정 파일에는 필드가 없어도 기본값 적용");
+    assert_eq!(cfg.channel, crate::ReleaseChannel::Stable);
+}
+
+#[test]
+fn test_release_channel_include_prerelease_alias() {
+    use crate::ReleaseChannel;
+
+    let mut cfg = UpdateConfig::default();
+    assert_eq!(cfg.effective_channel(), ReleaseChannel::Stable);
+    cfg.include_prerelease = true;
+    assert_eq!(cfg.effective_channel(), ReleaseChannel::Edge, "deprecated 플래그는 edge로 매핑");
+    cfg.channel = ReleaseChannel::Beta;
+    assert_eq!(cfg.effective_channel(), ReleaseChannel::Beta, "명시한 채널이 우선");
+
+    let cfg: UpdateConfig = serde_json::from_value(serde_json::json!({
+        "enabled": true,
+        "check_interval_hours": 3,
+        "auto_download": false,
+        "auto_apply": false,
+        "github_owner": "o",
+        "github_repo": "r",
+        "include_prerelease": false,
+        "channel": "beta",
+        "install_root": null
+    }))
+    .unwrap();
+    assert_eq!(cfg.channel, ReleaseChannel::Beta);
+}
+
+#[tokio::test]
+async fn test_release_channel_filters_core_releases() {
+    use crate::ReleaseChannel;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.config.include_prerelease = false;
+
+    let mut source = MemorySource::default();
+    for tag in ["v0.3.0-rc.1", "v0.2.0-beta.1", "v0.1.0"] {
+        let version = tag.trim_start_matches('v');
+        source.add_release("saba-chan", tag, serde_json::json!({
+            "release_version": version,
+            "components": { "gui": { "version": version, "asset": "gui.zip" } }
+        }), &[("gui.zip", b"gui")]);
+    }
+    for release in source.releases.get_mut("saba-chan").unwrap() {
+        release.prerelease = release.tag_name.contains('-');
+    }
+    manager.set_release_source(Arc::new(source));
+
+    for (channel, expected) in [
+        (ReleaseChannel::Stable, "0.1.0"),
+        (ReleaseChannel::Beta, "0.2.0-beta.1"),
+        (ReleaseChannel::Edge, "0.3.0-rc.1"),
+    ] {
+        manager.config.channel = channel;
+        let client = manager.create_client();
+        let (manifest, _) = manager.refresh_core_releases(&client).await.unwrap();
+        assert_eq!(manifest.release_version, expected, "{:?}", channel);
+    }
+}
+
+#[test]
+fn test_api_base_overrides_per_repo() {
+    let mut config = test_config("http://mock-global");
+    config.api_base_overrides.insert("saba-chan".to_string(), "http://mock-core".to_string());
+    config.api_base_overrides.insert("saba-chan-modules".to_string(), "  ".to_string());
+    let source = crate::GitHubSource::from_config(&config);
+
+    assert_eq!(source.api_base_for_repo("saba-chan"), Some("http://mock-core"));
+    assert_eq!(source.api_base_for_repo("saba-chan-module-palworld"), Some("http://mock-global"));
+    assert_eq!(
+        source.api_base_for_repo("saba-chan-modules"),
+        Some("http://mock-global"),
+        "빈 오버라이드는 전역 설정으로 폴백"
+    );
+
+    let mut config = test_config("http://mock-global");
+    config.api_base_url = None;
+    config.api_base_overrides.insert("saba-chan".to_string(), "http://mock-core".to_string());
+    let source = crate::GitHubSource::from_config(&config);
+    assert_eq!(source.api_base_for_repo("saba-chan-extensions"), None, "오버라이드 없는 리포는 실제 GitHub");
+}
+
+#[test]
+fn test_github_host_routes_api_to_enterprise() {
+    use crate::ReleaseSource;
+
+    let mut config = test_config("http://mock-global");
+    config.api_base_url = None;
+    config.github_host = Some("ghe.example.com".to_string());
+    config.api_base_overrides.insert("saba-chan-modules".to_string(), "http://mock-modules".to_string());
+    let source = crate::GitHubSource::from_config(&config);
+
+    assert_eq!(source.probe_endpoint("saba-chan").as_deref(), Some("https://ghe.example.com/api/v3"));
+    assert_eq!(
+        source.probe_endpoint("saba-chan-modules").as_deref(),
+        Some("http://mock-modules"),
+        "리포별 오버라이드가 Enterprise 호스트보다 우선"
+    );
+
+    config.github_host = Some("github.com".to_string());
+    let source = crate::GitHubSource::from_config(&config);
+    assert_eq!(source.probe_endpoint("saba-chan").as_deref(), Some(crate::github::DEFAULT_API_BASE));
+}
+
+#[test]
+fn test_select_module_asset_uses_pattern_or_default_names() {
+    let asset = |name: &str| crate::github::GitHubAsset {
+        name: name.to_string(),
+        size: 1,
+        browser_download_url: format!("https://example.com/{}", name),
+        content_type: None,
+    };
+    let linux = crate::Platform { os: "linux", arch: "x86_64" };
+    let windows = crate::Platform { os: "windows", arch: "x86_64" };
+    let pick = |assets: &[crate::github::GitHubAsset], version: &str, pattern: Option<&str>, platform: &crate::Platform| {
+        UpdateManager::select_module_asset(assets, "palworld", version, pattern, platform)
+            .found()
+            .map(|a| a.name.clone())
+    };
+    let assets = vec![asset("palworld-v1.2.0-windows-x86_64.zip"), asset("palworld-v1.2.0-linux-x86_64.zip"), asset("module-palworld.zip")];
+
+    let pattern = Some("{name}-v{version}-{os}-{arch}.zip");
+    assert_eq!(pick(&assets, "1.2.0", pattern, &linux).as_deref(), Some("palworld-v1.2.0-linux-x86_64.zip"));
+    assert_eq!(pick(&assets, "1.2.0", pattern, &windows).as_deref(), Some("palworld-v1.2.0-windows-x86_64.zip"));
+    // 패턴이 지정되면 기존 이름으로 폴백하지 않음
+    assert_eq!(pick(&assets, "2.0.0", pattern, &linux), None);
+
+    // 패턴이 없으면 기존 이름 규칙 — 플랫폼 표기가 붙은 변형은 현재 플랫폼용을 우선
+    let plain = vec![asset("palworld.zip")];
+    assert_eq!(pick(&plain, "1.2.0", None, &linux).as_deref(), Some("palworld.zip"));
+    let variants = vec![asset("module-palworld.zip"), asset("module-palworld-linux-x64.zip"), asset("module-palworld-windows-x64.zip")];
+    assert_eq!(pick(&variants, "1.2.0", None, &linux).as_deref(), Some("module-palworld-linux-x64.zip"));
+    assert_eq!(pick(&variants, "1.2.0", None, &windows).as_deref(), Some("module-palworld-windows-x64.zip"));
+    assert_eq!(pick(&[asset("palworldx.zip")], "1.2.0", None, &linux), None, "다른 이름의 접두어는 무시");
+
+    // 다른 플랫폼용 에셋만 있으면 잘못 고르지 않고 WrongPlatform
+    let windows_only = vec![asset("module-palworld-windows-x64.zip")];
+    assert!(matches!(
+        UpdateManager::select_module_asset(&windows_only, "palworld", "1.2.0", None, &linux),
+        crate::AssetMatch::WrongPlatform
+    ));
+}
+
+#[test]
+fn test_scan_modules_shared_by_repo_discovery_and_local_versions() {
+    let tmp = tempfile::tempdir().unwrap();
+    let modules = tmp.path().join("modules");
+    let write = |dir: &str, content: &str| {
+        std::fs::create_dir_all(modules.join(dir)).unwrap();
+        std::fs::write(modules.join(dir).join("module.toml"), content).unwrap();
+    };
+    // [module] 앞에 다른 테이블의 name/version이 있어도 [module] 값을 사용해야 함
+    write("palworld", "[meta]\nname = \"other\"\nversion = \"9.9.9\"\n\n[module]\nname = \"palworld\"\nversion = \"1.2.0\"\n\n[update]\ngithub_repo = \"saba-chan-module-palworld\"\nasset_pattern = \"{name}-v{version}-{os}.zip\"\n");
+    write("minecraft", "name = \"minecraft\"\nversion = \"2.0.0\"\n");
+    write("broken", "name = \"broken\n");
+    std::fs::create_dir_all(modules.join("empty")).unwrap();
+
+    let mut config = test_config("http://127.0.0.1:1");
+    config.install_root = Some(tmp.path().to_string_lossy().to_string());
+    let manager = UpdateManager::new(config, modules.to_str().unwrap());
+
+    let scanned = manager.scan_modules();
+    assert_eq!(scanned, vec![
+        crate::ModuleEntry { name: "minecraft".into(), version: Some("2.0.0".into()), repo: None, asset_pattern: None },
+        crate::ModuleEntry {
+            name: "palworld".into(),
+            version: Some("1.2.0".into()),
+            repo: Some("saba-chan-module-palworld".into()),
+            asset_pattern: Some("{name}-v{version}-{os}.zip".into()),
+        },
+    ]);
+
+    let repos: Vec<String> = manager.discover_module_repos().into_iter().map(|m| m.name).collect();
+    assert_eq!(repos, vec!["palworld".to_string()]);
+    let versions = manager.collect_local_versions();
+    assert_eq!(versions.get("module-palworld").map(String::as_str), Some("1.2.0"));
+    assert_eq!(versions.get("module-minecraft").map(String::as_str), Some("2.0.0"));
+    assert!(!versions.contains_key("module-other"));
+}
+
+fn apply_ok(component: &Component, restart_needed: bool) -> ApplyComponentResult {
+    ApplyComponentResult {
+        component: component.manifest_key(),
+        success: true,
+        message: String::new(),
+        stopped_processes: Vec::new(),
+        restart_needed,
+    }
+}
+
+#[test]
+fn test_build_apply_result_flags_daemon_and_self_update() {
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+
+    let result = manager.build_apply_result(vec![
+        apply_ok(&Component::Module("minecraft".to_string()), false),
+        apply_ok(&Component::CoreDaemon, true),
+        apply_ok(&Component::Gui, true),
+    ]);
+
+    assert!(result.restart_needed());
+    let script = result.daemon_restart_script.as_deref().expect("데몬 재시작 경로");
+    assert!(script.starts_with(&*tmp.path().to_string_lossy()));
+    assert!(script.contains("saba-core"));
+    assert_eq!(result.self_update_components, vec!["gui".to_string()]);
+    assert_eq!(
+        result.applied_names(),
+        vec![
+            Component::Module("minecraft".to_string()).display_name(),
+            Component::CoreDaemon.display_name(),
+            Component::Gui.display_name(),
+        ]
+    );
+}
+
+#[test]
+fn test_build_apply_result_modules_only() {
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+
+    let result = manager.build_apply_result(vec![
+        apply_ok(&Component::Module("minecraft".to_string()), false),
+        apply_ok(&Component::Extension("docker".to_string()), false),
+    ]);
+
+    assert!(!result.restart_needed());
+    assert!(result.daemon_restart_script.is_none());
+    assert!(result.self_update_components.is_empty());
+}
+
+#[test]
+fn test_process_checker_detects_running_binary_by_exe_name() {
+    // 테스트 바이너리 이름은 15자를 넘으므로 Linux에서도 실행파일 이름으로 매칭되어야 함.
+    // 자기 자신은 제외되므로 동일 바이너리를 자식 프로세스로 하나 더 띄운다.
+    let exe = std::env::current_exe().unwrap();
+    let exe_name = exe.file_name().unwrap().to_string_lossy().to_string();
+    let mut child = std::process::Command::new(&exe)
+        .args(["--exact", "test_process_checker_sleeping_child", "--ignored"])
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    let running = ProcessChecker::is_running(&exe_name);
+    let _ = child.kill();
+    let _ = child.wait();
+
+    assert!(running, "{} should be detected while the child runs", exe_name);
+    assert!(!ProcessChecker::is_running("saba-chan-definitely-not-running"));
+}
+
+#[tokio::test]
+async fn test_ensure_exited_reports_still_running() {
+    assert!(ProcessChecker::ensure_exited("saba-chan-definitely-not-running", 0).await.is_ok());
+
+    let exe = std::env::current_exe().unwrap();
+    let exe_name = exe.file_name().unwrap().to_string_lossy().to_string();
+    let mut child = std::process::Command::new(&exe)
+        .args(["--exact", "test_process_checker_sleeping_child", "--ignored"])
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    let result = ProcessChecker::ensure_exited(&exe_name, 1).await;
+    let _ = child.kill();
+    let _ = child.wait();
+
+    let err = result.expect_err("child process is still running").to_string();
+    assert!(err.contains("still running"), "Error: {}", err);
+}
+
+#[tokio::test]
+async fn test_ensure_exited_reports_whether_it_waited() {
+    assert!(!ProcessChecker::ensure_exited("saba-chan-definitely-not-running", 0).await.unwrap());
+
+    // 테스트 바이너리 자체도 같은 이름으로 실행 중이므로 고유한 이름의 사본을 띄움
+    let tmp = tempfile::tempdir().unwrap();
+    let exe_name = format!("saba-chan-wait-probe{}", std::env::consts::EXE_SUFFIX);
+    let exe = tmp.path().join(&exe_name);
+    std::fs::copy(std::env::current_exe().unwrap(), &exe).unwrap();
+    let mut child = std::process::Command::new(&exe)
+        .args(["--exact", "tests::test_process_checker_sleeping_child", "--ignored"])
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    for _ in 0..50 {
+        if ProcessChecker::is_running(&exe_name) {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    // 대기 중에 자식 프로세스를 종료시켜 제한 시간 안에 종료된 경우를 재현
+    let killer = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        let _ = child.kill();
+        let _ = child.wait();
+    });
+    let waited = ProcessChecker::ensure_exited(&exe_name, 10).await;
+    killer.join().unwrap();
+
+    assert!(waited.unwrap(), "{} should have been waited on", exe_name);
+}
+
+/// ProcessChecker 테스트가 자식 프로세스로 띄우는 대기용 테스트
+#[test]
+#[ignore]
+fn test_process_checker_sleeping_child() {
+    std::thread::sleep(std::time::Duration::from_secs(10));
+}
+
+fn backup_test_component(component: Component, latest: &str, pending: bool) -> crate::ComponentVersion {
+    crate::ComponentVersion {
+        component,
+        current_version: latest.to_string(),
+        latest_version: Some(latest.to_string()),
+        update_available: pending,
+        download_url: None,
+        asset_name: None,
+        release_notes: None,
+        published_at: None,
+        downloaded: pending,
+        downloaded_path: None,
+        installed: true,
+        warning: None,
+        requires: None,
+    }
+}
+
+#[tokio::test]
+async fn test_delta_patch_download_with_fallback() {
+    use std::io::Read;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    let binary = format!("saba-chan-cli{}", std::env::consts::EXE_SUFFIX);
+    let old = b"cli binary v1.0.0".to_vec();
+    let new = b"cli binary v1.0.1 (patched)".to_vec();
+    std::fs::write(tmp.path().join(&binary), &old).unwrap();
+
+    let mut cli = backup_test_component(Component::Cli, "1.0.1", true);
+    cli.current_version = "1.0.0".to_string();
+    manager.status.components.push(cli);
+
+    let full_path = tmp.path().join("full-cli.zip");
+    binary_test_zip(&full_path, &[(binary.as_str(), &new[..])]);
+    let full = std::fs::read(&full_path).unwrap();
+
+    let mut source = MemorySource::default();
+    source.files.insert("mem://cli.patch".to_string(), crate::delta::tests::make_patch(&old, &new));
+    source.files.insert("mem://cli.zip".to_string(), full.clone());
+    manager.set_release_source(Arc::new(source));
+
+    let sha256 = crate::integrity::compute_sha256_bytes(&full);
+    let mut rc = resolved_for_test("mem://cli.zip", Vec::new(), Some(&sha256));
+    rc.patch_from.insert("1.0.0".to_string(), "mem://cli.patch".to_string());
+    rc.binary_sha256 = Some(crate::integrity::compute_sha256_bytes(&new));
+
+    // 패치 적용 → 주 바이너리만 담긴 zip으로 스테이징
+    let dest = tmp.path().join("staging").join("cli.zip");
+    manager.download_or_patch("cli", &rc, &dest, None, None).await.unwrap();
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(&dest).unwrap()).unwrap();
+    let mut patched = Vec::new();
+    archive.by_name(&binary).unwrap().read_to_end(&mut patched).unwrap();
+    assert_eq!(patched, new);
+
+    // 결과 해시 불일치 → 전체 다운로드로 폴백
+    rc.binary_sha256 = Some("0".repeat(64));
+    manager.download_or_patch("cli", &rc, &dest, None, None).await.unwrap();
+    assert_eq!(std::fs::read(&dest).unwrap(), full);
+
+    // 로컬 버전에 맞는 패치가 없으면 전체 다운로드
+    rc.patch_from.clear();
+    rc.patch_from.insert("0.9.0".to_string(), "mem://cli.patch".to_string());
+    std::fs::remove_file(&dest).unwrap();
+    manager.download_or_patch("cli", &rc, &dest, None, None).await.unwrap();
+    assert_eq!(std::fs::read(&dest).unwrap(), full);
+}
+
+#[test]
+fn test_unreadable_local_version_is_not_an_update() {
+    use crate::{LocalVersion, VERSION_UNREADABLE};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+    let module = Component::Module("current".to_string());
+
+    assert_eq!(LocalVersion::classify(false, ""), LocalVersion::Missing);
+    assert_eq!(LocalVersion::classify(true, "dev-build"), LocalVersion::Unreadable);
+    assert_eq!(LocalVersion::classify(true, "1.2.0"), LocalVersion::Known("1.2.0".to_string()));
+
+    // 설치되어 있으나 버전을 읽지 못함 → 업데이트 표시 없이 경고
+    for current in ["", "dev-build"] {
+        assert_eq!(
+            manager.evaluate_update(&module, "2.0.0", current, true),
+            (false, Some(VERSION_UNREADABLE.to_string()))
+        );
+    }
+    // 미설치 → 설치 가능
+    assert_eq!(manager.evaluate_update(&module, "2.0.0", "", false), (true, None));
+    // 정상 버전 비교
+    assert_eq!(manager.evaluate_update(&module, "2.0.0", "1.0.0", true), (true, None));
+    assert_eq!(manager.evaluate_update(&module, "2.0.0", "2.0.0", true), (false, None));
+}
+
+#[test]
+fn test_pinned_component_suppresses_other_versions() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    let module = Component::Module("current".to_string());
+    manager.config.pinned.insert("module-current".to_string(), "v1.5.0".to_string());
+
+    assert_eq!(
+        manager.evaluate_update(&module, "2.0.0", "1.0.0", true),
+        (false, Some(crate::PINNED.to_string()))
+    );
+    // 최신 버전이 고정 버전과 같을 때만 업데이트로 표시
+    assert_eq!(manager.evaluate_update(&module, "1.5.0", "1.0.0", true), (true, None));
+    assert_eq!(manager.evaluate_update(&module, "1.5.0", "1.5.0", true), (false, None));
+    // 고정되지 않은 컴포넌트는 영향 없음
+    let other = Component::Module("old".to_string());
+    assert_eq!(manager.evaluate_update(&other, "2.0.0", "1.0.0", true), (true, None));
+}
+
+#[tokio::test]
+async fn test_apply_refuses_to_move_pinned_component() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.config.pinned.insert("locales".to_string(), "1.0.0".to_string());
+    let staged = tmp.path().join("locales.zip");
+    binary_test_zip(&staged, &[("ko.json", b"{}")]);
+    let mut comp = backup_test_component(Component::Locales, "1.0.1", true);
+    comp.downloaded_path = Some(staged.to_string_lossy().to_string());
+    manager.status.components.push(comp);
+
+    // 스테이징되어 있어도 고정 버전과 다르면 적용하지 않음
+    let result = manager.apply_components(&[], false, None).await.unwrap();
+    assert!(result.results.is_empty());
+    assert!(manager.apply_single_component(&Component::Locales).await.is_err());
+    assert!(!tmp.path().join("locales").join("ko.json").exists());
+}
+
+/// `requires`가 걸린 Locales 업데이트가 스테이징된 매니저
+fn dependency_test_manager(tmp: &std::path::Path, requires: &[(&str, &str)]) -> UpdateManager {
+    let mut manager = plan_test_manager(tmp);
+    let staged = tmp.join("locales.zip");
+    binary_test_zip(&staged, &[("ko.json", b"{}")]);
+    let mut comp = backup_test_component(Component::Locales, "1.0.1", true);
+    comp.downloaded_path = Some(staged.to_string_lossy().to_string());
+    comp.requires = Some(requires.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect());
+    manager.status.components.push(comp);
+    manager
+}
+
+#[tokio::test]
+async fn test_apply_blocks_unmet_dependencies_unless_forced() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = dependency_test_manager(tmp.path(), &[("module-dep-test", ">=2.0.0")]);
+
+    let blocked = manager.check_apply_dependencies(&[]);
+    assert_eq!(blocked.len(), 1);
+    assert_eq!(blocked[0].component, "locales");
+    assert_eq!(blocked[0].issues[0].required_component, "module-dep-test");
+
+    let result = manager.apply_components(&[], false, None).await.unwrap();
+    assert!(result.results.is_empty());
+    assert_eq!(result.blocked.len(), 1);
+    assert_ne!(result.exit_code(), crate::ApplyExitCode::Success);
+    assert!(!tmp.path().join("locales").join("ko.json").exists());
+
+    // force면 경고만 남기고 적용
+    let result = manager.apply_components(&[], true, None).await.unwrap();
+    assert_eq!(result.results.len(), 1);
+    assert!(result.blocked.is_empty());
+    assert!(tmp.path().join("locales").join("ko.json").exists());
+}
+
+#[test]
+fn test_dependency_satisfied_by_same_batch() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = dependency_test_manager(tmp.path(), &[("module-dep-test", ">=2.0.0")]);
+    // 같은 배치에서 의존 대상도 2.0.0으로 올라가면 통과
+    manager.status.components.push(backup_test_component(
+        Component::Module("dep-test".to_string()), "2.0.0", true,
+    ));
+    assert!(manager.check_apply_dependencies(&[]).is_empty());
+
+    // 의존 대상이 배치에서 빠지면 차단
+    let only_locales = ["locales".to_string()];
+    assert_eq!(manager.check_apply_dependencies(&only_locales).len(), 1);
+
+    // force로 확인한 경고는 이후 (pending 매니페스트를 읽는) 적용에서 다시 차단하지 않음
+    manager.acknowledge_dependencies(&only_locales);
+    assert!(manager.check_apply_dependencies(&only_locales).is_empty());
+}
+
+#[test]
+fn test_collect_stale_backups_only_verified_replacements() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    let exe = std::env::consts::EXE_SUFFIX;
+    for name in ["saba-core", "saba-chan-gui", "saba-chan-updater"] {
+        std::fs::write(tmp.path().join(format!("{}{}", name, exe)), b"live").unwrap();
+    }
+    for name in ["saba-core", "saba-chan-gui", "saba-chan-cli", "saba-chan-updater", "notes"] {
+        std::fs::write(tmp.path().join(format!("{}{}.old", name, exe)), b"old").unwrap();
+    }
+
+    manager.status.components = vec![
+        backup_test_component(Component::CoreDaemon, "0.2.0", false),
+        backup_test_component(Component::Gui, "0.3.0", false),
+        backup_test_component(Component::Cli, "0.2.0", false),
+        backup_test_component(Component::Updater, "0.2.0", true),
+    ];
+    let installed: std::collections::HashMap<String, String> = [
+        ("saba-core", "v0.2.0"),
+        ("gui", "0.2.0"),
+        ("cli", "0.2.0"),
+        ("updater", "0.2.0"),
+    ].iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+
+    let stale = manager.collect_stale_backups(&installed);
+    assert_eq!(
+        stale,
+        vec![tmp.path().join(format!("saba-core{}.old", exe))],
+        "GUI는 최신 미반영, CLI는 live 바이너리 없음, updater는 적용 대기 → 유지"
+    );
+}
+
+#[test]
+fn test_download_throttle_disabled_without_limit() {
+    assert!(crate::DownloadThrottle::new(None).is_none());
+    assert!(crate::DownloadThrottle::new(Some(0)).is_none());
+    assert!(UpdateConfig::default().max_download_kbps.is_none());
+}
+
+#[tokio::test]
+async fn test_download_throttle_limits_rate() {
+    let mut throttle = crate::DownloadThrottle::new(Some(1024)).unwrap();
+    let start = tokio::time::Instant::now();
+
+    // 버킷(1초 분량 = 1 MiB)을 비운 뒤 추가 256 KiB를 받으면 약 250ms가 걸려야 함
+    for _ in 0..20 {
+        throttle.consume(64 * 1024).await;
+    }
+    let elapsed = start.elapsed();
+    assert!(elapsed >= std::time::Duration::from_millis(200), "elapsed: {:?}", elapsed);
+    assert!(elapsed < std::time::Duration::from_secs(2), "elapsed: {:?}", elapsed);
+}
+
+fn binary_test_zip(path: &std::path::Path, files: &[(&str, &[u8])]) {
+    use std::io::Write;
+    let mut writer = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    for (name, data) in files {
+        writer.start_file(*name, zip::write::FileOptions::default()).unwrap();
+        writer.write_all(data).unwrap();
+    }
+    writer.finish().unwrap();
+}
+
+/// 현재 플랫폼의 실행파일 매직으로 시작하는 가짜 바이너리
+fn fake_executable(tag: &[u8]) -> Vec<u8> {
+    let mut data = if cfg!(target_os = "windows") {
+        b"MZ\x90\x00".to_vec()
+    } else if cfg!(target_os = "macos") {
+        vec![0xcf, 0xfa, 0xed, 0xfe]
+    } else {
+        b"\x7fELF".to_vec()
+    };
+    data.extend_from_slice(tag);
+    data
+}
+
+fn write_live_binary(path: &std::path::Path, data: &[u8]) {
+    std::fs::write(path, data).unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_apply_binary_update_accepts_valid_executable() {
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+    let exe = format!("saba-core{}", std::env::consts::EXE_SUFFIX);
+    write_live_binary(&tmp.path().join(&exe), &fake_executable(b"old"));
+
+    let staged = tmp.path().join("core.zip");
+    binary_test_zip(&staged, &[(&exe, &fake_executable(b"new"))]);
+
+    let stopped = manager.apply_binary_update("saba-core", staged.to_str().unwrap()).await.unwrap();
+    assert_eq!(std::fs::read(tmp.path().join(&exe)).unwrap(), fake_executable(b"new"));
+    assert!(stopped.is_empty(), "실행 중이 아니던 프로세스는 보고하지 않음: {:?}", stopped);
+}
+
+#[tokio::test]
+async fn test_apply_binary_update_restores_backup_on_invalid_executable() {
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+    let exe = format!("saba-core{}", std::env::consts::EXE_SUFFIX);
+    write_live_binary(&tmp.path().join(&exe), &fake_executable(b"old"));
+
+    let staged = tmp.path().join("core.zip");
+    binary_test_zip(&staged, &[(&exe, b"this is a README, not a binary")]);
+
+    let err = manager.apply_binary_update("saba-core", staged.to_str().unwrap()).await.unwrap_err();
+    assert!(err.to_string().contains("invalid executable"), "Error: {}", err);
+    assert_eq!(
+        std::fs::read(tmp.path().join(&exe)).unwrap(),
+        fake_executable(b"old"),
+        "이전 바이너리가 복원되어야 함"
+    );
+}
+
+#[tokio::test]
+async fn test_apply_binary_update_rejects_archive_without_primary_binary() {
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+    let exe = format!("saba-core{}", std::env::consts::EXE_SUFFIX);
+    write_live_binary(&tmp.path().join(&exe), &fake_executable(b"old"));
+
+    let staged = tmp.path().join("core.zip");
+    binary_test_zip(&staged, &[("README.md", b"# saba-core")]);
+
+    let err = manager.apply_binary_update("saba-core", staged.to_str().unwrap()).await.unwrap_err();
+    assert!(err.to_string().contains("does not contain"), "Error: {}", err);
+    assert!(!tmp.path().join("README.md").exists(), "아무 파일도 추출되지 않아야 함");
+    assert_eq!(std::fs::read(tmp.path().join(&exe)).unwrap(), fake_executable(b"old"));
+}
+
+fn shadow_test_manager(tmp: &std::path::Path) -> (UpdateManager, std::path::PathBuf, String) {
+    let root = tmp.join("install");
+    std::fs::create_dir_all(root.join("locales").join("en")).unwrap();
+    let exe = format!("saba-core{}", std::env::consts::EXE_SUFFIX);
+    write_live_binary(&root.join(&exe), &fake_executable(b"old"));
+
+    let mut config = test_config("http://127.0.0.1:1");
+    config.install_root = Some(root.to_string_lossy().to_string());
+    let mut manager = UpdateManager::new(config, tmp.join("modules").to_str().unwrap());
+    let mut core = backup_test_component(Component::CoreDaemon, "0.3.0", true);
+    core.downloaded_path = Some(tmp.join("core.zip").to_string_lossy().to_string());
+    let mut module = backup_test_component(Component::Module("minecraft".to_string()), "1.2.0", true);
+    module.downloaded_path = Some(tmp.join("module-minecraft.zip").to_string_lossy().to_string());
+    manager.status.components = vec![core, module];
+    (manager, root, exe)
+}
+
+#[tokio::test]
+async fn test_shadow_install_promotes_verified_tree() {
+    let tmp = tempfile::tempdir().unwrap();
+    let (mut manager, root, exe) = shadow_test_manager(tmp.path());
+    binary_test_zip(&tmp.path().join("core.zip"), &[(&exe, &fake_executable(b"new"))]);
+
+    let shadow = manager.apply_to_shadow().await.unwrap();
+    assert_eq!(shadow.applied_keys(), vec!["saba-core".to_string()]);
+    assert_eq!(shadow.deferred(), ["module-minecraft".to_string()]);
+    assert_eq!(std::fs::read(shadow.root().join(&exe)).unwrap(), fake_executable(b"new"));
+    assert_eq!(std::fs::read(root.join(&exe)).unwrap(), fake_executable(b"old"), "승격 전 라이브는 그대로");
+    assert!(manager.is_applying(), "섀도가 살아 있는 동안 적용 잠금 유지");
+
+    let shadow_root = shadow.root().to_path_buf();
+    let result = manager.promote_shadow(shadow).unwrap();
+    assert_eq!(std::fs::read(root.join(&exe)).unwrap(), fake_executable(b"new"));
+    assert!(root.join("locales").join("en").is_dir(), "기존 파일은 섀도 복사본으로 유지");
+    assert!(!shadow_root.exists());
+    assert!(!tmp.path().join(".install.previous").exists());
+    assert!(!tmp.path().join("core.zip").exists(), "스테이징 파일 삭제");
+    assert!(result.restart_needed());
+    assert!(result.daemon_restart_script.is_some());
+    assert!(!manager.is_applying());
+
+    let pending: Vec<String> = manager.pending_updates().into_iter().map(|p| p.component).collect();
+    assert_eq!(pending, vec!["module-minecraft".to_string()], "deferred 컴포넌트는 대기 상태로 남음");
+}
+
+#[tokio::test]
+async fn test_shadow_install_failure_leaves_live_untouched() {
+    let tmp = tempfile::tempdir().unwrap();
+    let (mut manager, root, exe) = shadow_test_manager(tmp.path());
+    binary_test_zip(&tmp.path().join("core.zip"), &[(&exe, b"this is a README, not a binary")]);
+
+    let err = manager.apply_to_shadow().await.unwrap_err();
+    assert!(err.to_string().contains("not a valid executable"), "Error: {}", err);
+    assert_eq!(std::fs::read(root.join(&exe)).unwrap(), fake_executable(b"old"));
+    assert!(!tmp.path().join(".install.shadow").exists(), "실패한 섀도는 삭제");
+    assert!(!manager.is_applying());
+    assert_eq!(manager.pending_updates().len(), 2);
+}
+
+#[test]
+fn test_shadow_recovers_interrupted_promotion() {
+    let tmp = tempfile::tempdir().unwrap();
+    let live = tmp.path().join("install");
+    let (shadow_dir, previous) = crate::shadow::swap_dirs(&live).unwrap();
+    assert_eq!(shadow_dir, tmp.path().join(".install.shadow"));
+    std::fs::create_dir_all(&previous).unwrap();
+    std::fs::write(previous.join("saba-core"), "old").unwrap();
+
+    crate::shadow::recover_swap(&live, &previous).unwrap();
+    assert_eq!(std::fs::read_to_string(live.join("saba-core")).unwrap(), "old");
+    assert!(!previous.exists());
+}
+
+#[test]
+fn test_pending_updates_lists_staged_components() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    let mut core = backup_test_component(Component::CoreDaemon, "0.3.0", true);
+    core.downloaded_path = Some("/staging/core.zip".to_string());
+    let mut module = backup_test_component(Component::Module("minecraft".to_string()), "1.2.0", true);
+    module.downloaded_path = Some("/staging/module-minecraft.zip".to_string());
+    manager.status.components = vec![
+        core,
+        module,
+        backup_test_component(Component::Gui, "0.3.0", false),
+    ];
+
+    let pending = manager.pending_updates();
+    assert_eq!(pending.len(), 2, "다운로드되지 않은 GUI는 제외");
+    assert_eq!(pending[0].component, "saba-core");
+    assert_eq!(pending[0].version.as_deref(), Some("0.3.0"));
+    assert_eq!(pending[0].staged_path, "/staging/core.zip");
+    assert!(pending[0].restart_required);
+    assert_eq!(pending[1].component, "module-minecraft");
+    assert!(!pending[1].restart_required);
+}
+
+#[test]
+fn test_pending_manifest_drops_staged_files_changed_after_download() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+    std::fs::create_dir_all(&manager.staging_dir).unwrap();
+
+    let downloaded_sha256 = crate::integrity::compute_sha256_bytes(b"downloaded");
+    let mut staged = Vec::new();
+    for component in [Component::CoreDaemon, Component::Cli, Component::Gui] {
+        let path = manager.staging_dir.join(format!("{}.zip", component.manifest_key()));
+        std::fs::write(&path, b"downloaded").unwrap();
+        let path = path.to_string_lossy().to_string();
+        // GUI는 다운로드 검증 해시가 기록되지 않은 항목
+        if component != Component::Gui {
+            manager.staged_sha256.insert(path.clone(), downloaded_sha256.clone());
+        }
+        let mut comp = backup_test_component(component, "2.0.0", true);
+        comp.downloaded_path = Some(path);
+        staged.push(comp);
+    }
+    manager.status.components = staged;
+
+    // 다운로드 이후, 매니페스트 저장 전에 CLI 스테이징 파일이 바뀜
+    std::fs::write(manager.staging_dir.join("cli.zip"), b"tampered").unwrap();
+    manager.save_pending_manifest().unwrap();
+
+    let saved: Vec<serde_json::Value> = serde_json::from_str(
+        &std::fs::read_to_string(manager.staging_dir.join("pending.json")).unwrap(),
+    ).unwrap();
+    assert_eq!(saved.len(), 2, "해시가 없는 GUI 항목은 기록하지 않음");
+    // 저장 시점이 아니라 다운로드 시점의 해시가 기록됨
+    for entry in &saved {
+        assert_eq!(entry["staged_sha256"].as_str(), Some(downloaded_sha256.as_str()));
+    }
+
+    // 바뀐 CLI 스테이징 파일은 적용 대상에서 제외
+    let mut restored = plan_test_manager(tmp.path());
+    restored.staging_dir = manager.staging_dir.clone();
+    assert_eq!(restored.load_pending_manifest().unwrap(), 1);
+    assert_eq!(restored.status.components[0].component, Component::CoreDaemon);
+}
+
+#[test]
+fn test_pending_manifest_from_copied_bundle_resolves_staged_paths() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut build = plan_test_manager(tmp.path());
+    build.staging_dir = tmp.path().join("build-staging");
+
+    // 빌드 서버: 절대 경로로 스테이징
+    let core_path = build.staging_dir.join("saba-core").join("saba-core.zip");
+    std::fs::create_dir_all(core_path.parent().unwrap()).unwrap();
+    std::fs::write(&core_path, b"core").unwrap();
+    let mut core = backup_test_component(Component::CoreDaemon, "2.0.0", true);
+    core.downloaded_path = Some(core_path.to_string_lossy().to_string());
+    build.staged_sha256.insert(core_path.to_string_lossy().to_string(), crate::integrity::compute_sha256_bytes(b"core"));
+    build.status.components = vec![core];
+    build.save_pending_manifest().unwrap();
+    let mut entries: Vec<serde_json::Value> = serde_json::from_str(
+        &std::fs::read_to_string(build.staging_dir.join("pending.json")).unwrap(),
+    ).unwrap();
+
+    // 에어갭 머신으로 스테이징 파일을 옮기고, 상대 경로 항목 추가
+    let bundle = tmp.path().join("usb").join("bundle");
+    std::fs::create_dir_all(bundle.join("saba-core")).unwrap();
+    std::fs::create_dir_all(bundle.join("cli")).unwrap();
+    std::fs::copy(&core_path, bundle.join("saba-core").join("saba-core.zip")).unwrap();
+    std::fs::write(bundle.join("cli").join("cli.zip"), b"cli").unwrap();
+    std::fs::remove_dir_all(&build.staging_dir).unwrap();
+
+    let mut cli = backup_test_component(Component::Cli, "2.0.0", true);
+    cli.downloaded_path = Some("cli/cli.zip".to_string());
+    entries.push(serde_json::to_value(&cli).unwrap());
+    std::fs::write(bundle.join("pending.json"), serde_json::to_string(&entries).unwrap()).unwrap();
+
+    let mut target = plan_test_manager(tmp.path());
+    assert_eq!(target.load_pending_manifest_from(&bundle.join("pending.json")).unwrap(), 2);
+    let staged = |key: &str| target.status.components.iter()
+        .find(|c| c.component.manifest_key() == key)
+        .and_then(|c| c.downloaded_path.clone())
+        .unwrap();
+    assert_eq!(staged("cli"), bundle.join("cli").join("cli.zip").to_string_lossy());
+    assert_eq!(staged("saba-core"), bundle.join("saba-core").join("saba-core.zip").to_string_lossy());
+
+    assert!(target.load_pending_manifest_from(&tmp.path().join("missing.json")).is_err());
+}
+
+#[test]
+fn test_component_status_returns_single_component_summary() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    let mut core = backup_test_component(Component::CoreDaemon, "0.3.0", true);
+    core.current_version = "0.2.0".to_string();
+    core.release_notes = Some("long changelog".to_string());
+    manager.status.components = vec![core, backup_test_component(Component::Gui, "0.3.0", false)];
+
+    let status = manager.component_status(&Component::CoreDaemon).unwrap();
+    assert_eq!(status.current_version, "0.2.0");
+    assert!(manager.component_status(&Component::Cli).is_none());
+
+    let summary = crate::ComponentSummary::from(&status);
+    assert_eq!(summary.component, "saba-core");
+    assert_eq!(summary.latest_version.as_deref(), Some("0.3.0"));
+    assert!(summary.update_available && summary.downloaded);
+    let json = serde_json::to_value(&summary).unwrap();
+    assert!(json.get("release_notes").is_none(), "요약에는 릴리즈 노트를 싣지 않음");
+}
+
+#[test]
+fn test_pending_changelog_dedupes_shared_release_notes() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    let with_notes = |component: Component, version: &str, notes: Option<&str>| {
+        let mut comp = backup_test_component(component, version, false);
+        comp.update_available = true;
+        comp.release_notes = notes.map(String::from);
+        comp
+    };
+    let core_notes = "## v0.4.0\n- 새 기능";
+    let mut unchanged = with_notes(Component::DiscordBot, "0.4.0", Some("bot"));
+    unchanged.update_available = false;
+    manager.status.components = vec![
+        with_notes(Component::CoreDaemon, "0.4.0", Some(core_notes)),
+        with_notes(Component::Cli, "0.4.0", Some(core_notes)),
+        with_notes(Component::Module("minecraft".into()), "1.2.0", Some("모듈 수정")),
+        with_notes(Component::Gui, "0.4.0", Some(&format!("{}\n", core_notes))),
+        with_notes(Component::Locales, "0.4.0", Some("locales")),
+        with_notes(Component::Module("palworld".into()), "2.0.0", Some("  ")),
+        unchanged,
+    ];
+
+    let changelog = manager.get_pending_changelog();
+    assert_eq!(changelog.len(), 2, "{:?}", changelog);
+    assert_eq!(changelog[0].component, "saba-core");
+    assert_eq!(changelog[0].notes, core_notes);
+    assert_eq!(changelog[0].shared_with, vec!["cli".to_string(), "gui".to_string()]);
+    assert_eq!(changelog[1].component, "module-minecraft");
+    assert_eq!(changelog[1].version.as_deref(), Some("1.2.0"));
+    assert!(changelog[1].shared_with.is_empty());
+
+    let json = serde_json::to_value(&changelog[1]).unwrap();
+    assert!(json.get("shared_with").is_none());
+}
+
+#[test]
+fn test_apply_exit_codes() {
+    use crate::ApplyExitCode;
+
+    assert_eq!(ApplyExitCode::from_outcome(0, false).code(), 0);
+    assert_eq!(ApplyExitCode::from_outcome(0, true).code(), 10);
+    assert_eq!(ApplyExitCode::from_outcome(1, true).code(), 20, "실패가 재시작보다 우선");
+
+    let network = anyhow::anyhow!(UpdaterError::NetworkError {
+        message: "connection reset".to_string(),
+        recoverable: true,
+    }).context("download failed");
+    assert_eq!(ApplyExitCode::from_error(&network), ApplyExitCode::NetworkError);
+    assert_eq!(ApplyExitCode::from_error(&network).code(), 30);
+    assert_eq!(ApplyExitCode::from_error(&anyhow::anyhow!("disk full")), ApplyExitCode::PartialFailure);
+
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+    let mut failed = apply_ok(&Component::Module("minecraft".to_string()), false);
+    failed.success = false;
+    assert_eq!(manager.build_apply_result(vec![]).exit_code(), ApplyExitCode::Success);
+    assert_eq!(
+        manager.build_apply_result(vec![apply_ok(&Component::CoreDaemon, true)]).exit_code(),
+        ApplyExitCode::RestartRequired
+    );
+    assert_eq!(manager.build_apply_result(vec![failed]).exit_code(), ApplyExitCode::PartialFailure);
+}
+
+#[test]
+fn test_completion_marker_exit_code_default() {
+    let legacy = r#"{"timestamp":"2026-01-01T00:00:00Z","updated_components":["gui"],"success":true,"message":null}"#;
+    let marker: crate::UpdateCompletionMarker = serde_json::from_str(legacy).unwrap();
+    assert_eq!(marker.exit_code, 0);
+    assert_eq!(crate::UpdateCompletionMarker::failure("x".to_string()).exit_code, 20);
+}
+
+#[test]
+fn test_verify_installed_reports_drift() {
+    use crate::VerifyIssue;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+    std::fs::write(
+        tmp.path().join("modules").join("current").join("module.toml"),
+        "name = \"current\"\nversion = \"1.1.0\"\n",
+    ).unwrap();
+
+    let installed: std::collections::HashMap<String, String> = [
+        ("module-current", "1.0.0"),
+        ("module-old", "0.1.0"),
+        ("module-removed", "2.0.0"),
+        ("installer", "0.1.0"),
+        ("saba-core", "0.1.0"),
+    ].iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+
+    let issues = manager.verify_installed_against(&installed);
+    assert_eq!(issues, vec![
+        ("installer".to_string(), VerifyIssue::UnknownComponent),
+        ("module-current".to_string(), VerifyIssue::VersionMismatch {
+            recorded: "1.0.0".to_string(),
+            detected: "1.1.0".to_string(),
+        }),
+        ("module-removed".to_string(), VerifyIssue::Missing),
+    ], "버전이 없는 module-old와 코어 데몬은 문제 없음");
+}
+
+// ═══════════════════════════════════════════════════════
+// 동시 적용 방지
+// ═══════════════════════════════════════════════════════
+
+#[test]
+fn test_begin_apply_is_exclusive() {
+    let tmp = tempfile::tempdir().unwrap();
+    let manager = plan_test_manager(tmp.path());
+
+    let lease = manager.begin_apply().unwrap();
+    assert!(manager.is_applying());
+
+    let err = manager.begin_apply().expect_err("second apply must be rejected");
+    assert!(matches!(err.downcast_ref::<UpdaterError>(), Some(UpdaterError::ApplyInProgress)));
+
+    drop(lease);
+    assert!(!manager.is_applying());
+    assert!(manager.begin_apply().is_ok());
+}
+
+#[test]
+fn test_process_lock_rejects_live_holder_and_reclaims_stale() {
+    use crate::lock::{ProcessLock, LOCK_FILE_NAME};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let lock_path = tmp.path().join(LOCK_FILE_NAME);
+    let own_pid = std::process::id().to_string();
+
+    // 살아 있는 다른 프로세스의 잠금 → 거부, 파일은 그대로
+    std::fs::write(&lock_path, "4242").unwrap();
+    let err = ProcessLock::acquire_with(tmp.path(), |pid| pid == 4242).unwrap_err();
+    assert!(matches!(err, UpdaterError::AlreadyRunning { pid: 4242 }), "{:?}", err);
+    assert_eq!(err.code(), "already_running");
+    assert_eq!(std::fs::read_to_string(&lock_path).unwrap(), "4242");
+
+    // 종료된 PID나 읽을 수 없는 내용 → stale로 회수하고 drop 시 삭제
+    for stale in ["4242", "garbage"] {
+        std::fs::write(&lock_path, stale).unwrap();
+        let lock = ProcessLock::acquire_with(tmp.path(), |_| false).unwrap();
+        assert_eq!(std::fs::read_to_string(&lock_path).unwrap(), own_pid);
+        drop(lock);
+        assert!(!lock_path.exists());
+    }
+
+    // 같은 프로세스가 만든 잠금은 공유하되 먼저 만든 쪽만 삭제
+    let first = ProcessLock::acquire_with(tmp.path(), |_| true).unwrap();
+    let second = ProcessLock::acquire_with(tmp.path(), |_| true).unwrap();
+    drop(second);
+    assert!(lock_path.exists());
+    drop(first);
+    assert!(!lock_path.exists());
+    assert_eq!(std::fs::read_dir(tmp.path()).unwrap().count(), 0, "임시 파일이 남지 않아야 함");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_process_lock_keeps_lock_replaced_during_stale_reclaim() {
+    use crate::lock::{ProcessLock, LOCK_FILE_NAME};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let lock_path = tmp.path().join(LOCK_FILE_NAME);
+    std::fs::write(&lock_path, "4242").unwrap();
+
+    // stale PID를 읽은 직후 다른 프로세스(5151)가 먼저 회수하고 새 잠금을 잡은 상황
+    let err = ProcessLock::acquire_with(tmp.path(), |pid| {
+        if pid == 4242 {
+            std::fs::remove_file(&lock_path).unwrap();
+            std::fs::write(&lock_path, "5151").unwrap();
+        }
+        false
+    })
+    .unwrap_err();
+
+    assert!(matches!(err, UpdaterError::AlreadyRunning { pid: 5151 }), "{:?}", err);
+    assert_eq!(std::fs::read_to_string(&lock_path).unwrap(), "5151", "새 잠금은 지우면 안 됨");
+    let leftovers: Vec<_> = std::fs::read_dir(tmp.path()).unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .filter(|name| name != LOCK_FILE_NAME)
+        .collect();
+    assert!(leftovers.is_empty(), "{:?}", leftovers);
+}
+
+#[test]
+fn test_begin_apply_rejected_while_other_process_holds_lock() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+    std::fs::create_dir_all(&manager.staging_dir).unwrap();
+
+    // 테스트 러너의 부모 프로세스는 실행 중이므로 다른 업데이터가 잡고 있는 것으로 취급
+    let parent = std::os::unix::process::parent_id();
+    let lock_path = manager.staging_dir.join(crate::lock::LOCK_FILE_NAME);
+    std::fs::write(&lock_path, parent.to_string()).unwrap();
+
+    let err = UpdaterError::from(manager.begin_apply().expect_err("lock held by another process"));
+    assert!(matches!(err, UpdaterError::AlreadyRunning { pid } if pid == parent), "{:?}", err);
+    assert!(!manager.is_applying(), "잠금 실패 시 적용 플래그도 해제");
+
+    std::fs::remove_file(&lock_path).unwrap();
+    let lease = manager.begin_apply().unwrap();
+    assert!(lock_path.exists());
+    drop(lease);
+    assert!(!lock_path.exists());
+}
+
+#[tokio::test]
+async fn test_apply_rejected_while_another_apply_runs() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.status.components.push(backup_test_component(Component::Module("minecraft".into()), "1.0.0", true));
+
+    let lease = manager.begin_apply().unwrap();
+    let err = manager.apply_updates().await.unwrap_err();
+    assert!(matches!(err, UpdaterError::ApplyInProgress));
+    let err = manager.apply_single_component(&Component::Module("minecraft".into())).await.unwrap_err();
+    assert!(matches!(err, UpdaterError::ApplyInProgress));
+    assert_eq!(err.code(), "apply_in_progress");
+    drop(lease);
+}
+
+#[tokio::test]
+async fn test_apply_guard_released_after_error() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    // 스테이징 파일이 깨져 있어 적용 도중 실패
+    let staged = tmp.path().join("module-minecraft.zip");
+    std::fs::write(&staged, b"not a zip").unwrap();
+    let mut comp = backup_test_component(Component::Module("minecraft".into()), "1.0.0", true);
+    comp.downloaded_path = Some(staged.to_string_lossy().to_string());
+    manager.status.components.push(comp);
+
+    let err = manager.apply_updates().await.unwrap_err();
+    assert!(!matches!(err, UpdaterError::ApplyInProgress), "{}", err);
+    assert!(!manager.is_applying());
+
+    let err = manager.apply_single_component(&Component::Module("minecraft".into())).await.unwrap_err();
+    assert!(!matches!(err, UpdaterError::ApplyInProgress), "{}", err);
+    assert!(!manager.is_applying());
+}
+
+#[test]
+fn test_gui_source_tree_requires_dev_markers() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = tmp.path();
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    std::fs::write(dir.join("package.json"), "{}").unwrap();
+
+    // 패키징된 GUI가 package.json + src를 포함하는 것만으로는 소스 트리가 아님
+    assert!(UpdateManager::gui_source_tree_reason(dir).is_none());
+
+    std::fs::write(dir.join("vite.config.js"), "").unwrap();
+    std::fs::create_dir_all(dir.join("node_modules")).unwrap();
+    let reason = UpdateManager::gui_source_tree_reason(dir).unwrap();
+    assert!(reason.contains("vite.config.js"), "{}", reason);
+    assert!(reason.contains("node_modules"), "{}", reason);
+
+    std::fs::remove_file(dir.join("package.json")).unwrap();
+    assert!(UpdateManager::gui_source_tree_reason(dir).is_none());
+}
+
+// ═══════════════════════════════════════════════════════
+// 자동 체크 지터
+// ═══════════════════════════════════════════════════════
+
+#[test]
+fn test_jittered_interval_within_ten_percent() {
+    let base = std::time::Duration::from_secs(3 * 3600);
+    for _ in 0..1000 {
+        let d = crate::scheduler::jittered_interval(base);
+        assert!(d >= base.mul_f64(0.9) && d <= base.mul_f64(1.1), "{:?}", d);
+    }
+    for _ in 0..1000 {
+        assert!(crate::scheduler::startup_delay() <= std::time::Duration::from_secs(300));
+    }
+}
+
+#[test]
+fn test_schedule_next_check_updates_status() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    assert!(manager.next_check_delay().is_none());
+
+    let before = crate::chrono_now_iso();
+    let delay = std::time::Duration::from_secs(2 * 3600 + 17);
+    manager.schedule_next_check(delay);
+    assert_eq!(manager.next_check_delay(), Some(delay));
+
+    let next = manager.get_status().next_check.unwrap();
+    assert_eq!(next.len(), before.len());
+    assert!(next > before, "{} should be after {}", next, before);
+}
+
+#[test]
+fn test_check_schedule_survives_restart() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+    assert!(manager.restore_check_schedule().is_none());
+
+    manager.status.last_check = Some(crate::chrono_now_iso());
+    manager.schedule_next_check(std::time::Duration::from_secs(3600));
+    manager.save_check_schedule().unwrap();
+
+    // 재시작: 새 매니저가 같은 staging 디렉터리에서 기록을 복원
+    let mut restarted = plan_test_manager(tmp.path());
+    restarted.staging_dir = tmp.path().join("staging");
+    let remaining = restarted.restore_check_schedule().unwrap();
+    assert!(remaining <= std::time::Duration::from_secs(3600));
+    assert!(remaining >= std::time::Duration::from_secs(3590), "{:?}", remaining);
+    assert_eq!(restarted.get_status().last_check, manager.get_status().last_check);
+    assert_eq!(restarted.next_check_delay(), Some(remaining));
+}
+
+#[test]
+fn test_check_schedule_past_due_or_corrupt() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().to_path_buf();
+
+    std::fs::write(
+        tmp.path().join("check-state.json"),
+        r#"{"last_check":"2020-01-01T00:00:00Z","next_check":"2020-01-01T03:00:00Z","next_check_unix":1577847600}"#,
+    )
+    .unwrap();
+    assert_eq!(manager.restore_check_schedule(), Some(std::time::Duration::ZERO));
+    assert_eq!(manager.get_status().last_check.as_deref(), Some("2020-01-01T00:00:00Z"));
+
+    std::fs::write(tmp.path().join("check-state.json"), "not json").unwrap();
+    assert!(manager.restore_check_schedule().is_none());
+}
+
+// ═══════════════════════════════════════════════════════
+// 포터블 모듈/익스텐션 디렉터리
+// ═══════════════════════════════════════════════════════
+
+#[test]
+fn test_modules_and_extensions_dir_overrides() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut config = test_config("http://127.0.0.1:1");
+    config.install_root = Some(tmp.path().to_string_lossy().to_string());
+    config.modules_dir = Some("data/modules".to_string());
+    config.extensions_dir = Some("data/extensions".to_string());
+
+    // 호출자가 넘긴 modules_dir보다 config 오버라이드가 우선
+    let elsewhere = tmp.path().join("elsewhere");
+    let manager = UpdateManager::new(config, elsewhere.to_str().unwrap());
+    let modules = tmp.path().join("data").join("modules");
+    let extensions = tmp.path().join("data").join("extensions");
+    assert!(modules.is_dir() && extensions.is_dir());
+    assert!(!elsewhere.exists());
+
+    // manifest의 install_dir보다 오버라이드가 우선
+    assert_eq!(
+        manager.resolve_install_dir_for_test(&Component::Module("mc".into()), Some("modules/mc")),
+        modules.join("mc")
+    );
+    assert_eq!(
+        manager.resolve_install_dir_for_test(&Component::Extension("docker".into()), None),
+        extensions.join("docker")
+    );
+    assert_eq!(
+        manager.resolve_install_dir_for_test(&Component::Locales, Some("locales")),
+        tmp.path().join("locales")
+    );
+
+    // 설치 감지도 같은 디렉터리를 사용
+    std::fs::create_dir_all(modules.join("mc")).unwrap();
+    std::fs::write(modules.join("mc").join("module.toml"), "name = \"mc\"\n").unwrap();
+    assert!(manager.is_component_installed(&Component::Module("mc".into())));
+}
+
+#[test]
+fn test_updater_path_override() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut config = test_config("http://127.0.0.1:1");
+    config.install_root = Some(tmp.path().to_string_lossy().to_string());
+    config.updater_path = Some("bin/my-updater".to_string());
+    let manager = UpdateManager::new(config, tmp.path().join("modules").to_str().unwrap());
+
+    // 지정한 경로에 파일이 없으면 다른 후보로 넘어가지 않고 실패
+    let err = UpdaterError::from(manager.find_updater_executable().unwrap_err());
+    assert_eq!(err.code(), "updater_not_found");
+    assert!(!err.is_recoverable());
+    let UpdaterError::UpdaterNotFound { searched } = &err else { panic!("{:?}", err) };
+    assert_eq!(searched, &vec![tmp.path().join("bin/my-updater").display().to_string()]);
+
+    // 상대경로는 install_root 기준
+    std::fs::create_dir_all(tmp.path().join("bin")).unwrap();
+    std::fs::write(tmp.path().join("bin/my-updater"), b"").unwrap();
+    assert_eq!(
+        manager.find_updater_executable().unwrap(),
+        tmp.path().join("bin/my-updater").display().to_string()
+    );
+}
+
+#[test]
+fn test_is_component_installed_at_least_checks_local_version() {
+    let tmp = tempfile::tempdir().unwrap();
+    let modules = tmp.path().join("modules");
+    let manager = UpdateManager::new(test_config("http://127.0.0.1:1"), modules.to_str().unwrap());
+    let mc = Component::Module("mc".into());
+
+    // 미설치 → 버전과 무관하게 false
+    assert!(!manager.is_component_installed_at_least(&mc, ">=1.0.0"));
+
+    std::fs::create_dir_all(modules.join("mc")).unwrap();
+    std::fs::write(
+        modules.join("mc").join("module.toml"),
+        "[module]\nname = \"mc\"\nversion = \"1.2.0\"\n",
+    ).unwrap();
+    assert!(manager.is_component_installed(&mc));
+    assert!(manager.is_component_installed_at_least(&mc, ">=1.2.0"));
+    assert!(manager.is_component_installed_at_least(&mc, "1.1.5"));
+    // 설치돼 있지만 너무 오래됨
+    assert!(!manager.is_component_installed_at_least(&mc, ">=1.3.0"));
+
+    // 버전을 감지할 수 없으면 충족하지 않는 것으로 취급
+    std::fs::write(modules.join("mc").join("module.toml"), "[module]\nname = \"mc\"\n").unwrap();
+    assert!(manager.is_component_installed(&mc));
+    assert!(!manager.is_component_installed_at_least(&mc, ">=1.0.0"));
+}
+
+// ═══════════════════════════════════════════════════════
+// 스테이징 정리
+// ═══════════════════════════════════════════════════════
+
+/// 파일/디렉터리의 수정 시각을 `days`일 전으로 되돌림
+fn age_path(path: &std::path::Path, days: u64) {
+    let when = std::time::SystemTime::now() - std::time::Duration::from_secs(days * 24 * 60 * 60);
+    std::fs::File::open(path).unwrap().set_modified(when).unwrap();
+}
+
+#[test]
+fn test_prune_staging_removes_only_stale_unreferenced_entries() {
+    let tmp = tempfile::tempdir().unwrap();
+    let staging = tmp.path().join("staging");
+    std::fs::create_dir_all(staging.join("old_backup")).unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = staging.clone();
+
+    let referenced = staging.join("saba-core").join("saba-core.zip");
+    std::fs::create_dir_all(referenced.parent().unwrap()).unwrap();
+    let mut pending = backup_test_component(Component::CoreDaemon, "2.0.0", true);
+    pending.downloaded_path = Some(referenced.to_string_lossy().to_string());
+    std::fs::write(staging.join("pending.json"), serde_json::to_string(&vec![pending]).unwrap()).unwrap();
+    std::fs::write(&referenced, b"staged").unwrap();
+    std::fs::write(staging.join("check-state.json"), b"{}").unwrap();
+    std::fs::write(staging.join("abandoned.zip"), b"12345").unwrap();
+    std::fs::write(staging.join("fresh.zip"), b"new").unwrap();
+    std::fs::write(staging.join("old_backup").join("bin"), b"abc").unwrap();
+
+    for name in ["pending.json", "check-state.json", "saba-core/saba-core.zip", "abandoned.zip"] {
+        age_path(&staging.join(name), 30);
+    }
+    // 디렉터리 핸들로 수정 시각을 바꾸는 것은 unix에서만 가능
+    if cfg!(unix) {
+        age_path(&staging.join("old_backup"), 30);
+        age_path(&staging.join("saba-core"), 30);
+    }
+
+    let expected = if cfg!(unix) { 5 + 3 } else { 5 };
+    assert_eq!(manager.prune_staging().unwrap(), expected);
+    assert!(!staging.join("abandoned.zip").exists());
+    assert_eq!(staging.join("old_backup").exists(), !cfg!(unix));
+    assert!(staging.join("fresh.zip").exists(), "기준보다 새 파일은 유지");
+    assert!(referenced.exists(), "pending.json이 참조하는 파일은 유지");
+    assert!(staging.join("pending.json").exists());
+    assert!(staging.join("check-state.json").exists());
+}
+
+#[test]
+fn test_prune_staging_keeps_everything_when_pending_unreadable_or_disabled() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().to_path_buf();
+
+    std::fs::write(tmp.path().join("abandoned.zip"), b"12345").unwrap();
+    age_path(&tmp.path().join("abandoned.zip"), 30);
+
+    manager.config.staging_max_age_days = 0;
+    assert_eq!(manager.prune_staging().unwrap(), 0);
+
+    manager.config.staging_max_age_days = 7;
+    std::fs::write(tmp.path().join("pending.json"), "not json").unwrap();
+    assert!(manager.prune_staging().is_err());
+    assert!(tmp.path().join("abandoned.zip").exists());
+}
+
+// ═══════════════════════════════════════════════════════
+// 새 업데이트 알림
+// ═══════════════════════════════════════════════════════
+
+/// 받은 요청 본문을 채널로 넘기고 200으로 응답하는 웹훅 서버
+async fn spawn_webhook_server() -> (String, tokio::sync::mpsc::UnboundedReceiver<serde_json::Value>) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+            let body = loop {
+                let n = socket.read(&mut buf).await.unwrap_or(0);
+                if n == 0 {
+                    break None;
+                }
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                let Some(header_end) = text.find("\r\n\r\n") else { continue };
+                let length = text[..header_end]
+                    .lines()
+                    .find_map(|l| l.to_ascii_lowercase().strip_prefix("content-length:").map(|v| v.trim().to_string()))
+                    .and_then(|v| v.parse::<usize>().ok())
+                    .unwrap_or(0);
+                if request.len() >= header_end + 4 + length {
+                    break Some(request[header_end + 4..header_end + 4 + length].to_vec());
+                }
+            };
+            let _ = socket
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+                .await;
+            if let Some(json) = body.and_then(|b| serde_json::from_slice(&b).ok()) {
+                let _ = tx.send(json);
+            }
+        }
+    });
+
+    (format!("http://{}/hook", addr), rx)
+}
+
+#[tokio::test]
+async fn test_update_notice_fires_once_per_version() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let (url, mut bodies) = spawn_webhook_server().await;
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+    manager.config.webhook_url = Some(url);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    manager.set_on_update_available(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+
+    let mut comp = backup_test_component(Component::CoreDaemon, "1.1.0", false);
+    comp.current_version = "1.0.0".to_string();
+    comp.update_available = true;
+    comp.release_notes = Some("fixes".to_string());
+    manager.status.components.push(comp);
+    manager.announce_new_updates().await;
+    manager.announce_new_updates().await;
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    let body = bodies.recv().await.unwrap();
+    assert_eq!(body["component"], "saba-core");
+    assert_eq!(body["latest"], "1.1.0");
+    assert_eq!(body["release_notes"], "fixes");
+    assert_eq!(body["current"], "1.0.0");
+
+    // 재시작 후에도 저장된 기록으로 같은 버전은 다시 알리지 않음
+    manager.status.last_check = Some(crate::chrono_now_iso());
+    manager.schedule_next_check(std::time::Duration::from_secs(3600));
+    manager.save_check_schedule().unwrap();
+
+    let mut restarted = plan_test_manager(tmp.path());
+    restarted.staging_dir = tmp.path().join("staging");
+    let restarted_calls = Arc::new(AtomicUsize::new(0));
+    let counter = restarted_calls.clone();
+    restarted.set_on_update_available(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+    restarted.status.components = manager.status.components.clone();
+    restarted.announce_new_updates().await;
+    assert_eq!(restarted_calls.load(Ordering::SeqCst), 0);
+}
+
+// ═══════════════════════════════════════════════════════
+// 적용 진행률 콜백
+// ═══════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_apply_components_reports_phases() {
+    use crate::{ApplyPhase, ApplyProgress};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    let staged = tmp.path().join("locales.zip");
+    binary_test_zip(&staged, &[("ko.json", b"{}")]);
+    let mut comp = backup_test_component(Component::Locales, "1.0.1", true);
+    comp.downloaded_path = Some(staged.to_string_lossy().to_string());
+    manager.status.components.push(comp);
+
+    let events = std::sync::Mutex::new(Vec::<ApplyProgress>::new());
+    let on_progress = |p: ApplyProgress| events.lock().unwrap().push(p);
+    let result = manager.apply_components(&[], false, Some(&on_progress)).await.unwrap();
+    assert_eq!(result.results.len(), 1);
+    assert!(tmp.path().join("locales").join("ko.json").exists());
+
+    let events = events.into_inner().unwrap();
+    let phases: Vec<ApplyPhase> = events.iter().map(|p| p.phase.clone()).collect();
+    assert_eq!(phases, vec![
+        ApplyPhase::Preparing,
+        ApplyPhase::Extracting { component: "locales".into() },
+        ApplyPhase::WritingManifest,
+        ApplyPhase::Completed,
+    ]);
+    let percents: Vec<u8> = events.iter().map(ApplyProgress::percent).collect();
+    assert_eq!(percents, vec![0, 0, 100, 100]);
+}
+
+#[tokio::test]
+async fn test_apply_components_reports_failure() {
+    use crate::{ApplyPhase, ApplyProgress};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    let staged = tmp.path().join("locales.zip");
+    std::fs::write(&staged, b"not a zip").unwrap();
+    let mut comp = backup_test_component(Component::Locales, "1.0.1", true);
+    comp.downloaded_path = Some(staged.to_string_lossy().to_string());
+    manager.status.components.push(comp);
+
+    let events = std::sync::Mutex::new(Vec::<ApplyProgress>::new());
+    let on_progress = |p: ApplyProgress| events.lock().unwrap().push(p);
+    assert!(manager.apply_components(&[], false, Some(&on_progress)).await.is_err());
+
+    let last = events.into_inner().unwrap().pop().unwrap();
+    assert_eq!(last.phase, ApplyPhase::Failed);
+    assert_eq!(last.current_component.as_deref(), Some("locales"));
+}
+
+#[tokio::test]
+async fn test_apply_components_records_missing_staged_file_and_continues() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    // 다운로드 후 스테이징 파일이 정리된 모듈 + 정상 스테이징된 로케일
+    let mut cleaned = backup_test_component(Component::Module("old".into()), "2.0.0", true);
+    cleaned.downloaded_path = Some(tmp.path().join("cleaned.zip").to_string_lossy().to_string());
+    let staged = tmp.path().join("locales.zip");
+    binary_test_zip(&staged, &[("ko.json", b"{}")]);
+    let mut ok = backup_test_component(Component::Locales, "1.0.1", true);
+    ok.downloaded_path = Some(staged.to_string_lossy().to_string());
+    manager.status.components = vec![cleaned, ok];
+
+    let result = manager.apply_components(&[], false, None).await.unwrap();
+    let outcome: Vec<(&str, bool)> = result.results.iter().map(|r| (r.component.as_str(), r.success)).collect();
+    assert_eq!(outcome, vec![("module-old", false), ("locales", true)]);
+    assert!(result.results[0].message.contains("Staged file missing"), "{}", result.results[0].message);
+    assert!(tmp.path().join("locales").join("ko.json").exists());
+
+    // 실패한 모듈은 적용 처리되지 않고 다시 다운로드 대상이 됨
+    let old = manager.status.components.iter().find(|c| c.component == Component::Module("old".into())).unwrap();
+    assert!(old.update_available);
+    assert!(!old.downloaded);
+    assert!(old.downloaded_path.is_none());
+}
+
+#[tokio::test]
+async fn test_apply_modules_only_reports_failures_and_continues() {
+    use crate::ForegroundApplier;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    // 첫 모듈은 스테이징 파일이 없어 실패, 두 번째는 정상 적용
+    let mut broken = backup_test_component(Component::Module("old".into()), "2.0.0", true);
+    broken.downloaded_path = Some(tmp.path().join("missing.zip").to_string_lossy().to_string());
+    let staged = tmp.path().join("module-current.zip");
+    binary_test_zip(&staged, &[("module.toml", b"name = \"current\"\n")]);
+    let mut ok = backup_test_component(Component::Module("current".into()), "2.0.0", true);
+    ok.downloaded_path = Some(staged.to_string_lossy().to_string());
+    manager.status.components = vec![broken, ok];
+
+    let applier = ForegroundApplier::new(Arc::new(tokio::sync::RwLock::new(manager)));
+    let results = applier.apply_modules_only().await.unwrap();
+    let outcome: Vec<(&str, bool)> = results.iter().map(|r| (r.component.as_str(), r.success)).collect();
+    assert_eq!(outcome, vec![("module-old", false), ("module-current", true)]);
+    assert!(!results[0].message.is_empty());
+
+    let progress = applier.get_progress().await.unwrap();
+    assert_eq!(progress.done, 1);
+    assert!(progress.message.contains("1 실패"), "{}", progress.message);
+}
+
+#[tokio::test]
+async fn test_module_and_extension_apply_roll_back_invalid_manifest() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+    manager.extensions_dir = tmp.path().join("extensions");
+
+    let module_dir = manager.modules_dir.join("palworld");
+    std::fs::create_dir_all(&module_dir).unwrap();
+    std::fs::write(module_dir.join("module.toml"), "[module]\nname = \"palworld\"\nversion = \"1.0.0\"\n").unwrap();
+    std::fs::write(module_dir.join("lifecycle.py"), "# v1").unwrap();
+
+    // module.toml이 빠진 릴리즈, 이름이 다른 릴리즈 → 모두 거부하고 v1 유지
+    let cases: [&[(&str, &[u8])]; 2] = [
+        &[("lifecycle.py", b"# v2")],
+        &[("module.toml", b"name = \"minecraft\"\nversion = \"2.0.0\"\n"), ("lifecycle.py", b"# v2")],
+    ];
+    for files in cases {
+        let staged = tmp.path().join("palworld.zip");
+        binary_test_zip(&staged, files);
+        let err = manager.apply_module_update("palworld", &staged.to_string_lossy()).await.unwrap_err();
+        assert!(err.to_string().contains("module.toml"), "{}", err);
+        assert_eq!(std::fs::read_to_string(module_dir.join("lifecycle.py")).unwrap(), "# v1");
+        assert!(std::fs::read_to_string(module_dir.join("module.toml")).unwrap().contains("1.0.0"));
+    }
+
+    let staged = tmp.path().join("palworld.zip");
+    binary_test_zip(&staged, &[("module.toml", b"[module]\nname = \"palworld\"\nversion = \"2.0.0\"\n")]);
+    manager.apply_module_update("palworld", &staged.to_string_lossy()).await.unwrap();
+    assert!(std::fs::read_to_string(module_dir.join("module.toml")).unwrap().contains("2.0.0"));
+
+    // 익스텐션은 manifest.json의 id로도 확인 (하이픈/언더스코어 동일 취급)
+    let ext_dir = manager.resolve_ext_dir("music-bot");
+    std::fs::create_dir_all(&ext_dir).unwrap();
+    std::fs::write(ext_dir.join("manifest.json"), r#"{"id":"music-bot","version":"1.0.0"}"#).unwrap();
+    let staged = tmp.path().join("music-bot.zip");
+    binary_test_zip(&staged, &[("manifest.json", br#"{"id":"other","version":"2.0.0"}"#)]);
+    assert!(manager.apply_extension_update("music-bot", &staged.to_string_lossy()).await.is_err());
+    assert!(std::fs::read_to_string(ext_dir.join("manifest.json")).unwrap().contains("1.0.0"));
+
+    binary_test_zip(&staged, &[("manifest.json", br#"{"id":"music_bot","version":"2.0.0"}"#)]);
+    manager.apply_extension_update("music-bot", &staged.to_string_lossy()).await.unwrap();
+    assert!(std::fs::read_to_string(ext_dir.join("manifest.json")).unwrap().contains("2.0.0"));
+}
+
+#[tokio::test]
+async fn test_module_update_keeps_preserve_list() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+
+    let module_dir = manager.modules_dir.join("palworld");
+    std::fs::create_dir_all(module_dir.join("data")).unwrap();
+    std::fs::create_dir_all(module_dir.join("presets/custom")).unwrap();
+    std::fs::write(
+        module_dir.join("module.toml"),
+        "[module]\nname = \"palworld\"\nversion = \"1.0.0\"\n\n[update]\npreserve = [\"data/\", \"config.yml\", \"presets/custom\", \"../escape\"]\n",
+    ).unwrap();
+    std::fs::write(module_dir.join("data/world.dat"), "save").unwrap();
+    std::fs::write(module_dir.join("config.yml"), "port: 1234").unwrap();
+    std::fs::write(module_dir.join("presets/custom/mine.json"), "{}").unwrap();
+    std::fs::write(module_dir.join("presets/default.json"), "old").unwrap();
+    std::fs::write(module_dir.join("lifecycle.py"), "# v1").unwrap();
+    std::fs::write(module_dir.join("obsolete.py"), "# v1").unwrap();
+
+    let staged = tmp.path().join("palworld.zip");
+    binary_test_zip(&staged, &[
+        ("module.toml", b"[module]\nname = \"palworld\"\nversion = \"2.0.0\"\n"),
+        ("config.yml", b"port: 8211"),
+        ("lifecycle.py", b"# v2"),
+    ]);
+    manager.apply_module_update("palworld", &staged.to_string_lossy()).await.unwrap();
+
+    assert_eq!(std::fs::read_to_string(module_dir.join("data/world.dat")).unwrap(), "save");
+    assert_eq!(std::fs::read_to_string(module_dir.join("config.yml")).unwrap(), "port: 1234");
+    assert!(module_dir.join("presets/custom/mine.json").exists());
+    assert!(!module_dir.join("presets/default.json").exists());
+    assert!(!module_dir.join("obsolete.py").exists());
+    assert_eq!(std::fs::read_to_string(module_dir.join("lifecycle.py")).unwrap(), "# v2");
+    assert!(std::fs::read_to_string(module_dir.join("module.toml")).unwrap().contains("2.0.0"));
+
+    // 새 module.toml에는 preserve가 없으므로 다음 업데이트는 기본 동작으로 정리
+    binary_test_zip(&staged, &[
+        ("module.toml", b"[module]\nname = \"palworld\"\nversion = \"3.0.0\"\n"),
+        ("config.yml", b"port: 8211"),
+    ]);
+    manager.apply_module_update("palworld", &staged.to_string_lossy()).await.unwrap();
+    assert!(!module_dir.join("data").exists());
+    assert_eq!(std::fs::read_to_string(module_dir.join("config.yml")).unwrap(), "port: 8211");
+}
+
+#[tokio::test]
+async fn test_module_update_swaps_via_sibling_temp_dir() {
+    let tmp = tempfile::tempdir().unwrap();
+    let mut manager = plan_test_manager(tmp.path());
+    manager.staging_dir = tmp.path().join("staging");
+
+    let module_dir = manager.modules_dir.join("palworld");
+    let updating = manager.modules_dir.join(".palworld.updating");
+    let previous = manager.modules_dir.join(".palworld.previous");
+    std::fs::create_dir_all(&module_dir).unwrap();
+    std::fs::write(module_dir.join("module.toml"), "[module]\nname = \"palworld\"\nversion = \"1.0.0\"\n").unwrap();
+    std::fs::write(module_dir.join("lifecycle.py"), "# v1").unwrap();
+
+    // 깨진 zip → 압축 해제 단계에서 실패해도 기존 모듈은 손대지 않음
+    let staged = tmp.path().join("palworld.zip");
+    std::fs::write(&staged, b"not a zip").unwrap();
+    assert!(manager.apply_module_update("palworld", &staged.to_string_lossy()).await.is_err());
+    assert_eq!(std::fs::read_to_string(module_dir.join("lifecycle.py")).unwrap(), "# v1");
+    assert!(!updating.exists());
+
+    // 이전 실행이 남긴 임시 디렉터리는 정리되고 모듈 목록에도 보이지 않음
+    std::fs::create_dir_all(&updating).unwrap();
+    std::fs::write(updating.join("module.toml"), "[module]\nname = \"palworld\"\nversion = \"9.9.9\"\n").unwrap();
+    let palworld: Vec<_> = manager.scan_modules().into_iter().filter(|m| m.name == "palworld").collect();
+    assert_eq!(palworld.len(), 1);
+    assert_eq!(palworld[0].version.as_deref(), Some("1.0.0"));
+
+    binary_test_zip(&staged, &[("module.toml", b"[module]\nname = \"palworld\"\nversion = \"2.0.0\"\n")]);
+    manager.apply_module_update("palworld", &staged.to_string_lossy()).await.unwrap();
+    assert!(std::fs::read_to_string(module_dir.join("module.toml")).unwrap().contains("2.0.0"));
+    assert!(!module_dir.join("lifecycle.py").exists());
+    assert!(!updating.exists() && !previous.exists());
+
+    // 교체 도중 중단(기존 → .previous 이후) → 다음 스캔에서 되돌림
+    std::fs::rename(&module_dir, &previous).unwrap();
+    let palworld = manager.scan_modules().into_iter().find(|m| m.name == "palworld").unwrap();
+    assert_eq!(palworld.version.as_deref(), Some("2.0.0"));
+    assert!(module_dir.exists() && !previous.exists());
+}
+
+// ═══════════════════════════════════════════════════════
+// 파일시스템 간 이동 (EXDEV)
+// ═══════════════════════════════════════════════════════
+
+fn cross_device_error() -> std::io::Error {
+    std::io::Error::from(std::io::ErrorKind::CrossesDevices)
+}
+
+#[test]
+fn test_move_file_falls_back_to_copy_across_devices() {
+    let tmp = tempfile::tempdir().unwrap();
+    let from = tmp.path().join("saba-core");
+    let to = tmp.path().join("saba-core.old");
+    std::fs::write(&from, b"binary").unwrap();
+
+    UpdateManager::move_file_using(&from, &to, |_, _| Err(cross_device_error())).unwrap();
+    assert!(!from.exists());
+    assert_eq!(std::fs::read(&to).unwrap(), b"binary");
+}
+
+#[test]
+fn test_move_file_propagates_other_errors() {
+    let tmp = tempfile::tempdir().unwrap();
+    let from = tmp.path().join("saba-core");
+    let to = tmp.path().join("saba-core.old");
+    std::fs::write(&from, b"binary").unwrap();
+
+    let err = UpdateManager::move_file_using(&from, &to, |_, _| {
+        Err(std::io::Error::from(std::io::ErrorKind::PermissionDenied))
+    })
+    .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::PermissionDenied);
+    assert!(from.exists());
+    assert!(!to.exists());
+}
+
+#[test]
+fn test_rename_with_retry_keeps_retrying_before_cross_device_fallback() {
+    let tmp = tempfile::tempdir().unwrap();
+    let from = tmp.path().join("saba-chan-gui.exe");
+    let to = tmp.path().join("saba-chan-gui.exe.old");
+    std::fs::write(&from, b"gui").unwrap();
+    std::fs::write(&to, b"stale backup").unwrap();
+
+    // 첫 시도는 파일 잠금, 이후에는 다른 드라이브로 판정
+    let attempts = std::cell::Cell::new(0);
+    UpdateManager::rename_with_retry_using(&from, &to, 2, |_, _| {
+        attempts.set(attempts.get() + 1);
+        if attempts.get() == 1 {
+            Err(std::io::Error::from(std::io::ErrorKind::PermissionDenied))
+        } else {
+            Err(cross_device_error())
+        }
+    })
+    .unwrap();
+
+    assert_eq!(attempts.get(), 2);
+    assert!(!from.exists());
+    assert_eq!(std::fs::read(&to).unwrap(), b"gui");
+}
+
+#[cfg(test)]
+mod run_all {
+    use super::*;
+
+    /// 유닛 테스트만 실행 (mock 서버 불필요)
+    #[test]
+    fn run_unit_tests() {
+        test_error_recovery_strategy();
+        test_is_recoverable_exhaustive();
+        test_retry_delay_exponential_backoff_with_cap();
+        test_retry_delay_timeout_base();
+        test_retry_delay_rate_limit_429();
+        test_retry_delay_api_5xx();
+        test_user_message_content();
+        test_display_all_variants();
+        test_from_io();
+        test_error_context_builder();
+        test_error_context_without_component();
+        test_recovery_strategy_zero_retries();
+        test_recovery_strategy_no_backoff();
+        test_recovery_strategy_full_lifecycle();
+        test_error_serde_roundtrip();
+        test_download_interrupted_progress_data();
+        test_component_manifest_key();
+        test_dependency_check_satisfied();
+        test_dependency_check_unsatisfied();
+        test_dependency_issue_not_installed();
+        test_dependency_check_serde_roundtrip();
+        test_component_info_requires_field();
+        test_component_info_requires_empty_by_default();
+        test_module_extension_no_restart_in_apply_result();
+        test_component_apply_priority_categories();
+        println!("\n═══════════════════════════════════════");
+        println!("✓ 모든 유닛 테스트 통과!");
+        println!("═══════════════════════════════════════\n");
+    }
+}
+
+#[test]
+fn test_relaunch_command_sets_working_dir_and_keeps_environment() {
+    use crate::{relaunch_command, RelaunchOptions};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let exe = tmp.path().join("bin").join("saba-chan-gui");
+    let args = vec!["--after-update".to_string()];
+
+    // 기본값: 실행 파일이 있는 디렉터리(install_root)에서 실행
+    let cmd = relaunch_command(&exe, &args, &RelaunchOptions::default());
+    assert_eq!(cmd.get_current_dir(), Some(tmp.path().join("bin").as_path()));
+    assert_eq!(cmd.get_args().collect::<Vec<_>>(), vec![std::ffi::OsStr::new("--after-update")]);
+    let envs: Vec<_> = cmd.get_envs().map(|(k, _)| k.to_os_string()).collect();
+    for (key, _) in std::env::vars_os() {
+        assert!(envs.contains(&key), "{:?} not passed through", key);
+    }
+
+    let options = RelaunchOptions { delay: std::time::Duration::from_millis(5000), working_dir: Some(tmp.path().to_path_buf()) };
+    let cmd = relaunch_command(&exe, &[], &options);
+    assert_eq!(cmd.get_current_dir(), Some(tmp.path()));
+    assert_eq!(RelaunchOptions::default().delay, RelaunchOptions::DEFAULT_DELAY);
+}
+
+#[test]
+fn test_relaunch_args_does_not_duplicate_after_update() {
+    use crate::relaunch_args;
+
+    let s = |v: &[&str]| v.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+    assert_eq!(relaunch_args(&s(&["--profile", "dev"]), true), s(&["--profile", "dev", "--after-update"]));
+    assert_eq!(relaunch_args(&s(&["--after-update", "--profile", "dev"]), true), s(&["--after-update", "--profile", "dev"]));
+    assert_eq!(relaunch_args(&s(&["--after-update", "--after-update"]), true), s(&["--after-update"]));
+    assert_eq!(relaunch_args(&s(&["--profile"]), false), s(&["--profile"]));
+    assert!(relaunch_args(&[], false).is_empty());
+}
+
+#[test]
+fn test_parse_apply_args_defaults_without_components() {
+    use crate::{parse_apply_args, RelaunchOptions};
+
+    let s = |v: &[&str]| v.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+    assert!(parse_apply_args(&s(&["updater", "--manifest", "m.json"])).is_err());
+
+    let config = parse_apply_args(&s(&["updater", "--apply"])).unwrap();
+    assert_eq!(config.install_root, None);
+    assert_eq!(config.manifest_path, None);
+    assert_eq!(config.relaunch_delay, RelaunchOptions::DEFAULT_DELAY);
+    assert_eq!(config.relaunch_exe, None);
+    assert!(config.relaunch_extra.is_empty());
+    assert!(config.components.is_empty());
+}
+
+#[test]
+fn test_parse_apply_args_install_root_before_or_after_relaunch() {
+    use crate::parse_apply_args;
+
+    let s = |v: &[&str]| v.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+    // saba-chan-cli의 launch-apply 형식: --install-root가 먼저, 뒤에 컴포넌트 키
+    let before = parse_apply_args(&s(&[
+        "updater", "--apply", "--install-root", "/opt/saba", "gui", "module-minecraft", "--relaunch", "gui.exe", "--dev",
+    ])).unwrap();
+    let after = parse_apply_args(&s(&[
+        "updater", "--apply", "gui", "module-minecraft", "--relaunch", "gui.exe", "--dev", "--install-root", "/opt/saba",
+    ])).unwrap();
+    assert_eq!(before, after);
+    assert_eq!(before.install_root, Some(std::path::PathBuf::from("/opt/saba")));
+    assert_eq!(before.components, s(&["gui", "module-minecraft"]));
+    assert_eq!(before.relaunch_exe.as_deref(), Some("gui.exe"));
+    assert_eq!(before.relaunch_extra, s(&["--dev"]));
+
+    let config = parse_apply_args(&s(&[
+        "updater", "--apply", "--relaunch", "gui.exe", "/src/gui", "--relaunch-delay", "5000", "--manifest", "bundle/pending.json", "--after-update",
+    ])).unwrap();
+    assert_eq!(config.relaunch_extra, s(&["/src/gui", "--after-update"]));
+    assert_eq!(config.relaunch_delay, std::time::Duration::from_millis(5000));
+    assert_eq!(config.manifest_path, Some(std::path::PathBuf::from("bundle/pending.json")));
+    assert!(config.components.is_empty());
+}
+
+#[test]
+fn test_parse_apply_args_rejects_missing_or_invalid_values() {
+    use crate::parse_apply_args;
+
+    let s = |v: &[&str]| v.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+    let cases: [&[&str]; 9] = [
+        &["updater", "--apply", "--install-root"],
+        &["updater", "--apply", "--install-root", "--relaunch", "gui.exe"],
+        &["updater", "--apply", "--manifest"],
+        &["updater", "--apply", "--relaunch-delay", "soon"],
+        &["updater", "--apply", "--relaunch"],
+        &["updater", "--apply", "--relaunch", "--install-root", "/opt/saba"],
+        &["updater", "--apply", "--install-root", "/a", "--relaunch", "gui.exe", "--install-root", "/b"],
+        &["updater", "--apply", "--relaunch", "gui.exe", "--relaunch", "cli.exe"],
+        &["updater", "--apply", "--unknown"],
+    ];
+    for case in cases {
+        assert!(parse_apply_args(&s(case)).is_err(), "{:?} should be rejected", case);
+    }
+}
+
+#[test]
+fn test_parse_apply_args_keeps_paths_with_spaces() {
+    use crate::parse_apply_args;
+
+    let s = |v: &[&str]| v.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+    let config = parse_apply_args(&s(&[
+        "updater", "--apply",
+        "--install-root", "C:\\Program Files\\Saba Chan",
+        "--manifest", "\"D:\\build out\\pending.json\"",
+        "--relaunch", "\"C:\\Program Files\\Saba Chan\\saba-chan-gui.exe\"", "--profile", "my profile",
+    ])).unwrap();
+    assert_eq!(config.install_root, Some(std::path::PathBuf::from("C:\\Program Files\\Saba Chan")));
+    assert_eq!(config.manifest_path, Some(std::path::PathBuf::from("D:\\build out\\pending.json")));
+    assert_eq!(config.relaunch_exe.as_deref(), Some("C:\\Program Files\\Saba Chan\\saba-chan-gui.exe"));
+    assert_eq!(config.relaunch_extra, s(&["--profile", "my profile"]));
+}
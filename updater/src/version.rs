@@ -43,6 +43,14 @@ impl SemVer {
     pub fn is_prerelease(&self) -> bool {
         self.prerelease.is_some()
     }
+
+    /// 최소 버전 요구사항(`">=1.2.0"` 또는 `"1.2.0"`)을 충족하는지 확인
+    ///
+    /// 요구사항을 해석할 수 없으면 충족하지 않는 것으로 봅니다.
+    pub fn satisfies(&self, requirement: &str) -> bool {
+        let min = requirement.trim().trim_start_matches(">=").trim();
+        SemVer::parse(min).is_some_and(|required| *self >= required)
+    }
 }
 
 impl fmt::Display for SemVer {
@@ -122,6 +130,17 @@ mod tests {
         assert!(v3.is_newer_than(&v2));
     }
 
+    #[test]
+    fn satisfies_minimum_requirement() {
+        let v = SemVer::parse("1.4.2").unwrap();
+        assert!(v.satisfies(">=1.4.0"));
+        assert!(v.satisfies("1.4.2"));
+        assert!(v.satisfies(" >= 1.4 "));
+        assert!(!v.satisfies(">=1.5.0"));
+        assert!(!SemVer::parse("1.5.0-rc.1").unwrap().satisfies(">=1.5.0"));
+        assert!(!v.satisfies("latest"));
+    }
+
     #[test]
     fn prerelease_less_than_release() {
         let pre = SemVer::parse("1.0.0-beta.1").unwrap();
@@ -5,11 +5,13 @@
 //! - GUI/CLI는 이벤트 구독을 통해 상태 변화를 수신
 //! - 포그라운드 작업(적용)은 명시적 요청 시에만 실행
 
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{mpsc, RwLock, broadcast};
 use std::time::Duration;
 
-use crate::{UpdateManager, Component, ComponentVersion};
+use crate::{scheduler, UpdateManager, UpdaterError, Component, ComponentVersion};
 
 /// 백그라운드 작업 타입
 #[derive(Debug, Clone)]
@@ -25,7 +27,11 @@ pub enum BackgroundTask {
 }
 
 /// 워커에서 발생하는 이벤트 (GUI/CLI에 브로드캐스트)
-#[derive(Debug, Clone)]
+///
+/// `{"event": "download_progress", ...}` 형태로 직렬화되어
+/// Tauri `worker:event` 같은 이벤트 채널에 그대로 실어 보낼 수 있다.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
 pub enum WorkerEvent {
     /// 버전 체크 시작
     CheckStarted,
@@ -35,7 +41,12 @@ pub enum WorkerEvent {
         components: Vec<ComponentVersion>,
     },
     /// 버전 체크 실패
-    CheckFailed { error: String },
+    CheckFailed {
+        error: String,
+        /// rate limit으로 실패했으면 다시 시도하기까지 기다릴 초
+        #[serde(skip_serializing_if = "Option::is_none")]
+        retry_after_secs: Option<u64>,
+    },
     /// 다운로드 시작
     DownloadStarted { component: String },
     /// 다운로드 진행률
@@ -50,6 +61,8 @@ pub enum WorkerEvent {
     DownloadFailed { component: String, error: String },
     /// 모든 다운로드 완료
     AllDownloadsCompleted { count: usize },
+    /// 연속 실패로 자동 다운로드에서 제외됨 (`until`: 격리 해제 시각, ISO 8601)
+    Quarantined { component: String, until: String },
     /// 업데이트 알림 (GUI에 표시용)
     UpdateNotification {
         title: String,
@@ -60,8 +73,8 @@ pub enum WorkerEvent {
     WorkerShutdown,
 }
 
-/// 백그라운드 워커 상태
-#[derive(Debug, Clone, Default)]
+/// 백그라운드 워커 상태 (GUI에 그대로 직렬화 가능)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct WorkerStatus {
     /// 현재 작업 중인지
     pub busy: bool,
@@ -73,6 +86,72 @@ pub struct WorkerStatus {
     pub next_check: Option<String>,
     /// 대기 중인 태스크 수
     pub pending_tasks: usize,
+    /// 컴포넌트별 연속 다운로드 실패 횟수 (manifest 키 기준, 성공 시 제거)
+    #[serde(default)]
+    pub consecutive_failures: HashMap<String, u32>,
+    /// 자동 다운로드에서 제외된 컴포넌트 (manifest 키 기준)
+    #[serde(default)]
+    pub quarantined: HashMap<String, Quarantine>,
+}
+
+/// 연속 실패 횟수가 이 값에 도달하면 자동 다운로드에서 격리
+pub const QUARANTINE_THRESHOLD: u32 = 5;
+/// 격리 기간
+pub const QUARANTINE_BACKOFF: Duration = Duration::from_secs(24 * 60 * 60);
+/// 다운로드 진행률 이벤트 최소 간격 (바이트 수가 바뀐 경우에만 전송)
+const PROGRESS_EVENT_INTERVAL: Duration = Duration::from_millis(250);
+
+/// 자동 다운로드 격리 정보
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Quarantine {
+    /// 격리 해제 시각 (ISO 8601)
+    pub until: String,
+    /// 격리 해제 시각 (UNIX 초) — 만료 판단용
+    pub until_unix: u64,
+    /// 격리를 유발한 마지막 에러
+    pub last_error: String,
+}
+
+impl WorkerStatus {
+    /// 자동 다운로드에서 제외해야 하는지 — 기간이 지난 격리는 해제
+    ///
+    /// 해제 후에도 실패 횟수는 유지되므로, 다시 한 번 실패하면 곧바로 재격리된다.
+    pub(crate) fn is_quarantined(&mut self, key: &str, now_unix: u64) -> bool {
+        match self.quarantined.get(key) {
+            Some(q) if q.until_unix > now_unix => true,
+            Some(_) => {
+                self.quarantined.remove(key);
+                tracing::info!("[Worker] Quarantine expired for {}", key);
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// 다운로드 실패 기록 — 임계치에 도달하면 격리하고 격리 정보를 반환
+    pub(crate) fn record_failure(&mut self, key: &str, error: &str, now_unix: u64) -> Option<Quarantine> {
+        let count = self.consecutive_failures.entry(key.to_string()).or_insert(0);
+        *count += 1;
+        if *count < QUARANTINE_THRESHOLD {
+            return None;
+        }
+        let until_unix = now_unix + QUARANTINE_BACKOFF.as_secs();
+        let quarantine = Quarantine {
+            until: crate::format_unix_timestamp(until_unix),
+            until_unix,
+            last_error: error.to_string(),
+        };
+        self.quarantined.insert(key.to_string(), quarantine.clone());
+        Some(quarantine)
+    }
+
+    /// 다운로드 성공 또는 수동 다운로드 요청 — 실패 기록과 격리 해제
+    pub(crate) fn clear_failures(&mut self, key: &str) {
+        self.consecutive_failures.remove(key);
+        if self.quarantined.remove(key).is_some() {
+            tracing::info!("[Worker] Quarantine cleared for {}", key);
+        }
+    }
 }
 
 /// 백그라운드 워커
@@ -138,7 +217,16 @@ impl BackgroundWorker {
 
     /// 현재 상태 조회
     pub async fn get_status(&self) -> WorkerStatus {
-        self.status.read().await.clone()
+        let mut status = self.status.read().await.clone();
+        status.pending_tasks = self.task_tx.max_capacity() - self.task_tx.capacity();
+        status
+    }
+
+    /// 워커가 갱신하는 상태 핸들 — 호스트(Tauri 등)가 직접 관리 상태로 공유할 때 사용
+    ///
+    /// 워커가 이벤트를 보낼 때마다 `busy`/`current_task`가 이 핸들에 반영된다.
+    pub fn status_handle(&self) -> Arc<RwLock<WorkerStatus>> {
+        self.status.clone()
     }
 
     /// 워커 종료
@@ -165,6 +253,22 @@ async fn worker_loop(
                         let _ = event_tx.send(WorkerEvent::WorkerShutdown);
                         break;
                     }
+                    // 적용 중에는 상태·스테이징·설치 루트를 건드리는 작업을 시작하지 않음
+                    BackgroundTask::CheckVersion { .. } if apply_in_progress(&manager).await => {
+                        let _ = event_tx.send(WorkerEvent::CheckFailed {
+                            error: UpdaterError::ApplyInProgress.to_string(),
+                            retry_after_secs: None,
+                        });
+                    }
+                    BackgroundTask::DownloadComponent { component } if apply_in_progress(&manager).await => {
+                        let _ = event_tx.send(WorkerEvent::DownloadFailed {
+                            component: component.display_name(),
+                            error: UpdaterError::ApplyInProgress.to_string(),
+                        });
+                    }
+                    BackgroundTask::DownloadAll if apply_in_progress(&manager).await => {
+                        let _ = event_tx.send(WorkerEvent::AllDownloadsCompleted { count: 0 });
+                    }
                     BackgroundTask::CheckVersion { manual } => {
                         handle_check_version(&manager, &event_tx, &status, manual).await;
                     }
@@ -182,6 +286,15 @@ async fn worker_loop(
     tracing::info!("[Worker] Background worker stopped");
 }
 
+/// 적용 lease가 잡혀 있는지 — 잡혀 있으면 워커 작업을 거절
+async fn apply_in_progress(manager: &Arc<RwLock<UpdateManager>>) -> bool {
+    let applying = manager.read().await.is_applying();
+    if applying {
+        tracing::info!("[Worker] Apply in progress — refusing background task");
+    }
+    applying
+}
+
 /// 버전 체크 처리
 async fn handle_check_version(
     manager: &Arc<RwLock<UpdateManager>>,
@@ -227,7 +340,7 @@ async fn handle_check_version(
             // 이벤트에는 Locales를 제외한 컴포넌트만 전달
             let visible_components: Vec<ComponentVersion> = {
                 let mgr = manager.read().await;
-                mgr.get_status().components.iter()
+                mgr.status().components.iter()
                     .filter(|c| !matches!(c.component, Component::Locales))
                     .cloned()
                     .collect()
@@ -257,7 +370,11 @@ async fn handle_check_version(
         }
         Err(e) => {
             let error = format!("{}", e);
-            let _ = event_tx.send(WorkerEvent::CheckFailed { error: error.clone() });
+            let retry_after_secs = match e {
+                UpdaterError::RateLimited { retry_after_secs } => Some(retry_after_secs),
+                _ => None,
+            };
+            let _ = event_tx.send(WorkerEvent::CheckFailed { error: error.clone(), retry_after_secs });
             tracing::error!("[Worker] Check failed: {}", error);
         }
     }
@@ -273,10 +390,10 @@ async fn handle_check_version(
 ///
 /// 실패 시에도 에러만 로깅하고 `false`를 반환 — 사용자 흐름에 영향 없음.
 async fn silent_apply_locales(manager: &Arc<RwLock<UpdateManager>>) -> bool {
-    // 1. Locales 업데이트가 있는지 확인
+    // 1. Locales 업데이트가 있는지 확인 (체크 사이에 적용이 시작됐으면 건너뜀)
     let has_locale_update = {
         let mgr = manager.read().await;
-        mgr.get_status().components.iter().any(|c| {
+        !mgr.is_applying() && mgr.status().components.iter().any(|c| {
             matches!(c.component, Component::Locales) && c.update_available
         })
     };
@@ -318,7 +435,7 @@ async fn silent_apply_locales(manager: &Arc<RwLock<UpdateManager>>) -> bool {
     }
 }
 
-/// 단일 컴포넌트 다운로드 처리
+/// 단일 컴포넌트 다운로드 처리 (수동 요청 — 격리 해제 후 시도)
 async fn handle_download_component(
     manager: &Arc<RwLock<UpdateManager>>,
     event_tx: &broadcast::Sender<WorkerEvent>,
@@ -331,24 +448,74 @@ async fn handle_download_component(
         let mut s = status.write().await;
         s.busy = true;
         s.current_task = Some(format!("Downloading {}...", comp_name));
+        s.clear_failures(&component.manifest_key());
+    }
+
+    download_one(manager, event_tx, status, component).await;
+
+    {
+        let mut s = status.write().await;
+        s.busy = false;
+        s.current_task = None;
     }
+}
+
+/// 컴포넌트 하나를 다운로드하고 연속 실패/격리 상태를 갱신 — 성공 여부 반환
+async fn download_one(
+    manager: &Arc<RwLock<UpdateManager>>,
+    event_tx: &broadcast::Sender<WorkerEvent>,
+    status: &Arc<RwLock<WorkerStatus>>,
+    component: &Component,
+) -> bool {
+    let comp_name = component.display_name();
+    let key = component.manifest_key();
 
     let _ = event_tx.send(WorkerEvent::DownloadStarted {
         component: comp_name.clone(),
     });
     tracing::info!("[Worker] Starting download: {}", comp_name);
 
-    let result = {
+    // 다운로드 중에는 Manager가 잠기므로 진행률 공유 핸들을 먼저 확보해 두고 주기적으로 전달
+    let progress = manager.read().await.download_progress.clone();
+    let download = async {
         let mut mgr = manager.write().await;
-        mgr.download_component(component).await
+        mgr.download_component_background(component).await
+    };
+    tokio::pin!(download);
+    let mut ticker = tokio::time::interval(PROGRESS_EVENT_INTERVAL);
+    let mut last_sent = None;
+    let result = loop {
+        tokio::select! {
+            result = &mut download => break result,
+            _ = ticker.tick() => {
+                let (downloaded, total) = {
+                    let p = progress.lock().unwrap_or_else(|e| e.into_inner());
+                    if !p.active || p.component.as_deref() != Some(key.as_str()) {
+                        continue;
+                    }
+                    (p.bytes_received, p.total_bytes)
+                };
+                if last_sent == Some(downloaded) {
+                    continue;
+                }
+                last_sent = Some(downloaded);
+                let _ = event_tx.send(WorkerEvent::DownloadProgress {
+                    component: comp_name.clone(),
+                    downloaded,
+                    total,
+                });
+            }
+        }
     };
 
     match result {
         Ok(_) => {
+            status.write().await.clear_failures(&key);
             let _ = event_tx.send(WorkerEvent::DownloadCompleted {
                 component: comp_name.clone(),
             });
             tracing::info!("[Worker] Download completed: {}", comp_name);
+            true
         }
         Err(e) => {
             let error = format!("{}", e);
@@ -357,17 +524,27 @@ async fn handle_download_component(
                 error: error.clone(),
             });
             tracing::error!("[Worker] Download failed for {}: {}", comp_name, error);
-        }
-    }
 
-    {
-        let mut s = status.write().await;
-        s.busy = false;
-        s.current_task = None;
+            let quarantine = status.write().await.record_failure(&key, &error, crate::unix_now_secs());
+            if let Some(q) = quarantine {
+                tracing::warn!(
+                    "[Worker] {} failed {} times in a row — skipping auto-download until {}",
+                    comp_name, QUARANTINE_THRESHOLD, q.until
+                );
+                let _ = event_tx.send(WorkerEvent::Quarantined {
+                    component: comp_name,
+                    until: q.until,
+                });
+            }
+            false
+        }
     }
 }
 
 /// 모든 업데이트 다운로드 처리
+///
+/// 컴포넌트별로 다운로드하므로 한 컴포넌트가 실패해도 나머지는 계속 받으며,
+/// 격리된 컴포넌트는 건너뛴다.
 async fn handle_download_all(
     manager: &Arc<RwLock<UpdateManager>>,
     event_tx: &broadcast::Sender<WorkerEvent>,
@@ -381,32 +558,35 @@ async fn handle_download_all(
 
     tracing::info!("[Worker] Starting download all");
 
-    let result = {
-        let mut mgr = manager.write().await;
-        mgr.download_available_updates().await
+    let pending: Vec<Component> = {
+        let mgr = manager.read().await;
+        // 다운로드 소스를 찾지 못한 컴포넌트는 실패로 세지 않고 제외
+        mgr.status().components.iter()
+            .filter(|c| c.update_available && !c.downloaded)
+            .filter(|c| mgr.resolved_components.contains_key(&c.component.manifest_key()))
+            .map(|c| c.component.clone())
+            .collect()
     };
 
-    match result {
-        Ok(downloaded) => {
-            let count = downloaded.len();
-            for name in &downloaded {
-                let _ = event_tx.send(WorkerEvent::DownloadCompleted {
-                    component: name.clone(),
-                });
-            }
-            let _ = event_tx.send(WorkerEvent::AllDownloadsCompleted { count });
-            tracing::info!("[Worker] All downloads completed: {} component(s)", count);
+    let mut count = 0;
+    for component in &pending {
+        // 컴포넌트 사이에 적용이 시작되면 나머지는 받지 않음
+        if apply_in_progress(manager).await {
+            break;
         }
-        Err(e) => {
-            let error = format!("{}", e);
-            let _ = event_tx.send(WorkerEvent::DownloadFailed {
-                component: "all".to_string(),
-                error: error.clone(),
-            });
-            tracing::error!("[Worker] Download all failed: {}", error);
+        let now = crate::unix_now_secs();
+        if status.write().await.is_quarantined(&component.manifest_key(), now) {
+            tracing::debug!("[Worker] Skipping quarantined component: {}", component.display_name());
+            continue;
+        }
+        if download_one(manager, event_tx, status, component).await {
+            count += 1;
         }
     }
 
+    let _ = event_tx.send(WorkerEvent::AllDownloadsCompleted { count });
+    tracing::info!("[Worker] All downloads completed: {}/{} component(s)", count, pending.len());
+
     {
         let mut s = status.write().await;
         s.busy = false;
@@ -415,17 +595,27 @@ async fn handle_download_all(
 }
 
 /// 자동 체크 스케줄러 — 설정된 간격으로 백그라운드 체크 실행
+///
+/// 여러 설치가 같은 시각에 GitHub을 호출하지 않도록 시작 시 0~5분 무작위 지연 후 첫 체크를 하고,
+/// 이후 간격에는 ±10% 지터를 적용한다. 예약된 시각은 `UpdateStatus.next_check`에 그대로 기록된다.
 pub struct AutoCheckScheduler {
     worker: Arc<BackgroundWorker>,
+    manager: Arc<RwLock<UpdateManager>>,
     interval_hours: u32,
     enabled: bool,
     handle: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl AutoCheckScheduler {
-    pub fn new(worker: Arc<BackgroundWorker>, interval_hours: u32, enabled: bool) -> Self {
+    pub fn new(
+        worker: Arc<BackgroundWorker>,
+        manager: Arc<RwLock<UpdateManager>>,
+        interval_hours: u32,
+        enabled: bool,
+    ) -> Self {
         Self {
             worker,
+            manager,
             interval_hours,
             enabled,
             handle: None,
@@ -440,15 +630,56 @@ impl AutoCheckScheduler {
         }
 
         let worker = self.worker.clone();
+        let manager = self.manager.clone();
         let interval = Duration::from_secs(self.interval_hours as u64 * 3600);
 
         let handle = tokio::spawn(async move {
+            // 최근 체크 기록이 있으면 예약돼 있던 시각까지 대기 (간격이 줄었으면 새 간격 이내로)
+            let mut delay = {
+                let mut mgr = manager.write().await;
+                let delay = match mgr.restore_check_schedule() {
+                    Some(remaining) if !remaining.is_zero() => remaining.min(interval),
+                    _ => scheduler::startup_delay(),
+                };
+                mgr.schedule_next_check(delay);
+                delay
+            };
+            tracing::info!("[Scheduler] First auto-check in {}s", delay.as_secs());
+
             loop {
-                tokio::time::sleep(interval).await;
+                tokio::time::sleep(delay).await;
                 tracing::info!("[Scheduler] Auto-check triggered");
-                if let Err(e) = worker.submit(BackgroundTask::CheckVersion { manual: false }).await {
-                    tracing::error!("[Scheduler] Failed to submit auto-check: {}", e);
-                }
+                let mut events = worker.subscribe();
+                let outcome = match worker.submit(BackgroundTask::CheckVersion { manual: false }).await {
+                    Ok(()) => match wait_for_check_result(&mut events).await {
+                        Some(outcome) => outcome,
+                        None => return,
+                    },
+                    Err(e) => {
+                        tracing::error!("[Scheduler] Failed to submit auto-check: {}", e);
+                        CheckOutcome::Failed
+                    }
+                };
+
+                // 체크가 성공하면 매니저가 지터를 적용해 예약한 시각을 그대로 따름
+                // rate limit이면 간격과 관계없이 한도가 풀리는 시각까지만 대기
+                let mut mgr = manager.write().await;
+                delay = match (outcome, mgr.next_check_delay()) {
+                    (CheckOutcome::RateLimited(wait), _) => {
+                        mgr.schedule_next_check(wait);
+                        tracing::warn!(
+                            "[Scheduler] GitHub rate limit — next auto-check at {}",
+                            mgr.status().next_check.clone().unwrap_or_default()
+                        );
+                        wait
+                    }
+                    (CheckOutcome::Completed, Some(next)) => next,
+                    _ => {
+                        let next = scheduler::jittered_interval(interval);
+                        mgr.schedule_next_check(next);
+                        next
+                    }
+                };
             }
         });
 
@@ -472,3 +703,27 @@ impl AutoCheckScheduler {
         self.start();
     }
 }
+
+/// 자동 체크 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckOutcome {
+    Completed,
+    Failed,
+    /// GitHub rate limit — 주어진 시간 후에 다시 시도
+    RateLimited(Duration),
+}
+
+/// 제출한 체크의 결과 이벤트 대기 (워커 종료 시 `None`)
+async fn wait_for_check_result(events: &mut broadcast::Receiver<WorkerEvent>) -> Option<CheckOutcome> {
+    loop {
+        match events.recv().await {
+            Ok(WorkerEvent::CheckCompleted { .. }) => return Some(CheckOutcome::Completed),
+            Ok(WorkerEvent::CheckFailed { retry_after_secs: Some(secs), .. }) => {
+                return Some(CheckOutcome::RateLimited(Duration::from_secs(secs)));
+            }
+            Ok(WorkerEvent::CheckFailed { .. }) => return Some(CheckOutcome::Failed),
+            Ok(WorkerEvent::WorkerShutdown) | Err(broadcast::error::RecvError::Closed) => return None,
+            Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+        }
+    }
+}